@@ -0,0 +1,121 @@
+#!/usr/bin/env python3
+"""Generates ProgramData.lnk, the synthetic link used by the tests in src/lib.rs.
+
+The file is not captured from a Windows machine: it is written by this script following
+[MS-SHLLINK], so it only checks the parser against the same reading of the specification. It models
+a shortcut to C:\\ProgramData as created by Explorer on Windows 10: an IDList of My Computer, C:
+and a file entry with a version 9 0xBEEF0004 extension block, a LinkInfo with a fixed volume and
+relative path and working directory strings, a TrackerDataBlock whose object ID is a version 1
+UUID from the creation time, a PropertyStoreDataBlock with the SID of the owner and the display
+name and modification time of the folder, and a SpecialFolderDataBlock and a
+KnownFolderDataBlock for CSIDL_COMMON_APPDATA / FOLDERID_ProgramData. Made-up values: the volume
+serial number, the MFT reference, the machine ID, the volume GUID, the MAC address and the SID.
+
+Run it from any directory to regenerate the fixture: python3 assets/make_program_data_lnk.py
+"""
+
+import datetime
+import os
+import struct
+import uuid
+
+def guid(s):
+    return uuid.UUID(s).bytes_le
+
+def filetime(dt):
+    epoch = datetime.datetime(1601,1,1)
+    d = dt - epoch
+    return (d.days*86400 + d.seconds)*10**7 + d.microseconds*10
+
+def fat(dt):
+    date = ((dt.year-1980)<<9) | (dt.month<<5) | dt.day
+    time = (dt.hour<<11) | (dt.minute<<5) | (dt.second//2)
+    return struct.pack('<HH', date, time)
+
+created = datetime.datetime(2019,3,19,6,54,12,612345)
+accessed = datetime.datetime(2019,12,7,9,14,52,303120)
+written = datetime.datetime(2019,12,7,9,14,52,303120)
+
+flags = 0x1|0x2|0x8|0x10|0x80|0x80000
+attrs = 0x2|0x10|0x2000
+hdr = struct.pack('<I', 0x4C) + guid('00021401-0000-0000-C000-000000000046')
+hdr += struct.pack('<II', flags, attrs)
+hdr += struct.pack('<QQQ', filetime(created), filetime(accessed), filetime(written))
+hdr += struct.pack('<IiI', 0, 0, 1) + b'\0\0' + b'\0'*10
+assert len(hdr) == 76
+
+# IDList
+root = b'\x1f\x50' + guid('20D04FE0-3AEA-1069-A2D8-08002B30309D')
+root = struct.pack('<H', len(root)+2) + root
+vol = b'\x2fC:\\'
+vol = vol + b'\0'*(23-len(vol))
+vol = struct.pack('<H', len(vol)+2) + vol
+name = b'PROGRA~3\0'
+body = b'\x31\x00' + struct.pack('<I', 0) + fat(written) + struct.pack('<H', 0x2012) + name
+if (len(body)+2) % 2: body += b'\0'
+ext_off = len(body)+2
+long_name = 'ProgramData\0'.encode('utf-16le')
+ext = struct.pack('<HI', 9, 0xBEEF0004) + fat(created) + fat(accessed) + struct.pack('<H', 0x2E)
+ext += struct.pack('<H', 0) + struct.pack('<Q', 0x1F9E | (2 << 48)) + struct.pack('<Q', 0)
+ext += struct.pack('<H', 0) + struct.pack('<I', 0) + struct.pack('<I', 0)
+ext += long_name + struct.pack('<H', ext_off)
+ext = struct.pack('<H', len(ext)+2) + ext
+body += ext
+fe = struct.pack('<H', len(body)+2) + body
+idlist = root + vol + fe + b'\0\0'
+idl = struct.pack('<H', len(idlist)) + idlist
+
+# LinkInfo
+volid = struct.pack('<IIII', 0x11, 3, 0xC4A2E67B, 0x10) + b'\0'
+lbp = b'C:\\ProgramData\0'
+cps = b'\0'
+hs = 0x1C
+off_vol = hs
+off_lbp = off_vol + len(volid)
+off_cps = off_lbp + len(lbp)
+total = off_cps + len(cps)
+li = struct.pack('<IIIIIII', total, hs, 1, off_vol, off_lbp, 0, off_cps) + volid + lbp + cps
+assert len(li) == total
+
+def sd(s):
+    return struct.pack('<H', len(s)) + s.encode('utf-16le')
+strings = sd('..\\..\\..\\..\\..\\ProgramData') + sd('C:\\ProgramData')
+
+# Extra data
+blocks = []
+
+mac = bytes([0x08,0x00,0x27,0x5a,0x1c,0x3e])
+t = filetime(created) + 5748192000000000  # UUID epoch (1582-10-15)
+obj = uuid.UUID(fields=(t & 0xFFFFFFFF, (t>>32) & 0xFFFF, ((t>>48) & 0x0FFF) | 0x1000, 0x80 | 0x12, 0x34, int.from_bytes(mac,'big')))
+volg = guid('94C3D7A1-6B0E-4F2D-9E2A-5C7B1D3E8F10')
+tracker = struct.pack('<IIII', 0x60, 0xA0000003, 0x58, 0) + b'desktop-7o2l8ab\0' + volg + obj.bytes_le + volg + obj.bytes_le
+assert len(tracker) == 0x60
+blocks.append(tracker)
+
+def lpwstr(s):
+    raw = (s+'\0').encode('utf-16le')
+    v = struct.pack('<HHI', 0x1F, 0, len(s)+1) + raw
+    while len(v) % 4: v += b'\0'
+    return v
+def prop(pid, typed):
+    v = struct.pack('<IB', pid, 0) + typed
+    return struct.pack('<I', len(v)+4) + v
+def storage(fmtid, props):
+    body = struct.pack('<I', 0x53505331) + guid(fmtid) + b''.join(props) + struct.pack('<I', 0)
+    return struct.pack('<I', len(body)+4) + body
+ps = storage('46588AE2-4CBC-4338-BBFC-139326986DCE', [prop(4, lpwstr('S-1-5-21-1004336348-1177238915-682003330-1001'))])
+ps += storage('B725F130-47EF-101A-A5F1-02608C9EEBAC', [
+    prop(10, lpwstr('ProgramData')),
+    prop(14, struct.pack('<HHQ', 0x40, 0, filetime(written))),
+])
+ps += struct.pack('<I', 0)
+blocks.append(struct.pack('<II', len(ps)+8, 0xA0000009) + ps)
+
+# both point at the file entry, the third item
+blocks.append(struct.pack('<IIII', 0x10, 0xA0000005, 0x23, 0x2D))
+blocks.append(struct.pack('<II', 0x1C, 0xA000000B) + guid('62AB5D82-FDC1-4DC3-A9DD-070D1D495D97') + struct.pack('<I', 0x2D))
+
+extra = b''.join(blocks) + struct.pack('<I', 0)
+data = hdr + idl + li + strings + extra
+with open(os.path.join(os.path.dirname(os.path.abspath(__file__)), 'ProgramData.lnk'), 'wb') as f:
+    f.write(data)
@@ -1,3 +1,41 @@
+extern crate lnk;
+
+use std::env;
+use std::fs;
+use lnk::{ExtraData, ParseOptions};
+use lnk::property_key::PropertyKeyRegistry;
+
+/// Prints the contents of the .lnk files given on the command line
 fn main() {
+    let registry = PropertyKeyRegistry::new();
+    let options = ParseOptions { detect_code_page: true, ..ParseOptions::default() };
+
+    for path in env::args().skip(1) {
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) => { eprintln!("{}: {}", path, e); continue; },
+        };
+
+        let shell_link = match lnk::ShellLink::try_from_with_options(&bytes, &options) {
+            Ok(shell_link) => shell_link,
+            Err(e) => { eprintln!("{}: {:?}", path, e); continue; },
+        };
+
+        println!("{}", path);
+        println!("{:#?}", shell_link.header);
+        if let Some(guess) = shell_link.code_page_guess {
+            println!("code page: {} ({:?} confidence, {:?})", guess.code_page, guess.confidence, guess.hint);
+        }
 
-}
\ No newline at end of file
+        for block in &shell_link.extra_data {
+            match *block {
+                ExtraData::PropertyStoreProps(ref block) => {
+                    for (name, value) in block.property_store.named_properties(&registry) {
+                        println!("  {}: {:?}", name, value);
+                    }
+                },
+                ref other => println!("{:#?}", other),
+            }
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! Windows code page identifiers ([MSDN-CODEPAGE]), as stored in a ConsoleFEDataBlock (section 2.5.2)
+//! and used for the strings of a shell link that are not stored in Unicode.
+
+pub mod detection;
+mod tables;
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use error::CodePageDecodeError;
+
+/// A Windows code page. Code pages without a name in this crate are kept as `Other`. Code pages
+/// are compared by their identifier, so `Other(932)` is equal to (and decodes like) `ShiftJis`.
+#[derive(Debug, Copy, Clone)]
+pub enum CodePage {
+    /// OEM United States
+    Ibm437,
+    /// OEM Multilingual Latin 1
+    Ibm850,
+    /// OEM Latin 2
+    Ibm852,
+    /// OEM Turkish
+    Ibm857,
+    /// OEM Russian
+    Cp866,
+    /// Thai
+    Windows874,
+    /// Japanese (Shift-JIS)
+    ShiftJis,
+    /// Simplified Chinese (GBK)
+    Gbk,
+    /// Korean (Unified Hangul Code)
+    Korean,
+    /// Traditional Chinese (Big5)
+    Big5,
+    /// Unicode UTF-16, little endian
+    Utf16Le,
+    /// Unicode UTF-16, big endian
+    Utf16Be,
+    /// ANSI Central European
+    Windows1250,
+    /// ANSI Cyrillic
+    Windows1251,
+    /// ANSI Latin 1
+    Windows1252,
+    /// ANSI Greek
+    Windows1253,
+    /// ANSI Turkish
+    Windows1254,
+    /// ANSI Hebrew
+    Windows1255,
+    /// ANSI Arabic
+    Windows1256,
+    /// ANSI Baltic
+    Windows1257,
+    /// ANSI/OEM Vietnamese
+    Windows1258,
+    /// US-ASCII
+    UsAscii,
+    /// ISO 8859-1 Latin 1
+    Iso8859_1,
+    /// Unicode UTF-8
+    Utf8,
+    Other(u32),
+}
+
+/// Code page identifiers and names of the named code pages
+const CODE_PAGE_MAP: [(CodePage, u32, &str); 24] = [
+    (CodePage::Ibm437, 437, "IBM437"),
+    (CodePage::Ibm850, 850, "IBM850"),
+    (CodePage::Ibm852, 852, "IBM852"),
+    (CodePage::Ibm857, 857, "IBM857"),
+    (CodePage::Cp866, 866, "cp866"),
+    (CodePage::Windows874, 874, "windows-874"),
+    (CodePage::ShiftJis, 932, "shift_jis"),
+    (CodePage::Gbk, 936, "gb2312"),
+    (CodePage::Korean, 949, "ks_c_5601-1987"),
+    (CodePage::Big5, 950, "big5"),
+    (CodePage::Utf16Le, 1200, "utf-16"),
+    (CodePage::Utf16Be, 1201, "unicodeFFFE"),
+    (CodePage::Windows1250, 1250, "windows-1250"),
+    (CodePage::Windows1251, 1251, "windows-1251"),
+    (CodePage::Windows1252, 1252, "windows-1252"),
+    (CodePage::Windows1253, 1253, "windows-1253"),
+    (CodePage::Windows1254, 1254, "windows-1254"),
+    (CodePage::Windows1255, 1255, "windows-1255"),
+    (CodePage::Windows1256, 1256, "windows-1256"),
+    (CodePage::Windows1257, 1257, "windows-1257"),
+    (CodePage::Windows1258, 1258, "windows-1258"),
+    (CodePage::UsAscii, 20127, "us-ascii"),
+    (CodePage::Iso8859_1, 28591, "iso-8859-1"),
+    (CodePage::Utf8, 65001, "utf-8"),
+];
+
+/// What to do with bytes that are not mapped to a character in the code page
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum DecodeMode {
+    /// Fail with `CodePageDecodeError::UnmappableBytes`
+    Strict,
+    /// Replace the bytes with U+FFFD REPLACEMENT CHARACTER
+    Lossy,
+}
+
+/// Code page used for strings that are not stored in Unicode if no other code page is configured
+pub const DEFAULT_CODE_PAGE: CodePage = CodePage::Windows1252;
+
+/// Tables of a double-byte character set: the single-byte characters of 0x80 to 0xFF and the
+/// double-byte characters of each lead byte
+type DbcsTables = (&'static [char; 128], &'static [(u8, [char; 191])]);
+
+impl CodePage {
+    /// The .NET / IANA name of the code page, e.g. `shift_jis` for 932. `None` for `Other` code pages.
+    pub fn name(&self) -> Option<&'static str> {
+        CODE_PAGE_MAP.iter().find(|x| x.0 == *self).map(|out| out.2)
+    }
+
+    /// Whether `decode` supports the code page
+    pub fn is_supported(&self) -> bool {
+        self.single_byte_table().is_some() || self.double_byte_tables().is_some()
+            || matches!(self.normalized(), CodePage::UsAscii | CodePage::Iso8859_1 | CodePage::Utf8)
+    }
+
+    /// Decodes a string in the code page. Supported are the Windows ANSI code pages 874 and 1250
+    /// to 1258, the OEM code pages 437, 850, 852, 857 and 866, the double-byte code pages 932 and
+    /// 936, US-ASCII, ISO 8859-1 and UTF-8.
+    pub fn decode(&self, input: &[u8], mode: DecodeMode) -> Result<String, CodePageDecodeError> {
+        use self::CodePageDecodeError::*;
+
+        let unmappable = |offset: usize, string: &mut String| match mode {
+            DecodeMode::Strict => Err(UnmappableBytes(*self, offset)),
+            DecodeMode::Lossy => { string.push(::std::char::REPLACEMENT_CHARACTER); Ok(()) },
+        };
+
+        if let Some(table) = self.single_byte_table() {
+            let mut string = String::with_capacity(input.len());
+            for (offset, byte) in input.iter().enumerate() {
+                match high_byte_char(table, *byte) {
+                    Some(c) => string.push(c),
+                    None => unmappable(offset, &mut string)?,
+                }
+            }
+            return Ok(string);
+        }
+
+        if let Some((single_bytes, lead_bytes)) = self.double_byte_tables() {
+            let mut string = String::with_capacity(input.len());
+            let mut offset = 0;
+            while offset < input.len() {
+                let byte = input[offset];
+                let lead = lead_bytes.binary_search_by_key(&byte, |x| x.0).ok().map(|i| lead_bytes[i].1);
+                match (lead, input.get(offset + 1)) {
+                    (Some(trail_bytes), Some(&trail)) if trail >= tables::FIRST_TRAIL_BYTE => {
+                        match trail_bytes.get((trail - tables::FIRST_TRAIL_BYTE) as usize) {
+                            Some(&c) if c != ::std::char::REPLACEMENT_CHARACTER => string.push(c),
+                            _ => unmappable(offset, &mut string)?,
+                        }
+                        offset += 2;
+                    },
+                    // A lead byte without a valid trail byte: skip only the lead byte, so that an
+                    // ASCII character following it is not lost
+                    (Some(_), _) => {
+                        unmappable(offset, &mut string)?;
+                        offset += 1;
+                    },
+                    (None, _) => {
+                        match high_byte_char(single_bytes, byte) {
+                            Some(c) => string.push(c),
+                            None => unmappable(offset, &mut string)?,
+                        }
+                        offset += 1;
+                    },
+                }
+            }
+            return Ok(string);
+        }
+
+        match self.normalized() {
+            CodePage::Iso8859_1 => Ok(input.iter().map(|byte| *byte as char).collect()),
+            CodePage::UsAscii => {
+                let mut string = String::with_capacity(input.len());
+                for (offset, byte) in input.iter().enumerate() {
+                    if byte.is_ascii() {
+                        string.push(*byte as char);
+                    } else {
+                        unmappable(offset, &mut string)?;
+                    }
+                }
+                Ok(string)
+            },
+            CodePage::Utf8 => match mode {
+                DecodeMode::Strict => ::std::str::from_utf8(input)
+                    .map(|s| s.to_string())
+                    .map_err(|e| UnmappableBytes(*self, e.valid_up_to())),
+                DecodeMode::Lossy => Ok(String::from_utf8_lossy(input).into_owned()),
+            },
+            _ => Err(UnsupportedCodePage(*self)),
+        }
+    }
+
+    /// The named variant of an `Other` code page with a known identifier, other code pages are
+    /// returned as-is
+    fn normalized(&self) -> CodePage {
+        CodePage::from(u32::from(*self))
+    }
+
+    fn single_byte_table(&self) -> Option<&'static [char; 128]> {
+        use self::CodePage::*;
+        let table = match self.normalized() {
+            Ibm437 => &tables::CP437,
+            Ibm850 => &tables::CP850,
+            Ibm852 => &tables::CP852,
+            Ibm857 => &tables::CP857,
+            Cp866 => &tables::CP866,
+            Windows874 => &tables::CP874,
+            Windows1250 => &tables::CP1250,
+            Windows1251 => &tables::CP1251,
+            Windows1252 => &tables::CP1252,
+            Windows1253 => &tables::CP1253,
+            Windows1254 => &tables::CP1254,
+            Windows1255 => &tables::CP1255,
+            Windows1256 => &tables::CP1256,
+            Windows1257 => &tables::CP1257,
+            Windows1258 => &tables::CP1258,
+            _ => return None,
+        };
+        Some(table)
+    }
+
+    fn double_byte_tables(&self) -> Option<DbcsTables> {
+        match self.normalized() {
+            CodePage::ShiftJis => Some((&tables::CP932, &tables::CP932_LEAD_BYTES)),
+            CodePage::Gbk => Some((&tables::CP936, &tables::CP936_LEAD_BYTES)),
+            _ => None,
+        }
+    }
+}
+
+/// Looks up a byte in a table of the characters of the bytes 0x80 to 0xFF, bytes below 0x80 are ASCII
+fn high_byte_char(table: &[char; 128], byte: u8) -> Option<char> {
+    if byte < 0x80 {
+        return Some(byte as char);
+    }
+    Some(table[byte as usize - 0x80]).filter(|c| *c != ::std::char::REPLACEMENT_CHARACTER)
+}
+
+/// A string that is stored in a code page instead of in Unicode. The original bytes are kept, so
+/// that the string can be decoded again with another code page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct AnsiString {
+    /// The bytes of the string, without the NULL terminator
+    pub bytes: Vec<u8>,
+    /// The bytes, decoded with the code page of the `ParseOptions` (`DEFAULT_CODE_PAGE` by default)
+    pub string: String,
+}
+
+impl AnsiString {
+    pub fn decode(bytes: &[u8], code_page: CodePage, mode: DecodeMode) -> Result<Self, CodePageDecodeError> {
+        Ok(AnsiString {
+            bytes: bytes.to_vec(),
+            string: code_page.decode(bytes, mode)?,
+        })
+    }
+
+    /// Decodes the string with `DEFAULT_CODE_PAGE`, replacing unmapped bytes
+    pub(crate) fn decode_default(bytes: &[u8]) -> Self {
+        AnsiString {
+            bytes: bytes.to_vec(),
+            string: DEFAULT_CODE_PAGE.decode(bytes, DecodeMode::Lossy).unwrap(),
+        }
+    }
+
+    /// Decodes the original bytes again with another code page
+    pub fn redecode(&mut self, code_page: CodePage, mode: DecodeMode) -> Result<(), CodePageDecodeError> {
+        self.string = code_page.decode(&self.bytes, mode)?;
+        Ok(())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+}
+
+impl fmt::Display for AnsiString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+impl PartialEq<str> for AnsiString {
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for AnsiString {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.string == *other
+    }
+}
+
+impl From<u32> for CodePage {
+    fn from(input: u32) -> Self {
+        CODE_PAGE_MAP.iter()
+        .find(|x| x.1 == input)
+        .map(|out| out.0)
+        .unwrap_or(CodePage::Other(input))
+    }
+}
+
+impl From<CodePage> for u32 {
+    fn from(input: CodePage) -> u32 {
+        match input {
+            CodePage::Other(code_page) => code_page,
+            // compares the variants, as comparing code pages converts them to u32
+            named => CODE_PAGE_MAP.iter()
+                .find(|x| mem::discriminant(&x.0) == mem::discriminant(&named))
+                .map(|out| out.1)
+                .unwrap(),
+        }
+    }
+}
+
+impl PartialEq for CodePage {
+    fn eq(&self, other: &CodePage) -> bool {
+        u32::from(*self) == u32::from(*other)
+    }
+}
+
+impl Eq for CodePage {}
+
+impl Hash for CodePage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        u32::from(*self).hash(state)
+    }
+}
+
+impl Ord for CodePage {
+    fn cmp(&self, other: &CodePage) -> Ordering {
+        u32::from(*self).cmp(&u32::from(*other))
+    }
+}
+
+impl PartialOrd for CodePage {
+    fn partial_cmp(&self, other: &CodePage) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for CodePage {
+    /// Formats the code page as its identifier and name, e.g. `932 (shift_jis)`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{} ({})", u32::from(*self), name),
+            None => write!(f, "{}", u32::from(*self)),
+        }
+    }
+}
+
+#[test]
+fn decode_code_pages() {
+    use error::CodePageDecodeError::*;
+
+    assert_eq!(CodePage::Windows1252.decode(b"caf\xE9 \x80", DecodeMode::Strict), Ok("café €".to_string()));
+    assert_eq!(CodePage::Windows1251.decode(b"\xCF\xF0\xE8\xE2\xE5\xF2", DecodeMode::Strict), Ok("Привет".to_string()));
+    assert_eq!(CodePage::Cp866.decode(b"\x8F\xE0\xA8\xA2\xA5\xE2", DecodeMode::Strict), Ok("Привет".to_string()));
+    assert_eq!(CodePage::Ibm437.decode(b"\xC9\xCD\xBB", DecodeMode::Strict), Ok("╔═╗".to_string()));
+    assert_eq!(CodePage::ShiftJis.decode(b"C:\\\x83\x66\x83\x58\x83\x4E\x83\x67\x83\x62\x83\x76 \xB1", DecodeMode::Strict), Ok("C:\\デスクトップ ｱ".to_string()));
+    assert_eq!(CodePage::Gbk.decode(b"\xD7\xC0\xC3\xE6", DecodeMode::Strict), Ok("桌面".to_string()));
+
+    assert_eq!(CodePage::Windows1252.decode(b"a\x81", DecodeMode::Strict), Err(UnmappableBytes(CodePage::Windows1252, 1)));
+    assert_eq!(CodePage::Windows1252.decode(b"a\x81", DecodeMode::Lossy), Ok("a\u{FFFD}".to_string()));
+    assert_eq!(CodePage::ShiftJis.decode(b"\x83 a", DecodeMode::Lossy), Ok("\u{FFFD} a".to_string()));
+    assert_eq!(CodePage::ShiftJis.decode(b"ab\x83", DecodeMode::Strict), Err(UnmappableBytes(CodePage::ShiftJis, 2)));
+    assert_eq!(CodePage::Big5.decode(b"a", DecodeMode::Lossy), Err(UnsupportedCodePage(CodePage::Big5)));
+
+    let mut string = AnsiString::decode_default(b"\xCF\xF0\xE8\xE2\xE5\xF2");
+    assert_eq!(string, "Ïðèâåò");
+    string.redecode(CodePage::Windows1251, DecodeMode::Strict).unwrap();
+    assert_eq!(string, "Привет");
+}
+
+#[test]
+fn code_page_from_u32() {
+    assert_eq!(CodePage::from(932), CodePage::ShiftJis);
+    assert_eq!(CodePage::from(1252).to_string(), "1252 (windows-1252)");
+    assert_eq!(CodePage::from(10000), CodePage::Other(10000));
+    assert_eq!(CodePage::from(10000).to_string(), "10000");
+    assert_eq!(CodePage::Other(932), CodePage::ShiftJis);
+    assert_eq!(CodePage::Other(932).name(), Some("shift_jis"));
+    assert_eq!(CodePage::Other(1251).decode(b"\xCF\xF0\xE8\xE2\xE5\xF2", DecodeMode::Strict), Ok("Привет".to_string()));
+    assert_ne!(CodePage::Other(10000), CodePage::Other(10001));
+    for &(code_page, id, _) in CODE_PAGE_MAP.iter() {
+        assert_eq!(u32::from(code_page), id);
+    }
+}
@@ -0,0 +1,286 @@
+//! Guesses the code page of the strings of a shell link that are not stored in Unicode, for links
+//! from systems with an unknown system default code page.
+//!
+//! The hints are, in order of reliability: Unicode copies of the same string (LinkInfo and the
+//! environment data blocks store most paths twice), the code page of the ConsoleFEDataBlock, and
+//! how plausible the text decoded with each candidate code page looks.
+
+use super::{CodePage, DecodeMode, DEFAULT_CODE_PAGE};
+use {ExtraData, ShellLink};
+
+/// How reliable a guess is
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Confidence {
+    /// Plausible, but other code pages decode the strings about as well
+    Low,
+    /// Supported by a hint that is usually, but not always, right
+    Medium,
+    /// The strings decode the same in all candidate code pages, or match their Unicode copies
+    High,
+}
+
+/// What a guess is based on
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum CodePageHint {
+    /// All strings are ASCII, so every candidate code page decodes them the same
+    AsciiOnly,
+    /// The code page decodes the strings to their Unicode copies in the LinkInfo or extra data blocks
+    UnicodeCopies,
+    /// The code page of the ConsoleFEDataBlock, or the ANSI code page of the same locale if it is an
+    /// OEM code page
+    ConsoleCodePage,
+    /// The text decoded with the code page looks more plausible than with the other candidates
+    ByteFrequency,
+    /// No candidate code page decodes all strings, `DEFAULT_CODE_PAGE` is returned
+    Fallback,
+}
+
+/// A guess of the system default code page of the machine that created a link
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct CodePageGuess {
+    pub code_page: CodePage,
+    pub confidence: Confidence,
+    pub hint: CodePageHint,
+}
+
+/// Code pages that are used as system default code page, the first one wins ties
+const ANSI_CANDIDATES: [CodePage; 13] = [
+    CodePage::Windows1252,
+    CodePage::Windows1250,
+    CodePage::Windows1251,
+    CodePage::Windows1253,
+    CodePage::Windows1254,
+    CodePage::Windows1255,
+    CodePage::Windows1256,
+    CodePage::Windows1257,
+    CodePage::Windows1258,
+    CodePage::Windows874,
+    CodePage::ShiftJis,
+    CodePage::Gbk,
+    CodePage::Utf8,
+];
+
+/// OEM code pages, only matched against Unicode copies
+const OEM_CANDIDATES: [CodePage; 5] = [
+    CodePage::Ibm437,
+    CodePage::Ibm850,
+    CodePage::Ibm852,
+    CodePage::Ibm857,
+    CodePage::Cp866,
+];
+
+/// ANSI code page of the locales using an OEM code page
+const OEM_TO_ANSI: [(CodePage, CodePage); 5] = [
+    (CodePage::Ibm437, CodePage::Windows1252),
+    (CodePage::Ibm850, CodePage::Windows1252),
+    (CodePage::Ibm852, CodePage::Windows1250),
+    (CodePage::Ibm857, CodePage::Windows1254),
+    (CodePage::Cp866, CodePage::Windows1251),
+];
+
+/// The most frequent letters of the Cyrillic, Greek, Arabic and Hebrew alphabets. Single-byte code
+/// pages map the same bytes to letters of different alphabets, a text in the right alphabet
+/// contains more of its frequent letters.
+const FREQUENT_LETTERS: &str = "оеаинтсрвлαοιετσςνηυρاليمونهربיוהלארתבמנש";
+
+/// Minimum lead of the best plausibility score over the next best different decoding for a
+/// `Confidence::Medium` guess
+const BYTE_FREQUENCY_MARGIN: i32 = 2;
+
+impl ShellLink {
+    /// Guesses the code page of the strings that are not stored in Unicode. The strings are
+    /// read from the raw bytes, so the code page they were decoded with doesn't matter.
+    pub fn detect_code_page(&self) -> CodePageGuess {
+        let strings = self.ansi_strings();
+
+        if strings.iter().all(|(bytes, _)| bytes.is_ascii()) {
+            return guess(DEFAULT_CODE_PAGE, Confidence::High, CodePageHint::AsciiOnly);
+        }
+
+        // strings with non-ASCII characters and a Unicode copy
+        let copies = strings.iter()
+            .filter_map(|&(bytes, unicode)| unicode.map(|unicode| (bytes, unicode)))
+            .filter(|(bytes, _)| !bytes.is_ascii())
+            .collect::<Vec<_>>();
+        if !copies.is_empty() {
+            // the candidate must also decode the strings without a Unicode copy
+            let matching = ANSI_CANDIDATES.iter().chain(OEM_CANDIDATES.iter()).find(|code_page| {
+                copies.iter().all(|&(bytes, unicode)| decodes_to(**code_page, bytes, unicode))
+                    && strings.iter().all(|(bytes, _)| code_page.decode(bytes, DecodeMode::Strict).is_ok())
+            });
+            if let Some(code_page) = matching {
+                return guess(*code_page, Confidence::High, CodePageHint::UnicodeCopies);
+            }
+        }
+
+        let console_code_page = self.extra_data.iter().filter_map(|block| match *block {
+            ExtraData::ConsoleFeProps(ref block) => Some(block.code_page),
+            _ => None,
+        }).next();
+        if let Some(code_page) = console_code_page {
+            let code_page = OEM_TO_ANSI.iter().find(|x| x.0 == code_page).map(|out| out.1).unwrap_or(code_page);
+            let decodes_all = code_page.is_supported()
+                && strings.iter().all(|(bytes, _)| code_page.decode(bytes, DecodeMode::Strict).is_ok());
+            if decodes_all {
+                return guess(code_page, Confidence::Medium, CodePageHint::ConsoleCodePage);
+            }
+        }
+
+        // decode all strings with every candidate, dropping candidates that can't decode them
+        let mut scores: Vec<(CodePage, Vec<String>, i32)> = Vec::new();
+        for code_page in ANSI_CANDIDATES.iter() {
+            let decoded = strings.iter()
+                .map(|(bytes, _)| code_page.decode(bytes, DecodeMode::Strict))
+                .collect::<Result<Vec<String>, _>>();
+            if let Ok(decoded) = decoded {
+                let score = decoded.iter().map(|string| plausibility(*code_page, string)).sum();
+                scores.push((*code_page, decoded, score));
+            }
+        }
+
+        let best = match scores.iter().max_by_key(|&&(code_page, _, score)| {
+            // max_by_key returns the last maximum, so prefer earlier candidates explicitly
+            let position = ANSI_CANDIDATES.iter().position(|c| *c == code_page).unwrap_or(0);
+            (score, -(position as i32))
+        }) {
+            Some(best) => best,
+            None => return guess(DEFAULT_CODE_PAGE, Confidence::Low, CodePageHint::Fallback),
+        };
+
+        // candidates decoding the strings the same as the best one are not competitors
+        let runner_up = scores.iter()
+            .filter(|(_, decoded, _)| *decoded != best.1)
+            .map(|x| x.2)
+            .max();
+        let confident = best.2 > 0 && runner_up.map_or(true, |score| best.2 - score >= BYTE_FREQUENCY_MARGIN);
+        let confidence = if confident { Confidence::Medium } else { Confidence::Low };
+        guess(best.0, confidence, CodePageHint::ByteFrequency)
+    }
+}
+
+fn guess(code_page: CodePage, confidence: Confidence, hint: CodePageHint) -> CodePageGuess {
+    CodePageGuess {
+        code_page,
+        confidence,
+        hint,
+    }
+}
+
+/// Whether the bytes decode to the Unicode string. Characters that are not in the code page are
+/// stored as '?', so a '?' matches any character.
+fn decodes_to(code_page: CodePage, bytes: &[u8], unicode: &str) -> bool {
+    let decoded = match code_page.decode(bytes, DecodeMode::Strict) {
+        Ok(decoded) => decoded,
+        Err(_) => return false,
+    };
+    decoded.chars().count() == unicode.chars().count()
+        && decoded.chars().zip(unicode.chars()).all(|(a, b)| a == b || a == '?')
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Hebrew,
+    Arabic,
+    Thai,
+    Cjk,
+    HalfwidthKana,
+    Other,
+}
+
+fn script(c: char) -> Script {
+    match c {
+        'A'..='Z' | 'a'..='z' | '\u{C0}'..='\u{24F}' | '\u{1E00}'..='\u{1EFF}' => Script::Latin,
+        '\u{370}'..='\u{3FF}' => Script::Greek,
+        '\u{400}'..='\u{4FF}' => Script::Cyrillic,
+        '\u{590}'..='\u{5FF}' => Script::Hebrew,
+        '\u{600}'..='\u{6FF}' => Script::Arabic,
+        '\u{E00}'..='\u{E7F}' => Script::Thai,
+        '\u{3040}'..='\u{30FF}' | '\u{3400}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' => Script::Cjk,
+        '\u{FF61}'..='\u{FF9F}' => Script::HalfwidthKana,
+        _ => Script::Other,
+    }
+}
+
+/// Number of bytes the character takes in the code page
+fn encoded_len(code_page: CodePage, c: char) -> i32 {
+    match code_page {
+        CodePage::Utf8 => c.len_utf8() as i32,
+        CodePage::ShiftJis | CodePage::Gbk if !c.is_ascii() && script(c) != Script::HalfwidthKana && c != '€' => 2,
+        _ => 1,
+    }
+}
+
+/// Scores how much a decoded string looks like real text. Every byte of a non-ASCII letter
+/// counts as one point (two for `FREQUENT_LETTERS`), every byte of an unusual character (control
+/// characters, box drawing, private use) as minus two. Words mixing Latin letters with letters of
+/// another alphabet, runs of accented Latin letters and single ideographs inside Latin words are
+/// penalized.
+fn plausibility(code_page: CodePage, string: &str) -> i32 {
+    let chars = string.chars().collect::<Vec<char>>();
+    let mut score = 0;
+
+    for (i, &c) in chars.iter().enumerate() {
+        let previous = if i > 0 { Some(chars[i - 1]) } else { None };
+        let next = chars.get(i + 1).cloned();
+
+        if !c.is_ascii() {
+            let len = encoded_len(code_page, c);
+            if c.is_alphabetic() {
+                score += len;
+                if c.to_lowercase().any(|c| FREQUENT_LETTERS.contains(c)) {
+                    score += 1;
+                }
+            } else if !is_common_symbol(c) {
+                score -= 2 * len;
+            }
+        }
+
+        if let Some(previous) = previous.filter(|p| p.is_alphabetic() && c.is_alphabetic()) {
+            let (a, b) = (script(previous), script(c));
+            let is_alphabet = |s: Script| s != Script::Cjk && s != Script::Other;
+            if a != b && is_alphabet(a) && is_alphabet(b) {
+                score -= 3;
+            } else if a == Script::Latin && b == Script::Latin && !previous.is_ascii() && !c.is_ascii() {
+                score -= 1;
+            }
+        }
+
+        let is_ascii_letter = |c: Option<char>| c.map_or(false, |c| c.is_ascii_alphabetic());
+        if script(c) == Script::Cjk && is_ascii_letter(previous) && is_ascii_letter(next) {
+            score -= 4;
+        }
+    }
+
+    score
+}
+
+/// Punctuation, symbols and digits that are common in text but not letters
+fn is_common_symbol(c: char) -> bool {
+    match c {
+        '\u{A0}'..='\u{BF}' | '\u{D7}' | '\u{F7}' | '\u{2000}'..='\u{206F}' | '\u{20AC}' | '\u{2122}' => true,
+        // fullwidth forms and CJK punctuation
+        '\u{3000}'..='\u{303F}' | '\u{FF01}'..='\u{FF60}' => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn plausibility_of_decodings() {
+    // "Müller" is Latin with one accented letter in Windows-1252, but mixes scripts in Windows-1251
+    let muller = b"M\xFCller";
+    let latin = plausibility(CodePage::Windows1252, &CodePage::Windows1252.decode(muller, DecodeMode::Strict).unwrap());
+    let cyrillic = plausibility(CodePage::Windows1251, &CodePage::Windows1251.decode(muller, DecodeMode::Strict).unwrap());
+    assert!(latin > cyrillic);
+
+    // "Привет" is a run of accented letters in Windows-1252
+    let privet = b"\xCF\xF0\xE8\xE2\xE5\xF2";
+    let latin = plausibility(CodePage::Windows1252, &CodePage::Windows1252.decode(privet, DecodeMode::Strict).unwrap());
+    let cyrillic = plausibility(CodePage::Windows1251, &CodePage::Windows1251.decode(privet, DecodeMode::Strict).unwrap());
+    assert!(cyrillic > latin);
+
+    assert!(decodes_to(CodePage::ShiftJis, b"\x83\x66?", "デ\u{2603}"));
+    assert!(!decodes_to(CodePage::Windows1252, b"\x83\x66", "デ"));
+}
@@ -0,0 +1,232 @@
+//! Decoding tables of the supported code pages, generated from the Microsoft code page mapping tables.
+//! Bytes 0x00 to 0x7F are ASCII in all supported code pages and not part of the tables.
+//! U+FFFD marks bytes (and byte sequences) that are not mapped to a character.
+
+/// Characters of the bytes 0x80 to 0xFF of code page 437
+pub static CP437: [char; 128] = ['Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{A0}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 850
+pub static CP850: [char; 128] = ['Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '®', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©', '╣', '║', '╗', '╝', '¢', '¥', '┐', '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤', 'ð', 'Ð', 'Ê', 'Ë', 'È', 'ı', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì', '▀', 'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ', 'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´', '\u{AD}', '±', '‗', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{A0}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 852
+pub static CP852: [char; 128] = ['Ç', 'ü', 'é', 'â', 'ä', 'ů', 'ć', 'ç', 'ł', 'ë', 'Ő', 'ő', 'î', 'Ź', 'Ä', 'Ć', 'É', 'Ĺ', 'ĺ', 'ô', 'ö', 'Ľ', 'ľ', 'Ś', 'ś', 'Ö', 'Ü', 'Ť', 'ť', 'Ł', '×', 'č', 'á', 'í', 'ó', 'ú', 'Ą', 'ą', 'Ž', 'ž', 'Ę', 'ę', '¬', 'ź', 'Č', 'ş', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'Ě', 'Ş', '╣', '║', '╗', '╝', 'Ż', 'ż', '┐', '└', '┴', '┬', '├', '─', '┼', 'Ă', 'ă', '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤', 'đ', 'Đ', 'Ď', 'Ë', 'ď', 'Ň', 'Í', 'Î', 'ě', '┘', '┌', '█', '▄', 'Ţ', 'Ů', '▀', 'Ó', 'ß', 'Ô', 'Ń', 'ń', 'ň', 'Š', 'š', 'Ŕ', 'Ú', 'ŕ', 'Ű', 'ý', 'Ý', 'ţ', '´', '\u{AD}', '˝', '˛', 'ˇ', '˘', '§', '÷', '¸', '°', '¨', '˙', 'ű', 'Ř', 'ř', '■', '\u{A0}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 857
+pub static CP857: [char; 128] = ['Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ı', 'Ä', 'Å', 'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'İ', 'Ö', 'Ü', 'ø', '£', 'Ø', 'Ş', 'ş', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'Ğ', 'ğ', '¿', '®', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©', '╣', '║', '╗', '╝', '¢', '¥', '┐', '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤', 'º', 'ª', 'Ê', 'Ë', 'È', '\u{FFFD}', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì', '▀', 'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', '\u{FFFD}', '×', 'Ú', 'Û', 'Ù', 'ì', 'ÿ', '¯', '´', '\u{AD}', '±', '\u{FFFD}', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{A0}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 866
+pub static CP866: [char; 128] = ['А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', 'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я', 'Ё', 'ё', 'Є', 'є', 'Ї', 'ї', 'Ў', 'ў', '°', '∙', '·', '√', '№', '¤', '■', '\u{A0}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 874
+pub static CP874: [char; 128] = ['€', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '…', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{A0}', 'ก', 'ข', 'ฃ', 'ค', 'ฅ', 'ฆ', 'ง', 'จ', 'ฉ', 'ช', 'ซ', 'ฌ', 'ญ', 'ฎ', 'ฏ', 'ฐ', 'ฑ', 'ฒ', 'ณ', 'ด', 'ต', 'ถ', 'ท', 'ธ', 'น', 'บ', 'ป', 'ผ', 'ฝ', 'พ', 'ฟ', 'ภ', 'ม', 'ย', 'ร', 'ฤ', 'ล', 'ฦ', 'ว', 'ศ', 'ษ', 'ส', 'ห', 'ฬ', 'อ', 'ฮ', 'ฯ', 'ะ', 'ั', 'า', 'ำ', 'ิ', 'ี', 'ึ', 'ื', 'ุ', 'ู', 'ฺ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '฿', 'เ', 'แ', 'โ', 'ใ', 'ไ', 'ๅ', 'ๆ', '็', '่', '้', '๊', '๋', '์', 'ํ', '๎', '๏', '๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙', '๚', '๛', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1250
+pub static CP1250: [char; 128] = ['€', '\u{FFFD}', '‚', '\u{FFFD}', '„', '…', '†', '‡', '\u{FFFD}', '‰', 'Š', '‹', 'Ś', 'Ť', 'Ž', 'Ź', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '\u{FFFD}', '™', 'š', '›', 'ś', 'ť', 'ž', 'ź', '\u{A0}', 'ˇ', '˘', 'Ł', '¤', 'Ą', '¦', '§', '¨', '©', 'Ş', '«', '¬', '\u{AD}', '®', 'Ż', '°', '±', '˛', 'ł', '´', 'µ', '¶', '·', '¸', 'ą', 'ş', '»', 'Ľ', '˝', 'ľ', 'ż', 'Ŕ', 'Á', 'Â', 'Ă', 'Ä', 'Ĺ', 'Ć', 'Ç', 'Č', 'É', 'Ę', 'Ë', 'Ě', 'Í', 'Î', 'Ď', 'Đ', 'Ń', 'Ň', 'Ó', 'Ô', 'Ő', 'Ö', '×', 'Ř', 'Ů', 'Ú', 'Ű', 'Ü', 'Ý', 'Ţ', 'ß', 'ŕ', 'á', 'â', 'ă', 'ä', 'ĺ', 'ć', 'ç', 'č', 'é', 'ę', 'ë', 'ě', 'í', 'î', 'ď', 'đ', 'ń', 'ň', 'ó', 'ô', 'ő', 'ö', '÷', 'ř', 'ů', 'ú', 'ű', 'ü', 'ý', 'ţ', '˙'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1251
+pub static CP1251: [char; 128] = ['Ђ', 'Ѓ', '‚', 'ѓ', '„', '…', '†', '‡', '€', '‰', 'Љ', '‹', 'Њ', 'Ќ', 'Ћ', 'Џ', 'ђ', '‘', '’', '“', '”', '•', '–', '—', '\u{FFFD}', '™', 'љ', '›', 'њ', 'ќ', 'ћ', 'џ', '\u{A0}', 'Ў', 'ў', 'Ј', '¤', 'Ґ', '¦', '§', 'Ё', '©', 'Є', '«', '¬', '\u{AD}', '®', 'Ї', '°', '±', 'І', 'і', 'ґ', 'µ', '¶', '·', 'ё', '№', 'є', '»', 'ј', 'Ѕ', 'ѕ', 'ї', 'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', 'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1252
+pub static CP1252: [char; 128] = ['€', '\u{FFFD}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{FFFD}', 'Ž', '\u{FFFD}', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{FFFD}', 'ž', 'Ÿ', '\u{A0}', '¡', '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ª', '«', '¬', '\u{AD}', '®', '¯', '°', '±', '²', '³', '´', 'µ', '¶', '·', '¸', '¹', 'º', '»', '¼', '½', '¾', '¿', 'À', 'Á', 'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'þ', 'ÿ'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1253
+pub static CP1253: [char; 128] = ['€', '\u{FFFD}', '‚', 'ƒ', '„', '…', '†', '‡', '\u{FFFD}', '‰', '\u{FFFD}', '‹', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '\u{FFFD}', '™', '\u{FFFD}', '›', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{A0}', '΅', 'Ά', '£', '¤', '¥', '¦', '§', '¨', '©', '\u{FFFD}', '«', '¬', '\u{AD}', '®', '―', '°', '±', '²', '³', '΄', 'µ', '¶', '·', 'Έ', 'Ή', 'Ί', '»', 'Ό', '½', 'Ύ', 'Ώ', 'ΐ', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ρ', '\u{FFFD}', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'Ϊ', 'Ϋ', 'ά', 'έ', 'ή', 'ί', 'ΰ', 'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'ς', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', 'ϊ', 'ϋ', 'ό', 'ύ', 'ώ', '\u{FFFD}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1254
+pub static CP1254: [char; 128] = ['€', '\u{FFFD}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{FFFD}', '\u{FFFD}', 'Ÿ', '\u{A0}', '¡', '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ª', '«', '¬', '\u{AD}', '®', '¯', '°', '±', '²', '³', '´', 'µ', '¶', '·', '¸', '¹', 'º', '»', '¼', '½', '¾', '¿', 'À', 'Á', 'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ğ', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'İ', 'Ş', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ğ', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û', 'ü', 'ı', 'ş', 'ÿ'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1255
+pub static CP1255: [char; 128] = ['€', '\u{FFFD}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', '\u{FFFD}', '‹', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', '\u{FFFD}', '›', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{A0}', '¡', '¢', '£', '₪', '¥', '¦', '§', '¨', '©', '×', '«', '¬', '\u{AD}', '®', '¯', '°', '±', '²', '³', '´', 'µ', '¶', '·', '¸', '¹', '÷', '»', '¼', '½', '¾', '¿', 'ְ', 'ֱ', 'ֲ', 'ֳ', 'ִ', 'ֵ', 'ֶ', 'ַ', 'ָ', 'ֹ', '\u{FFFD}', 'ֻ', 'ּ', 'ֽ', '־', 'ֿ', '׀', 'ׁ', 'ׂ', '׃', 'װ', 'ױ', 'ײ', '׳', '״', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט', 'י', 'ך', 'כ', 'ל', 'ם', 'מ', 'ן', 'נ', 'ס', 'ע', 'ף', 'פ', 'ץ', 'צ', 'ק', 'ר', 'ש', 'ת', '\u{FFFD}', '\u{FFFD}', '\u{200E}', '\u{200F}', '\u{FFFD}'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1256
+pub static CP1256: [char; 128] = ['€', 'پ', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'ٹ', '‹', 'Œ', 'چ', 'ژ', 'ڈ', 'گ', '‘', '’', '“', '”', '•', '–', '—', 'ک', '™', 'ڑ', '›', 'œ', '\u{200C}', '\u{200D}', 'ں', '\u{A0}', '،', '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ھ', '«', '¬', '\u{AD}', '®', '¯', '°', '±', '²', '³', '´', 'µ', '¶', '·', '¸', '¹', '؛', '»', '¼', '½', '¾', '؟', 'ہ', 'ء', 'آ', 'أ', 'ؤ', 'إ', 'ئ', 'ا', 'ب', 'ة', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض', '×', 'ط', 'ظ', 'ع', 'غ', 'ـ', 'ف', 'ق', 'ك', 'à', 'ل', 'â', 'م', 'ن', 'ه', 'و', 'ç', 'è', 'é', 'ê', 'ë', 'ى', 'ي', 'î', 'ï', 'ً', 'ٌ', 'ٍ', 'َ', 'ô', 'ُ', 'ِ', '÷', 'ّ', 'ù', 'ْ', 'û', 'ü', '\u{200E}', '\u{200F}', 'ے'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1257
+pub static CP1257: [char; 128] = ['€', '\u{FFFD}', '‚', '\u{FFFD}', '„', '…', '†', '‡', '\u{FFFD}', '‰', '\u{FFFD}', '‹', '\u{FFFD}', '¨', 'ˇ', '¸', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '\u{FFFD}', '™', '\u{FFFD}', '›', '\u{FFFD}', '¯', '˛', '\u{FFFD}', '\u{A0}', '\u{FFFD}', '¢', '£', '¤', '\u{FFFD}', '¦', '§', 'Ø', '©', 'Ŗ', '«', '¬', '\u{AD}', '®', 'Æ', '°', '±', '²', '³', '´', 'µ', '¶', '·', 'ø', '¹', 'ŗ', '»', '¼', '½', '¾', 'æ', 'Ą', 'Į', 'Ā', 'Ć', 'Ä', 'Å', 'Ę', 'Ē', 'Č', 'É', 'Ź', 'Ė', 'Ģ', 'Ķ', 'Ī', 'Ļ', 'Š', 'Ń', 'Ņ', 'Ó', 'Ō', 'Õ', 'Ö', '×', 'Ų', 'Ł', 'Ś', 'Ū', 'Ü', 'Ż', 'Ž', 'ß', 'ą', 'į', 'ā', 'ć', 'ä', 'å', 'ę', 'ē', 'č', 'é', 'ź', 'ė', 'ģ', 'ķ', 'ī', 'ļ', 'š', 'ń', 'ņ', 'ó', 'ō', 'õ', 'ö', '÷', 'ų', 'ł', 'ś', 'ū', 'ü', 'ż', 'ž', '˙'];
+/// Characters of the bytes 0x80 to 0xFF of code page 1258
+pub static CP1258: [char; 128] = ['€', '\u{FFFD}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', '\u{FFFD}', '‹', 'Œ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', '\u{FFFD}', '›', 'œ', '\u{FFFD}', '\u{FFFD}', 'Ÿ', '\u{A0}', '¡', '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ª', '«', '¬', '\u{AD}', '®', '¯', '°', '±', '²', '³', '´', 'µ', '¶', '·', '¸', '¹', 'º', '»', '¼', '½', '¾', '¿', 'À', 'Á', 'Â', 'Ă', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', '̀', 'Í', 'Î', 'Ï', 'Đ', 'Ñ', '̉', 'Ó', 'Ô', 'Ơ', 'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ư', '̃', 'ß', 'à', 'á', 'â', 'ă', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', '́', 'í', 'î', 'ï', 'đ', 'ñ', '̣', 'ó', 'ô', 'ơ', 'ö', '÷', 'ø', 'ù', 'ú', 'û', 'ü', 'ư', '₫', 'ÿ'];
+
+/// First trail byte of a double-byte character, all tables of trail bytes start at this byte
+pub const FIRST_TRAIL_BYTE: u8 = 0x40;
+
+/// Characters of the single bytes 0x80 to 0xFF of code page 932, lead bytes are unmapped
+pub static CP932: [char; 128] = ['\u{80}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{F8F0}', '｡', '｢', '｣', '､', '･', 'ｦ', 'ｧ', 'ｨ', 'ｩ', 'ｪ', 'ｫ', 'ｬ', 'ｭ', 'ｮ', 'ｯ', 'ｰ', 'ｱ', 'ｲ', 'ｳ', 'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', 'ｹ', 'ｺ', 'ｻ', 'ｼ', 'ｽ', 'ｾ', 'ｿ', 'ﾀ', 'ﾁ', 'ﾂ', 'ﾃ', 'ﾄ', 'ﾅ', 'ﾆ', 'ﾇ', 'ﾈ', 'ﾉ', 'ﾊ', 'ﾋ', 'ﾌ', 'ﾍ', 'ﾎ', 'ﾏ', 'ﾐ', 'ﾑ', 'ﾒ', 'ﾓ', 'ﾔ', 'ﾕ', 'ﾖ', 'ﾗ', 'ﾘ', 'ﾙ', 'ﾚ', 'ﾛ', 'ﾜ', 'ﾝ', 'ﾞ', 'ﾟ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{F8F1}', '\u{F8F2}', '\u{F8F3}'];
+/// Characters of the double-byte sequences of code page 932: for each lead byte, the characters
+/// of the trail bytes 0x40 to 0xFE
+pub static CP932_LEAD_BYTES: [(u8, [char; 191]); 55] = [
+    (0x81, ['\u{3000}', '、', '。', '，', '．', '・', '：', '；', '？', '！', '゛', '゜', '´', '｀', '¨', '＾', '￣', '＿', 'ヽ', 'ヾ', 'ゝ', 'ゞ', '〃', '仝', '々', '〆', '〇', 'ー', '―', '‐', '／', '＼', '～', '∥', '｜', '…', '‥', '‘', '’', '“', '”', '（', '）', '〔', '〕', '［', '］', '｛', '｝', '〈', '〉', '《', '》', '「', '」', '『', '』', '【', '】', '＋', '－', '±', '×', '\u{FFFD}', '÷', '＝', '≠', '＜', '＞', '≦', '≧', '∞', '∴', '♂', '♀', '°', '′', '″', '℃', '￥', '＄', '￠', '￡', '％', '＃', '＆', '＊', '＠', '§', '☆', '★', '○', '●', '◎', '◇', '◆', '□', '■', '△', '▲', '▽', '▼', '※', '〒', '→', '←', '↑', '↓', '〓', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '∈', '∋', '⊆', '⊇', '⊂', '⊃', '∪', '∩', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '∧', '∨', '￢', '⇒', '⇔', '∀', '∃', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '∠', '⊥', '⌒', '∂', '∇', '≡', '≒', '≪', '≫', '√', '∽', '∝', '∵', '∫', '∬', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'Å', '‰', '♯', '♭', '♪', '†', '‡', '¶', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '◯', '\u{FFFD}', '\u{FFFD}']),
+    (0x82, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '０', '１', '２', '３', '４', '５', '６', '７', '８', '９', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'Ａ', 'Ｂ', 'Ｃ', 'Ｄ', 'Ｅ', 'Ｆ', 'Ｇ', 'Ｈ', 'Ｉ', 'Ｊ', 'Ｋ', 'Ｌ', 'Ｍ', 'Ｎ', 'Ｏ', 'Ｐ', 'Ｑ', 'Ｒ', 'Ｓ', 'Ｔ', 'Ｕ', 'Ｖ', 'Ｗ', 'Ｘ', 'Ｙ', 'Ｚ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ａ', 'ｂ', 'ｃ', 'ｄ', 'ｅ', 'ｆ', 'ｇ', 'ｈ', 'ｉ', 'ｊ', 'ｋ', 'ｌ', 'ｍ', 'ｎ', 'ｏ', 'ｐ', 'ｑ', 'ｒ', 'ｓ', 'ｔ', 'ｕ', 'ｖ', 'ｗ', 'ｘ', 'ｙ', 'ｚ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ぁ', 'あ', 'ぃ', 'い', 'ぅ', 'う', 'ぇ', 'え', 'ぉ', 'お', 'か', 'が', 'き', 'ぎ', 'く', 'ぐ', 'け', 'げ', 'こ', 'ご', 'さ', 'ざ', 'し', 'じ', 'す', 'ず', 'せ', 'ぜ', 'そ', 'ぞ', 'た', 'だ', 'ち', 'ぢ', 'っ', 'つ', 'づ', 'て', 'で', 'と', 'ど', 'な', 'に', 'ぬ', 'ね', 'の', 'は', 'ば', 'ぱ', 'ひ', 'び', 'ぴ', 'ふ', 'ぶ', 'ぷ', 'へ', 'べ', 'ぺ', 'ほ', 'ぼ', 'ぽ', 'ま', 'み', 'む', 'め', 'も', 'ゃ', 'や', 'ゅ', 'ゆ', 'ょ', 'よ', 'ら', 'り', 'る', 'れ', 'ろ', 'ゎ', 'わ', 'ゐ', 'ゑ', 'を', 'ん', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0x83, ['ァ', 'ア', 'ィ', 'イ', 'ゥ', 'ウ', 'ェ', 'エ', 'ォ', 'オ', 'カ', 'ガ', 'キ', 'ギ', 'ク', 'グ', 'ケ', 'ゲ', 'コ', 'ゴ', 'サ', 'ザ', 'シ', 'ジ', 'ス', 'ズ', 'セ', 'ゼ', 'ソ', 'ゾ', 'タ', 'ダ', 'チ', 'ヂ', 'ッ', 'ツ', 'ヅ', 'テ', 'デ', 'ト', 'ド', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'バ', 'パ', 'ヒ', 'ビ', 'ピ', 'フ', 'ブ', 'プ', 'ヘ', 'ベ', 'ペ', 'ホ', 'ボ', 'ポ', 'マ', 'ミ', '\u{FFFD}', 'ム', 'メ', 'モ', 'ャ', 'ヤ', 'ュ', 'ユ', 'ョ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ヮ', 'ワ', 'ヰ', 'ヱ', 'ヲ', 'ン', 'ヴ', 'ヵ', 'ヶ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0x84, ['А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ё', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'а', 'б', 'в', 'г', 'д', 'е', 'ё', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', '\u{FFFD}', 'о', 'п', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '─', '│', '┌', '┐', '┘', '└', '├', '┬', '┤', '┴', '┼', '━', '┃', '┏', '┓', '┛', '┗', '┣', '┳', '┫', '┻', '╋', '┠', '┯', '┨', '┷', '┿', '┝', '┰', '┥', '┸', '╂', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0x87, ['①', '②', '③', '④', '⑤', '⑥', '⑦', '⑧', '⑨', '⑩', '⑪', '⑫', '⑬', '⑭', '⑮', '⑯', '⑰', '⑱', '⑲', '⑳', 'Ⅰ', 'Ⅱ', 'Ⅲ', 'Ⅳ', 'Ⅴ', 'Ⅵ', 'Ⅶ', 'Ⅷ', 'Ⅸ', 'Ⅹ', '\u{FFFD}', '㍉', '㌔', '㌢', '㍍', '㌘', '㌧', '㌃', '㌶', '㍑', '㍗', '㌍', '㌦', '㌣', '㌫', '㍊', '㌻', '㎜', '㎝', '㎞', '㎎', '㎏', '㏄', '㎡', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '㍻', '\u{FFFD}', '〝', '〟', '№', '㏍', '℡', '㊤', '㊥', '㊦', '㊧', '㊨', '㈱', '㈲', '㈹', '㍾', '㍽', '㍼', '≒', '≡', '∫', '∮', '∑', '√', '⊥', '∠', '∟', '⊿', '∵', '∩', '∪', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0x88, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '亜', '唖', '娃', '阿', '哀', '愛', '挨', '姶', '逢', '葵', '茜', '穐', '悪', '握', '渥', '旭', '葦', '芦', '鯵', '梓', '圧', '斡', '扱', '宛', '姐', '虻', '飴', '絢', '綾', '鮎', '或', '粟', '袷', '安', '庵', '按', '暗', '案', '闇', '鞍', '杏', '以', '伊', '位', '依', '偉', '囲', '夷', '委', '威', '尉', '惟', '意', '慰', '易', '椅', '為', '畏', '異', '移', '維', '緯', '胃', '萎', '衣', '謂', '違', '遺', '医', '井', '亥', '域', '育', '郁', '磯', '一', '壱', '溢', '逸', '稲', '茨', '芋', '鰯', '允', '印', '咽', '員', '因', '姻', '引', '飲', '淫', '胤', '蔭', '\u{FFFD}', '\u{FFFD}']),
+    (0x89, ['院', '陰', '隠', '韻', '吋', '右', '宇', '烏', '羽', '迂', '雨', '卯', '鵜', '窺', '丑', '碓', '臼', '渦', '嘘', '唄', '欝', '蔚', '鰻', '姥', '厩', '浦', '瓜', '閏', '噂', '云', '運', '雲', '荏', '餌', '叡', '営', '嬰', '影', '映', '曳', '栄', '永', '泳', '洩', '瑛', '盈', '穎', '頴', '英', '衛', '詠', '鋭', '液', '疫', '益', '駅', '悦', '謁', '越', '閲', '榎', '厭', '円', '\u{FFFD}', '園', '堰', '奄', '宴', '延', '怨', '掩', '援', '沿', '演', '炎', '焔', '煙', '燕', '猿', '縁', '艶', '苑', '薗', '遠', '鉛', '鴛', '塩', '於', '汚', '甥', '凹', '央', '奥', '往', '応', '押', '旺', '横', '欧', '殴', '王', '翁', '襖', '鴬', '鴎', '黄', '岡', '沖', '荻', '億', '屋', '憶', '臆', '桶', '牡', '乙', '俺', '卸', '恩', '温', '穏', '音', '下', '化', '仮', '何', '伽', '価', '佳', '加', '可', '嘉', '夏', '嫁', '家', '寡', '科', '暇', '果', '架', '歌', '河', '火', '珂', '禍', '禾', '稼', '箇', '花', '苛', '茄', '荷', '華', '菓', '蝦', '課', '嘩', '貨', '迦', '過', '霞', '蚊', '俄', '峨', '我', '牙', '画', '臥', '芽', '蛾', '賀', '雅', '餓', '駕', '介', '会', '解', '回', '塊', '壊', '廻', '快', '怪', '悔', '恢', '懐', '戒', '拐', '改', '\u{FFFD}', '\u{FFFD}']),
+    (0x8A, ['魁', '晦', '械', '海', '灰', '界', '皆', '絵', '芥', '蟹', '開', '階', '貝', '凱', '劾', '外', '咳', '害', '崖', '慨', '概', '涯', '碍', '蓋', '街', '該', '鎧', '骸', '浬', '馨', '蛙', '垣', '柿', '蛎', '鈎', '劃', '嚇', '各', '廓', '拡', '撹', '格', '核', '殻', '獲', '確', '穫', '覚', '角', '赫', '較', '郭', '閣', '隔', '革', '学', '岳', '楽', '額', '顎', '掛', '笠', '樫', '\u{FFFD}', '橿', '梶', '鰍', '潟', '割', '喝', '恰', '括', '活', '渇', '滑', '葛', '褐', '轄', '且', '鰹', '叶', '椛', '樺', '鞄', '株', '兜', '竃', '蒲', '釜', '鎌', '噛', '鴨', '栢', '茅', '萱', '粥', '刈', '苅', '瓦', '乾', '侃', '冠', '寒', '刊', '勘', '勧', '巻', '喚', '堪', '姦', '完', '官', '寛', '干', '幹', '患', '感', '慣', '憾', '換', '敢', '柑', '桓', '棺', '款', '歓', '汗', '漢', '澗', '潅', '環', '甘', '監', '看', '竿', '管', '簡', '緩', '缶', '翰', '肝', '艦', '莞', '観', '諌', '貫', '還', '鑑', '間', '閑', '関', '陥', '韓', '館', '舘', '丸', '含', '岸', '巌', '玩', '癌', '眼', '岩', '翫', '贋', '雁', '頑', '顔', '願', '企', '伎', '危', '喜', '器', '基', '奇', '嬉', '寄', '岐', '希', '幾', '忌', '揮', '机', '旗', '既', '期', '棋', '棄', '\u{FFFD}', '\u{FFFD}']),
+    (0x8B, ['機', '帰', '毅', '気', '汽', '畿', '祈', '季', '稀', '紀', '徽', '規', '記', '貴', '起', '軌', '輝', '飢', '騎', '鬼', '亀', '偽', '儀', '妓', '宜', '戯', '技', '擬', '欺', '犠', '疑', '祇', '義', '蟻', '誼', '議', '掬', '菊', '鞠', '吉', '吃', '喫', '桔', '橘', '詰', '砧', '杵', '黍', '却', '客', '脚', '虐', '逆', '丘', '久', '仇', '休', '及', '吸', '宮', '弓', '急', '救', '\u{FFFD}', '朽', '求', '汲', '泣', '灸', '球', '究', '窮', '笈', '級', '糾', '給', '旧', '牛', '去', '居', '巨', '拒', '拠', '挙', '渠', '虚', '許', '距', '鋸', '漁', '禦', '魚', '亨', '享', '京', '供', '侠', '僑', '兇', '競', '共', '凶', '協', '匡', '卿', '叫', '喬', '境', '峡', '強', '彊', '怯', '恐', '恭', '挟', '教', '橋', '況', '狂', '狭', '矯', '胸', '脅', '興', '蕎', '郷', '鏡', '響', '饗', '驚', '仰', '凝', '尭', '暁', '業', '局', '曲', '極', '玉', '桐', '粁', '僅', '勤', '均', '巾', '錦', '斤', '欣', '欽', '琴', '禁', '禽', '筋', '緊', '芹', '菌', '衿', '襟', '謹', '近', '金', '吟', '銀', '九', '倶', '句', '区', '狗', '玖', '矩', '苦', '躯', '駆', '駈', '駒', '具', '愚', '虞', '喰', '空', '偶', '寓', '遇', '隅', '串', '櫛', '釧', '屑', '屈', '\u{FFFD}', '\u{FFFD}']),
+    (0x8C, ['掘', '窟', '沓', '靴', '轡', '窪', '熊', '隈', '粂', '栗', '繰', '桑', '鍬', '勲', '君', '薫', '訓', '群', '軍', '郡', '卦', '袈', '祁', '係', '傾', '刑', '兄', '啓', '圭', '珪', '型', '契', '形', '径', '恵', '慶', '慧', '憩', '掲', '携', '敬', '景', '桂', '渓', '畦', '稽', '系', '経', '継', '繋', '罫', '茎', '荊', '蛍', '計', '詣', '警', '軽', '頚', '鶏', '芸', '迎', '鯨', '\u{FFFD}', '劇', '戟', '撃', '激', '隙', '桁', '傑', '欠', '決', '潔', '穴', '結', '血', '訣', '月', '件', '倹', '倦', '健', '兼', '券', '剣', '喧', '圏', '堅', '嫌', '建', '憲', '懸', '拳', '捲', '検', '権', '牽', '犬', '献', '研', '硯', '絹', '県', '肩', '見', '謙', '賢', '軒', '遣', '鍵', '険', '顕', '験', '鹸', '元', '原', '厳', '幻', '弦', '減', '源', '玄', '現', '絃', '舷', '言', '諺', '限', '乎', '個', '古', '呼', '固', '姑', '孤', '己', '庫', '弧', '戸', '故', '枯', '湖', '狐', '糊', '袴', '股', '胡', '菰', '虎', '誇', '跨', '鈷', '雇', '顧', '鼓', '五', '互', '伍', '午', '呉', '吾', '娯', '後', '御', '悟', '梧', '檎', '瑚', '碁', '語', '誤', '護', '醐', '乞', '鯉', '交', '佼', '侯', '候', '倖', '光', '公', '功', '効', '勾', '厚', '口', '向', '\u{FFFD}', '\u{FFFD}']),
+    (0x8D, ['后', '喉', '坑', '垢', '好', '孔', '孝', '宏', '工', '巧', '巷', '幸', '広', '庚', '康', '弘', '恒', '慌', '抗', '拘', '控', '攻', '昂', '晃', '更', '杭', '校', '梗', '構', '江', '洪', '浩', '港', '溝', '甲', '皇', '硬', '稿', '糠', '紅', '紘', '絞', '綱', '耕', '考', '肯', '肱', '腔', '膏', '航', '荒', '行', '衡', '講', '貢', '購', '郊', '酵', '鉱', '砿', '鋼', '閤', '降', '\u{FFFD}', '項', '香', '高', '鴻', '剛', '劫', '号', '合', '壕', '拷', '濠', '豪', '轟', '麹', '克', '刻', '告', '国', '穀', '酷', '鵠', '黒', '獄', '漉', '腰', '甑', '忽', '惚', '骨', '狛', '込', '此', '頃', '今', '困', '坤', '墾', '婚', '恨', '懇', '昏', '昆', '根', '梱', '混', '痕', '紺', '艮', '魂', '些', '佐', '叉', '唆', '嵯', '左', '差', '査', '沙', '瑳', '砂', '詐', '鎖', '裟', '坐', '座', '挫', '債', '催', '再', '最', '哉', '塞', '妻', '宰', '彩', '才', '採', '栽', '歳', '済', '災', '采', '犀', '砕', '砦', '祭', '斎', '細', '菜', '裁', '載', '際', '剤', '在', '材', '罪', '財', '冴', '坂', '阪', '堺', '榊', '肴', '咲', '崎', '埼', '碕', '鷺', '作', '削', '咋', '搾', '昨', '朔', '柵', '窄', '策', '索', '錯', '桜', '鮭', '笹', '匙', '冊', '刷', '\u{FFFD}', '\u{FFFD}']),
+    (0x8E, ['察', '拶', '撮', '擦', '札', '殺', '薩', '雑', '皐', '鯖', '捌', '錆', '鮫', '皿', '晒', '三', '傘', '参', '山', '惨', '撒', '散', '桟', '燦', '珊', '産', '算', '纂', '蚕', '讃', '賛', '酸', '餐', '斬', '暫', '残', '仕', '仔', '伺', '使', '刺', '司', '史', '嗣', '四', '士', '始', '姉', '姿', '子', '屍', '市', '師', '志', '思', '指', '支', '孜', '斯', '施', '旨', '枝', '止', '\u{FFFD}', '死', '氏', '獅', '祉', '私', '糸', '紙', '紫', '肢', '脂', '至', '視', '詞', '詩', '試', '誌', '諮', '資', '賜', '雌', '飼', '歯', '事', '似', '侍', '児', '字', '寺', '慈', '持', '時', '次', '滋', '治', '爾', '璽', '痔', '磁', '示', '而', '耳', '自', '蒔', '辞', '汐', '鹿', '式', '識', '鴫', '竺', '軸', '宍', '雫', '七', '叱', '執', '失', '嫉', '室', '悉', '湿', '漆', '疾', '質', '実', '蔀', '篠', '偲', '柴', '芝', '屡', '蕊', '縞', '舎', '写', '射', '捨', '赦', '斜', '煮', '社', '紗', '者', '謝', '車', '遮', '蛇', '邪', '借', '勺', '尺', '杓', '灼', '爵', '酌', '釈', '錫', '若', '寂', '弱', '惹', '主', '取', '守', '手', '朱', '殊', '狩', '珠', '種', '腫', '趣', '酒', '首', '儒', '受', '呪', '寿', '授', '樹', '綬', '需', '囚', '収', '周', '\u{FFFD}', '\u{FFFD}']),
+    (0x8F, ['宗', '就', '州', '修', '愁', '拾', '洲', '秀', '秋', '終', '繍', '習', '臭', '舟', '蒐', '衆', '襲', '讐', '蹴', '輯', '週', '酋', '酬', '集', '醜', '什', '住', '充', '十', '従', '戎', '柔', '汁', '渋', '獣', '縦', '重', '銃', '叔', '夙', '宿', '淑', '祝', '縮', '粛', '塾', '熟', '出', '術', '述', '俊', '峻', '春', '瞬', '竣', '舜', '駿', '准', '循', '旬', '楯', '殉', '淳', '\u{FFFD}', '準', '潤', '盾', '純', '巡', '遵', '醇', '順', '処', '初', '所', '暑', '曙', '渚', '庶', '緒', '署', '書', '薯', '藷', '諸', '助', '叙', '女', '序', '徐', '恕', '鋤', '除', '傷', '償', '勝', '匠', '升', '召', '哨', '商', '唱', '嘗', '奨', '妾', '娼', '宵', '将', '小', '少', '尚', '庄', '床', '廠', '彰', '承', '抄', '招', '掌', '捷', '昇', '昌', '昭', '晶', '松', '梢', '樟', '樵', '沼', '消', '渉', '湘', '焼', '焦', '照', '症', '省', '硝', '礁', '祥', '称', '章', '笑', '粧', '紹', '肖', '菖', '蒋', '蕉', '衝', '裳', '訟', '証', '詔', '詳', '象', '賞', '醤', '鉦', '鍾', '鐘', '障', '鞘', '上', '丈', '丞', '乗', '冗', '剰', '城', '場', '壌', '嬢', '常', '情', '擾', '条', '杖', '浄', '状', '畳', '穣', '蒸', '譲', '醸', '錠', '嘱', '埴', '飾', '\u{FFFD}', '\u{FFFD}']),
+    (0x90, ['拭', '植', '殖', '燭', '織', '職', '色', '触', '食', '蝕', '辱', '尻', '伸', '信', '侵', '唇', '娠', '寝', '審', '心', '慎', '振', '新', '晋', '森', '榛', '浸', '深', '申', '疹', '真', '神', '秦', '紳', '臣', '芯', '薪', '親', '診', '身', '辛', '進', '針', '震', '人', '仁', '刃', '塵', '壬', '尋', '甚', '尽', '腎', '訊', '迅', '陣', '靭', '笥', '諏', '須', '酢', '図', '厨', '\u{FFFD}', '逗', '吹', '垂', '帥', '推', '水', '炊', '睡', '粋', '翠', '衰', '遂', '酔', '錐', '錘', '随', '瑞', '髄', '崇', '嵩', '数', '枢', '趨', '雛', '据', '杉', '椙', '菅', '頗', '雀', '裾', '澄', '摺', '寸', '世', '瀬', '畝', '是', '凄', '制', '勢', '姓', '征', '性', '成', '政', '整', '星', '晴', '棲', '栖', '正', '清', '牲', '生', '盛', '精', '聖', '声', '製', '西', '誠', '誓', '請', '逝', '醒', '青', '静', '斉', '税', '脆', '隻', '席', '惜', '戚', '斥', '昔', '析', '石', '積', '籍', '績', '脊', '責', '赤', '跡', '蹟', '碩', '切', '拙', '接', '摂', '折', '設', '窃', '節', '説', '雪', '絶', '舌', '蝉', '仙', '先', '千', '占', '宣', '専', '尖', '川', '戦', '扇', '撰', '栓', '栴', '泉', '浅', '洗', '染', '潜', '煎', '煽', '旋', '穿', '箭', '線', '\u{FFFD}', '\u{FFFD}']),
+    (0x91, ['繊', '羨', '腺', '舛', '船', '薦', '詮', '賎', '践', '選', '遷', '銭', '銑', '閃', '鮮', '前', '善', '漸', '然', '全', '禅', '繕', '膳', '糎', '噌', '塑', '岨', '措', '曾', '曽', '楚', '狙', '疏', '疎', '礎', '祖', '租', '粗', '素', '組', '蘇', '訴', '阻', '遡', '鼠', '僧', '創', '双', '叢', '倉', '喪', '壮', '奏', '爽', '宋', '層', '匝', '惣', '想', '捜', '掃', '挿', '掻', '\u{FFFD}', '操', '早', '曹', '巣', '槍', '槽', '漕', '燥', '争', '痩', '相', '窓', '糟', '総', '綜', '聡', '草', '荘', '葬', '蒼', '藻', '装', '走', '送', '遭', '鎗', '霜', '騒', '像', '増', '憎', '臓', '蔵', '贈', '造', '促', '側', '則', '即', '息', '捉', '束', '測', '足', '速', '俗', '属', '賊', '族', '続', '卒', '袖', '其', '揃', '存', '孫', '尊', '損', '村', '遜', '他', '多', '太', '汰', '詑', '唾', '堕', '妥', '惰', '打', '柁', '舵', '楕', '陀', '駄', '騨', '体', '堆', '対', '耐', '岱', '帯', '待', '怠', '態', '戴', '替', '泰', '滞', '胎', '腿', '苔', '袋', '貸', '退', '逮', '隊', '黛', '鯛', '代', '台', '大', '第', '醍', '題', '鷹', '滝', '瀧', '卓', '啄', '宅', '托', '択', '拓', '沢', '濯', '琢', '託', '鐸', '濁', '諾', '茸', '凧', '蛸', '只', '\u{FFFD}', '\u{FFFD}']),
+    (0x92, ['叩', '但', '達', '辰', '奪', '脱', '巽', '竪', '辿', '棚', '谷', '狸', '鱈', '樽', '誰', '丹', '単', '嘆', '坦', '担', '探', '旦', '歎', '淡', '湛', '炭', '短', '端', '箪', '綻', '耽', '胆', '蛋', '誕', '鍛', '団', '壇', '弾', '断', '暖', '檀', '段', '男', '談', '値', '知', '地', '弛', '恥', '智', '池', '痴', '稚', '置', '致', '蜘', '遅', '馳', '築', '畜', '竹', '筑', '蓄', '\u{FFFD}', '逐', '秩', '窒', '茶', '嫡', '着', '中', '仲', '宙', '忠', '抽', '昼', '柱', '注', '虫', '衷', '註', '酎', '鋳', '駐', '樗', '瀦', '猪', '苧', '著', '貯', '丁', '兆', '凋', '喋', '寵', '帖', '帳', '庁', '弔', '張', '彫', '徴', '懲', '挑', '暢', '朝', '潮', '牒', '町', '眺', '聴', '脹', '腸', '蝶', '調', '諜', '超', '跳', '銚', '長', '頂', '鳥', '勅', '捗', '直', '朕', '沈', '珍', '賃', '鎮', '陳', '津', '墜', '椎', '槌', '追', '鎚', '痛', '通', '塚', '栂', '掴', '槻', '佃', '漬', '柘', '辻', '蔦', '綴', '鍔', '椿', '潰', '坪', '壷', '嬬', '紬', '爪', '吊', '釣', '鶴', '亭', '低', '停', '偵', '剃', '貞', '呈', '堤', '定', '帝', '底', '庭', '廷', '弟', '悌', '抵', '挺', '提', '梯', '汀', '碇', '禎', '程', '締', '艇', '訂', '諦', '蹄', '逓', '\u{FFFD}', '\u{FFFD}']),
+    (0x93, ['邸', '鄭', '釘', '鼎', '泥', '摘', '擢', '敵', '滴', '的', '笛', '適', '鏑', '溺', '哲', '徹', '撤', '轍', '迭', '鉄', '典', '填', '天', '展', '店', '添', '纏', '甜', '貼', '転', '顛', '点', '伝', '殿', '澱', '田', '電', '兎', '吐', '堵', '塗', '妬', '屠', '徒', '斗', '杜', '渡', '登', '菟', '賭', '途', '都', '鍍', '砥', '砺', '努', '度', '土', '奴', '怒', '倒', '党', '冬', '\u{FFFD}', '凍', '刀', '唐', '塔', '塘', '套', '宕', '島', '嶋', '悼', '投', '搭', '東', '桃', '梼', '棟', '盗', '淘', '湯', '涛', '灯', '燈', '当', '痘', '祷', '等', '答', '筒', '糖', '統', '到', '董', '蕩', '藤', '討', '謄', '豆', '踏', '逃', '透', '鐙', '陶', '頭', '騰', '闘', '働', '動', '同', '堂', '導', '憧', '撞', '洞', '瞳', '童', '胴', '萄', '道', '銅', '峠', '鴇', '匿', '得', '徳', '涜', '特', '督', '禿', '篤', '毒', '独', '読', '栃', '橡', '凸', '突', '椴', '届', '鳶', '苫', '寅', '酉', '瀞', '噸', '屯', '惇', '敦', '沌', '豚', '遁', '頓', '呑', '曇', '鈍', '奈', '那', '内', '乍', '凪', '薙', '謎', '灘', '捺', '鍋', '楢', '馴', '縄', '畷', '南', '楠', '軟', '難', '汝', '二', '尼', '弐', '迩', '匂', '賑', '肉', '虹', '廿', '日', '乳', '入', '\u{FFFD}', '\u{FFFD}']),
+    (0x94, ['如', '尿', '韮', '任', '妊', '忍', '認', '濡', '禰', '祢', '寧', '葱', '猫', '熱', '年', '念', '捻', '撚', '燃', '粘', '乃', '廼', '之', '埜', '嚢', '悩', '濃', '納', '能', '脳', '膿', '農', '覗', '蚤', '巴', '把', '播', '覇', '杷', '波', '派', '琶', '破', '婆', '罵', '芭', '馬', '俳', '廃', '拝', '排', '敗', '杯', '盃', '牌', '背', '肺', '輩', '配', '倍', '培', '媒', '梅', '\u{FFFD}', '楳', '煤', '狽', '買', '売', '賠', '陪', '這', '蝿', '秤', '矧', '萩', '伯', '剥', '博', '拍', '柏', '泊', '白', '箔', '粕', '舶', '薄', '迫', '曝', '漠', '爆', '縛', '莫', '駁', '麦', '函', '箱', '硲', '箸', '肇', '筈', '櫨', '幡', '肌', '畑', '畠', '八', '鉢', '溌', '発', '醗', '髪', '伐', '罰', '抜', '筏', '閥', '鳩', '噺', '塙', '蛤', '隼', '伴', '判', '半', '反', '叛', '帆', '搬', '斑', '板', '氾', '汎', '版', '犯', '班', '畔', '繁', '般', '藩', '販', '範', '釆', '煩', '頒', '飯', '挽', '晩', '番', '盤', '磐', '蕃', '蛮', '匪', '卑', '否', '妃', '庇', '彼', '悲', '扉', '批', '披', '斐', '比', '泌', '疲', '皮', '碑', '秘', '緋', '罷', '肥', '被', '誹', '費', '避', '非', '飛', '樋', '簸', '備', '尾', '微', '枇', '毘', '琵', '眉', '美', '\u{FFFD}', '\u{FFFD}']),
+    (0x95, ['鼻', '柊', '稗', '匹', '疋', '髭', '彦', '膝', '菱', '肘', '弼', '必', '畢', '筆', '逼', '桧', '姫', '媛', '紐', '百', '謬', '俵', '彪', '標', '氷', '漂', '瓢', '票', '表', '評', '豹', '廟', '描', '病', '秒', '苗', '錨', '鋲', '蒜', '蛭', '鰭', '品', '彬', '斌', '浜', '瀕', '貧', '賓', '頻', '敏', '瓶', '不', '付', '埠', '夫', '婦', '富', '冨', '布', '府', '怖', '扶', '敷', '\u{FFFD}', '斧', '普', '浮', '父', '符', '腐', '膚', '芙', '譜', '負', '賦', '赴', '阜', '附', '侮', '撫', '武', '舞', '葡', '蕪', '部', '封', '楓', '風', '葺', '蕗', '伏', '副', '復', '幅', '服', '福', '腹', '複', '覆', '淵', '弗', '払', '沸', '仏', '物', '鮒', '分', '吻', '噴', '墳', '憤', '扮', '焚', '奮', '粉', '糞', '紛', '雰', '文', '聞', '丙', '併', '兵', '塀', '幣', '平', '弊', '柄', '並', '蔽', '閉', '陛', '米', '頁', '僻', '壁', '癖', '碧', '別', '瞥', '蔑', '箆', '偏', '変', '片', '篇', '編', '辺', '返', '遍', '便', '勉', '娩', '弁', '鞭', '保', '舗', '鋪', '圃', '捕', '歩', '甫', '補', '輔', '穂', '募', '墓', '慕', '戊', '暮', '母', '簿', '菩', '倣', '俸', '包', '呆', '報', '奉', '宝', '峰', '峯', '崩', '庖', '抱', '捧', '放', '方', '朋', '\u{FFFD}', '\u{FFFD}']),
+    (0x96, ['法', '泡', '烹', '砲', '縫', '胞', '芳', '萌', '蓬', '蜂', '褒', '訪', '豊', '邦', '鋒', '飽', '鳳', '鵬', '乏', '亡', '傍', '剖', '坊', '妨', '帽', '忘', '忙', '房', '暴', '望', '某', '棒', '冒', '紡', '肪', '膨', '謀', '貌', '貿', '鉾', '防', '吠', '頬', '北', '僕', '卜', '墨', '撲', '朴', '牧', '睦', '穆', '釦', '勃', '没', '殆', '堀', '幌', '奔', '本', '翻', '凡', '盆', '\u{FFFD}', '摩', '磨', '魔', '麻', '埋', '妹', '昧', '枚', '毎', '哩', '槙', '幕', '膜', '枕', '鮪', '柾', '鱒', '桝', '亦', '俣', '又', '抹', '末', '沫', '迄', '侭', '繭', '麿', '万', '慢', '満', '漫', '蔓', '味', '未', '魅', '巳', '箕', '岬', '密', '蜜', '湊', '蓑', '稔', '脈', '妙', '粍', '民', '眠', '務', '夢', '無', '牟', '矛', '霧', '鵡', '椋', '婿', '娘', '冥', '名', '命', '明', '盟', '迷', '銘', '鳴', '姪', '牝', '滅', '免', '棉', '綿', '緬', '面', '麺', '摸', '模', '茂', '妄', '孟', '毛', '猛', '盲', '網', '耗', '蒙', '儲', '木', '黙', '目', '杢', '勿', '餅', '尤', '戻', '籾', '貰', '問', '悶', '紋', '門', '匁', '也', '冶', '夜', '爺', '耶', '野', '弥', '矢', '厄', '役', '約', '薬', '訳', '躍', '靖', '柳', '薮', '鑓', '愉', '愈', '油', '癒', '\u{FFFD}', '\u{FFFD}']),
+    (0x97, ['諭', '輸', '唯', '佑', '優', '勇', '友', '宥', '幽', '悠', '憂', '揖', '有', '柚', '湧', '涌', '猶', '猷', '由', '祐', '裕', '誘', '遊', '邑', '郵', '雄', '融', '夕', '予', '余', '与', '誉', '輿', '預', '傭', '幼', '妖', '容', '庸', '揚', '揺', '擁', '曜', '楊', '様', '洋', '溶', '熔', '用', '窯', '羊', '耀', '葉', '蓉', '要', '謡', '踊', '遥', '陽', '養', '慾', '抑', '欲', '\u{FFFD}', '沃', '浴', '翌', '翼', '淀', '羅', '螺', '裸', '来', '莱', '頼', '雷', '洛', '絡', '落', '酪', '乱', '卵', '嵐', '欄', '濫', '藍', '蘭', '覧', '利', '吏', '履', '李', '梨', '理', '璃', '痢', '裏', '裡', '里', '離', '陸', '律', '率', '立', '葎', '掠', '略', '劉', '流', '溜', '琉', '留', '硫', '粒', '隆', '竜', '龍', '侶', '慮', '旅', '虜', '了', '亮', '僚', '両', '凌', '寮', '料', '梁', '涼', '猟', '療', '瞭', '稜', '糧', '良', '諒', '遼', '量', '陵', '領', '力', '緑', '倫', '厘', '林', '淋', '燐', '琳', '臨', '輪', '隣', '鱗', '麟', '瑠', '塁', '涙', '累', '類', '令', '伶', '例', '冷', '励', '嶺', '怜', '玲', '礼', '苓', '鈴', '隷', '零', '霊', '麗', '齢', '暦', '歴', '列', '劣', '烈', '裂', '廉', '恋', '憐', '漣', '煉', '簾', '練', '聯', '\u{FFFD}', '\u{FFFD}']),
+    (0x98, ['蓮', '連', '錬', '呂', '魯', '櫓', '炉', '賂', '路', '露', '労', '婁', '廊', '弄', '朗', '楼', '榔', '浪', '漏', '牢', '狼', '篭', '老', '聾', '蝋', '郎', '六', '麓', '禄', '肋', '録', '論', '倭', '和', '話', '歪', '賄', '脇', '惑', '枠', '鷲', '亙', '亘', '鰐', '詫', '藁', '蕨', '椀', '湾', '碗', '腕', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '弌', '丐', '丕', '个', '丱', '丶', '丼', '丿', '乂', '乖', '乘', '亂', '亅', '豫', '亊', '舒', '弍', '于', '亞', '亟', '亠', '亢', '亰', '亳', '亶', '从', '仍', '仄', '仆', '仂', '仗', '仞', '仭', '仟', '价', '伉', '佚', '估', '佛', '佝', '佗', '佇', '佶', '侈', '侏', '侘', '佻', '佩', '佰', '侑', '佯', '來', '侖', '儘', '俔', '俟', '俎', '俘', '俛', '俑', '俚', '俐', '俤', '俥', '倚', '倨', '倔', '倪', '倥', '倅', '伜', '俶', '倡', '倩', '倬', '俾', '俯', '們', '倆', '偃', '假', '會', '偕', '偐', '偈', '做', '偖', '偬', '偸', '傀', '傚', '傅', '傴', '傲', '\u{FFFD}', '\u{FFFD}']),
+    (0x99, ['僉', '僊', '傳', '僂', '僖', '僞', '僥', '僭', '僣', '僮', '價', '僵', '儉', '儁', '儂', '儖', '儕', '儔', '儚', '儡', '儺', '儷', '儼', '儻', '儿', '兀', '兒', '兌', '兔', '兢', '竸', '兩', '兪', '兮', '冀', '冂', '囘', '册', '冉', '冏', '冑', '冓', '冕', '冖', '冤', '冦', '冢', '冩', '冪', '冫', '决', '冱', '冲', '冰', '况', '冽', '凅', '凉', '凛', '几', '處', '凩', '凭', '\u{FFFD}', '凰', '凵', '凾', '刄', '刋', '刔', '刎', '刧', '刪', '刮', '刳', '刹', '剏', '剄', '剋', '剌', '剞', '剔', '剪', '剴', '剩', '剳', '剿', '剽', '劍', '劔', '劒', '剱', '劈', '劑', '辨', '辧', '劬', '劭', '劼', '劵', '勁', '勍', '勗', '勞', '勣', '勦', '飭', '勠', '勳', '勵', '勸', '勹', '匆', '匈', '甸', '匍', '匐', '匏', '匕', '匚', '匣', '匯', '匱', '匳', '匸', '區', '卆', '卅', '丗', '卉', '卍', '凖', '卞', '卩', '卮', '夘', '卻', '卷', '厂', '厖', '厠', '厦', '厥', '厮', '厰', '厶', '參', '簒', '雙', '叟', '曼', '燮', '叮', '叨', '叭', '叺', '吁', '吽', '呀', '听', '吭', '吼', '吮', '吶', '吩', '吝', '呎', '咏', '呵', '咎', '呟', '呱', '呷', '呰', '咒', '呻', '咀', '呶', '咄', '咐', '咆', '哇', '咢', '咸', '咥', '咬', '哄', '哈', '咨', '\u{FFFD}', '\u{FFFD}']),
+    (0x9A, ['咫', '哂', '咤', '咾', '咼', '哘', '哥', '哦', '唏', '唔', '哽', '哮', '哭', '哺', '哢', '唹', '啀', '啣', '啌', '售', '啜', '啅', '啖', '啗', '唸', '唳', '啝', '喙', '喀', '咯', '喊', '喟', '啻', '啾', '喘', '喞', '單', '啼', '喃', '喩', '喇', '喨', '嗚', '嗅', '嗟', '嗄', '嗜', '嗤', '嗔', '嘔', '嗷', '嘖', '嗾', '嗽', '嘛', '嗹', '噎', '噐', '營', '嘴', '嘶', '嘲', '嘸', '\u{FFFD}', '噫', '噤', '嘯', '噬', '噪', '嚆', '嚀', '嚊', '嚠', '嚔', '嚏', '嚥', '嚮', '嚶', '嚴', '囂', '嚼', '囁', '囃', '囀', '囈', '囎', '囑', '囓', '囗', '囮', '囹', '圀', '囿', '圄', '圉', '圈', '國', '圍', '圓', '團', '圖', '嗇', '圜', '圦', '圷', '圸', '坎', '圻', '址', '坏', '坩', '埀', '垈', '坡', '坿', '垉', '垓', '垠', '垳', '垤', '垪', '垰', '埃', '埆', '埔', '埒', '埓', '堊', '埖', '埣', '堋', '堙', '堝', '塲', '堡', '塢', '塋', '塰', '毀', '塒', '堽', '塹', '墅', '墹', '墟', '墫', '墺', '壞', '墻', '墸', '墮', '壅', '壓', '壑', '壗', '壙', '壘', '壥', '壜', '壤', '壟', '壯', '壺', '壹', '壻', '壼', '壽', '夂', '夊', '夐', '夛', '梦', '夥', '夬', '夭', '夲', '夸', '夾', '竒', '奕', '奐', '奎', '奚', '奘', '奢', '奠', '奧', '奬', '奩', '\u{FFFD}', '\u{FFFD}']),
+    (0x9B, ['奸', '妁', '妝', '佞', '侫', '妣', '妲', '姆', '姨', '姜', '妍', '姙', '姚', '娥', '娟', '娑', '娜', '娉', '娚', '婀', '婬', '婉', '娵', '娶', '婢', '婪', '媚', '媼', '媾', '嫋', '嫂', '媽', '嫣', '嫗', '嫦', '嫩', '嫖', '嫺', '嫻', '嬌', '嬋', '嬖', '嬲', '嫐', '嬪', '嬶', '嬾', '孃', '孅', '孀', '孑', '孕', '孚', '孛', '孥', '孩', '孰', '孳', '孵', '學', '斈', '孺', '宀', '\u{FFFD}', '它', '宦', '宸', '寃', '寇', '寉', '寔', '寐', '寤', '實', '寢', '寞', '寥', '寫', '寰', '寶', '寳', '尅', '將', '專', '對', '尓', '尠', '尢', '尨', '尸', '尹', '屁', '屆', '屎', '屓', '屐', '屏', '孱', '屬', '屮', '乢', '屶', '屹', '岌', '岑', '岔', '妛', '岫', '岻', '岶', '岼', '岷', '峅', '岾', '峇', '峙', '峩', '峽', '峺', '峭', '嶌', '峪', '崋', '崕', '崗', '嵜', '崟', '崛', '崑', '崔', '崢', '崚', '崙', '崘', '嵌', '嵒', '嵎', '嵋', '嵬', '嵳', '嵶', '嶇', '嶄', '嶂', '嶢', '嶝', '嶬', '嶮', '嶽', '嶐', '嶷', '嶼', '巉', '巍', '巓', '巒', '巖', '巛', '巫', '已', '巵', '帋', '帚', '帙', '帑', '帛', '帶', '帷', '幄', '幃', '幀', '幎', '幗', '幔', '幟', '幢', '幤', '幇', '幵', '并', '幺', '麼', '广', '庠', '廁', '廂', '廈', '廐', '廏', '\u{FFFD}', '\u{FFFD}']),
+    (0x9C, ['廖', '廣', '廝', '廚', '廛', '廢', '廡', '廨', '廩', '廬', '廱', '廳', '廰', '廴', '廸', '廾', '弃', '弉', '彝', '彜', '弋', '弑', '弖', '弩', '弭', '弸', '彁', '彈', '彌', '彎', '弯', '彑', '彖', '彗', '彙', '彡', '彭', '彳', '彷', '徃', '徂', '彿', '徊', '很', '徑', '徇', '從', '徙', '徘', '徠', '徨', '徭', '徼', '忖', '忻', '忤', '忸', '忱', '忝', '悳', '忿', '怡', '恠', '\u{FFFD}', '怙', '怐', '怩', '怎', '怱', '怛', '怕', '怫', '怦', '怏', '怺', '恚', '恁', '恪', '恷', '恟', '恊', '恆', '恍', '恣', '恃', '恤', '恂', '恬', '恫', '恙', '悁', '悍', '惧', '悃', '悚', '悄', '悛', '悖', '悗', '悒', '悧', '悋', '惡', '悸', '惠', '惓', '悴', '忰', '悽', '惆', '悵', '惘', '慍', '愕', '愆', '惶', '惷', '愀', '惴', '惺', '愃', '愡', '惻', '惱', '愍', '愎', '慇', '愾', '愨', '愧', '慊', '愿', '愼', '愬', '愴', '愽', '慂', '慄', '慳', '慷', '慘', '慙', '慚', '慫', '慴', '慯', '慥', '慱', '慟', '慝', '慓', '慵', '憙', '憖', '憇', '憬', '憔', '憚', '憊', '憑', '憫', '憮', '懌', '懊', '應', '懷', '懈', '懃', '懆', '憺', '懋', '罹', '懍', '懦', '懣', '懶', '懺', '懴', '懿', '懽', '懼', '懾', '戀', '戈', '戉', '戍', '戌', '戔', '戛', '\u{FFFD}', '\u{FFFD}']),
+    (0x9D, ['戞', '戡', '截', '戮', '戰', '戲', '戳', '扁', '扎', '扞', '扣', '扛', '扠', '扨', '扼', '抂', '抉', '找', '抒', '抓', '抖', '拔', '抃', '抔', '拗', '拑', '抻', '拏', '拿', '拆', '擔', '拈', '拜', '拌', '拊', '拂', '拇', '抛', '拉', '挌', '拮', '拱', '挧', '挂', '挈', '拯', '拵', '捐', '挾', '捍', '搜', '捏', '掖', '掎', '掀', '掫', '捶', '掣', '掏', '掉', '掟', '掵', '捫', '\u{FFFD}', '捩', '掾', '揩', '揀', '揆', '揣', '揉', '插', '揶', '揄', '搖', '搴', '搆', '搓', '搦', '搶', '攝', '搗', '搨', '搏', '摧', '摯', '摶', '摎', '攪', '撕', '撓', '撥', '撩', '撈', '撼', '據', '擒', '擅', '擇', '撻', '擘', '擂', '擱', '擧', '舉', '擠', '擡', '抬', '擣', '擯', '攬', '擶', '擴', '擲', '擺', '攀', '擽', '攘', '攜', '攅', '攤', '攣', '攫', '攴', '攵', '攷', '收', '攸', '畋', '效', '敖', '敕', '敍', '敘', '敞', '敝', '敲', '數', '斂', '斃', '變', '斛', '斟', '斫', '斷', '旃', '旆', '旁', '旄', '旌', '旒', '旛', '旙', '无', '旡', '旱', '杲', '昊', '昃', '旻', '杳', '昵', '昶', '昴', '昜', '晏', '晄', '晉', '晁', '晞', '晝', '晤', '晧', '晨', '晟', '晢', '晰', '暃', '暈', '暎', '暉', '暄', '暘', '暝', '曁', '暹', '曉', '暾', '暼', '\u{FFFD}', '\u{FFFD}']),
+    (0x9E, ['曄', '暸', '曖', '曚', '曠', '昿', '曦', '曩', '曰', '曵', '曷', '朏', '朖', '朞', '朦', '朧', '霸', '朮', '朿', '朶', '杁', '朸', '朷', '杆', '杞', '杠', '杙', '杣', '杤', '枉', '杰', '枩', '杼', '杪', '枌', '枋', '枦', '枡', '枅', '枷', '柯', '枴', '柬', '枳', '柩', '枸', '柤', '柞', '柝', '柢', '柮', '枹', '柎', '柆', '柧', '檜', '栞', '框', '栩', '桀', '桍', '栲', '桎', '\u{FFFD}', '梳', '栫', '桙', '档', '桷', '桿', '梟', '梏', '梭', '梔', '條', '梛', '梃', '檮', '梹', '桴', '梵', '梠', '梺', '椏', '梍', '桾', '椁', '棊', '椈', '棘', '椢', '椦', '棡', '椌', '棍', '棔', '棧', '棕', '椶', '椒', '椄', '棗', '棣', '椥', '棹', '棠', '棯', '椨', '椪', '椚', '椣', '椡', '棆', '楹', '楷', '楜', '楸', '楫', '楔', '楾', '楮', '椹', '楴', '椽', '楙', '椰', '楡', '楞', '楝', '榁', '楪', '榲', '榮', '槐', '榿', '槁', '槓', '榾', '槎', '寨', '槊', '槝', '榻', '槃', '榧', '樮', '榑', '榠', '榜', '榕', '榴', '槞', '槨', '樂', '樛', '槿', '權', '槹', '槲', '槧', '樅', '榱', '樞', '槭', '樔', '槫', '樊', '樒', '櫁', '樣', '樓', '橄', '樌', '橲', '樶', '橸', '橇', '橢', '橙', '橦', '橈', '樸', '樢', '檐', '檍', '檠', '檄', '檢', '檣', '\u{FFFD}', '\u{FFFD}']),
+    (0x9F, ['檗', '蘗', '檻', '櫃', '櫂', '檸', '檳', '檬', '櫞', '櫑', '櫟', '檪', '櫚', '櫪', '櫻', '欅', '蘖', '櫺', '欒', '欖', '鬱', '欟', '欸', '欷', '盜', '欹', '飮', '歇', '歃', '歉', '歐', '歙', '歔', '歛', '歟', '歡', '歸', '歹', '歿', '殀', '殄', '殃', '殍', '殘', '殕', '殞', '殤', '殪', '殫', '殯', '殲', '殱', '殳', '殷', '殼', '毆', '毋', '毓', '毟', '毬', '毫', '毳', '毯', '\u{FFFD}', '麾', '氈', '氓', '气', '氛', '氤', '氣', '汞', '汕', '汢', '汪', '沂', '沍', '沚', '沁', '沛', '汾', '汨', '汳', '沒', '沐', '泄', '泱', '泓', '沽', '泗', '泅', '泝', '沮', '沱', '沾', '沺', '泛', '泯', '泙', '泪', '洟', '衍', '洶', '洫', '洽', '洸', '洙', '洵', '洳', '洒', '洌', '浣', '涓', '浤', '浚', '浹', '浙', '涎', '涕', '濤', '涅', '淹', '渕', '渊', '涵', '淇', '淦', '涸', '淆', '淬', '淞', '淌', '淨', '淒', '淅', '淺', '淙', '淤', '淕', '淪', '淮', '渭', '湮', '渮', '渙', '湲', '湟', '渾', '渣', '湫', '渫', '湶', '湍', '渟', '湃', '渺', '湎', '渤', '滿', '渝', '游', '溂', '溪', '溘', '滉', '溷', '滓', '溽', '溯', '滄', '溲', '滔', '滕', '溏', '溥', '滂', '溟', '潁', '漑', '灌', '滬', '滸', '滾', '漿', '滲', '漱', '滯', '漲', '滌', '\u{FFFD}', '\u{FFFD}']),
+    (0xE0, ['漾', '漓', '滷', '澆', '潺', '潸', '澁', '澀', '潯', '潛', '濳', '潭', '澂', '潼', '潘', '澎', '澑', '濂', '潦', '澳', '澣', '澡', '澤', '澹', '濆', '澪', '濟', '濕', '濬', '濔', '濘', '濱', '濮', '濛', '瀉', '瀋', '濺', '瀑', '瀁', '瀏', '濾', '瀛', '瀚', '潴', '瀝', '瀘', '瀟', '瀰', '瀾', '瀲', '灑', '灣', '炙', '炒', '炯', '烱', '炬', '炸', '炳', '炮', '烟', '烋', '烝', '\u{FFFD}', '烙', '焉', '烽', '焜', '焙', '煥', '煕', '熈', '煦', '煢', '煌', '煖', '煬', '熏', '燻', '熄', '熕', '熨', '熬', '燗', '熹', '熾', '燒', '燉', '燔', '燎', '燠', '燬', '燧', '燵', '燼', '燹', '燿', '爍', '爐', '爛', '爨', '爭', '爬', '爰', '爲', '爻', '爼', '爿', '牀', '牆', '牋', '牘', '牴', '牾', '犂', '犁', '犇', '犒', '犖', '犢', '犧', '犹', '犲', '狃', '狆', '狄', '狎', '狒', '狢', '狠', '狡', '狹', '狷', '倏', '猗', '猊', '猜', '猖', '猝', '猴', '猯', '猩', '猥', '猾', '獎', '獏', '默', '獗', '獪', '獨', '獰', '獸', '獵', '獻', '獺', '珈', '玳', '珎', '玻', '珀', '珥', '珮', '珞', '璢', '琅', '瑯', '琥', '珸', '琲', '琺', '瑕', '琿', '瑟', '瑙', '瑁', '瑜', '瑩', '瑰', '瑣', '瑪', '瑶', '瑾', '璋', '璞', '璧', '瓊', '瓏', '瓔', '珱', '\u{FFFD}', '\u{FFFD}']),
+    (0xE1, ['瓠', '瓣', '瓧', '瓩', '瓮', '瓲', '瓰', '瓱', '瓸', '瓷', '甄', '甃', '甅', '甌', '甎', '甍', '甕', '甓', '甞', '甦', '甬', '甼', '畄', '畍', '畊', '畉', '畛', '畆', '畚', '畩', '畤', '畧', '畫', '畭', '畸', '當', '疆', '疇', '畴', '疊', '疉', '疂', '疔', '疚', '疝', '疥', '疣', '痂', '疳', '痃', '疵', '疽', '疸', '疼', '疱', '痍', '痊', '痒', '痙', '痣', '痞', '痾', '痿', '\u{FFFD}', '痼', '瘁', '痰', '痺', '痲', '痳', '瘋', '瘍', '瘉', '瘟', '瘧', '瘠', '瘡', '瘢', '瘤', '瘴', '瘰', '瘻', '癇', '癈', '癆', '癜', '癘', '癡', '癢', '癨', '癩', '癪', '癧', '癬', '癰', '癲', '癶', '癸', '發', '皀', '皃', '皈', '皋', '皎', '皖', '皓', '皙', '皚', '皰', '皴', '皸', '皹', '皺', '盂', '盍', '盖', '盒', '盞', '盡', '盥', '盧', '盪', '蘯', '盻', '眈', '眇', '眄', '眩', '眤', '眞', '眥', '眦', '眛', '眷', '眸', '睇', '睚', '睨', '睫', '睛', '睥', '睿', '睾', '睹', '瞎', '瞋', '瞑', '瞠', '瞞', '瞰', '瞶', '瞹', '瞿', '瞼', '瞽', '瞻', '矇', '矍', '矗', '矚', '矜', '矣', '矮', '矼', '砌', '砒', '礦', '砠', '礪', '硅', '碎', '硴', '碆', '硼', '碚', '碌', '碣', '碵', '碪', '碯', '磑', '磆', '磋', '磔', '碾', '碼', '磅', '磊', '磬', '\u{FFFD}', '\u{FFFD}']),
+    (0xE2, ['磧', '磚', '磽', '磴', '礇', '礒', '礑', '礙', '礬', '礫', '祀', '祠', '祗', '祟', '祚', '祕', '祓', '祺', '祿', '禊', '禝', '禧', '齋', '禪', '禮', '禳', '禹', '禺', '秉', '秕', '秧', '秬', '秡', '秣', '稈', '稍', '稘', '稙', '稠', '稟', '禀', '稱', '稻', '稾', '稷', '穃', '穗', '穉', '穡', '穢', '穩', '龝', '穰', '穹', '穽', '窈', '窗', '窕', '窘', '窖', '窩', '竈', '窰', '\u{FFFD}', '窶', '竅', '竄', '窿', '邃', '竇', '竊', '竍', '竏', '竕', '竓', '站', '竚', '竝', '竡', '竢', '竦', '竭', '竰', '笂', '笏', '笊', '笆', '笳', '笘', '笙', '笞', '笵', '笨', '笶', '筐', '筺', '笄', '筍', '笋', '筌', '筅', '筵', '筥', '筴', '筧', '筰', '筱', '筬', '筮', '箝', '箘', '箟', '箍', '箜', '箚', '箋', '箒', '箏', '筝', '箙', '篋', '篁', '篌', '篏', '箴', '篆', '篝', '篩', '簑', '簔', '篦', '篥', '籠', '簀', '簇', '簓', '篳', '篷', '簗', '簍', '篶', '簣', '簧', '簪', '簟', '簷', '簫', '簽', '籌', '籃', '籔', '籏', '籀', '籐', '籘', '籟', '籤', '籖', '籥', '籬', '籵', '粃', '粐', '粤', '粭', '粢', '粫', '粡', '粨', '粳', '粲', '粱', '粮', '粹', '粽', '糀', '糅', '糂', '糘', '糒', '糜', '糢', '鬻', '糯', '糲', '糴', '糶', '糺', '紆', '\u{FFFD}', '\u{FFFD}']),
+    (0xE3, ['紂', '紜', '紕', '紊', '絅', '絋', '紮', '紲', '紿', '紵', '絆', '絳', '絖', '絎', '絲', '絨', '絮', '絏', '絣', '經', '綉', '絛', '綏', '絽', '綛', '綺', '綮', '綣', '綵', '緇', '綽', '綫', '總', '綢', '綯', '緜', '綸', '綟', '綰', '緘', '緝', '緤', '緞', '緻', '緲', '緡', '縅', '縊', '縣', '縡', '縒', '縱', '縟', '縉', '縋', '縢', '繆', '繦', '縻', '縵', '縹', '繃', '縷', '\u{FFFD}', '縲', '縺', '繧', '繝', '繖', '繞', '繙', '繚', '繹', '繪', '繩', '繼', '繻', '纃', '緕', '繽', '辮', '繿', '纈', '纉', '續', '纒', '纐', '纓', '纔', '纖', '纎', '纛', '纜', '缸', '缺', '罅', '罌', '罍', '罎', '罐', '网', '罕', '罔', '罘', '罟', '罠', '罨', '罩', '罧', '罸', '羂', '羆', '羃', '羈', '羇', '羌', '羔', '羞', '羝', '羚', '羣', '羯', '羲', '羹', '羮', '羶', '羸', '譱', '翅', '翆', '翊', '翕', '翔', '翡', '翦', '翩', '翳', '翹', '飜', '耆', '耄', '耋', '耒', '耘', '耙', '耜', '耡', '耨', '耿', '耻', '聊', '聆', '聒', '聘', '聚', '聟', '聢', '聨', '聳', '聲', '聰', '聶', '聹', '聽', '聿', '肄', '肆', '肅', '肛', '肓', '肚', '肭', '冐', '肬', '胛', '胥', '胙', '胝', '胄', '胚', '胖', '脉', '胯', '胱', '脛', '脩', '脣', '脯', '腋', '\u{FFFD}', '\u{FFFD}']),
+    (0xE4, ['隋', '腆', '脾', '腓', '腑', '胼', '腱', '腮', '腥', '腦', '腴', '膃', '膈', '膊', '膀', '膂', '膠', '膕', '膤', '膣', '腟', '膓', '膩', '膰', '膵', '膾', '膸', '膽', '臀', '臂', '膺', '臉', '臍', '臑', '臙', '臘', '臈', '臚', '臟', '臠', '臧', '臺', '臻', '臾', '舁', '舂', '舅', '與', '舊', '舍', '舐', '舖', '舩', '舫', '舸', '舳', '艀', '艙', '艘', '艝', '艚', '艟', '艤', '\u{FFFD}', '艢', '艨', '艪', '艫', '舮', '艱', '艷', '艸', '艾', '芍', '芒', '芫', '芟', '芻', '芬', '苡', '苣', '苟', '苒', '苴', '苳', '苺', '莓', '范', '苻', '苹', '苞', '茆', '苜', '茉', '苙', '茵', '茴', '茖', '茲', '茱', '荀', '茹', '荐', '荅', '茯', '茫', '茗', '茘', '莅', '莚', '莪', '莟', '莢', '莖', '茣', '莎', '莇', '莊', '荼', '莵', '荳', '荵', '莠', '莉', '莨', '菴', '萓', '菫', '菎', '菽', '萃', '菘', '萋', '菁', '菷', '萇', '菠', '菲', '萍', '萢', '萠', '莽', '萸', '蔆', '菻', '葭', '萪', '萼', '蕚', '蒄', '葷', '葫', '蒭', '葮', '蒂', '葩', '葆', '萬', '葯', '葹', '萵', '蓊', '葢', '蒹', '蒿', '蒟', '蓙', '蓍', '蒻', '蓚', '蓐', '蓁', '蓆', '蓖', '蒡', '蔡', '蓿', '蓴', '蔗', '蔘', '蔬', '蔟', '蔕', '蔔', '蓼', '蕀', '蕣', '蕘', '蕈', '\u{FFFD}', '\u{FFFD}']),
+    (0xE5, ['蕁', '蘂', '蕋', '蕕', '薀', '薤', '薈', '薑', '薊', '薨', '蕭', '薔', '薛', '藪', '薇', '薜', '蕷', '蕾', '薐', '藉', '薺', '藏', '薹', '藐', '藕', '藝', '藥', '藜', '藹', '蘊', '蘓', '蘋', '藾', '藺', '蘆', '蘢', '蘚', '蘰', '蘿', '虍', '乕', '虔', '號', '虧', '虱', '蚓', '蚣', '蚩', '蚪', '蚋', '蚌', '蚶', '蚯', '蛄', '蛆', '蚰', '蛉', '蠣', '蚫', '蛔', '蛞', '蛩', '蛬', '\u{FFFD}', '蛟', '蛛', '蛯', '蜒', '蜆', '蜈', '蜀', '蜃', '蛻', '蜑', '蜉', '蜍', '蛹', '蜊', '蜴', '蜿', '蜷', '蜻', '蜥', '蜩', '蜚', '蝠', '蝟', '蝸', '蝌', '蝎', '蝴', '蝗', '蝨', '蝮', '蝙', '蝓', '蝣', '蝪', '蠅', '螢', '螟', '螂', '螯', '蟋', '螽', '蟀', '蟐', '雖', '螫', '蟄', '螳', '蟇', '蟆', '螻', '蟯', '蟲', '蟠', '蠏', '蠍', '蟾', '蟶', '蟷', '蠎', '蟒', '蠑', '蠖', '蠕', '蠢', '蠡', '蠱', '蠶', '蠹', '蠧', '蠻', '衄', '衂', '衒', '衙', '衞', '衢', '衫', '袁', '衾', '袞', '衵', '衽', '袵', '衲', '袂', '袗', '袒', '袮', '袙', '袢', '袍', '袤', '袰', '袿', '袱', '裃', '裄', '裔', '裘', '裙', '裝', '裹', '褂', '裼', '裴', '裨', '裲', '褄', '褌', '褊', '褓', '襃', '褞', '褥', '褪', '褫', '襁', '襄', '褻', '褶', '褸', '襌', '褝', '襠', '襞', '\u{FFFD}', '\u{FFFD}']),
+    (0xE6, ['襦', '襤', '襭', '襪', '襯', '襴', '襷', '襾', '覃', '覈', '覊', '覓', '覘', '覡', '覩', '覦', '覬', '覯', '覲', '覺', '覽', '覿', '觀', '觚', '觜', '觝', '觧', '觴', '觸', '訃', '訖', '訐', '訌', '訛', '訝', '訥', '訶', '詁', '詛', '詒', '詆', '詈', '詼', '詭', '詬', '詢', '誅', '誂', '誄', '誨', '誡', '誑', '誥', '誦', '誚', '誣', '諄', '諍', '諂', '諚', '諫', '諳', '諧', '\u{FFFD}', '諤', '諱', '謔', '諠', '諢', '諷', '諞', '諛', '謌', '謇', '謚', '諡', '謖', '謐', '謗', '謠', '謳', '鞫', '謦', '謫', '謾', '謨', '譁', '譌', '譏', '譎', '證', '譖', '譛', '譚', '譫', '譟', '譬', '譯', '譴', '譽', '讀', '讌', '讎', '讒', '讓', '讖', '讙', '讚', '谺', '豁', '谿', '豈', '豌', '豎', '豐', '豕', '豢', '豬', '豸', '豺', '貂', '貉', '貅', '貊', '貍', '貎', '貔', '豼', '貘', '戝', '貭', '貪', '貽', '貲', '貳', '貮', '貶', '賈', '賁', '賤', '賣', '賚', '賽', '賺', '賻', '贄', '贅', '贊', '贇', '贏', '贍', '贐', '齎', '贓', '賍', '贔', '贖', '赧', '赭', '赱', '赳', '趁', '趙', '跂', '趾', '趺', '跏', '跚', '跖', '跌', '跛', '跋', '跪', '跫', '跟', '跣', '跼', '踈', '踉', '跿', '踝', '踞', '踐', '踟', '蹂', '踵', '踰', '踴', '蹊', '\u{FFFD}', '\u{FFFD}']),
+    (0xE7, ['蹇', '蹉', '蹌', '蹐', '蹈', '蹙', '蹤', '蹠', '踪', '蹣', '蹕', '蹶', '蹲', '蹼', '躁', '躇', '躅', '躄', '躋', '躊', '躓', '躑', '躔', '躙', '躪', '躡', '躬', '躰', '軆', '躱', '躾', '軅', '軈', '軋', '軛', '軣', '軼', '軻', '軫', '軾', '輊', '輅', '輕', '輒', '輙', '輓', '輜', '輟', '輛', '輌', '輦', '輳', '輻', '輹', '轅', '轂', '輾', '轌', '轉', '轆', '轎', '轗', '轜', '\u{FFFD}', '轢', '轣', '轤', '辜', '辟', '辣', '辭', '辯', '辷', '迚', '迥', '迢', '迪', '迯', '邇', '迴', '逅', '迹', '迺', '逑', '逕', '逡', '逍', '逞', '逖', '逋', '逧', '逶', '逵', '逹', '迸', '遏', '遐', '遑', '遒', '逎', '遉', '逾', '遖', '遘', '遞', '遨', '遯', '遶', '隨', '遲', '邂', '遽', '邁', '邀', '邊', '邉', '邏', '邨', '邯', '邱', '邵', '郢', '郤', '扈', '郛', '鄂', '鄒', '鄙', '鄲', '鄰', '酊', '酖', '酘', '酣', '酥', '酩', '酳', '酲', '醋', '醉', '醂', '醢', '醫', '醯', '醪', '醵', '醴', '醺', '釀', '釁', '釉', '釋', '釐', '釖', '釟', '釡', '釛', '釼', '釵', '釶', '鈞', '釿', '鈔', '鈬', '鈕', '鈑', '鉞', '鉗', '鉅', '鉉', '鉤', '鉈', '銕', '鈿', '鉋', '鉐', '銜', '銖', '銓', '銛', '鉚', '鋏', '銹', '銷', '鋩', '錏', '鋺', '鍄', '錮', '\u{FFFD}', '\u{FFFD}']),
+    (0xE8, ['錙', '錢', '錚', '錣', '錺', '錵', '錻', '鍜', '鍠', '鍼', '鍮', '鍖', '鎰', '鎬', '鎭', '鎔', '鎹', '鏖', '鏗', '鏨', '鏥', '鏘', '鏃', '鏝', '鏐', '鏈', '鏤', '鐚', '鐔', '鐓', '鐃', '鐇', '鐐', '鐶', '鐫', '鐵', '鐡', '鐺', '鑁', '鑒', '鑄', '鑛', '鑠', '鑢', '鑞', '鑪', '鈩', '鑰', '鑵', '鑷', '鑽', '鑚', '鑼', '鑾', '钁', '鑿', '閂', '閇', '閊', '閔', '閖', '閘', '閙', '\u{FFFD}', '閠', '閨', '閧', '閭', '閼', '閻', '閹', '閾', '闊', '濶', '闃', '闍', '闌', '闕', '闔', '闖', '關', '闡', '闥', '闢', '阡', '阨', '阮', '阯', '陂', '陌', '陏', '陋', '陷', '陜', '陞', '陝', '陟', '陦', '陲', '陬', '隍', '隘', '隕', '隗', '險', '隧', '隱', '隲', '隰', '隴', '隶', '隸', '隹', '雎', '雋', '雉', '雍', '襍', '雜', '霍', '雕', '雹', '霄', '霆', '霈', '霓', '霎', '霑', '霏', '霖', '霙', '霤', '霪', '霰', '霹', '霽', '霾', '靄', '靆', '靈', '靂', '靉', '靜', '靠', '靤', '靦', '靨', '勒', '靫', '靱', '靹', '鞅', '靼', '鞁', '靺', '鞆', '鞋', '鞏', '鞐', '鞜', '鞨', '鞦', '鞣', '鞳', '鞴', '韃', '韆', '韈', '韋', '韜', '韭', '齏', '韲', '竟', '韶', '韵', '頏', '頌', '頸', '頤', '頡', '頷', '頽', '顆', '顏', '顋', '顫', '顯', '顰', '\u{FFFD}', '\u{FFFD}']),
+    (0xE9, ['顱', '顴', '顳', '颪', '颯', '颱', '颶', '飄', '飃', '飆', '飩', '飫', '餃', '餉', '餒', '餔', '餘', '餡', '餝', '餞', '餤', '餠', '餬', '餮', '餽', '餾', '饂', '饉', '饅', '饐', '饋', '饑', '饒', '饌', '饕', '馗', '馘', '馥', '馭', '馮', '馼', '駟', '駛', '駝', '駘', '駑', '駭', '駮', '駱', '駲', '駻', '駸', '騁', '騏', '騅', '駢', '騙', '騫', '騷', '驅', '驂', '驀', '驃', '\u{FFFD}', '騾', '驕', '驍', '驛', '驗', '驟', '驢', '驥', '驤', '驩', '驫', '驪', '骭', '骰', '骼', '髀', '髏', '髑', '髓', '體', '髞', '髟', '髢', '髣', '髦', '髯', '髫', '髮', '髴', '髱', '髷', '髻', '鬆', '鬘', '鬚', '鬟', '鬢', '鬣', '鬥', '鬧', '鬨', '鬩', '鬪', '鬮', '鬯', '鬲', '魄', '魃', '魏', '魍', '魎', '魑', '魘', '魴', '鮓', '鮃', '鮑', '鮖', '鮗', '鮟', '鮠', '鮨', '鮴', '鯀', '鯊', '鮹', '鯆', '鯏', '鯑', '鯒', '鯣', '鯢', '鯤', '鯔', '鯡', '鰺', '鯲', '鯱', '鯰', '鰕', '鰔', '鰉', '鰓', '鰌', '鰆', '鰈', '鰒', '鰊', '鰄', '鰮', '鰛', '鰥', '鰤', '鰡', '鰰', '鱇', '鰲', '鱆', '鰾', '鱚', '鱠', '鱧', '鱶', '鱸', '鳧', '鳬', '鳰', '鴉', '鴈', '鳫', '鴃', '鴆', '鴪', '鴦', '鶯', '鴣', '鴟', '鵄', '鴕', '鴒', '鵁', '鴿', '鴾', '鵆', '鵈', '\u{FFFD}', '\u{FFFD}']),
+    (0xEA, ['鵝', '鵞', '鵤', '鵑', '鵐', '鵙', '鵲', '鶉', '鶇', '鶫', '鵯', '鵺', '鶚', '鶤', '鶩', '鶲', '鷄', '鷁', '鶻', '鶸', '鶺', '鷆', '鷏', '鷂', '鷙', '鷓', '鷸', '鷦', '鷭', '鷯', '鷽', '鸚', '鸛', '鸞', '鹵', '鹹', '鹽', '麁', '麈', '麋', '麌', '麒', '麕', '麑', '麝', '麥', '麩', '麸', '麪', '麭', '靡', '黌', '黎', '黏', '黐', '黔', '黜', '點', '黝', '黠', '黥', '黨', '黯', '\u{FFFD}', '黴', '黶', '黷', '黹', '黻', '黼', '黽', '鼇', '鼈', '皷', '鼕', '鼡', '鼬', '鼾', '齊', '齒', '齔', '齣', '齟', '齠', '齡', '齦', '齧', '齬', '齪', '齷', '齲', '齶', '龕', '龜', '龠', '堯', '槇', '遙', '瑤', '凜', '熙', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xED, ['纊', '褜', '鍈', '銈', '蓜', '俉', '炻', '昱', '棈', '鋹', '曻', '彅', '丨', '仡', '仼', '伀', '伃', '伹', '佖', '侒', '侊', '侚', '侔', '俍', '偀', '倢', '俿', '倞', '偆', '偰', '偂', '傔', '僴', '僘', '兊', '兤', '冝', '冾', '凬', '刕', '劜', '劦', '勀', '勛', '匀', '匇', '匤', '卲', '厓', '厲', '叝', '﨎', '咜', '咊', '咩', '哿', '喆', '坙', '坥', '垬', '埈', '埇', '﨏', '\u{FFFD}', '塚', '增', '墲', '夋', '奓', '奛', '奝', '奣', '妤', '妺', '孖', '寀', '甯', '寘', '寬', '尞', '岦', '岺', '峵', '崧', '嵓', '﨑', '嵂', '嵭', '嶸', '嶹', '巐', '弡', '弴', '彧', '德', '忞', '恝', '悅', '悊', '惞', '惕', '愠', '惲', '愑', '愷', '愰', '憘', '戓', '抦', '揵', '摠', '撝', '擎', '敎', '昀', '昕', '昻', '昉', '昮', '昞', '昤', '晥', '晗', '晙', '晴', '晳', '暙', '暠', '暲', '暿', '曺', '朎', '朗', '杦', '枻', '桒', '柀', '栁', '桄', '棏', '﨓', '楨', '﨔', '榘', '槢', '樰', '橫', '橆', '橳', '橾', '櫢', '櫤', '毖', '氿', '汜', '沆', '汯', '泚', '洄', '涇', '浯', '涖', '涬', '淏', '淸', '淲', '淼', '渹', '湜', '渧', '渼', '溿', '澈', '澵', '濵', '瀅', '瀇', '瀨', '炅', '炫', '焏', '焄', '煜', '煆', '煇', '凞', '燁', '燾', '犱', '\u{FFFD}', '\u{FFFD}']),
+    (0xEE, ['犾', '猤', '猪', '獷', '玽', '珉', '珖', '珣', '珒', '琇', '珵', '琦', '琪', '琩', '琮', '瑢', '璉', '璟', '甁', '畯', '皂', '皜', '皞', '皛', '皦', '益', '睆', '劯', '砡', '硎', '硤', '硺', '礰', '礼', '神', '祥', '禔', '福', '禛', '竑', '竧', '靖', '竫', '箞', '精', '絈', '絜', '綷', '綠', '緖', '繒', '罇', '羡', '羽', '茁', '荢', '荿', '菇', '菶', '葈', '蒴', '蕓', '蕙', '\u{FFFD}', '蕫', '﨟', '薰', '蘒', '﨡', '蠇', '裵', '訒', '訷', '詹', '誧', '誾', '諟', '諸', '諶', '譓', '譿', '賰', '賴', '贒', '赶', '﨣', '軏', '﨤', '逸', '遧', '郞', '都', '鄕', '鄧', '釚', '釗', '釞', '釭', '釮', '釤', '釥', '鈆', '鈐', '鈊', '鈺', '鉀', '鈼', '鉎', '鉙', '鉑', '鈹', '鉧', '銧', '鉷', '鉸', '鋧', '鋗', '鋙', '鋐', '﨧', '鋕', '鋠', '鋓', '錥', '錡', '鋻', '﨨', '錞', '鋿', '錝', '錂', '鍰', '鍗', '鎤', '鏆', '鏞', '鏸', '鐱', '鑅', '鑈', '閒', '隆', '﨩', '隝', '隯', '霳', '霻', '靃', '靍', '靏', '靑', '靕', '顗', '顥', '飯', '飼', '餧', '館', '馞', '驎', '髙', '髜', '魵', '魲', '鮏', '鮱', '鮻', '鰀', '鵰', '鵫', '鶴', '鸙', '黑', '\u{FFFD}', '\u{FFFD}', 'ⅰ', 'ⅱ', 'ⅲ', 'ⅳ', 'ⅴ', 'ⅵ', 'ⅶ', 'ⅷ', 'ⅸ', 'ⅹ', '￢', '￤', '＇', '＂', '\u{FFFD}', '\u{FFFD}']),
+    (0xF0, ['\u{E000}', '\u{E001}', '\u{E002}', '\u{E003}', '\u{E004}', '\u{E005}', '\u{E006}', '\u{E007}', '\u{E008}', '\u{E009}', '\u{E00A}', '\u{E00B}', '\u{E00C}', '\u{E00D}', '\u{E00E}', '\u{E00F}', '\u{E010}', '\u{E011}', '\u{E012}', '\u{E013}', '\u{E014}', '\u{E015}', '\u{E016}', '\u{E017}', '\u{E018}', '\u{E019}', '\u{E01A}', '\u{E01B}', '\u{E01C}', '\u{E01D}', '\u{E01E}', '\u{E01F}', '\u{E020}', '\u{E021}', '\u{E022}', '\u{E023}', '\u{E024}', '\u{E025}', '\u{E026}', '\u{E027}', '\u{E028}', '\u{E029}', '\u{E02A}', '\u{E02B}', '\u{E02C}', '\u{E02D}', '\u{E02E}', '\u{E02F}', '\u{E030}', '\u{E031}', '\u{E032}', '\u{E033}', '\u{E034}', '\u{E035}', '\u{E036}', '\u{E037}', '\u{E038}', '\u{E039}', '\u{E03A}', '\u{E03B}', '\u{E03C}', '\u{E03D}', '\u{E03E}', '\u{FFFD}', '\u{E03F}', '\u{E040}', '\u{E041}', '\u{E042}', '\u{E043}', '\u{E044}', '\u{E045}', '\u{E046}', '\u{E047}', '\u{E048}', '\u{E049}', '\u{E04A}', '\u{E04B}', '\u{E04C}', '\u{E04D}', '\u{E04E}', '\u{E04F}', '\u{E050}', '\u{E051}', '\u{E052}', '\u{E053}', '\u{E054}', '\u{E055}', '\u{E056}', '\u{E057}', '\u{E058}', '\u{E059}', '\u{E05A}', '\u{E05B}', '\u{E05C}', '\u{E05D}', '\u{E05E}', '\u{E05F}', '\u{E060}', '\u{E061}', '\u{E062}', '\u{E063}', '\u{E064}', '\u{E065}', '\u{E066}', '\u{E067}', '\u{E068}', '\u{E069}', '\u{E06A}', '\u{E06B}', '\u{E06C}', '\u{E06D}', '\u{E06E}', '\u{E06F}', '\u{E070}', '\u{E071}', '\u{E072}', '\u{E073}', '\u{E074}', '\u{E075}', '\u{E076}', '\u{E077}', '\u{E078}', '\u{E079}', '\u{E07A}', '\u{E07B}', '\u{E07C}', '\u{E07D}', '\u{E07E}', '\u{E07F}', '\u{E080}', '\u{E081}', '\u{E082}', '\u{E083}', '\u{E084}', '\u{E085}', '\u{E086}', '\u{E087}', '\u{E088}', '\u{E089}', '\u{E08A}', '\u{E08B}', '\u{E08C}', '\u{E08D}', '\u{E08E}', '\u{E08F}', '\u{E090}', '\u{E091}', '\u{E092}', '\u{E093}', '\u{E094}', '\u{E095}', '\u{E096}', '\u{E097}', '\u{E098}', '\u{E099}', '\u{E09A}', '\u{E09B}', '\u{E09C}', '\u{E09D}', '\u{E09E}', '\u{E09F}', '\u{E0A0}', '\u{E0A1}', '\u{E0A2}', '\u{E0A3}', '\u{E0A4}', '\u{E0A5}', '\u{E0A6}', '\u{E0A7}', '\u{E0A8}', '\u{E0A9}', '\u{E0AA}', '\u{E0AB}', '\u{E0AC}', '\u{E0AD}', '\u{E0AE}', '\u{E0AF}', '\u{E0B0}', '\u{E0B1}', '\u{E0B2}', '\u{E0B3}', '\u{E0B4}', '\u{E0B5}', '\u{E0B6}', '\u{E0B7}', '\u{E0B8}', '\u{E0B9}', '\u{E0BA}', '\u{E0BB}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF1, ['\u{E0BC}', '\u{E0BD}', '\u{E0BE}', '\u{E0BF}', '\u{E0C0}', '\u{E0C1}', '\u{E0C2}', '\u{E0C3}', '\u{E0C4}', '\u{E0C5}', '\u{E0C6}', '\u{E0C7}', '\u{E0C8}', '\u{E0C9}', '\u{E0CA}', '\u{E0CB}', '\u{E0CC}', '\u{E0CD}', '\u{E0CE}', '\u{E0CF}', '\u{E0D0}', '\u{E0D1}', '\u{E0D2}', '\u{E0D3}', '\u{E0D4}', '\u{E0D5}', '\u{E0D6}', '\u{E0D7}', '\u{E0D8}', '\u{E0D9}', '\u{E0DA}', '\u{E0DB}', '\u{E0DC}', '\u{E0DD}', '\u{E0DE}', '\u{E0DF}', '\u{E0E0}', '\u{E0E1}', '\u{E0E2}', '\u{E0E3}', '\u{E0E4}', '\u{E0E5}', '\u{E0E6}', '\u{E0E7}', '\u{E0E8}', '\u{E0E9}', '\u{E0EA}', '\u{E0EB}', '\u{E0EC}', '\u{E0ED}', '\u{E0EE}', '\u{E0EF}', '\u{E0F0}', '\u{E0F1}', '\u{E0F2}', '\u{E0F3}', '\u{E0F4}', '\u{E0F5}', '\u{E0F6}', '\u{E0F7}', '\u{E0F8}', '\u{E0F9}', '\u{E0FA}', '\u{FFFD}', '\u{E0FB}', '\u{E0FC}', '\u{E0FD}', '\u{E0FE}', '\u{E0FF}', '\u{E100}', '\u{E101}', '\u{E102}', '\u{E103}', '\u{E104}', '\u{E105}', '\u{E106}', '\u{E107}', '\u{E108}', '\u{E109}', '\u{E10A}', '\u{E10B}', '\u{E10C}', '\u{E10D}', '\u{E10E}', '\u{E10F}', '\u{E110}', '\u{E111}', '\u{E112}', '\u{E113}', '\u{E114}', '\u{E115}', '\u{E116}', '\u{E117}', '\u{E118}', '\u{E119}', '\u{E11A}', '\u{E11B}', '\u{E11C}', '\u{E11D}', '\u{E11E}', '\u{E11F}', '\u{E120}', '\u{E121}', '\u{E122}', '\u{E123}', '\u{E124}', '\u{E125}', '\u{E126}', '\u{E127}', '\u{E128}', '\u{E129}', '\u{E12A}', '\u{E12B}', '\u{E12C}', '\u{E12D}', '\u{E12E}', '\u{E12F}', '\u{E130}', '\u{E131}', '\u{E132}', '\u{E133}', '\u{E134}', '\u{E135}', '\u{E136}', '\u{E137}', '\u{E138}', '\u{E139}', '\u{E13A}', '\u{E13B}', '\u{E13C}', '\u{E13D}', '\u{E13E}', '\u{E13F}', '\u{E140}', '\u{E141}', '\u{E142}', '\u{E143}', '\u{E144}', '\u{E145}', '\u{E146}', '\u{E147}', '\u{E148}', '\u{E149}', '\u{E14A}', '\u{E14B}', '\u{E14C}', '\u{E14D}', '\u{E14E}', '\u{E14F}', '\u{E150}', '\u{E151}', '\u{E152}', '\u{E153}', '\u{E154}', '\u{E155}', '\u{E156}', '\u{E157}', '\u{E158}', '\u{E159}', '\u{E15A}', '\u{E15B}', '\u{E15C}', '\u{E15D}', '\u{E15E}', '\u{E15F}', '\u{E160}', '\u{E161}', '\u{E162}', '\u{E163}', '\u{E164}', '\u{E165}', '\u{E166}', '\u{E167}', '\u{E168}', '\u{E169}', '\u{E16A}', '\u{E16B}', '\u{E16C}', '\u{E16D}', '\u{E16E}', '\u{E16F}', '\u{E170}', '\u{E171}', '\u{E172}', '\u{E173}', '\u{E174}', '\u{E175}', '\u{E176}', '\u{E177}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF2, ['\u{E178}', '\u{E179}', '\u{E17A}', '\u{E17B}', '\u{E17C}', '\u{E17D}', '\u{E17E}', '\u{E17F}', '\u{E180}', '\u{E181}', '\u{E182}', '\u{E183}', '\u{E184}', '\u{E185}', '\u{E186}', '\u{E187}', '\u{E188}', '\u{E189}', '\u{E18A}', '\u{E18B}', '\u{E18C}', '\u{E18D}', '\u{E18E}', '\u{E18F}', '\u{E190}', '\u{E191}', '\u{E192}', '\u{E193}', '\u{E194}', '\u{E195}', '\u{E196}', '\u{E197}', '\u{E198}', '\u{E199}', '\u{E19A}', '\u{E19B}', '\u{E19C}', '\u{E19D}', '\u{E19E}', '\u{E19F}', '\u{E1A0}', '\u{E1A1}', '\u{E1A2}', '\u{E1A3}', '\u{E1A4}', '\u{E1A5}', '\u{E1A6}', '\u{E1A7}', '\u{E1A8}', '\u{E1A9}', '\u{E1AA}', '\u{E1AB}', '\u{E1AC}', '\u{E1AD}', '\u{E1AE}', '\u{E1AF}', '\u{E1B0}', '\u{E1B1}', '\u{E1B2}', '\u{E1B3}', '\u{E1B4}', '\u{E1B5}', '\u{E1B6}', '\u{FFFD}', '\u{E1B7}', '\u{E1B8}', '\u{E1B9}', '\u{E1BA}', '\u{E1BB}', '\u{E1BC}', '\u{E1BD}', '\u{E1BE}', '\u{E1BF}', '\u{E1C0}', '\u{E1C1}', '\u{E1C2}', '\u{E1C3}', '\u{E1C4}', '\u{E1C5}', '\u{E1C6}', '\u{E1C7}', '\u{E1C8}', '\u{E1C9}', '\u{E1CA}', '\u{E1CB}', '\u{E1CC}', '\u{E1CD}', '\u{E1CE}', '\u{E1CF}', '\u{E1D0}', '\u{E1D1}', '\u{E1D2}', '\u{E1D3}', '\u{E1D4}', '\u{E1D5}', '\u{E1D6}', '\u{E1D7}', '\u{E1D8}', '\u{E1D9}', '\u{E1DA}', '\u{E1DB}', '\u{E1DC}', '\u{E1DD}', '\u{E1DE}', '\u{E1DF}', '\u{E1E0}', '\u{E1E1}', '\u{E1E2}', '\u{E1E3}', '\u{E1E4}', '\u{E1E5}', '\u{E1E6}', '\u{E1E7}', '\u{E1E8}', '\u{E1E9}', '\u{E1EA}', '\u{E1EB}', '\u{E1EC}', '\u{E1ED}', '\u{E1EE}', '\u{E1EF}', '\u{E1F0}', '\u{E1F1}', '\u{E1F2}', '\u{E1F3}', '\u{E1F4}', '\u{E1F5}', '\u{E1F6}', '\u{E1F7}', '\u{E1F8}', '\u{E1F9}', '\u{E1FA}', '\u{E1FB}', '\u{E1FC}', '\u{E1FD}', '\u{E1FE}', '\u{E1FF}', '\u{E200}', '\u{E201}', '\u{E202}', '\u{E203}', '\u{E204}', '\u{E205}', '\u{E206}', '\u{E207}', '\u{E208}', '\u{E209}', '\u{E20A}', '\u{E20B}', '\u{E20C}', '\u{E20D}', '\u{E20E}', '\u{E20F}', '\u{E210}', '\u{E211}', '\u{E212}', '\u{E213}', '\u{E214}', '\u{E215}', '\u{E216}', '\u{E217}', '\u{E218}', '\u{E219}', '\u{E21A}', '\u{E21B}', '\u{E21C}', '\u{E21D}', '\u{E21E}', '\u{E21F}', '\u{E220}', '\u{E221}', '\u{E222}', '\u{E223}', '\u{E224}', '\u{E225}', '\u{E226}', '\u{E227}', '\u{E228}', '\u{E229}', '\u{E22A}', '\u{E22B}', '\u{E22C}', '\u{E22D}', '\u{E22E}', '\u{E22F}', '\u{E230}', '\u{E231}', '\u{E232}', '\u{E233}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF3, ['\u{E234}', '\u{E235}', '\u{E236}', '\u{E237}', '\u{E238}', '\u{E239}', '\u{E23A}', '\u{E23B}', '\u{E23C}', '\u{E23D}', '\u{E23E}', '\u{E23F}', '\u{E240}', '\u{E241}', '\u{E242}', '\u{E243}', '\u{E244}', '\u{E245}', '\u{E246}', '\u{E247}', '\u{E248}', '\u{E249}', '\u{E24A}', '\u{E24B}', '\u{E24C}', '\u{E24D}', '\u{E24E}', '\u{E24F}', '\u{E250}', '\u{E251}', '\u{E252}', '\u{E253}', '\u{E254}', '\u{E255}', '\u{E256}', '\u{E257}', '\u{E258}', '\u{E259}', '\u{E25A}', '\u{E25B}', '\u{E25C}', '\u{E25D}', '\u{E25E}', '\u{E25F}', '\u{E260}', '\u{E261}', '\u{E262}', '\u{E263}', '\u{E264}', '\u{E265}', '\u{E266}', '\u{E267}', '\u{E268}', '\u{E269}', '\u{E26A}', '\u{E26B}', '\u{E26C}', '\u{E26D}', '\u{E26E}', '\u{E26F}', '\u{E270}', '\u{E271}', '\u{E272}', '\u{FFFD}', '\u{E273}', '\u{E274}', '\u{E275}', '\u{E276}', '\u{E277}', '\u{E278}', '\u{E279}', '\u{E27A}', '\u{E27B}', '\u{E27C}', '\u{E27D}', '\u{E27E}', '\u{E27F}', '\u{E280}', '\u{E281}', '\u{E282}', '\u{E283}', '\u{E284}', '\u{E285}', '\u{E286}', '\u{E287}', '\u{E288}', '\u{E289}', '\u{E28A}', '\u{E28B}', '\u{E28C}', '\u{E28D}', '\u{E28E}', '\u{E28F}', '\u{E290}', '\u{E291}', '\u{E292}', '\u{E293}', '\u{E294}', '\u{E295}', '\u{E296}', '\u{E297}', '\u{E298}', '\u{E299}', '\u{E29A}', '\u{E29B}', '\u{E29C}', '\u{E29D}', '\u{E29E}', '\u{E29F}', '\u{E2A0}', '\u{E2A1}', '\u{E2A2}', '\u{E2A3}', '\u{E2A4}', '\u{E2A5}', '\u{E2A6}', '\u{E2A7}', '\u{E2A8}', '\u{E2A9}', '\u{E2AA}', '\u{E2AB}', '\u{E2AC}', '\u{E2AD}', '\u{E2AE}', '\u{E2AF}', '\u{E2B0}', '\u{E2B1}', '\u{E2B2}', '\u{E2B3}', '\u{E2B4}', '\u{E2B5}', '\u{E2B6}', '\u{E2B7}', '\u{E2B8}', '\u{E2B9}', '\u{E2BA}', '\u{E2BB}', '\u{E2BC}', '\u{E2BD}', '\u{E2BE}', '\u{E2BF}', '\u{E2C0}', '\u{E2C1}', '\u{E2C2}', '\u{E2C3}', '\u{E2C4}', '\u{E2C5}', '\u{E2C6}', '\u{E2C7}', '\u{E2C8}', '\u{E2C9}', '\u{E2CA}', '\u{E2CB}', '\u{E2CC}', '\u{E2CD}', '\u{E2CE}', '\u{E2CF}', '\u{E2D0}', '\u{E2D1}', '\u{E2D2}', '\u{E2D3}', '\u{E2D4}', '\u{E2D5}', '\u{E2D6}', '\u{E2D7}', '\u{E2D8}', '\u{E2D9}', '\u{E2DA}', '\u{E2DB}', '\u{E2DC}', '\u{E2DD}', '\u{E2DE}', '\u{E2DF}', '\u{E2E0}', '\u{E2E1}', '\u{E2E2}', '\u{E2E3}', '\u{E2E4}', '\u{E2E5}', '\u{E2E6}', '\u{E2E7}', '\u{E2E8}', '\u{E2E9}', '\u{E2EA}', '\u{E2EB}', '\u{E2EC}', '\u{E2ED}', '\u{E2EE}', '\u{E2EF}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF4, ['\u{E2F0}', '\u{E2F1}', '\u{E2F2}', '\u{E2F3}', '\u{E2F4}', '\u{E2F5}', '\u{E2F6}', '\u{E2F7}', '\u{E2F8}', '\u{E2F9}', '\u{E2FA}', '\u{E2FB}', '\u{E2FC}', '\u{E2FD}', '\u{E2FE}', '\u{E2FF}', '\u{E300}', '\u{E301}', '\u{E302}', '\u{E303}', '\u{E304}', '\u{E305}', '\u{E306}', '\u{E307}', '\u{E308}', '\u{E309}', '\u{E30A}', '\u{E30B}', '\u{E30C}', '\u{E30D}', '\u{E30E}', '\u{E30F}', '\u{E310}', '\u{E311}', '\u{E312}', '\u{E313}', '\u{E314}', '\u{E315}', '\u{E316}', '\u{E317}', '\u{E318}', '\u{E319}', '\u{E31A}', '\u{E31B}', '\u{E31C}', '\u{E31D}', '\u{E31E}', '\u{E31F}', '\u{E320}', '\u{E321}', '\u{E322}', '\u{E323}', '\u{E324}', '\u{E325}', '\u{E326}', '\u{E327}', '\u{E328}', '\u{E329}', '\u{E32A}', '\u{E32B}', '\u{E32C}', '\u{E32D}', '\u{E32E}', '\u{FFFD}', '\u{E32F}', '\u{E330}', '\u{E331}', '\u{E332}', '\u{E333}', '\u{E334}', '\u{E335}', '\u{E336}', '\u{E337}', '\u{E338}', '\u{E339}', '\u{E33A}', '\u{E33B}', '\u{E33C}', '\u{E33D}', '\u{E33E}', '\u{E33F}', '\u{E340}', '\u{E341}', '\u{E342}', '\u{E343}', '\u{E344}', '\u{E345}', '\u{E346}', '\u{E347}', '\u{E348}', '\u{E349}', '\u{E34A}', '\u{E34B}', '\u{E34C}', '\u{E34D}', '\u{E34E}', '\u{E34F}', '\u{E350}', '\u{E351}', '\u{E352}', '\u{E353}', '\u{E354}', '\u{E355}', '\u{E356}', '\u{E357}', '\u{E358}', '\u{E359}', '\u{E35A}', '\u{E35B}', '\u{E35C}', '\u{E35D}', '\u{E35E}', '\u{E35F}', '\u{E360}', '\u{E361}', '\u{E362}', '\u{E363}', '\u{E364}', '\u{E365}', '\u{E366}', '\u{E367}', '\u{E368}', '\u{E369}', '\u{E36A}', '\u{E36B}', '\u{E36C}', '\u{E36D}', '\u{E36E}', '\u{E36F}', '\u{E370}', '\u{E371}', '\u{E372}', '\u{E373}', '\u{E374}', '\u{E375}', '\u{E376}', '\u{E377}', '\u{E378}', '\u{E379}', '\u{E37A}', '\u{E37B}', '\u{E37C}', '\u{E37D}', '\u{E37E}', '\u{E37F}', '\u{E380}', '\u{E381}', '\u{E382}', '\u{E383}', '\u{E384}', '\u{E385}', '\u{E386}', '\u{E387}', '\u{E388}', '\u{E389}', '\u{E38A}', '\u{E38B}', '\u{E38C}', '\u{E38D}', '\u{E38E}', '\u{E38F}', '\u{E390}', '\u{E391}', '\u{E392}', '\u{E393}', '\u{E394}', '\u{E395}', '\u{E396}', '\u{E397}', '\u{E398}', '\u{E399}', '\u{E39A}', '\u{E39B}', '\u{E39C}', '\u{E39D}', '\u{E39E}', '\u{E39F}', '\u{E3A0}', '\u{E3A1}', '\u{E3A2}', '\u{E3A3}', '\u{E3A4}', '\u{E3A5}', '\u{E3A6}', '\u{E3A7}', '\u{E3A8}', '\u{E3A9}', '\u{E3AA}', '\u{E3AB}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF5, ['\u{E3AC}', '\u{E3AD}', '\u{E3AE}', '\u{E3AF}', '\u{E3B0}', '\u{E3B1}', '\u{E3B2}', '\u{E3B3}', '\u{E3B4}', '\u{E3B5}', '\u{E3B6}', '\u{E3B7}', '\u{E3B8}', '\u{E3B9}', '\u{E3BA}', '\u{E3BB}', '\u{E3BC}', '\u{E3BD}', '\u{E3BE}', '\u{E3BF}', '\u{E3C0}', '\u{E3C1}', '\u{E3C2}', '\u{E3C3}', '\u{E3C4}', '\u{E3C5}', '\u{E3C6}', '\u{E3C7}', '\u{E3C8}', '\u{E3C9}', '\u{E3CA}', '\u{E3CB}', '\u{E3CC}', '\u{E3CD}', '\u{E3CE}', '\u{E3CF}', '\u{E3D0}', '\u{E3D1}', '\u{E3D2}', '\u{E3D3}', '\u{E3D4}', '\u{E3D5}', '\u{E3D6}', '\u{E3D7}', '\u{E3D8}', '\u{E3D9}', '\u{E3DA}', '\u{E3DB}', '\u{E3DC}', '\u{E3DD}', '\u{E3DE}', '\u{E3DF}', '\u{E3E0}', '\u{E3E1}', '\u{E3E2}', '\u{E3E3}', '\u{E3E4}', '\u{E3E5}', '\u{E3E6}', '\u{E3E7}', '\u{E3E8}', '\u{E3E9}', '\u{E3EA}', '\u{FFFD}', '\u{E3EB}', '\u{E3EC}', '\u{E3ED}', '\u{E3EE}', '\u{E3EF}', '\u{E3F0}', '\u{E3F1}', '\u{E3F2}', '\u{E3F3}', '\u{E3F4}', '\u{E3F5}', '\u{E3F6}', '\u{E3F7}', '\u{E3F8}', '\u{E3F9}', '\u{E3FA}', '\u{E3FB}', '\u{E3FC}', '\u{E3FD}', '\u{E3FE}', '\u{E3FF}', '\u{E400}', '\u{E401}', '\u{E402}', '\u{E403}', '\u{E404}', '\u{E405}', '\u{E406}', '\u{E407}', '\u{E408}', '\u{E409}', '\u{E40A}', '\u{E40B}', '\u{E40C}', '\u{E40D}', '\u{E40E}', '\u{E40F}', '\u{E410}', '\u{E411}', '\u{E412}', '\u{E413}', '\u{E414}', '\u{E415}', '\u{E416}', '\u{E417}', '\u{E418}', '\u{E419}', '\u{E41A}', '\u{E41B}', '\u{E41C}', '\u{E41D}', '\u{E41E}', '\u{E41F}', '\u{E420}', '\u{E421}', '\u{E422}', '\u{E423}', '\u{E424}', '\u{E425}', '\u{E426}', '\u{E427}', '\u{E428}', '\u{E429}', '\u{E42A}', '\u{E42B}', '\u{E42C}', '\u{E42D}', '\u{E42E}', '\u{E42F}', '\u{E430}', '\u{E431}', '\u{E432}', '\u{E433}', '\u{E434}', '\u{E435}', '\u{E436}', '\u{E437}', '\u{E438}', '\u{E439}', '\u{E43A}', '\u{E43B}', '\u{E43C}', '\u{E43D}', '\u{E43E}', '\u{E43F}', '\u{E440}', '\u{E441}', '\u{E442}', '\u{E443}', '\u{E444}', '\u{E445}', '\u{E446}', '\u{E447}', '\u{E448}', '\u{E449}', '\u{E44A}', '\u{E44B}', '\u{E44C}', '\u{E44D}', '\u{E44E}', '\u{E44F}', '\u{E450}', '\u{E451}', '\u{E452}', '\u{E453}', '\u{E454}', '\u{E455}', '\u{E456}', '\u{E457}', '\u{E458}', '\u{E459}', '\u{E45A}', '\u{E45B}', '\u{E45C}', '\u{E45D}', '\u{E45E}', '\u{E45F}', '\u{E460}', '\u{E461}', '\u{E462}', '\u{E463}', '\u{E464}', '\u{E465}', '\u{E466}', '\u{E467}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF6, ['\u{E468}', '\u{E469}', '\u{E46A}', '\u{E46B}', '\u{E46C}', '\u{E46D}', '\u{E46E}', '\u{E46F}', '\u{E470}', '\u{E471}', '\u{E472}', '\u{E473}', '\u{E474}', '\u{E475}', '\u{E476}', '\u{E477}', '\u{E478}', '\u{E479}', '\u{E47A}', '\u{E47B}', '\u{E47C}', '\u{E47D}', '\u{E47E}', '\u{E47F}', '\u{E480}', '\u{E481}', '\u{E482}', '\u{E483}', '\u{E484}', '\u{E485}', '\u{E486}', '\u{E487}', '\u{E488}', '\u{E489}', '\u{E48A}', '\u{E48B}', '\u{E48C}', '\u{E48D}', '\u{E48E}', '\u{E48F}', '\u{E490}', '\u{E491}', '\u{E492}', '\u{E493}', '\u{E494}', '\u{E495}', '\u{E496}', '\u{E497}', '\u{E498}', '\u{E499}', '\u{E49A}', '\u{E49B}', '\u{E49C}', '\u{E49D}', '\u{E49E}', '\u{E49F}', '\u{E4A0}', '\u{E4A1}', '\u{E4A2}', '\u{E4A3}', '\u{E4A4}', '\u{E4A5}', '\u{E4A6}', '\u{FFFD}', '\u{E4A7}', '\u{E4A8}', '\u{E4A9}', '\u{E4AA}', '\u{E4AB}', '\u{E4AC}', '\u{E4AD}', '\u{E4AE}', '\u{E4AF}', '\u{E4B0}', '\u{E4B1}', '\u{E4B2}', '\u{E4B3}', '\u{E4B4}', '\u{E4B5}', '\u{E4B6}', '\u{E4B7}', '\u{E4B8}', '\u{E4B9}', '\u{E4BA}', '\u{E4BB}', '\u{E4BC}', '\u{E4BD}', '\u{E4BE}', '\u{E4BF}', '\u{E4C0}', '\u{E4C1}', '\u{E4C2}', '\u{E4C3}', '\u{E4C4}', '\u{E4C5}', '\u{E4C6}', '\u{E4C7}', '\u{E4C8}', '\u{E4C9}', '\u{E4CA}', '\u{E4CB}', '\u{E4CC}', '\u{E4CD}', '\u{E4CE}', '\u{E4CF}', '\u{E4D0}', '\u{E4D1}', '\u{E4D2}', '\u{E4D3}', '\u{E4D4}', '\u{E4D5}', '\u{E4D6}', '\u{E4D7}', '\u{E4D8}', '\u{E4D9}', '\u{E4DA}', '\u{E4DB}', '\u{E4DC}', '\u{E4DD}', '\u{E4DE}', '\u{E4DF}', '\u{E4E0}', '\u{E4E1}', '\u{E4E2}', '\u{E4E3}', '\u{E4E4}', '\u{E4E5}', '\u{E4E6}', '\u{E4E7}', '\u{E4E8}', '\u{E4E9}', '\u{E4EA}', '\u{E4EB}', '\u{E4EC}', '\u{E4ED}', '\u{E4EE}', '\u{E4EF}', '\u{E4F0}', '\u{E4F1}', '\u{E4F2}', '\u{E4F3}', '\u{E4F4}', '\u{E4F5}', '\u{E4F6}', '\u{E4F7}', '\u{E4F8}', '\u{E4F9}', '\u{E4FA}', '\u{E4FB}', '\u{E4FC}', '\u{E4FD}', '\u{E4FE}', '\u{E4FF}', '\u{E500}', '\u{E501}', '\u{E502}', '\u{E503}', '\u{E504}', '\u{E505}', '\u{E506}', '\u{E507}', '\u{E508}', '\u{E509}', '\u{E50A}', '\u{E50B}', '\u{E50C}', '\u{E50D}', '\u{E50E}', '\u{E50F}', '\u{E510}', '\u{E511}', '\u{E512}', '\u{E513}', '\u{E514}', '\u{E515}', '\u{E516}', '\u{E517}', '\u{E518}', '\u{E519}', '\u{E51A}', '\u{E51B}', '\u{E51C}', '\u{E51D}', '\u{E51E}', '\u{E51F}', '\u{E520}', '\u{E521}', '\u{E522}', '\u{E523}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF7, ['\u{E524}', '\u{E525}', '\u{E526}', '\u{E527}', '\u{E528}', '\u{E529}', '\u{E52A}', '\u{E52B}', '\u{E52C}', '\u{E52D}', '\u{E52E}', '\u{E52F}', '\u{E530}', '\u{E531}', '\u{E532}', '\u{E533}', '\u{E534}', '\u{E535}', '\u{E536}', '\u{E537}', '\u{E538}', '\u{E539}', '\u{E53A}', '\u{E53B}', '\u{E53C}', '\u{E53D}', '\u{E53E}', '\u{E53F}', '\u{E540}', '\u{E541}', '\u{E542}', '\u{E543}', '\u{E544}', '\u{E545}', '\u{E546}', '\u{E547}', '\u{E548}', '\u{E549}', '\u{E54A}', '\u{E54B}', '\u{E54C}', '\u{E54D}', '\u{E54E}', '\u{E54F}', '\u{E550}', '\u{E551}', '\u{E552}', '\u{E553}', '\u{E554}', '\u{E555}', '\u{E556}', '\u{E557}', '\u{E558}', '\u{E559}', '\u{E55A}', '\u{E55B}', '\u{E55C}', '\u{E55D}', '\u{E55E}', '\u{E55F}', '\u{E560}', '\u{E561}', '\u{E562}', '\u{FFFD}', '\u{E563}', '\u{E564}', '\u{E565}', '\u{E566}', '\u{E567}', '\u{E568}', '\u{E569}', '\u{E56A}', '\u{E56B}', '\u{E56C}', '\u{E56D}', '\u{E56E}', '\u{E56F}', '\u{E570}', '\u{E571}', '\u{E572}', '\u{E573}', '\u{E574}', '\u{E575}', '\u{E576}', '\u{E577}', '\u{E578}', '\u{E579}', '\u{E57A}', '\u{E57B}', '\u{E57C}', '\u{E57D}', '\u{E57E}', '\u{E57F}', '\u{E580}', '\u{E581}', '\u{E582}', '\u{E583}', '\u{E584}', '\u{E585}', '\u{E586}', '\u{E587}', '\u{E588}', '\u{E589}', '\u{E58A}', '\u{E58B}', '\u{E58C}', '\u{E58D}', '\u{E58E}', '\u{E58F}', '\u{E590}', '\u{E591}', '\u{E592}', '\u{E593}', '\u{E594}', '\u{E595}', '\u{E596}', '\u{E597}', '\u{E598}', '\u{E599}', '\u{E59A}', '\u{E59B}', '\u{E59C}', '\u{E59D}', '\u{E59E}', '\u{E59F}', '\u{E5A0}', '\u{E5A1}', '\u{E5A2}', '\u{E5A3}', '\u{E5A4}', '\u{E5A5}', '\u{E5A6}', '\u{E5A7}', '\u{E5A8}', '\u{E5A9}', '\u{E5AA}', '\u{E5AB}', '\u{E5AC}', '\u{E5AD}', '\u{E5AE}', '\u{E5AF}', '\u{E5B0}', '\u{E5B1}', '\u{E5B2}', '\u{E5B3}', '\u{E5B4}', '\u{E5B5}', '\u{E5B6}', '\u{E5B7}', '\u{E5B8}', '\u{E5B9}', '\u{E5BA}', '\u{E5BB}', '\u{E5BC}', '\u{E5BD}', '\u{E5BE}', '\u{E5BF}', '\u{E5C0}', '\u{E5C1}', '\u{E5C2}', '\u{E5C3}', '\u{E5C4}', '\u{E5C5}', '\u{E5C6}', '\u{E5C7}', '\u{E5C8}', '\u{E5C9}', '\u{E5CA}', '\u{E5CB}', '\u{E5CC}', '\u{E5CD}', '\u{E5CE}', '\u{E5CF}', '\u{E5D0}', '\u{E5D1}', '\u{E5D2}', '\u{E5D3}', '\u{E5D4}', '\u{E5D5}', '\u{E5D6}', '\u{E5D7}', '\u{E5D8}', '\u{E5D9}', '\u{E5DA}', '\u{E5DB}', '\u{E5DC}', '\u{E5DD}', '\u{E5DE}', '\u{E5DF}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF8, ['\u{E5E0}', '\u{E5E1}', '\u{E5E2}', '\u{E5E3}', '\u{E5E4}', '\u{E5E5}', '\u{E5E6}', '\u{E5E7}', '\u{E5E8}', '\u{E5E9}', '\u{E5EA}', '\u{E5EB}', '\u{E5EC}', '\u{E5ED}', '\u{E5EE}', '\u{E5EF}', '\u{E5F0}', '\u{E5F1}', '\u{E5F2}', '\u{E5F3}', '\u{E5F4}', '\u{E5F5}', '\u{E5F6}', '\u{E5F7}', '\u{E5F8}', '\u{E5F9}', '\u{E5FA}', '\u{E5FB}', '\u{E5FC}', '\u{E5FD}', '\u{E5FE}', '\u{E5FF}', '\u{E600}', '\u{E601}', '\u{E602}', '\u{E603}', '\u{E604}', '\u{E605}', '\u{E606}', '\u{E607}', '\u{E608}', '\u{E609}', '\u{E60A}', '\u{E60B}', '\u{E60C}', '\u{E60D}', '\u{E60E}', '\u{E60F}', '\u{E610}', '\u{E611}', '\u{E612}', '\u{E613}', '\u{E614}', '\u{E615}', '\u{E616}', '\u{E617}', '\u{E618}', '\u{E619}', '\u{E61A}', '\u{E61B}', '\u{E61C}', '\u{E61D}', '\u{E61E}', '\u{FFFD}', '\u{E61F}', '\u{E620}', '\u{E621}', '\u{E622}', '\u{E623}', '\u{E624}', '\u{E625}', '\u{E626}', '\u{E627}', '\u{E628}', '\u{E629}', '\u{E62A}', '\u{E62B}', '\u{E62C}', '\u{E62D}', '\u{E62E}', '\u{E62F}', '\u{E630}', '\u{E631}', '\u{E632}', '\u{E633}', '\u{E634}', '\u{E635}', '\u{E636}', '\u{E637}', '\u{E638}', '\u{E639}', '\u{E63A}', '\u{E63B}', '\u{E63C}', '\u{E63D}', '\u{E63E}', '\u{E63F}', '\u{E640}', '\u{E641}', '\u{E642}', '\u{E643}', '\u{E644}', '\u{E645}', '\u{E646}', '\u{E647}', '\u{E648}', '\u{E649}', '\u{E64A}', '\u{E64B}', '\u{E64C}', '\u{E64D}', '\u{E64E}', '\u{E64F}', '\u{E650}', '\u{E651}', '\u{E652}', '\u{E653}', '\u{E654}', '\u{E655}', '\u{E656}', '\u{E657}', '\u{E658}', '\u{E659}', '\u{E65A}', '\u{E65B}', '\u{E65C}', '\u{E65D}', '\u{E65E}', '\u{E65F}', '\u{E660}', '\u{E661}', '\u{E662}', '\u{E663}', '\u{E664}', '\u{E665}', '\u{E666}', '\u{E667}', '\u{E668}', '\u{E669}', '\u{E66A}', '\u{E66B}', '\u{E66C}', '\u{E66D}', '\u{E66E}', '\u{E66F}', '\u{E670}', '\u{E671}', '\u{E672}', '\u{E673}', '\u{E674}', '\u{E675}', '\u{E676}', '\u{E677}', '\u{E678}', '\u{E679}', '\u{E67A}', '\u{E67B}', '\u{E67C}', '\u{E67D}', '\u{E67E}', '\u{E67F}', '\u{E680}', '\u{E681}', '\u{E682}', '\u{E683}', '\u{E684}', '\u{E685}', '\u{E686}', '\u{E687}', '\u{E688}', '\u{E689}', '\u{E68A}', '\u{E68B}', '\u{E68C}', '\u{E68D}', '\u{E68E}', '\u{E68F}', '\u{E690}', '\u{E691}', '\u{E692}', '\u{E693}', '\u{E694}', '\u{E695}', '\u{E696}', '\u{E697}', '\u{E698}', '\u{E699}', '\u{E69A}', '\u{E69B}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF9, ['\u{E69C}', '\u{E69D}', '\u{E69E}', '\u{E69F}', '\u{E6A0}', '\u{E6A1}', '\u{E6A2}', '\u{E6A3}', '\u{E6A4}', '\u{E6A5}', '\u{E6A6}', '\u{E6A7}', '\u{E6A8}', '\u{E6A9}', '\u{E6AA}', '\u{E6AB}', '\u{E6AC}', '\u{E6AD}', '\u{E6AE}', '\u{E6AF}', '\u{E6B0}', '\u{E6B1}', '\u{E6B2}', '\u{E6B3}', '\u{E6B4}', '\u{E6B5}', '\u{E6B6}', '\u{E6B7}', '\u{E6B8}', '\u{E6B9}', '\u{E6BA}', '\u{E6BB}', '\u{E6BC}', '\u{E6BD}', '\u{E6BE}', '\u{E6BF}', '\u{E6C0}', '\u{E6C1}', '\u{E6C2}', '\u{E6C3}', '\u{E6C4}', '\u{E6C5}', '\u{E6C6}', '\u{E6C7}', '\u{E6C8}', '\u{E6C9}', '\u{E6CA}', '\u{E6CB}', '\u{E6CC}', '\u{E6CD}', '\u{E6CE}', '\u{E6CF}', '\u{E6D0}', '\u{E6D1}', '\u{E6D2}', '\u{E6D3}', '\u{E6D4}', '\u{E6D5}', '\u{E6D6}', '\u{E6D7}', '\u{E6D8}', '\u{E6D9}', '\u{E6DA}', '\u{FFFD}', '\u{E6DB}', '\u{E6DC}', '\u{E6DD}', '\u{E6DE}', '\u{E6DF}', '\u{E6E0}', '\u{E6E1}', '\u{E6E2}', '\u{E6E3}', '\u{E6E4}', '\u{E6E5}', '\u{E6E6}', '\u{E6E7}', '\u{E6E8}', '\u{E6E9}', '\u{E6EA}', '\u{E6EB}', '\u{E6EC}', '\u{E6ED}', '\u{E6EE}', '\u{E6EF}', '\u{E6F0}', '\u{E6F1}', '\u{E6F2}', '\u{E6F3}', '\u{E6F4}', '\u{E6F5}', '\u{E6F6}', '\u{E6F7}', '\u{E6F8}', '\u{E6F9}', '\u{E6FA}', '\u{E6FB}', '\u{E6FC}', '\u{E6FD}', '\u{E6FE}', '\u{E6FF}', '\u{E700}', '\u{E701}', '\u{E702}', '\u{E703}', '\u{E704}', '\u{E705}', '\u{E706}', '\u{E707}', '\u{E708}', '\u{E709}', '\u{E70A}', '\u{E70B}', '\u{E70C}', '\u{E70D}', '\u{E70E}', '\u{E70F}', '\u{E710}', '\u{E711}', '\u{E712}', '\u{E713}', '\u{E714}', '\u{E715}', '\u{E716}', '\u{E717}', '\u{E718}', '\u{E719}', '\u{E71A}', '\u{E71B}', '\u{E71C}', '\u{E71D}', '\u{E71E}', '\u{E71F}', '\u{E720}', '\u{E721}', '\u{E722}', '\u{E723}', '\u{E724}', '\u{E725}', '\u{E726}', '\u{E727}', '\u{E728}', '\u{E729}', '\u{E72A}', '\u{E72B}', '\u{E72C}', '\u{E72D}', '\u{E72E}', '\u{E72F}', '\u{E730}', '\u{E731}', '\u{E732}', '\u{E733}', '\u{E734}', '\u{E735}', '\u{E736}', '\u{E737}', '\u{E738}', '\u{E739}', '\u{E73A}', '\u{E73B}', '\u{E73C}', '\u{E73D}', '\u{E73E}', '\u{E73F}', '\u{E740}', '\u{E741}', '\u{E742}', '\u{E743}', '\u{E744}', '\u{E745}', '\u{E746}', '\u{E747}', '\u{E748}', '\u{E749}', '\u{E74A}', '\u{E74B}', '\u{E74C}', '\u{E74D}', '\u{E74E}', '\u{E74F}', '\u{E750}', '\u{E751}', '\u{E752}', '\u{E753}', '\u{E754}', '\u{E755}', '\u{E756}', '\u{E757}', '\u{FFFD}', '\u{FFFD}']),
+    (0xFA, ['ⅰ', 'ⅱ', 'ⅲ', 'ⅳ', 'ⅴ', 'ⅵ', 'ⅶ', 'ⅷ', 'ⅸ', 'ⅹ', 'Ⅰ', 'Ⅱ', 'Ⅲ', 'Ⅳ', 'Ⅴ', 'Ⅵ', 'Ⅶ', 'Ⅷ', 'Ⅸ', 'Ⅹ', '￢', '￤', '＇', '＂', '㈱', '№', '℡', '∵', '纊', '褜', '鍈', '銈', '蓜', '俉', '炻', '昱', '棈', '鋹', '曻', '彅', '丨', '仡', '仼', '伀', '伃', '伹', '佖', '侒', '侊', '侚', '侔', '俍', '偀', '倢', '俿', '倞', '偆', '偰', '偂', '傔', '僴', '僘', '兊', '\u{FFFD}', '兤', '冝', '冾', '凬', '刕', '劜', '劦', '勀', '勛', '匀', '匇', '匤', '卲', '厓', '厲', '叝', '﨎', '咜', '咊', '咩', '哿', '喆', '坙', '坥', '垬', '埈', '埇', '﨏', '塚', '增', '墲', '夋', '奓', '奛', '奝', '奣', '妤', '妺', '孖', '寀', '甯', '寘', '寬', '尞', '岦', '岺', '峵', '崧', '嵓', '﨑', '嵂', '嵭', '嶸', '嶹', '巐', '弡', '弴', '彧', '德', '忞', '恝', '悅', '悊', '惞', '惕', '愠', '惲', '愑', '愷', '愰', '憘', '戓', '抦', '揵', '摠', '撝', '擎', '敎', '昀', '昕', '昻', '昉', '昮', '昞', '昤', '晥', '晗', '晙', '晴', '晳', '暙', '暠', '暲', '暿', '曺', '朎', '朗', '杦', '枻', '桒', '柀', '栁', '桄', '棏', '﨓', '楨', '﨔', '榘', '槢', '樰', '橫', '橆', '橳', '橾', '櫢', '櫤', '毖', '氿', '汜', '沆', '汯', '泚', '洄', '涇', '浯', '\u{FFFD}', '\u{FFFD}']),
+    (0xFB, ['涖', '涬', '淏', '淸', '淲', '淼', '渹', '湜', '渧', '渼', '溿', '澈', '澵', '濵', '瀅', '瀇', '瀨', '炅', '炫', '焏', '焄', '煜', '煆', '煇', '凞', '燁', '燾', '犱', '犾', '猤', '猪', '獷', '玽', '珉', '珖', '珣', '珒', '琇', '珵', '琦', '琪', '琩', '琮', '瑢', '璉', '璟', '甁', '畯', '皂', '皜', '皞', '皛', '皦', '益', '睆', '劯', '砡', '硎', '硤', '硺', '礰', '礼', '神', '\u{FFFD}', '祥', '禔', '福', '禛', '竑', '竧', '靖', '竫', '箞', '精', '絈', '絜', '綷', '綠', '緖', '繒', '罇', '羡', '羽', '茁', '荢', '荿', '菇', '菶', '葈', '蒴', '蕓', '蕙', '蕫', '﨟', '薰', '蘒', '﨡', '蠇', '裵', '訒', '訷', '詹', '誧', '誾', '諟', '諸', '諶', '譓', '譿', '賰', '賴', '贒', '赶', '﨣', '軏', '﨤', '逸', '遧', '郞', '都', '鄕', '鄧', '釚', '釗', '釞', '釭', '釮', '釤', '釥', '鈆', '鈐', '鈊', '鈺', '鉀', '鈼', '鉎', '鉙', '鉑', '鈹', '鉧', '銧', '鉷', '鉸', '鋧', '鋗', '鋙', '鋐', '﨧', '鋕', '鋠', '鋓', '錥', '錡', '鋻', '﨨', '錞', '鋿', '錝', '錂', '鍰', '鍗', '鎤', '鏆', '鏞', '鏸', '鐱', '鑅', '鑈', '閒', '隆', '﨩', '隝', '隯', '霳', '霻', '靃', '靍', '靏', '靑', '靕', '顗', '顥', '飯', '飼', '餧', '館', '馞', '驎', '髙', '\u{FFFD}', '\u{FFFD}']),
+    (0xFC, ['髜', '魵', '魲', '鮏', '鮱', '鮻', '鰀', '鵰', '鵫', '鶴', '鸙', '黑', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+];
+
+/// Characters of the single bytes 0x80 to 0xFF of code page 936, lead bytes are unmapped
+pub static CP936: [char; 128] = ['€', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}'];
+/// Characters of the double-byte sequences of code page 936: for each lead byte, the characters
+/// of the trail bytes 0x40 to 0xFE
+pub static CP936_LEAD_BYTES: [(u8, [char; 191]); 126] = [
+    (0x81, ['丂', '丄', '丅', '丆', '丏', '丒', '丗', '丟', '丠', '両', '丣', '並', '丩', '丮', '丯', '丱', '丳', '丵', '丷', '丼', '乀', '乁', '乂', '乄', '乆', '乊', '乑', '乕', '乗', '乚', '乛', '乢', '乣', '乤', '乥', '乧', '乨', '乪', '乫', '乬', '乭', '乮', '乯', '乲', '乴', '乵', '乶', '乷', '乸', '乹', '乺', '乻', '乼', '乽', '乿', '亀', '亁', '亂', '亃', '亄', '亅', '亇', '亊', '\u{FFFD}', '亐', '亖', '亗', '亙', '亜', '亝', '亞', '亣', '亪', '亯', '亰', '亱', '亴', '亶', '亷', '亸', '亹', '亼', '亽', '亾', '仈', '仌', '仏', '仐', '仒', '仚', '仛', '仜', '仠', '仢', '仦', '仧', '仩', '仭', '仮', '仯', '仱', '仴', '仸', '仹', '仺', '仼', '仾', '伀', '伂', '伃', '伄', '伅', '伆', '伇', '伈', '伋', '伌', '伒', '伓', '伔', '伕', '伖', '伜', '伝', '伡', '伣', '伨', '伩', '伬', '伭', '伮', '伱', '伳', '伵', '伷', '伹', '伻', '伾', '伿', '佀', '佁', '佂', '佄', '佅', '佇', '佈', '佉', '佊', '佋', '佌', '佒', '佔', '佖', '佡', '佢', '佦', '佨', '佪', '佫', '佭', '佮', '佱', '佲', '併', '佷', '佸', '佹', '佺', '佽', '侀', '侁', '侂', '侅', '來', '侇', '侊', '侌', '侎', '侐', '侒', '侓', '侕', '侖', '侘', '侙', '侚', '侜', '侞', '侟', '価', '侢']),
+    (0x82, ['侤', '侫', '侭', '侰', '侱', '侲', '侳', '侴', '侶', '侷', '侸', '侹', '侺', '侻', '侼', '侽', '侾', '俀', '俁', '係', '俆', '俇', '俈', '俉', '俋', '俌', '俍', '俒', '俓', '俔', '俕', '俖', '俙', '俛', '俠', '俢', '俤', '俥', '俧', '俫', '俬', '俰', '俲', '俴', '俵', '俶', '俷', '俹', '俻', '俼', '俽', '俿', '倀', '倁', '倂', '倃', '倄', '倅', '倆', '倇', '倈', '倉', '倊', '\u{FFFD}', '個', '倎', '倐', '們', '倓', '倕', '倖', '倗', '倛', '倝', '倞', '倠', '倢', '倣', '値', '倧', '倫', '倯', '倰', '倱', '倲', '倳', '倴', '倵', '倶', '倷', '倸', '倹', '倻', '倽', '倿', '偀', '偁', '偂', '偄', '偅', '偆', '偉', '偊', '偋', '偍', '偐', '偑', '偒', '偓', '偔', '偖', '偗', '偘', '偙', '偛', '偝', '偞', '偟', '偠', '偡', '偢', '偣', '偤', '偦', '偧', '偨', '偩', '偪', '偫', '偭', '偮', '偯', '偰', '偱', '偲', '偳', '側', '偵', '偸', '偹', '偺', '偼', '偽', '傁', '傂', '傃', '傄', '傆', '傇', '傉', '傊', '傋', '傌', '傎', '傏', '傐', '傑', '傒', '傓', '傔', '傕', '傖', '傗', '傘', '備', '傚', '傛', '傜', '傝', '傞', '傟', '傠', '傡', '傢', '傤', '傦', '傪', '傫', '傭', '傮', '傯', '傰', '傱', '傳', '傴', '債', '傶', '傷', '傸', '傹', '傼']),
+    (0x83, ['傽', '傾', '傿', '僀', '僁', '僂', '僃', '僄', '僅', '僆', '僇', '僈', '僉', '僊', '僋', '僌', '働', '僎', '僐', '僑', '僒', '僓', '僔', '僕', '僗', '僘', '僙', '僛', '僜', '僝', '僞', '僟', '僠', '僡', '僢', '僣', '僤', '僥', '僨', '僩', '僪', '僫', '僯', '僰', '僱', '僲', '僴', '僶', '僷', '僸', '價', '僺', '僼', '僽', '僾', '僿', '儀', '儁', '儂', '儃', '億', '儅', '儈', '\u{FFFD}', '儉', '儊', '儌', '儍', '儎', '儏', '儐', '儑', '儓', '儔', '儕', '儖', '儗', '儘', '儙', '儚', '儛', '儜', '儝', '儞', '償', '儠', '儢', '儣', '儤', '儥', '儦', '儧', '儨', '儩', '優', '儫', '儬', '儭', '儮', '儯', '儰', '儱', '儲', '儳', '儴', '儵', '儶', '儷', '儸', '儹', '儺', '儻', '儼', '儽', '儾', '兂', '兇', '兊', '兌', '兎', '兏', '児', '兒', '兓', '兗', '兘', '兙', '兛', '兝', '兞', '兟', '兠', '兡', '兣', '兤', '兦', '內', '兩', '兪', '兯', '兲', '兺', '兾', '兿', '冃', '冄', '円', '冇', '冊', '冋', '冎', '冏', '冐', '冑', '冓', '冔', '冘', '冚', '冝', '冞', '冟', '冡', '冣', '冦', '冧', '冨', '冩', '冪', '冭', '冮', '冴', '冸', '冹', '冺', '冾', '冿', '凁', '凂', '凃', '凅', '凈', '凊', '凍', '凎', '凐', '凒', '凓', '凔', '凕', '凖', '凗']),
+    (0x84, ['凘', '凙', '凚', '凜', '凞', '凟', '凢', '凣', '凥', '処', '凧', '凨', '凩', '凪', '凬', '凮', '凱', '凲', '凴', '凷', '凾', '刄', '刅', '刉', '刋', '刌', '刏', '刐', '刓', '刔', '刕', '刜', '刞', '刟', '刡', '刢', '刣', '別', '刦', '刧', '刪', '刬', '刯', '刱', '刲', '刴', '刵', '刼', '刾', '剄', '剅', '剆', '則', '剈', '剉', '剋', '剎', '剏', '剒', '剓', '剕', '剗', '剘', '\u{FFFD}', '剙', '剚', '剛', '剝', '剟', '剠', '剢', '剣', '剤', '剦', '剨', '剫', '剬', '剭', '剮', '剰', '剱', '剳', '剴', '創', '剶', '剷', '剸', '剹', '剺', '剻', '剼', '剾', '劀', '劃', '劄', '劅', '劆', '劇', '劉', '劊', '劋', '劌', '劍', '劎', '劏', '劑', '劒', '劔', '劕', '劖', '劗', '劘', '劙', '劚', '劜', '劤', '劥', '劦', '劧', '劮', '劯', '劰', '労', '劵', '劶', '劷', '劸', '効', '劺', '劻', '劼', '劽', '勀', '勁', '勂', '勄', '勅', '勆', '勈', '勊', '勌', '勍', '勎', '勏', '勑', '勓', '勔', '動', '勗', '務', '勚', '勛', '勜', '勝', '勞', '勠', '勡', '勢', '勣', '勥', '勦', '勧', '勨', '勩', '勪', '勫', '勬', '勭', '勮', '勯', '勱', '勲', '勳', '勴', '勵', '勶', '勷', '勸', '勻', '勼', '勽', '匁', '匂', '匃', '匄', '匇', '匉', '匊', '匋', '匌', '匎']),
+    (0x85, ['匑', '匒', '匓', '匔', '匘', '匛', '匜', '匞', '匟', '匢', '匤', '匥', '匧', '匨', '匩', '匫', '匬', '匭', '匯', '匰', '匱', '匲', '匳', '匴', '匵', '匶', '匷', '匸', '匼', '匽', '區', '卂', '卄', '卆', '卋', '卌', '卍', '卐', '協', '単', '卙', '卛', '卝', '卥', '卨', '卪', '卬', '卭', '卲', '卶', '卹', '卻', '卼', '卽', '卾', '厀', '厁', '厃', '厇', '厈', '厊', '厎', '厏', '\u{FFFD}', '厐', '厑', '厒', '厓', '厔', '厖', '厗', '厙', '厛', '厜', '厞', '厠', '厡', '厤', '厧', '厪', '厫', '厬', '厭', '厯', '厰', '厱', '厲', '厳', '厴', '厵', '厷', '厸', '厹', '厺', '厼', '厽', '厾', '叀', '參', '叄', '叅', '叆', '叇', '収', '叏', '叐', '叒', '叓', '叕', '叚', '叜', '叝', '叞', '叡', '叢', '叧', '叴', '叺', '叾', '叿', '吀', '吂', '吅', '吇', '吋', '吔', '吘', '吙', '吚', '吜', '吢', '吤', '吥', '吪', '吰', '吳', '吶', '吷', '吺', '吽', '吿', '呁', '呂', '呄', '呅', '呇', '呉', '呌', '呍', '呎', '呏', '呑', '呚', '呝', '呞', '呟', '呠', '呡', '呣', '呥', '呧', '呩', '呪', '呫', '呬', '呭', '呮', '呯', '呰', '呴', '呹', '呺', '呾', '呿', '咁', '咃', '咅', '咇', '咈', '咉', '咊', '咍', '咑', '咓', '咗', '咘', '咜', '咞', '咟', '咠', '咡']),
+    (0x86, ['咢', '咥', '咮', '咰', '咲', '咵', '咶', '咷', '咹', '咺', '咼', '咾', '哃', '哅', '哊', '哋', '哖', '哘', '哛', '哠', '員', '哢', '哣', '哤', '哫', '哬', '哯', '哰', '哱', '哴', '哵', '哶', '哷', '哸', '哹', '哻', '哾', '唀', '唂', '唃', '唄', '唅', '唈', '唊', '唋', '唌', '唍', '唎', '唒', '唓', '唕', '唖', '唗', '唘', '唙', '唚', '唜', '唝', '唞', '唟', '唡', '唥', '唦', '\u{FFFD}', '唨', '唩', '唫', '唭', '唲', '唴', '唵', '唶', '唸', '唹', '唺', '唻', '唽', '啀', '啂', '啅', '啇', '啈', '啋', '啌', '啍', '啎', '問', '啑', '啒', '啓', '啔', '啗', '啘', '啙', '啚', '啛', '啝', '啞', '啟', '啠', '啢', '啣', '啨', '啩', '啫', '啯', '啰', '啱', '啲', '啳', '啴', '啹', '啺', '啽', '啿', '喅', '喆', '喌', '喍', '喎', '喐', '喒', '喓', '喕', '喖', '喗', '喚', '喛', '喞', '喠', '喡', '喢', '喣', '喤', '喥', '喦', '喨', '喩', '喪', '喫', '喬', '喭', '單', '喯', '喰', '喲', '喴', '営', '喸', '喺', '喼', '喿', '嗀', '嗁', '嗂', '嗃', '嗆', '嗇', '嗈', '嗊', '嗋', '嗎', '嗏', '嗐', '嗕', '嗗', '嗘', '嗙', '嗚', '嗛', '嗞', '嗠', '嗢', '嗧', '嗩', '嗭', '嗮', '嗰', '嗱', '嗴', '嗶', '嗸', '嗹', '嗺', '嗻', '嗼', '嗿', '嘂', '嘃', '嘄', '嘅']),
+    (0x87, ['嘆', '嘇', '嘊', '嘋', '嘍', '嘐', '嘑', '嘒', '嘓', '嘔', '嘕', '嘖', '嘗', '嘙', '嘚', '嘜', '嘝', '嘠', '嘡', '嘢', '嘥', '嘦', '嘨', '嘩', '嘪', '嘫', '嘮', '嘯', '嘰', '嘳', '嘵', '嘷', '嘸', '嘺', '嘼', '嘽', '嘾', '噀', '噁', '噂', '噃', '噄', '噅', '噆', '噇', '噈', '噉', '噊', '噋', '噏', '噐', '噑', '噒', '噓', '噕', '噖', '噚', '噛', '噝', '噞', '噟', '噠', '噡', '\u{FFFD}', '噣', '噥', '噦', '噧', '噭', '噮', '噯', '噰', '噲', '噳', '噴', '噵', '噷', '噸', '噹', '噺', '噽', '噾', '噿', '嚀', '嚁', '嚂', '嚃', '嚄', '嚇', '嚈', '嚉', '嚊', '嚋', '嚌', '嚍', '嚐', '嚑', '嚒', '嚔', '嚕', '嚖', '嚗', '嚘', '嚙', '嚚', '嚛', '嚜', '嚝', '嚞', '嚟', '嚠', '嚡', '嚢', '嚤', '嚥', '嚦', '嚧', '嚨', '嚩', '嚪', '嚫', '嚬', '嚭', '嚮', '嚰', '嚱', '嚲', '嚳', '嚴', '嚵', '嚶', '嚸', '嚹', '嚺', '嚻', '嚽', '嚾', '嚿', '囀', '囁', '囂', '囃', '囄', '囅', '囆', '囇', '囈', '囉', '囋', '囌', '囍', '囎', '囏', '囐', '囑', '囒', '囓', '囕', '囖', '囘', '囙', '囜', '団', '囥', '囦', '囧', '囨', '囩', '囪', '囬', '囮', '囯', '囲', '図', '囶', '囷', '囸', '囻', '囼', '圀', '圁', '圂', '圅', '圇', '國', '圌', '圍', '圎', '圏', '圐', '圑']),
+    (0x88, ['園', '圓', '圔', '圕', '圖', '圗', '團', '圙', '圚', '圛', '圝', '圞', '圠', '圡', '圢', '圤', '圥', '圦', '圧', '圫', '圱', '圲', '圴', '圵', '圶', '圷', '圸', '圼', '圽', '圿', '坁', '坃', '坄', '坅', '坆', '坈', '坉', '坋', '坒', '坓', '坔', '坕', '坖', '坘', '坙', '坢', '坣', '坥', '坧', '坬', '坮', '坰', '坱', '坲', '坴', '坵', '坸', '坹', '坺', '坽', '坾', '坿', '垀', '\u{FFFD}', '垁', '垇', '垈', '垉', '垊', '垍', '垎', '垏', '垐', '垑', '垔', '垕', '垖', '垗', '垘', '垙', '垚', '垜', '垝', '垞', '垟', '垥', '垨', '垪', '垬', '垯', '垰', '垱', '垳', '垵', '垶', '垷', '垹', '垺', '垻', '垼', '垽', '垾', '垿', '埀', '埁', '埄', '埅', '埆', '埇', '埈', '埉', '埊', '埌', '埍', '埐', '埑', '埓', '埖', '埗', '埛', '埜', '埞', '埡', '埢', '埣', '埥', '埦', '埧', '埨', '埩', '埪', '埫', '埬', '埮', '埰', '埱', '埲', '埳', '埵', '埶', '執', '埻', '埼', '埾', '埿', '堁', '堃', '堄', '堅', '堈', '堉', '堊', '堌', '堎', '堏', '堐', '堒', '堓', '堔', '堖', '堗', '堘', '堚', '堛', '堜', '堝', '堟', '堢', '堣', '堥', '堦', '堧', '堨', '堩', '堫', '堬', '堭', '堮', '堯', '報', '堲', '堳', '場', '堶', '堷', '堸', '堹', '堺', '堻', '堼', '堽']),
+    (0x89, ['堾', '堿', '塀', '塁', '塂', '塃', '塅', '塆', '塇', '塈', '塉', '塊', '塋', '塎', '塏', '塐', '塒', '塓', '塕', '塖', '塗', '塙', '塚', '塛', '塜', '塝', '塟', '塠', '塡', '塢', '塣', '塤', '塦', '塧', '塨', '塩', '塪', '塭', '塮', '塯', '塰', '塱', '塲', '塳', '塴', '塵', '塶', '塷', '塸', '塹', '塺', '塻', '塼', '塽', '塿', '墂', '墄', '墆', '墇', '墈', '墊', '墋', '墌', '\u{FFFD}', '墍', '墎', '墏', '墐', '墑', '墔', '墕', '墖', '増', '墘', '墛', '墜', '墝', '墠', '墡', '墢', '墣', '墤', '墥', '墦', '墧', '墪', '墫', '墬', '墭', '墮', '墯', '墰', '墱', '墲', '墳', '墴', '墵', '墶', '墷', '墸', '墹', '墺', '墻', '墽', '墾', '墿', '壀', '壂', '壃', '壄', '壆', '壇', '壈', '壉', '壊', '壋', '壌', '壍', '壎', '壏', '壐', '壒', '壓', '壔', '壖', '壗', '壘', '壙', '壚', '壛', '壜', '壝', '壞', '壟', '壠', '壡', '壢', '壣', '壥', '壦', '壧', '壨', '壩', '壪', '壭', '壯', '壱', '売', '壴', '壵', '壷', '壸', '壺', '壻', '壼', '壽', '壾', '壿', '夀', '夁', '夃', '夅', '夆', '夈', '変', '夊', '夋', '夌', '夎', '夐', '夑', '夒', '夓', '夗', '夘', '夛', '夝', '夞', '夠', '夡', '夢', '夣', '夦', '夨', '夬', '夰', '夲', '夳', '夵', '夶', '夻']),
+    (0x8A, ['夽', '夾', '夿', '奀', '奃', '奅', '奆', '奊', '奌', '奍', '奐', '奒', '奓', '奙', '奛', '奜', '奝', '奞', '奟', '奡', '奣', '奤', '奦', '奧', '奨', '奩', '奪', '奫', '奬', '奭', '奮', '奯', '奰', '奱', '奲', '奵', '奷', '奺', '奻', '奼', '奾', '奿', '妀', '妅', '妉', '妋', '妌', '妎', '妏', '妐', '妑', '妔', '妕', '妘', '妚', '妛', '妜', '妝', '妟', '妠', '妡', '妢', '妦', '\u{FFFD}', '妧', '妬', '妭', '妰', '妱', '妳', '妴', '妵', '妶', '妷', '妸', '妺', '妼', '妽', '妿', '姀', '姁', '姂', '姃', '姄', '姅', '姇', '姈', '姉', '姌', '姍', '姎', '姏', '姕', '姖', '姙', '姛', '姞', '姟', '姠', '姡', '姢', '姤', '姦', '姧', '姩', '姪', '姫', '姭', '姮', '姯', '姰', '姱', '姲', '姳', '姴', '姵', '姶', '姷', '姸', '姺', '姼', '姽', '姾', '娀', '娂', '娊', '娋', '娍', '娎', '娏', '娐', '娒', '娔', '娕', '娖', '娗', '娙', '娚', '娛', '娝', '娞', '娡', '娢', '娤', '娦', '娧', '娨', '娪', '娫', '娬', '娭', '娮', '娯', '娰', '娳', '娵', '娷', '娸', '娹', '娺', '娻', '娽', '娾', '娿', '婁', '婂', '婃', '婄', '婅', '婇', '婈', '婋', '婌', '婍', '婎', '婏', '婐', '婑', '婒', '婓', '婔', '婖', '婗', '婘', '婙', '婛', '婜', '婝', '婞', '婟', '婠']),
+    (0x8B, ['婡', '婣', '婤', '婥', '婦', '婨', '婩', '婫', '婬', '婭', '婮', '婯', '婰', '婱', '婲', '婳', '婸', '婹', '婻', '婼', '婽', '婾', '媀', '媁', '媂', '媃', '媄', '媅', '媆', '媇', '媈', '媉', '媊', '媋', '媌', '媍', '媎', '媏', '媐', '媑', '媓', '媔', '媕', '媖', '媗', '媘', '媙', '媜', '媝', '媞', '媟', '媠', '媡', '媢', '媣', '媤', '媥', '媦', '媧', '媨', '媩', '媫', '媬', '\u{FFFD}', '媭', '媮', '媯', '媰', '媱', '媴', '媶', '媷', '媹', '媺', '媻', '媼', '媽', '媿', '嫀', '嫃', '嫄', '嫅', '嫆', '嫇', '嫈', '嫊', '嫋', '嫍', '嫎', '嫏', '嫐', '嫑', '嫓', '嫕', '嫗', '嫙', '嫚', '嫛', '嫝', '嫞', '嫟', '嫢', '嫤', '嫥', '嫧', '嫨', '嫪', '嫬', '嫭', '嫮', '嫯', '嫰', '嫲', '嫳', '嫴', '嫵', '嫶', '嫷', '嫸', '嫹', '嫺', '嫻', '嫼', '嫽', '嫾', '嫿', '嬀', '嬁', '嬂', '嬃', '嬄', '嬅', '嬆', '嬇', '嬈', '嬊', '嬋', '嬌', '嬍', '嬎', '嬏', '嬐', '嬑', '嬒', '嬓', '嬔', '嬕', '嬘', '嬙', '嬚', '嬛', '嬜', '嬝', '嬞', '嬟', '嬠', '嬡', '嬢', '嬣', '嬤', '嬥', '嬦', '嬧', '嬨', '嬩', '嬪', '嬫', '嬬', '嬭', '嬮', '嬯', '嬰', '嬱', '嬳', '嬵', '嬶', '嬸', '嬹', '嬺', '嬻', '嬼', '嬽', '嬾', '嬿', '孁', '孂', '孃', '孄', '孅', '孆', '孇']),
+    (0x8C, ['孈', '孉', '孊', '孋', '孌', '孍', '孎', '孏', '孒', '孖', '孞', '孠', '孡', '孧', '孨', '孫', '孭', '孮', '孯', '孲', '孴', '孶', '孷', '學', '孹', '孻', '孼', '孾', '孿', '宂', '宆', '宊', '宍', '宎', '宐', '宑', '宒', '宔', '宖', '実', '宧', '宨', '宩', '宬', '宭', '宮', '宯', '宱', '宲', '宷', '宺', '宻', '宼', '寀', '寁', '寃', '寈', '寉', '寊', '寋', '寍', '寎', '寏', '\u{FFFD}', '寑', '寔', '寕', '寖', '寗', '寘', '寙', '寚', '寛', '寜', '寠', '寢', '寣', '實', '寧', '審', '寪', '寫', '寬', '寭', '寯', '寱', '寲', '寳', '寴', '寵', '寶', '寷', '寽', '対', '尀', '専', '尃', '尅', '將', '專', '尋', '尌', '對', '導', '尐', '尒', '尓', '尗', '尙', '尛', '尞', '尟', '尠', '尡', '尣', '尦', '尨', '尩', '尪', '尫', '尭', '尮', '尯', '尰', '尲', '尳', '尵', '尶', '尷', '屃', '屄', '屆', '屇', '屌', '屍', '屒', '屓', '屔', '屖', '屗', '屘', '屚', '屛', '屜', '屝', '屟', '屢', '層', '屧', '屨', '屩', '屪', '屫', '屬', '屭', '屰', '屲', '屳', '屴', '屵', '屶', '屷', '屸', '屻', '屼', '屽', '屾', '岀', '岃', '岄', '岅', '岆', '岇', '岉', '岊', '岋', '岎', '岏', '岒', '岓', '岕', '岝', '岞', '岟', '岠', '岡', '岤', '岥', '岦', '岧', '岨']),
+    (0x8D, ['岪', '岮', '岯', '岰', '岲', '岴', '岶', '岹', '岺', '岻', '岼', '岾', '峀', '峂', '峃', '峅', '峆', '峇', '峈', '峉', '峊', '峌', '峍', '峎', '峏', '峐', '峑', '峓', '峔', '峕', '峖', '峗', '峘', '峚', '峛', '峜', '峝', '峞', '峟', '峠', '峢', '峣', '峧', '峩', '峫', '峬', '峮', '峯', '峱', '峲', '峳', '峴', '峵', '島', '峷', '峸', '峹', '峺', '峼', '峽', '峾', '峿', '崀', '\u{FFFD}', '崁', '崄', '崅', '崈', '崉', '崊', '崋', '崌', '崍', '崏', '崐', '崑', '崒', '崓', '崕', '崗', '崘', '崙', '崚', '崜', '崝', '崟', '崠', '崡', '崢', '崣', '崥', '崨', '崪', '崫', '崬', '崯', '崰', '崱', '崲', '崳', '崵', '崶', '崷', '崸', '崹', '崺', '崻', '崼', '崿', '嵀', '嵁', '嵂', '嵃', '嵄', '嵅', '嵆', '嵈', '嵉', '嵍', '嵎', '嵏', '嵐', '嵑', '嵒', '嵓', '嵔', '嵕', '嵖', '嵗', '嵙', '嵚', '嵜', '嵞', '嵟', '嵠', '嵡', '嵢', '嵣', '嵤', '嵥', '嵦', '嵧', '嵨', '嵪', '嵭', '嵮', '嵰', '嵱', '嵲', '嵳', '嵵', '嵶', '嵷', '嵸', '嵹', '嵺', '嵻', '嵼', '嵽', '嵾', '嵿', '嶀', '嶁', '嶃', '嶄', '嶅', '嶆', '嶇', '嶈', '嶉', '嶊', '嶋', '嶌', '嶍', '嶎', '嶏', '嶐', '嶑', '嶒', '嶓', '嶔', '嶕', '嶖', '嶗', '嶘', '嶚', '嶛', '嶜', '嶞', '嶟', '嶠']),
+    (0x8E, ['嶡', '嶢', '嶣', '嶤', '嶥', '嶦', '嶧', '嶨', '嶩', '嶪', '嶫', '嶬', '嶭', '嶮', '嶯', '嶰', '嶱', '嶲', '嶳', '嶴', '嶵', '嶶', '嶸', '嶹', '嶺', '嶻', '嶼', '嶽', '嶾', '嶿', '巀', '巁', '巂', '巃', '巄', '巆', '巇', '巈', '巉', '巊', '巋', '巌', '巎', '巏', '巐', '巑', '巒', '巓', '巔', '巕', '巖', '巗', '巘', '巙', '巚', '巜', '巟', '巠', '巣', '巤', '巪', '巬', '巭', '\u{FFFD}', '巰', '巵', '巶', '巸', '巹', '巺', '巻', '巼', '巿', '帀', '帄', '帇', '帉', '帊', '帋', '帍', '帎', '帒', '帓', '帗', '帞', '帟', '帠', '帡', '帢', '帣', '帤', '帥', '帨', '帩', '帪', '師', '帬', '帯', '帰', '帲', '帳', '帴', '帵', '帶', '帹', '帺', '帾', '帿', '幀', '幁', '幃', '幆', '幇', '幈', '幉', '幊', '幋', '幍', '幎', '幏', '幐', '幑', '幒', '幓', '幖', '幗', '幘', '幙', '幚', '幜', '幝', '幟', '幠', '幣', '幤', '幥', '幦', '幧', '幨', '幩', '幪', '幫', '幬', '幭', '幮', '幯', '幰', '幱', '幵', '幷', '幹', '幾', '庁', '庂', '広', '庅', '庈', '庉', '庌', '庍', '庎', '庒', '庘', '庛', '庝', '庡', '庢', '庣', '庤', '庨', '庩', '庪', '庫', '庬', '庮', '庯', '庰', '庱', '庲', '庴', '庺', '庻', '庼', '庽', '庿', '廀', '廁', '廂', '廃', '廄', '廅']),
+    (0x8F, ['廆', '廇', '廈', '廋', '廌', '廍', '廎', '廏', '廐', '廔', '廕', '廗', '廘', '廙', '廚', '廜', '廝', '廞', '廟', '廠', '廡', '廢', '廣', '廤', '廥', '廦', '廧', '廩', '廫', '廬', '廭', '廮', '廯', '廰', '廱', '廲', '廳', '廵', '廸', '廹', '廻', '廼', '廽', '弅', '弆', '弇', '弉', '弌', '弍', '弎', '弐', '弒', '弔', '弖', '弙', '弚', '弜', '弝', '弞', '弡', '弢', '弣', '弤', '\u{FFFD}', '弨', '弫', '弬', '弮', '弰', '弲', '弳', '弴', '張', '弶', '強', '弸', '弻', '弽', '弾', '弿', '彁', '彂', '彃', '彄', '彅', '彆', '彇', '彈', '彉', '彊', '彋', '彌', '彍', '彎', '彏', '彑', '彔', '彙', '彚', '彛', '彜', '彞', '彟', '彠', '彣', '彥', '彧', '彨', '彫', '彮', '彯', '彲', '彴', '彵', '彶', '彸', '彺', '彽', '彾', '彿', '徃', '徆', '徍', '徎', '徏', '徑', '従', '徔', '徖', '徚', '徛', '徝', '從', '徟', '徠', '徢', '徣', '徤', '徥', '徦', '徧', '復', '徫', '徬', '徯', '徰', '徱', '徲', '徳', '徴', '徶', '徸', '徹', '徺', '徻', '徾', '徿', '忀', '忁', '忂', '忇', '忈', '忊', '忋', '忎', '忓', '忔', '忕', '忚', '忛', '応', '忞', '忟', '忢', '忣', '忥', '忦', '忨', '忩', '忬', '忯', '忰', '忲', '忳', '忴', '忶', '忷', '忹', '忺', '忼', '怇']),
+    (0x90, ['怈', '怉', '怋', '怌', '怐', '怑', '怓', '怗', '怘', '怚', '怞', '怟', '怢', '怣', '怤', '怬', '怭', '怮', '怰', '怱', '怲', '怳', '怴', '怶', '怷', '怸', '怹', '怺', '怽', '怾', '恀', '恄', '恅', '恆', '恇', '恈', '恉', '恊', '恌', '恎', '恏', '恑', '恓', '恔', '恖', '恗', '恘', '恛', '恜', '恞', '恟', '恠', '恡', '恥', '恦', '恮', '恱', '恲', '恴', '恵', '恷', '恾', '悀', '\u{FFFD}', '悁', '悂', '悅', '悆', '悇', '悈', '悊', '悋', '悎', '悏', '悐', '悑', '悓', '悕', '悗', '悘', '悙', '悜', '悞', '悡', '悢', '悤', '悥', '悧', '悩', '悪', '悮', '悰', '悳', '悵', '悶', '悷', '悹', '悺', '悽', '悾', '悿', '惀', '惁', '惂', '惃', '惄', '惇', '惈', '惉', '惌', '惍', '惎', '惏', '惐', '惒', '惓', '惔', '惖', '惗', '惙', '惛', '惞', '惡', '惢', '惣', '惤', '惥', '惪', '惱', '惲', '惵', '惷', '惸', '惻', '惼', '惽', '惾', '惿', '愂', '愃', '愄', '愅', '愇', '愊', '愋', '愌', '愐', '愑', '愒', '愓', '愔', '愖', '愗', '愘', '愙', '愛', '愜', '愝', '愞', '愡', '愢', '愥', '愨', '愩', '愪', '愬', '愭', '愮', '愯', '愰', '愱', '愲', '愳', '愴', '愵', '愶', '愷', '愸', '愹', '愺', '愻', '愼', '愽', '愾', '慀', '慁', '慂', '慃', '慄', '慅', '慆']),
+    (0x91, ['慇', '慉', '態', '慍', '慏', '慐', '慒', '慓', '慔', '慖', '慗', '慘', '慙', '慚', '慛', '慜', '慞', '慟', '慠', '慡', '慣', '慤', '慥', '慦', '慩', '慪', '慫', '慬', '慭', '慮', '慯', '慱', '慲', '慳', '慴', '慶', '慸', '慹', '慺', '慻', '慼', '慽', '慾', '慿', '憀', '憁', '憂', '憃', '憄', '憅', '憆', '憇', '憈', '憉', '憊', '憌', '憍', '憏', '憐', '憑', '憒', '憓', '憕', '\u{FFFD}', '憖', '憗', '憘', '憙', '憚', '憛', '憜', '憞', '憟', '憠', '憡', '憢', '憣', '憤', '憥', '憦', '憪', '憫', '憭', '憮', '憯', '憰', '憱', '憲', '憳', '憴', '憵', '憶', '憸', '憹', '憺', '憻', '憼', '憽', '憿', '懀', '懁', '懃', '懄', '懅', '懆', '懇', '應', '懌', '懍', '懎', '懏', '懐', '懓', '懕', '懖', '懗', '懘', '懙', '懚', '懛', '懜', '懝', '懞', '懟', '懠', '懡', '懢', '懣', '懤', '懥', '懧', '懨', '懩', '懪', '懫', '懬', '懭', '懮', '懯', '懰', '懱', '懲', '懳', '懴', '懶', '懷', '懸', '懹', '懺', '懻', '懼', '懽', '懾', '戀', '戁', '戂', '戃', '戄', '戅', '戇', '戉', '戓', '戔', '戙', '戜', '戝', '戞', '戠', '戣', '戦', '戧', '戨', '戩', '戫', '戭', '戯', '戰', '戱', '戲', '戵', '戶', '戸', '戹', '戺', '戻', '戼', '扂', '扄', '扅', '扆', '扊']),
+    (0x92, ['扏', '扐', '払', '扖', '扗', '扙', '扚', '扜', '扝', '扞', '扟', '扠', '扡', '扢', '扤', '扥', '扨', '扱', '扲', '扴', '扵', '扷', '扸', '扺', '扻', '扽', '抁', '抂', '抃', '抅', '抆', '抇', '抈', '抋', '抌', '抍', '抎', '抏', '抐', '抔', '抙', '抜', '抝', '択', '抣', '抦', '抧', '抩', '抪', '抭', '抮', '抯', '抰', '抲', '抳', '抴', '抶', '抷', '抸', '抺', '抾', '拀', '拁', '\u{FFFD}', '拃', '拋', '拏', '拑', '拕', '拝', '拞', '拠', '拡', '拤', '拪', '拫', '拰', '拲', '拵', '拸', '拹', '拺', '拻', '挀', '挃', '挄', '挅', '挆', '挊', '挋', '挌', '挍', '挏', '挐', '挒', '挓', '挔', '挕', '挗', '挘', '挙', '挜', '挦', '挧', '挩', '挬', '挭', '挮', '挰', '挱', '挳', '挴', '挵', '挶', '挷', '挸', '挻', '挼', '挾', '挿', '捀', '捁', '捄', '捇', '捈', '捊', '捑', '捒', '捓', '捔', '捖', '捗', '捘', '捙', '捚', '捛', '捜', '捝', '捠', '捤', '捥', '捦', '捨', '捪', '捫', '捬', '捯', '捰', '捲', '捳', '捴', '捵', '捸', '捹', '捼', '捽', '捾', '捿', '掁', '掃', '掄', '掅', '掆', '掋', '掍', '掑', '掓', '掔', '掕', '掗', '掙', '掚', '掛', '掜', '掝', '掞', '掟', '採', '掤', '掦', '掫', '掯', '掱', '掲', '掵', '掶', '掹', '掻', '掽', '掿', '揀']),
+    (0x93, ['揁', '揂', '揃', '揅', '揇', '揈', '揊', '揋', '揌', '揑', '揓', '揔', '揕', '揗', '揘', '揙', '揚', '換', '揜', '揝', '揟', '揢', '揤', '揥', '揦', '揧', '揨', '揫', '揬', '揮', '揯', '揰', '揱', '揳', '揵', '揷', '揹', '揺', '揻', '揼', '揾', '搃', '搄', '搆', '搇', '搈', '搉', '搊', '損', '搎', '搑', '搒', '搕', '搖', '搗', '搘', '搙', '搚', '搝', '搟', '搢', '搣', '搤', '\u{FFFD}', '搥', '搧', '搨', '搩', '搫', '搮', '搯', '搰', '搱', '搲', '搳', '搵', '搶', '搷', '搸', '搹', '搻', '搼', '搾', '摀', '摂', '摃', '摉', '摋', '摌', '摍', '摎', '摏', '摐', '摑', '摓', '摕', '摖', '摗', '摙', '摚', '摛', '摜', '摝', '摟', '摠', '摡', '摢', '摣', '摤', '摥', '摦', '摨', '摪', '摫', '摬', '摮', '摯', '摰', '摱', '摲', '摳', '摴', '摵', '摶', '摷', '摻', '摼', '摽', '摾', '摿', '撀', '撁', '撃', '撆', '撈', '撉', '撊', '撋', '撌', '撍', '撎', '撏', '撐', '撓', '撔', '撗', '撘', '撚', '撛', '撜', '撝', '撟', '撠', '撡', '撢', '撣', '撥', '撦', '撧', '撨', '撪', '撫', '撯', '撱', '撲', '撳', '撴', '撶', '撹', '撻', '撽', '撾', '撿', '擁', '擃', '擄', '擆', '擇', '擈', '擉', '擊', '擋', '擌', '擏', '擑', '擓', '擔', '擕', '擖', '擙', '據']),
+    (0x94, ['擛', '擜', '擝', '擟', '擠', '擡', '擣', '擥', '擧', '擨', '擩', '擪', '擫', '擬', '擭', '擮', '擯', '擰', '擱', '擲', '擳', '擴', '擵', '擶', '擷', '擸', '擹', '擺', '擻', '擼', '擽', '擾', '擿', '攁', '攂', '攃', '攄', '攅', '攆', '攇', '攈', '攊', '攋', '攌', '攍', '攎', '攏', '攐', '攑', '攓', '攔', '攕', '攖', '攗', '攙', '攚', '攛', '攜', '攝', '攞', '攟', '攠', '攡', '\u{FFFD}', '攢', '攣', '攤', '攦', '攧', '攨', '攩', '攪', '攬', '攭', '攰', '攱', '攲', '攳', '攷', '攺', '攼', '攽', '敀', '敁', '敂', '敃', '敄', '敆', '敇', '敊', '敋', '敍', '敎', '敐', '敒', '敓', '敔', '敗', '敘', '敚', '敜', '敟', '敠', '敡', '敤', '敥', '敧', '敨', '敩', '敪', '敭', '敮', '敯', '敱', '敳', '敵', '敶', '數', '敹', '敺', '敻', '敼', '敽', '敾', '敿', '斀', '斁', '斂', '斃', '斄', '斅', '斆', '斈', '斉', '斊', '斍', '斎', '斏', '斒', '斔', '斕', '斖', '斘', '斚', '斝', '斞', '斠', '斢', '斣', '斦', '斨', '斪', '斬', '斮', '斱', '斲', '斳', '斴', '斵', '斶', '斷', '斸', '斺', '斻', '斾', '斿', '旀', '旂', '旇', '旈', '旉', '旊', '旍', '旐', '旑', '旓', '旔', '旕', '旘', '旙', '旚', '旛', '旜', '旝', '旞', '旟', '旡', '旣', '旤', '旪', '旫']),
+    (0x95, ['旲', '旳', '旴', '旵', '旸', '旹', '旻', '旼', '旽', '旾', '旿', '昁', '昄', '昅', '昇', '昈', '昉', '昋', '昍', '昐', '昑', '昒', '昖', '昗', '昘', '昚', '昛', '昜', '昞', '昡', '昢', '昣', '昤', '昦', '昩', '昪', '昫', '昬', '昮', '昰', '昲', '昳', '昷', '昸', '昹', '昺', '昻', '昽', '昿', '晀', '時', '晄', '晅', '晆', '晇', '晈', '晉', '晊', '晍', '晎', '晐', '晑', '晘', '\u{FFFD}', '晙', '晛', '晜', '晝', '晞', '晠', '晢', '晣', '晥', '晧', '晩', '晪', '晫', '晬', '晭', '晱', '晲', '晳', '晵', '晸', '晹', '晻', '晼', '晽', '晿', '暀', '暁', '暃', '暅', '暆', '暈', '暉', '暊', '暋', '暍', '暎', '暏', '暐', '暒', '暓', '暔', '暕', '暘', '暙', '暚', '暛', '暜', '暞', '暟', '暠', '暡', '暢', '暣', '暤', '暥', '暦', '暩', '暪', '暫', '暬', '暭', '暯', '暰', '暱', '暲', '暳', '暵', '暶', '暷', '暸', '暺', '暻', '暼', '暽', '暿', '曀', '曁', '曂', '曃', '曄', '曅', '曆', '曇', '曈', '曉', '曊', '曋', '曌', '曍', '曎', '曏', '曐', '曑', '曒', '曓', '曔', '曕', '曖', '曗', '曘', '曚', '曞', '曟', '曠', '曡', '曢', '曣', '曤', '曥', '曧', '曨', '曪', '曫', '曬', '曭', '曮', '曯', '曱', '曵', '曶', '書', '曺', '曻', '曽', '朁', '朂', '會']),
+    (0x96, ['朄', '朅', '朆', '朇', '朌', '朎', '朏', '朑', '朒', '朓', '朖', '朘', '朙', '朚', '朜', '朞', '朠', '朡', '朢', '朣', '朤', '朥', '朧', '朩', '朮', '朰', '朲', '朳', '朶', '朷', '朸', '朹', '朻', '朼', '朾', '朿', '杁', '杄', '杅', '杇', '杊', '杋', '杍', '杒', '杔', '杕', '杗', '杘', '杙', '杚', '杛', '杝', '杢', '杣', '杤', '杦', '杧', '杫', '杬', '杮', '東', '杴', '杶', '\u{FFFD}', '杸', '杹', '杺', '杻', '杽', '枀', '枂', '枃', '枅', '枆', '枈', '枊', '枌', '枍', '枎', '枏', '枑', '枒', '枓', '枔', '枖', '枙', '枛', '枟', '枠', '枡', '枤', '枦', '枩', '枬', '枮', '枱', '枲', '枴', '枹', '枺', '枻', '枼', '枽', '枾', '枿', '柀', '柂', '柅', '柆', '柇', '柈', '柉', '柊', '柋', '柌', '柍', '柎', '柕', '柖', '柗', '柛', '柟', '柡', '柣', '柤', '柦', '柧', '柨', '柪', '柫', '柭', '柮', '柲', '柵', '柶', '柷', '柸', '柹', '柺', '査', '柼', '柾', '栁', '栂', '栃', '栄', '栆', '栍', '栐', '栒', '栔', '栕', '栘', '栙', '栚', '栛', '栜', '栞', '栟', '栠', '栢', '栣', '栤', '栥', '栦', '栧', '栨', '栫', '栬', '栭', '栮', '栯', '栰', '栱', '栴', '栵', '栶', '栺', '栻', '栿', '桇', '桋', '桍', '桏', '桒', '桖', '桗', '桘', '桙', '桚', '桛']),
+    (0x97, ['桜', '桝', '桞', '桟', '桪', '桬', '桭', '桮', '桯', '桰', '桱', '桲', '桳', '桵', '桸', '桹', '桺', '桻', '桼', '桽', '桾', '桿', '梀', '梂', '梄', '梇', '梈', '梉', '梊', '梋', '梌', '梍', '梎', '梐', '梑', '梒', '梔', '梕', '梖', '梘', '梙', '梚', '梛', '梜', '條', '梞', '梟', '梠', '梡', '梣', '梤', '梥', '梩', '梪', '梫', '梬', '梮', '梱', '梲', '梴', '梶', '梷', '梸', '\u{FFFD}', '梹', '梺', '梻', '梼', '梽', '梾', '梿', '棁', '棃', '棄', '棅', '棆', '棇', '棈', '棊', '棌', '棎', '棏', '棐', '棑', '棓', '棔', '棖', '棗', '棙', '棛', '棜', '棝', '棞', '棟', '棡', '棢', '棤', '棥', '棦', '棧', '棨', '棩', '棪', '棫', '棬', '棭', '棯', '棲', '棳', '棴', '棶', '棷', '棸', '棻', '棽', '棾', '棿', '椀', '椂', '椃', '椄', '椆', '椇', '椈', '椉', '椊', '椌', '椏', '椑', '椓', '椔', '椕', '椖', '椗', '椘', '椙', '椚', '椛', '検', '椝', '椞', '椡', '椢', '椣', '椥', '椦', '椧', '椨', '椩', '椪', '椫', '椬', '椮', '椯', '椱', '椲', '椳', '椵', '椶', '椷', '椸', '椺', '椻', '椼', '椾', '楀', '楁', '楃', '楄', '楅', '楆', '楇', '楈', '楉', '楊', '楋', '楌', '楍', '楎', '楏', '楐', '楑', '楒', '楓', '楕', '楖', '楘', '楙', '楛', '楜', '楟']),
+    (0x98, ['楡', '楢', '楤', '楥', '楧', '楨', '楩', '楪', '楬', '業', '楯', '楰', '楲', '楳', '楴', '極', '楶', '楺', '楻', '楽', '楾', '楿', '榁', '榃', '榅', '榊', '榋', '榌', '榎', '榏', '榐', '榑', '榒', '榓', '榖', '榗', '榙', '榚', '榝', '榞', '榟', '榠', '榡', '榢', '榣', '榤', '榥', '榦', '榩', '榪', '榬', '榮', '榯', '榰', '榲', '榳', '榵', '榶', '榸', '榹', '榺', '榼', '榽', '\u{FFFD}', '榾', '榿', '槀', '槂', '槃', '槄', '槅', '槆', '槇', '槈', '槉', '構', '槍', '槏', '槑', '槒', '槓', '槕', '槖', '槗', '様', '槙', '槚', '槜', '槝', '槞', '槡', '槢', '槣', '槤', '槥', '槦', '槧', '槨', '槩', '槪', '槫', '槬', '槮', '槯', '槰', '槱', '槳', '槴', '槵', '槶', '槷', '槸', '槹', '槺', '槻', '槼', '槾', '樀', '樁', '樂', '樃', '樄', '樅', '樆', '樇', '樈', '樉', '樋', '樌', '樍', '樎', '樏', '樐', '樑', '樒', '樓', '樔', '樕', '樖', '標', '樚', '樛', '樜', '樝', '樞', '樠', '樢', '樣', '樤', '樥', '樦', '樧', '権', '樫', '樬', '樭', '樮', '樰', '樲', '樳', '樴', '樶', '樷', '樸', '樹', '樺', '樻', '樼', '樿', '橀', '橁', '橂', '橃', '橅', '橆', '橈', '橉', '橊', '橋', '橌', '橍', '橎', '橏', '橑', '橒', '橓', '橔', '橕', '橖', '橗', '橚']),
+    (0x99, ['橜', '橝', '橞', '機', '橠', '橢', '橣', '橤', '橦', '橧', '橨', '橩', '橪', '橫', '橬', '橭', '橮', '橯', '橰', '橲', '橳', '橴', '橵', '橶', '橷', '橸', '橺', '橻', '橽', '橾', '橿', '檁', '檂', '檃', '檅', '檆', '檇', '檈', '檉', '檊', '檋', '檌', '檍', '檏', '檒', '檓', '檔', '檕', '檖', '檘', '檙', '檚', '檛', '檜', '檝', '檞', '檟', '檡', '檢', '檣', '檤', '檥', '檦', '\u{FFFD}', '檧', '檨', '檪', '檭', '檮', '檯', '檰', '檱', '檲', '檳', '檴', '檵', '檶', '檷', '檸', '檹', '檺', '檻', '檼', '檽', '檾', '檿', '櫀', '櫁', '櫂', '櫃', '櫄', '櫅', '櫆', '櫇', '櫈', '櫉', '櫊', '櫋', '櫌', '櫍', '櫎', '櫏', '櫐', '櫑', '櫒', '櫓', '櫔', '櫕', '櫖', '櫗', '櫘', '櫙', '櫚', '櫛', '櫜', '櫝', '櫞', '櫟', '櫠', '櫡', '櫢', '櫣', '櫤', '櫥', '櫦', '櫧', '櫨', '櫩', '櫪', '櫫', '櫬', '櫭', '櫮', '櫯', '櫰', '櫱', '櫲', '櫳', '櫴', '櫵', '櫶', '櫷', '櫸', '櫹', '櫺', '櫻', '櫼', '櫽', '櫾', '櫿', '欀', '欁', '欂', '欃', '欄', '欅', '欆', '欇', '欈', '欉', '權', '欋', '欌', '欍', '欎', '欏', '欐', '欑', '欒', '欓', '欔', '欕', '欖', '欗', '欘', '欙', '欚', '欛', '欜', '欝', '欞', '欟', '欥', '欦', '欨', '欩', '欪', '欫', '欬', '欭', '欮']),
+    (0x9A, ['欯', '欰', '欱', '欳', '欴', '欵', '欶', '欸', '欻', '欼', '欽', '欿', '歀', '歁', '歂', '歄', '歅', '歈', '歊', '歋', '歍', '歎', '歏', '歐', '歑', '歒', '歓', '歔', '歕', '歖', '歗', '歘', '歚', '歛', '歜', '歝', '歞', '歟', '歠', '歡', '歨', '歩', '歫', '歬', '歭', '歮', '歯', '歰', '歱', '歲', '歳', '歴', '歵', '歶', '歷', '歸', '歺', '歽', '歾', '歿', '殀', '殅', '殈', '\u{FFFD}', '殌', '殎', '殏', '殐', '殑', '殔', '殕', '殗', '殘', '殙', '殜', '殝', '殞', '殟', '殠', '殢', '殣', '殤', '殥', '殦', '殧', '殨', '殩', '殫', '殬', '殭', '殮', '殯', '殰', '殱', '殲', '殶', '殸', '殹', '殺', '殻', '殼', '殽', '殾', '毀', '毃', '毄', '毆', '毇', '毈', '毉', '毊', '毌', '毎', '毐', '毑', '毘', '毚', '毜', '毝', '毞', '毟', '毠', '毢', '毣', '毤', '毥', '毦', '毧', '毨', '毩', '毬', '毭', '毮', '毰', '毱', '毲', '毴', '毶', '毷', '毸', '毺', '毻', '毼', '毾', '毿', '氀', '氁', '氂', '氃', '氄', '氈', '氉', '氊', '氋', '氌', '氎', '氒', '気', '氜', '氝', '氞', '氠', '氣', '氥', '氫', '氬', '氭', '氱', '氳', '氶', '氷', '氹', '氺', '氻', '氼', '氾', '氿', '汃', '汄', '汅', '汈', '汋', '汌', '汍', '汎', '汏', '汑', '汒', '汓', '汖', '汘']),
+    (0x9B, ['汙', '汚', '汢', '汣', '汥', '汦', '汧', '汫', '汬', '汭', '汮', '汯', '汱', '汳', '汵', '汷', '汸', '決', '汻', '汼', '汿', '沀', '沄', '沇', '沊', '沋', '沍', '沎', '沑', '沒', '沕', '沖', '沗', '沘', '沚', '沜', '沝', '沞', '沠', '沢', '沨', '沬', '沯', '沰', '沴', '沵', '沶', '沷', '沺', '泀', '況', '泂', '泃', '泆', '泇', '泈', '泋', '泍', '泎', '泏', '泑', '泒', '泘', '\u{FFFD}', '泙', '泚', '泜', '泝', '泟', '泤', '泦', '泧', '泩', '泬', '泭', '泲', '泴', '泹', '泿', '洀', '洂', '洃', '洅', '洆', '洈', '洉', '洊', '洍', '洏', '洐', '洑', '洓', '洔', '洕', '洖', '洘', '洜', '洝', '洟', '洠', '洡', '洢', '洣', '洤', '洦', '洨', '洩', '洬', '洭', '洯', '洰', '洴', '洶', '洷', '洸', '洺', '洿', '浀', '浂', '浄', '浉', '浌', '浐', '浕', '浖', '浗', '浘', '浛', '浝', '浟', '浡', '浢', '浤', '浥', '浧', '浨', '浫', '浬', '浭', '浰', '浱', '浲', '浳', '浵', '浶', '浹', '浺', '浻', '浽', '浾', '浿', '涀', '涁', '涃', '涄', '涆', '涇', '涊', '涋', '涍', '涏', '涐', '涒', '涖', '涗', '涘', '涙', '涚', '涜', '涢', '涥', '涬', '涭', '涰', '涱', '涳', '涴', '涶', '涷', '涹', '涺', '涻', '涼', '涽', '涾', '淁', '淂', '淃', '淈', '淉', '淊']),
+    (0x9C, ['淍', '淎', '淏', '淐', '淒', '淓', '淔', '淕', '淗', '淚', '淛', '淜', '淟', '淢', '淣', '淥', '淧', '淨', '淩', '淪', '淭', '淯', '淰', '淲', '淴', '淵', '淶', '淸', '淺', '淽', '淾', '淿', '渀', '渁', '渂', '渃', '渄', '渆', '渇', '済', '渉', '渋', '渏', '渒', '渓', '渕', '渘', '渙', '減', '渜', '渞', '渟', '渢', '渦', '渧', '渨', '渪', '測', '渮', '渰', '渱', '渳', '渵', '\u{FFFD}', '渶', '渷', '渹', '渻', '渼', '渽', '渾', '渿', '湀', '湁', '湂', '湅', '湆', '湇', '湈', '湉', '湊', '湋', '湌', '湏', '湐', '湑', '湒', '湕', '湗', '湙', '湚', '湜', '湝', '湞', '湠', '湡', '湢', '湣', '湤', '湥', '湦', '湧', '湨', '湩', '湪', '湬', '湭', '湯', '湰', '湱', '湲', '湳', '湴', '湵', '湶', '湷', '湸', '湹', '湺', '湻', '湼', '湽', '満', '溁', '溂', '溄', '溇', '溈', '溊', '溋', '溌', '溍', '溎', '溑', '溒', '溓', '溔', '溕', '準', '溗', '溙', '溚', '溛', '溝', '溞', '溠', '溡', '溣', '溤', '溦', '溨', '溩', '溫', '溬', '溭', '溮', '溰', '溳', '溵', '溸', '溹', '溼', '溾', '溿', '滀', '滃', '滄', '滅', '滆', '滈', '滉', '滊', '滌', '滍', '滎', '滐', '滒', '滖', '滘', '滙', '滛', '滜', '滝', '滣', '滧', '滪', '滫', '滬', '滭', '滮', '滯']),
+    (0x9D, ['滰', '滱', '滲', '滳', '滵', '滶', '滷', '滸', '滺', '滻', '滼', '滽', '滾', '滿', '漀', '漁', '漃', '漄', '漅', '漇', '漈', '漊', '漋', '漌', '漍', '漎', '漐', '漑', '漒', '漖', '漗', '漘', '漙', '漚', '漛', '漜', '漝', '漞', '漟', '漡', '漢', '漣', '漥', '漦', '漧', '漨', '漬', '漮', '漰', '漲', '漴', '漵', '漷', '漸', '漹', '漺', '漻', '漼', '漽', '漿', '潀', '潁', '潂', '\u{FFFD}', '潃', '潄', '潅', '潈', '潉', '潊', '潌', '潎', '潏', '潐', '潑', '潒', '潓', '潔', '潕', '潖', '潗', '潙', '潚', '潛', '潝', '潟', '潠', '潡', '潣', '潤', '潥', '潧', '潨', '潩', '潪', '潫', '潬', '潯', '潰', '潱', '潳', '潵', '潶', '潷', '潹', '潻', '潽', '潾', '潿', '澀', '澁', '澂', '澃', '澅', '澆', '澇', '澊', '澋', '澏', '澐', '澑', '澒', '澓', '澔', '澕', '澖', '澗', '澘', '澙', '澚', '澛', '澝', '澞', '澟', '澠', '澢', '澣', '澤', '澥', '澦', '澨', '澩', '澪', '澫', '澬', '澭', '澮', '澯', '澰', '澱', '澲', '澴', '澵', '澷', '澸', '澺', '澻', '澼', '澽', '澾', '澿', '濁', '濃', '濄', '濅', '濆', '濇', '濈', '濊', '濋', '濌', '濍', '濎', '濏', '濐', '濓', '濔', '濕', '濖', '濗', '濘', '濙', '濚', '濛', '濜', '濝', '濟', '濢', '濣', '濤', '濥']),
+    (0x9E, ['濦', '濧', '濨', '濩', '濪', '濫', '濬', '濭', '濰', '濱', '濲', '濳', '濴', '濵', '濶', '濷', '濸', '濹', '濺', '濻', '濼', '濽', '濾', '濿', '瀀', '瀁', '瀂', '瀃', '瀄', '瀅', '瀆', '瀇', '瀈', '瀉', '瀊', '瀋', '瀌', '瀍', '瀎', '瀏', '瀐', '瀒', '瀓', '瀔', '瀕', '瀖', '瀗', '瀘', '瀙', '瀜', '瀝', '瀞', '瀟', '瀠', '瀡', '瀢', '瀤', '瀥', '瀦', '瀧', '瀨', '瀩', '瀪', '\u{FFFD}', '瀫', '瀬', '瀭', '瀮', '瀯', '瀰', '瀱', '瀲', '瀳', '瀴', '瀶', '瀷', '瀸', '瀺', '瀻', '瀼', '瀽', '瀾', '瀿', '灀', '灁', '灂', '灃', '灄', '灅', '灆', '灇', '灈', '灉', '灊', '灋', '灍', '灎', '灐', '灑', '灒', '灓', '灔', '灕', '灖', '灗', '灘', '灙', '灚', '灛', '灜', '灝', '灟', '灠', '灡', '灢', '灣', '灤', '灥', '灦', '灧', '灨', '灩', '灪', '灮', '灱', '灲', '灳', '灴', '灷', '灹', '灺', '灻', '災', '炁', '炂', '炃', '炄', '炆', '炇', '炈', '炋', '炌', '炍', '炏', '炐', '炑', '炓', '炗', '炘', '炚', '炛', '炞', '炟', '炠', '炡', '炢', '炣', '炤', '炥', '炦', '炧', '炨', '炩', '炪', '炰', '炲', '炴', '炵', '炶', '為', '炾', '炿', '烄', '烅', '烆', '烇', '烉', '烋', '烌', '烍', '烎', '烏', '烐', '烑', '烒', '烓', '烔', '烕', '烖', '烗', '烚']),
+    (0x9F, ['烜', '烝', '烞', '烠', '烡', '烢', '烣', '烥', '烪', '烮', '烰', '烱', '烲', '烳', '烴', '烵', '烶', '烸', '烺', '烻', '烼', '烾', '烿', '焀', '焁', '焂', '焃', '焄', '焅', '焆', '焇', '焈', '焋', '焌', '焍', '焎', '焏', '焑', '焒', '焔', '焗', '焛', '焜', '焝', '焞', '焟', '焠', '無', '焢', '焣', '焤', '焥', '焧', '焨', '焩', '焪', '焫', '焬', '焭', '焮', '焲', '焳', '焴', '\u{FFFD}', '焵', '焷', '焸', '焹', '焺', '焻', '焼', '焽', '焾', '焿', '煀', '煁', '煂', '煃', '煄', '煆', '煇', '煈', '煉', '煋', '煍', '煏', '煐', '煑', '煒', '煓', '煔', '煕', '煖', '煗', '煘', '煙', '煚', '煛', '煝', '煟', '煠', '煡', '煢', '煣', '煥', '煩', '煪', '煫', '煬', '煭', '煯', '煰', '煱', '煴', '煵', '煶', '煷', '煹', '煻', '煼', '煾', '煿', '熀', '熁', '熂', '熃', '熅', '熆', '熇', '熈', '熉', '熋', '熌', '熍', '熎', '熐', '熑', '熒', '熓', '熕', '熖', '熗', '熚', '熛', '熜', '熝', '熞', '熡', '熢', '熣', '熤', '熥', '熦', '熧', '熩', '熪', '熫', '熭', '熮', '熯', '熰', '熱', '熲', '熴', '熶', '熷', '熸', '熺', '熻', '熼', '熽', '熾', '熿', '燀', '燁', '燂', '燄', '燅', '燆', '燇', '燈', '燉', '燊', '燋', '燌', '燍', '燏', '燐', '燑', '燒', '燓']),
+    (0xA0, ['燖', '燗', '燘', '燙', '燚', '燛', '燜', '燝', '燞', '營', '燡', '燢', '燣', '燤', '燦', '燨', '燩', '燪', '燫', '燬', '燭', '燯', '燰', '燱', '燲', '燳', '燴', '燵', '燶', '燷', '燸', '燺', '燻', '燼', '燽', '燾', '燿', '爀', '爁', '爂', '爃', '爄', '爅', '爇', '爈', '爉', '爊', '爋', '爌', '爍', '爎', '爏', '爐', '爑', '爒', '爓', '爔', '爕', '爖', '爗', '爘', '爙', '爚', '\u{FFFD}', '爛', '爜', '爞', '爟', '爠', '爡', '爢', '爣', '爤', '爥', '爦', '爧', '爩', '爫', '爭', '爮', '爯', '爲', '爳', '爴', '爺', '爼', '爾', '牀', '牁', '牂', '牃', '牄', '牅', '牆', '牉', '牊', '牋', '牎', '牏', '牐', '牑', '牓', '牔', '牕', '牗', '牘', '牚', '牜', '牞', '牠', '牣', '牤', '牥', '牨', '牪', '牫', '牬', '牭', '牰', '牱', '牳', '牴', '牶', '牷', '牸', '牻', '牼', '牽', '犂', '犃', '犅', '犆', '犇', '犈', '犉', '犌', '犎', '犐', '犑', '犓', '犔', '犕', '犖', '犗', '犘', '犙', '犚', '犛', '犜', '犝', '犞', '犠', '犡', '犢', '犣', '犤', '犥', '犦', '犧', '犨', '犩', '犪', '犫', '犮', '犱', '犲', '犳', '犵', '犺', '犻', '犼', '犽', '犾', '犿', '狀', '狅', '狆', '狇', '狉', '狊', '狋', '狌', '狏', '狑', '狓', '狔', '狕', '狖', '狘', '狚', '狛']),
+    (0xA1, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{3000}', '、', '。', '·', 'ˉ', 'ˇ', '¨', '〃', '々', '—', '～', '‖', '…', '‘', '’', '“', '”', '〔', '〕', '〈', '〉', '《', '》', '「', '」', '『', '』', '〖', '〗', '【', '】', '±', '×', '÷', '∶', '∧', '∨', '∑', '∏', '∪', '∩', '∈', '∷', '√', '⊥', '∥', '∠', '⌒', '⊙', '∫', '∮', '≡', '≌', '≈', '∽', '∝', '≠', '≮', '≯', '≤', '≥', '∞', '∵', '∴', '♂', '♀', '°', '′', '″', '℃', '＄', '¤', '￠', '￡', '‰', '§', '№', '☆', '★', '○', '●', '◎', '◇', '◆', '□', '■', '△', '▲', '※', '→', '←', '↑', '↓', '〓']),
+    (0xA2, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ⅰ', 'ⅱ', 'ⅲ', 'ⅳ', 'ⅴ', 'ⅵ', 'ⅶ', 'ⅷ', 'ⅸ', 'ⅹ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '⒈', '⒉', '⒊', '⒋', '⒌', '⒍', '⒎', '⒏', '⒐', '⒑', '⒒', '⒓', '⒔', '⒕', '⒖', '⒗', '⒘', '⒙', '⒚', '⒛', '⑴', '⑵', '⑶', '⑷', '⑸', '⑹', '⑺', '⑻', '⑼', '⑽', '⑾', '⑿', '⒀', '⒁', '⒂', '⒃', '⒄', '⒅', '⒆', '⒇', '①', '②', '③', '④', '⑤', '⑥', '⑦', '⑧', '⑨', '⑩', '\u{FFFD}', '\u{FFFD}', '㈠', '㈡', '㈢', '㈣', '㈤', '㈥', '㈦', '㈧', '㈨', '㈩', '\u{FFFD}', '\u{FFFD}', 'Ⅰ', 'Ⅱ', 'Ⅲ', 'Ⅳ', 'Ⅴ', 'Ⅵ', 'Ⅶ', 'Ⅷ', 'Ⅸ', 'Ⅹ', 'Ⅺ', 'Ⅻ', '\u{FFFD}', '\u{FFFD}']),
+    (0xA3, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '！', '＂', '＃', '￥', '％', '＆', '＇', '（', '）', '＊', '＋', '，', '－', '．', '／', '０', '１', '２', '３', '４', '５', '６', '７', '８', '９', '：', '；', '＜', '＝', '＞', '？', '＠', 'Ａ', 'Ｂ', 'Ｃ', 'Ｄ', 'Ｅ', 'Ｆ', 'Ｇ', 'Ｈ', 'Ｉ', 'Ｊ', 'Ｋ', 'Ｌ', 'Ｍ', 'Ｎ', 'Ｏ', 'Ｐ', 'Ｑ', 'Ｒ', 'Ｓ', 'Ｔ', 'Ｕ', 'Ｖ', 'Ｗ', 'Ｘ', 'Ｙ', 'Ｚ', '［', '＼', '］', '＾', '＿', '｀', 'ａ', 'ｂ', 'ｃ', 'ｄ', 'ｅ', 'ｆ', 'ｇ', 'ｈ', 'ｉ', 'ｊ', 'ｋ', 'ｌ', 'ｍ', 'ｎ', 'ｏ', 'ｐ', 'ｑ', 'ｒ', 'ｓ', 'ｔ', 'ｕ', 'ｖ', 'ｗ', 'ｘ', 'ｙ', 'ｚ', '｛', '｜', '｝', '￣']),
+    (0xA4, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ぁ', 'あ', 'ぃ', 'い', 'ぅ', 'う', 'ぇ', 'え', 'ぉ', 'お', 'か', 'が', 'き', 'ぎ', 'く', 'ぐ', 'け', 'げ', 'こ', 'ご', 'さ', 'ざ', 'し', 'じ', 'す', 'ず', 'せ', 'ぜ', 'そ', 'ぞ', 'た', 'だ', 'ち', 'ぢ', 'っ', 'つ', 'づ', 'て', 'で', 'と', 'ど', 'な', 'に', 'ぬ', 'ね', 'の', 'は', 'ば', 'ぱ', 'ひ', 'び', 'ぴ', 'ふ', 'ぶ', 'ぷ', 'へ', 'べ', 'ぺ', 'ほ', 'ぼ', 'ぽ', 'ま', 'み', 'む', 'め', 'も', 'ゃ', 'や', 'ゅ', 'ゆ', 'ょ', 'よ', 'ら', 'り', 'る', 'れ', 'ろ', 'ゎ', 'わ', 'ゐ', 'ゑ', 'を', 'ん', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xA5, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ァ', 'ア', 'ィ', 'イ', 'ゥ', 'ウ', 'ェ', 'エ', 'ォ', 'オ', 'カ', 'ガ', 'キ', 'ギ', 'ク', 'グ', 'ケ', 'ゲ', 'コ', 'ゴ', 'サ', 'ザ', 'シ', 'ジ', 'ス', 'ズ', 'セ', 'ゼ', 'ソ', 'ゾ', 'タ', 'ダ', 'チ', 'ヂ', 'ッ', 'ツ', 'ヅ', 'テ', 'デ', 'ト', 'ド', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'バ', 'パ', 'ヒ', 'ビ', 'ピ', 'フ', 'ブ', 'プ', 'ヘ', 'ベ', 'ペ', 'ホ', 'ボ', 'ポ', 'マ', 'ミ', 'ム', 'メ', 'モ', 'ャ', 'ヤ', 'ュ', 'ユ', 'ョ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ヮ', 'ワ', 'ヰ', 'ヱ', 'ヲ', 'ン', 'ヴ', 'ヵ', 'ヶ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xA6, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '︵', '︶', '︹', '︺', '︿', '﹀', '︽', '︾', '﹁', '﹂', '﹃', '﹄', '\u{FFFD}', '\u{FFFD}', '︻', '︼', '︷', '︸', '︱', '\u{FFFD}', '︳', '︴', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xA7, ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ё', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'а', 'б', 'в', 'г', 'д', 'е', 'ё', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xA8, ['ˊ', 'ˋ', '˙', '–', '―', '‥', '‵', '℅', '℉', '↖', '↗', '↘', '↙', '∕', '∟', '∣', '≒', '≦', '≧', '⊿', '═', '║', '╒', '╓', '╔', '╕', '╖', '╗', '╘', '╙', '╚', '╛', '╜', '╝', '╞', '╟', '╠', '╡', '╢', '╣', '╤', '╥', '╦', '╧', '╨', '╩', '╪', '╫', '╬', '╭', '╮', '╯', '╰', '╱', '╲', '╳', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '\u{FFFD}', '█', '▉', '▊', '▋', '▌', '▍', '▎', '▏', '▓', '▔', '▕', '▼', '▽', '◢', '◣', '◤', '◥', '☉', '⊕', '〒', '〝', '〞', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ā', 'á', 'ǎ', 'à', 'ē', 'é', 'ě', 'è', 'ī', 'í', 'ǐ', 'ì', 'ō', 'ó', 'ǒ', 'ò', 'ū', 'ú', 'ǔ', 'ù', 'ǖ', 'ǘ', 'ǚ', 'ǜ', 'ü', 'ê', 'ɑ', '\u{FFFD}', 'ń', 'ň', '\u{FFFD}', 'ɡ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ㄅ', 'ㄆ', 'ㄇ', 'ㄈ', 'ㄉ', 'ㄊ', 'ㄋ', 'ㄌ', 'ㄍ', 'ㄎ', 'ㄏ', 'ㄐ', 'ㄑ', 'ㄒ', 'ㄓ', 'ㄔ', 'ㄕ', 'ㄖ', 'ㄗ', 'ㄘ', 'ㄙ', 'ㄚ', 'ㄛ', 'ㄜ', 'ㄝ', 'ㄞ', 'ㄟ', 'ㄠ', 'ㄡ', 'ㄢ', 'ㄣ', 'ㄤ', 'ㄥ', 'ㄦ', 'ㄧ', 'ㄨ', 'ㄩ', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xA9, ['〡', '〢', '〣', '〤', '〥', '〦', '〧', '〨', '〩', '㊣', '㎎', '㎏', '㎜', '㎝', '㎞', '㎡', '㏄', '㏎', '㏑', '㏒', '㏕', '︰', '￢', '￤', '\u{FFFD}', '℡', '㈱', '\u{FFFD}', '‐', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', 'ー', '゛', '゜', 'ヽ', 'ヾ', '〆', 'ゝ', 'ゞ', '﹉', '﹊', '﹋', '﹌', '﹍', '﹎', '﹏', '﹐', '﹑', '﹒', '﹔', '﹕', '﹖', '﹗', '﹙', '﹚', '﹛', '﹜', '﹝', '﹞', '﹟', '﹠', '﹡', '\u{FFFD}', '﹢', '﹣', '﹤', '﹥', '﹦', '﹨', '﹩', '﹪', '﹫', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '〇', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '─', '━', '│', '┃', '┄', '┅', '┆', '┇', '┈', '┉', '┊', '┋', '┌', '┍', '┎', '┏', '┐', '┑', '┒', '┓', '└', '┕', '┖', '┗', '┘', '┙', '┚', '┛', '├', '┝', '┞', '┟', '┠', '┡', '┢', '┣', '┤', '┥', '┦', '┧', '┨', '┩', '┪', '┫', '┬', '┭', '┮', '┯', '┰', '┱', '┲', '┳', '┴', '┵', '┶', '┷', '┸', '┹', '┺', '┻', '┼', '┽', '┾', '┿', '╀', '╁', '╂', '╃', '╄', '╅', '╆', '╇', '╈', '╉', '╊', '╋', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xAA, ['狜', '狝', '狟', '狢', '狣', '狤', '狥', '狦', '狧', '狪', '狫', '狵', '狶', '狹', '狽', '狾', '狿', '猀', '猂', '猄', '猅', '猆', '猇', '猈', '猉', '猋', '猌', '猍', '猏', '猐', '猑', '猒', '猔', '猘', '猙', '猚', '猟', '猠', '猣', '猤', '猦', '猧', '猨', '猭', '猯', '猰', '猲', '猳', '猵', '猶', '猺', '猻', '猼', '猽', '獀', '獁', '獂', '獃', '獄', '獅', '獆', '獇', '獈', '\u{FFFD}', '獉', '獊', '獋', '獌', '獎', '獏', '獑', '獓', '獔', '獕', '獖', '獘', '獙', '獚', '獛', '獜', '獝', '獞', '獟', '獡', '獢', '獣', '獤', '獥', '獦', '獧', '獨', '獩', '獪', '獫', '獮', '獰', '獱', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xAB, ['獲', '獳', '獴', '獵', '獶', '獷', '獸', '獹', '獺', '獻', '獼', '獽', '獿', '玀', '玁', '玂', '玃', '玅', '玆', '玈', '玊', '玌', '玍', '玏', '玐', '玒', '玓', '玔', '玕', '玗', '玘', '玙', '玚', '玜', '玝', '玞', '玠', '玡', '玣', '玤', '玥', '玦', '玧', '玨', '玪', '玬', '玭', '玱', '玴', '玵', '玶', '玸', '玹', '玼', '玽', '玾', '玿', '珁', '珃', '珄', '珅', '珆', '珇', '\u{FFFD}', '珋', '珌', '珎', '珒', '珓', '珔', '珕', '珖', '珗', '珘', '珚', '珛', '珜', '珝', '珟', '珡', '珢', '珣', '珤', '珦', '珨', '珪', '珫', '珬', '珮', '珯', '珰', '珱', '珳', '珴', '珵', '珶', '珷', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xAC, ['珸', '珹', '珺', '珻', '珼', '珽', '現', '珿', '琀', '琁', '琂', '琄', '琇', '琈', '琋', '琌', '琍', '琎', '琑', '琒', '琓', '琔', '琕', '琖', '琗', '琘', '琙', '琜', '琝', '琞', '琟', '琠', '琡', '琣', '琤', '琧', '琩', '琫', '琭', '琯', '琱', '琲', '琷', '琸', '琹', '琺', '琻', '琽', '琾', '琿', '瑀', '瑂', '瑃', '瑄', '瑅', '瑆', '瑇', '瑈', '瑉', '瑊', '瑋', '瑌', '瑍', '\u{FFFD}', '瑎', '瑏', '瑐', '瑑', '瑒', '瑓', '瑔', '瑖', '瑘', '瑝', '瑠', '瑡', '瑢', '瑣', '瑤', '瑥', '瑦', '瑧', '瑨', '瑩', '瑪', '瑫', '瑬', '瑮', '瑯', '瑱', '瑲', '瑳', '瑴', '瑵', '瑸', '瑹', '瑺', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xAD, ['瑻', '瑼', '瑽', '瑿', '璂', '璄', '璅', '璆', '璈', '璉', '璊', '璌', '璍', '璏', '璑', '璒', '璓', '璔', '璕', '璖', '璗', '璘', '璙', '璚', '璛', '璝', '璟', '璠', '璡', '璢', '璣', '璤', '璥', '璦', '璪', '璫', '璬', '璭', '璮', '璯', '環', '璱', '璲', '璳', '璴', '璵', '璶', '璷', '璸', '璹', '璻', '璼', '璽', '璾', '璿', '瓀', '瓁', '瓂', '瓃', '瓄', '瓅', '瓆', '瓇', '\u{FFFD}', '瓈', '瓉', '瓊', '瓋', '瓌', '瓍', '瓎', '瓏', '瓐', '瓑', '瓓', '瓔', '瓕', '瓖', '瓗', '瓘', '瓙', '瓚', '瓛', '瓝', '瓟', '瓡', '瓥', '瓧', '瓨', '瓩', '瓪', '瓫', '瓬', '瓭', '瓰', '瓱', '瓲', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xAE, ['瓳', '瓵', '瓸', '瓹', '瓺', '瓻', '瓼', '瓽', '瓾', '甀', '甁', '甂', '甃', '甅', '甆', '甇', '甈', '甉', '甊', '甋', '甌', '甎', '甐', '甒', '甔', '甕', '甖', '甗', '甛', '甝', '甞', '甠', '甡', '產', '産', '甤', '甦', '甧', '甪', '甮', '甴', '甶', '甹', '甼', '甽', '甿', '畁', '畂', '畃', '畄', '畆', '畇', '畉', '畊', '畍', '畐', '畑', '畒', '畓', '畕', '畖', '畗', '畘', '\u{FFFD}', '畝', '畞', '畟', '畠', '畡', '畢', '畣', '畤', '畧', '畨', '畩', '畫', '畬', '畭', '畮', '畯', '異', '畱', '畳', '畵', '當', '畷', '畺', '畻', '畼', '畽', '畾', '疀', '疁', '疂', '疄', '疅', '疇', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xAF, ['疈', '疉', '疊', '疌', '疍', '疎', '疐', '疓', '疕', '疘', '疛', '疜', '疞', '疢', '疦', '疧', '疨', '疩', '疪', '疭', '疶', '疷', '疺', '疻', '疿', '痀', '痁', '痆', '痋', '痌', '痎', '痏', '痐', '痑', '痓', '痗', '痙', '痚', '痜', '痝', '痟', '痠', '痡', '痥', '痩', '痬', '痭', '痮', '痯', '痲', '痳', '痵', '痶', '痷', '痸', '痺', '痻', '痽', '痾', '瘂', '瘄', '瘆', '瘇', '\u{FFFD}', '瘈', '瘉', '瘋', '瘍', '瘎', '瘏', '瘑', '瘒', '瘓', '瘔', '瘖', '瘚', '瘜', '瘝', '瘞', '瘡', '瘣', '瘧', '瘨', '瘬', '瘮', '瘯', '瘱', '瘲', '瘶', '瘷', '瘹', '瘺', '瘻', '瘽', '癁', '療', '癄', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xB0, ['癅', '癆', '癇', '癈', '癉', '癊', '癋', '癎', '癏', '癐', '癑', '癒', '癓', '癕', '癗', '癘', '癙', '癚', '癛', '癝', '癟', '癠', '癡', '癢', '癤', '癥', '癦', '癧', '癨', '癩', '癪', '癬', '癭', '癮', '癰', '癱', '癲', '癳', '癴', '癵', '癶', '癷', '癹', '発', '發', '癿', '皀', '皁', '皃', '皅', '皉', '皊', '皌', '皍', '皏', '皐', '皒', '皔', '皕', '皗', '皘', '皚', '皛', '\u{FFFD}', '皜', '皝', '皞', '皟', '皠', '皡', '皢', '皣', '皥', '皦', '皧', '皨', '皩', '皪', '皫', '皬', '皭', '皯', '皰', '皳', '皵', '皶', '皷', '皸', '皹', '皺', '皻', '皼', '皽', '皾', '盀', '盁', '盃', '啊', '阿', '埃', '挨', '哎', '唉', '哀', '皑', '癌', '蔼', '矮', '艾', '碍', '爱', '隘', '鞍', '氨', '安', '俺', '按', '暗', '岸', '胺', '案', '肮', '昂', '盎', '凹', '敖', '熬', '翱', '袄', '傲', '奥', '懊', '澳', '芭', '捌', '扒', '叭', '吧', '笆', '八', '疤', '巴', '拔', '跋', '靶', '把', '耙', '坝', '霸', '罢', '爸', '白', '柏', '百', '摆', '佰', '败', '拜', '稗', '斑', '班', '搬', '扳', '般', '颁', '板', '版', '扮', '拌', '伴', '瓣', '半', '办', '绊', '邦', '帮', '梆', '榜', '膀', '绑', '棒', '磅', '蚌', '镑', '傍', '谤', '苞', '胞', '包', '褒', '剥']),
+    (0xB1, ['盄', '盇', '盉', '盋', '盌', '盓', '盕', '盙', '盚', '盜', '盝', '盞', '盠', '盡', '盢', '監', '盤', '盦', '盧', '盨', '盩', '盪', '盫', '盬', '盭', '盰', '盳', '盵', '盶', '盷', '盺', '盻', '盽', '盿', '眀', '眂', '眃', '眅', '眆', '眊', '県', '眎', '眏', '眐', '眑', '眒', '眓', '眔', '眕', '眖', '眗', '眘', '眛', '眜', '眝', '眞', '眡', '眣', '眤', '眥', '眧', '眪', '眫', '\u{FFFD}', '眬', '眮', '眰', '眱', '眲', '眳', '眴', '眹', '眻', '眽', '眾', '眿', '睂', '睄', '睅', '睆', '睈', '睉', '睊', '睋', '睌', '睍', '睎', '睏', '睒', '睓', '睔', '睕', '睖', '睗', '睘', '睙', '睜', '薄', '雹', '保', '堡', '饱', '宝', '抱', '报', '暴', '豹', '鲍', '爆', '杯', '碑', '悲', '卑', '北', '辈', '背', '贝', '钡', '倍', '狈', '备', '惫', '焙', '被', '奔', '苯', '本', '笨', '崩', '绷', '甭', '泵', '蹦', '迸', '逼', '鼻', '比', '鄙', '笔', '彼', '碧', '蓖', '蔽', '毕', '毙', '毖', '币', '庇', '痹', '闭', '敝', '弊', '必', '辟', '壁', '臂', '避', '陛', '鞭', '边', '编', '贬', '扁', '便', '变', '卞', '辨', '辩', '辫', '遍', '标', '彪', '膘', '表', '鳖', '憋', '别', '瘪', '彬', '斌', '濒', '滨', '宾', '摈', '兵', '冰', '柄', '丙', '秉', '饼', '炳']),
+    (0xB2, ['睝', '睞', '睟', '睠', '睤', '睧', '睩', '睪', '睭', '睮', '睯', '睰', '睱', '睲', '睳', '睴', '睵', '睶', '睷', '睸', '睺', '睻', '睼', '瞁', '瞂', '瞃', '瞆', '瞇', '瞈', '瞉', '瞊', '瞋', '瞏', '瞐', '瞓', '瞔', '瞕', '瞖', '瞗', '瞘', '瞙', '瞚', '瞛', '瞜', '瞝', '瞞', '瞡', '瞣', '瞤', '瞦', '瞨', '瞫', '瞭', '瞮', '瞯', '瞱', '瞲', '瞴', '瞶', '瞷', '瞸', '瞹', '瞺', '\u{FFFD}', '瞼', '瞾', '矀', '矁', '矂', '矃', '矄', '矅', '矆', '矇', '矈', '矉', '矊', '矋', '矌', '矎', '矏', '矐', '矑', '矒', '矓', '矔', '矕', '矖', '矘', '矙', '矚', '矝', '矞', '矟', '矠', '矡', '矤', '病', '并', '玻', '菠', '播', '拨', '钵', '波', '博', '勃', '搏', '铂', '箔', '伯', '帛', '舶', '脖', '膊', '渤', '泊', '驳', '捕', '卜', '哺', '补', '埠', '不', '布', '步', '簿', '部', '怖', '擦', '猜', '裁', '材', '才', '财', '睬', '踩', '采', '彩', '菜', '蔡', '餐', '参', '蚕', '残', '惭', '惨', '灿', '苍', '舱', '仓', '沧', '藏', '操', '糙', '槽', '曹', '草', '厕', '策', '侧', '册', '测', '层', '蹭', '插', '叉', '茬', '茶', '查', '碴', '搽', '察', '岔', '差', '诧', '拆', '柴', '豺', '搀', '掺', '蝉', '馋', '谗', '缠', '铲', '产', '阐', '颤', '昌', '猖']),
+    (0xB3, ['矦', '矨', '矪', '矯', '矰', '矱', '矲', '矴', '矵', '矷', '矹', '矺', '矻', '矼', '砃', '砄', '砅', '砆', '砇', '砈', '砊', '砋', '砎', '砏', '砐', '砓', '砕', '砙', '砛', '砞', '砠', '砡', '砢', '砤', '砨', '砪', '砫', '砮', '砯', '砱', '砲', '砳', '砵', '砶', '砽', '砿', '硁', '硂', '硃', '硄', '硆', '硈', '硉', '硊', '硋', '硍', '硏', '硑', '硓', '硔', '硘', '硙', '硚', '\u{FFFD}', '硛', '硜', '硞', '硟', '硠', '硡', '硢', '硣', '硤', '硥', '硦', '硧', '硨', '硩', '硯', '硰', '硱', '硲', '硳', '硴', '硵', '硶', '硸', '硹', '硺', '硻', '硽', '硾', '硿', '碀', '碁', '碂', '碃', '场', '尝', '常', '长', '偿', '肠', '厂', '敞', '畅', '唱', '倡', '超', '抄', '钞', '朝', '嘲', '潮', '巢', '吵', '炒', '车', '扯', '撤', '掣', '彻', '澈', '郴', '臣', '辰', '尘', '晨', '忱', '沉', '陈', '趁', '衬', '撑', '称', '城', '橙', '成', '呈', '乘', '程', '惩', '澄', '诚', '承', '逞', '骋', '秤', '吃', '痴', '持', '匙', '池', '迟', '弛', '驰', '耻', '齿', '侈', '尺', '赤', '翅', '斥', '炽', '充', '冲', '虫', '崇', '宠', '抽', '酬', '畴', '踌', '稠', '愁', '筹', '仇', '绸', '瞅', '丑', '臭', '初', '出', '橱', '厨', '躇', '锄', '雏', '滁', '除', '楚']),
+    (0xB4, ['碄', '碅', '碆', '碈', '碊', '碋', '碏', '碐', '碒', '碔', '碕', '碖', '碙', '碝', '碞', '碠', '碢', '碤', '碦', '碨', '碩', '碪', '碫', '碬', '碭', '碮', '碯', '碵', '碶', '碷', '碸', '確', '碻', '碼', '碽', '碿', '磀', '磂', '磃', '磄', '磆', '磇', '磈', '磌', '磍', '磎', '磏', '磑', '磒', '磓', '磖', '磗', '磘', '磚', '磛', '磜', '磝', '磞', '磟', '磠', '磡', '磢', '磣', '\u{FFFD}', '磤', '磥', '磦', '磧', '磩', '磪', '磫', '磭', '磮', '磯', '磰', '磱', '磳', '磵', '磶', '磸', '磹', '磻', '磼', '磽', '磾', '磿', '礀', '礂', '礃', '礄', '礆', '礇', '礈', '礉', '礊', '礋', '礌', '础', '储', '矗', '搐', '触', '处', '揣', '川', '穿', '椽', '传', '船', '喘', '串', '疮', '窗', '幢', '床', '闯', '创', '吹', '炊', '捶', '锤', '垂', '春', '椿', '醇', '唇', '淳', '纯', '蠢', '戳', '绰', '疵', '茨', '磁', '雌', '辞', '慈', '瓷', '词', '此', '刺', '赐', '次', '聪', '葱', '囱', '匆', '从', '丛', '凑', '粗', '醋', '簇', '促', '蹿', '篡', '窜', '摧', '崔', '催', '脆', '瘁', '粹', '淬', '翠', '村', '存', '寸', '磋', '撮', '搓', '措', '挫', '错', '搭', '达', '答', '瘩', '打', '大', '呆', '歹', '傣', '戴', '带', '殆', '代', '贷', '袋', '待', '逮']),
+    (0xB5, ['礍', '礎', '礏', '礐', '礑', '礒', '礔', '礕', '礖', '礗', '礘', '礙', '礚', '礛', '礜', '礝', '礟', '礠', '礡', '礢', '礣', '礥', '礦', '礧', '礨', '礩', '礪', '礫', '礬', '礭', '礮', '礯', '礰', '礱', '礲', '礳', '礵', '礶', '礷', '礸', '礹', '礽', '礿', '祂', '祃', '祄', '祅', '祇', '祊', '祋', '祌', '祍', '祎', '祏', '祐', '祑', '祒', '祔', '祕', '祘', '祙', '祡', '祣', '\u{FFFD}', '祤', '祦', '祩', '祪', '祫', '祬', '祮', '祰', '祱', '祲', '祳', '祴', '祵', '祶', '祹', '祻', '祼', '祽', '祾', '祿', '禂', '禃', '禆', '禇', '禈', '禉', '禋', '禌', '禍', '禎', '禐', '禑', '禒', '怠', '耽', '担', '丹', '单', '郸', '掸', '胆', '旦', '氮', '但', '惮', '淡', '诞', '弹', '蛋', '当', '挡', '党', '荡', '档', '刀', '捣', '蹈', '倒', '岛', '祷', '导', '到', '稻', '悼', '道', '盗', '德', '得', '的', '蹬', '灯', '登', '等', '瞪', '凳', '邓', '堤', '低', '滴', '迪', '敌', '笛', '狄', '涤', '翟', '嫡', '抵', '底', '地', '蒂', '第', '帝', '弟', '递', '缔', '颠', '掂', '滇', '碘', '点', '典', '靛', '垫', '电', '佃', '甸', '店', '惦', '奠', '淀', '殿', '碉', '叼', '雕', '凋', '刁', '掉', '吊', '钓', '调', '跌', '爹', '碟', '蝶', '迭', '谍', '叠']),
+    (0xB6, ['禓', '禔', '禕', '禖', '禗', '禘', '禙', '禛', '禜', '禝', '禞', '禟', '禠', '禡', '禢', '禣', '禤', '禥', '禦', '禨', '禩', '禪', '禫', '禬', '禭', '禮', '禯', '禰', '禱', '禲', '禴', '禵', '禶', '禷', '禸', '禼', '禿', '秂', '秄', '秅', '秇', '秈', '秊', '秌', '秎', '秏', '秐', '秓', '秔', '秖', '秗', '秙', '秚', '秛', '秜', '秝', '秞', '秠', '秡', '秢', '秥', '秨', '秪', '\u{FFFD}', '秬', '秮', '秱', '秲', '秳', '秴', '秵', '秶', '秷', '秹', '秺', '秼', '秾', '秿', '稁', '稄', '稅', '稇', '稈', '稉', '稊', '稌', '稏', '稐', '稑', '稒', '稓', '稕', '稖', '稘', '稙', '稛', '稜', '丁', '盯', '叮', '钉', '顶', '鼎', '锭', '定', '订', '丢', '东', '冬', '董', '懂', '动', '栋', '侗', '恫', '冻', '洞', '兜', '抖', '斗', '陡', '豆', '逗', '痘', '都', '督', '毒', '犊', '独', '读', '堵', '睹', '赌', '杜', '镀', '肚', '度', '渡', '妒', '端', '短', '锻', '段', '断', '缎', '堆', '兑', '队', '对', '墩', '吨', '蹲', '敦', '顿', '囤', '钝', '盾', '遁', '掇', '哆', '多', '夺', '垛', '躲', '朵', '跺', '舵', '剁', '惰', '堕', '蛾', '峨', '鹅', '俄', '额', '讹', '娥', '恶', '厄', '扼', '遏', '鄂', '饿', '恩', '而', '儿', '耳', '尔', '饵', '洱', '二']),
+    (0xB7, ['稝', '稟', '稡', '稢', '稤', '稥', '稦', '稧', '稨', '稩', '稪', '稫', '稬', '稭', '種', '稯', '稰', '稱', '稲', '稴', '稵', '稶', '稸', '稺', '稾', '穀', '穁', '穂', '穃', '穄', '穅', '穇', '穈', '穉', '穊', '穋', '穌', '積', '穎', '穏', '穐', '穒', '穓', '穔', '穕', '穖', '穘', '穙', '穚', '穛', '穜', '穝', '穞', '穟', '穠', '穡', '穢', '穣', '穤', '穥', '穦', '穧', '穨', '\u{FFFD}', '穩', '穪', '穫', '穬', '穭', '穮', '穯', '穱', '穲', '穳', '穵', '穻', '穼', '穽', '穾', '窂', '窅', '窇', '窉', '窊', '窋', '窌', '窎', '窏', '窐', '窓', '窔', '窙', '窚', '窛', '窞', '窡', '窢', '贰', '发', '罚', '筏', '伐', '乏', '阀', '法', '珐', '藩', '帆', '番', '翻', '樊', '矾', '钒', '繁', '凡', '烦', '反', '返', '范', '贩', '犯', '饭', '泛', '坊', '芳', '方', '肪', '房', '防', '妨', '仿', '访', '纺', '放', '菲', '非', '啡', '飞', '肥', '匪', '诽', '吠', '肺', '废', '沸', '费', '芬', '酚', '吩', '氛', '分', '纷', '坟', '焚', '汾', '粉', '奋', '份', '忿', '愤', '粪', '丰', '封', '枫', '蜂', '峰', '锋', '风', '疯', '烽', '逢', '冯', '缝', '讽', '奉', '凤', '佛', '否', '夫', '敷', '肤', '孵', '扶', '拂', '辐', '幅', '氟', '符', '伏', '俘', '服']),
+    (0xB8, ['窣', '窤', '窧', '窩', '窪', '窫', '窮', '窯', '窰', '窱', '窲', '窴', '窵', '窶', '窷', '窸', '窹', '窺', '窻', '窼', '窽', '窾', '竀', '竁', '竂', '竃', '竄', '竅', '竆', '竇', '竈', '竉', '竊', '竌', '竍', '竎', '竏', '竐', '竑', '竒', '竓', '竔', '竕', '竗', '竘', '竚', '竛', '竜', '竝', '竡', '竢', '竤', '竧', '竨', '竩', '竪', '竫', '竬', '竮', '竰', '竱', '竲', '竳', '\u{FFFD}', '竴', '竵', '競', '竷', '竸', '竻', '竼', '竾', '笀', '笁', '笂', '笅', '笇', '笉', '笌', '笍', '笎', '笐', '笒', '笓', '笖', '笗', '笘', '笚', '笜', '笝', '笟', '笡', '笢', '笣', '笧', '笩', '笭', '浮', '涪', '福', '袱', '弗', '甫', '抚', '辅', '俯', '釜', '斧', '脯', '腑', '府', '腐', '赴', '副', '覆', '赋', '复', '傅', '付', '阜', '父', '腹', '负', '富', '讣', '附', '妇', '缚', '咐', '噶', '嘎', '该', '改', '概', '钙', '盖', '溉', '干', '甘', '杆', '柑', '竿', '肝', '赶', '感', '秆', '敢', '赣', '冈', '刚', '钢', '缸', '肛', '纲', '岗', '港', '杠', '篙', '皋', '高', '膏', '羔', '糕', '搞', '镐', '稿', '告', '哥', '歌', '搁', '戈', '鸽', '胳', '疙', '割', '革', '葛', '格', '蛤', '阁', '隔', '铬', '个', '各', '给', '根', '跟', '耕', '更', '庚', '羹']),
+    (0xB9, ['笯', '笰', '笲', '笴', '笵', '笶', '笷', '笹', '笻', '笽', '笿', '筀', '筁', '筂', '筃', '筄', '筆', '筈', '筊', '筍', '筎', '筓', '筕', '筗', '筙', '筜', '筞', '筟', '筡', '筣', '筤', '筥', '筦', '筧', '筨', '筩', '筪', '筫', '筬', '筭', '筯', '筰', '筳', '筴', '筶', '筸', '筺', '筼', '筽', '筿', '箁', '箂', '箃', '箄', '箆', '箇', '箈', '箉', '箊', '箋', '箌', '箎', '箏', '\u{FFFD}', '箑', '箒', '箓', '箖', '箘', '箙', '箚', '箛', '箞', '箟', '箠', '箣', '箤', '箥', '箮', '箯', '箰', '箲', '箳', '箵', '箶', '箷', '箹', '箺', '箻', '箼', '箽', '箾', '箿', '節', '篂', '篃', '範', '埂', '耿', '梗', '工', '攻', '功', '恭', '龚', '供', '躬', '公', '宫', '弓', '巩', '汞', '拱', '贡', '共', '钩', '勾', '沟', '苟', '狗', '垢', '构', '购', '够', '辜', '菇', '咕', '箍', '估', '沽', '孤', '姑', '鼓', '古', '蛊', '骨', '谷', '股', '故', '顾', '固', '雇', '刮', '瓜', '剐', '寡', '挂', '褂', '乖', '拐', '怪', '棺', '关', '官', '冠', '观', '管', '馆', '罐', '惯', '灌', '贯', '光', '广', '逛', '瑰', '规', '圭', '硅', '归', '龟', '闺', '轨', '鬼', '诡', '癸', '桂', '柜', '跪', '贵', '刽', '辊', '滚', '棍', '锅', '郭', '国', '果', '裹', '过', '哈']),
+    (0xBA, ['篅', '篈', '築', '篊', '篋', '篍', '篎', '篏', '篐', '篒', '篔', '篕', '篖', '篗', '篘', '篛', '篜', '篞', '篟', '篠', '篢', '篣', '篤', '篧', '篨', '篩', '篫', '篬', '篭', '篯', '篰', '篲', '篳', '篴', '篵', '篶', '篸', '篹', '篺', '篻', '篽', '篿', '簀', '簁', '簂', '簃', '簄', '簅', '簆', '簈', '簉', '簊', '簍', '簎', '簐', '簑', '簒', '簓', '簔', '簕', '簗', '簘', '簙', '\u{FFFD}', '簚', '簛', '簜', '簝', '簞', '簠', '簡', '簢', '簣', '簤', '簥', '簨', '簩', '簫', '簬', '簭', '簮', '簯', '簰', '簱', '簲', '簳', '簴', '簵', '簶', '簷', '簹', '簺', '簻', '簼', '簽', '簾', '籂', '骸', '孩', '海', '氦', '亥', '害', '骇', '酣', '憨', '邯', '韩', '含', '涵', '寒', '函', '喊', '罕', '翰', '撼', '捍', '旱', '憾', '悍', '焊', '汗', '汉', '夯', '杭', '航', '壕', '嚎', '豪', '毫', '郝', '好', '耗', '号', '浩', '呵', '喝', '荷', '菏', '核', '禾', '和', '何', '合', '盒', '貉', '阂', '河', '涸', '赫', '褐', '鹤', '贺', '嘿', '黑', '痕', '很', '狠', '恨', '哼', '亨', '横', '衡', '恒', '轰', '哄', '烘', '虹', '鸿', '洪', '宏', '弘', '红', '喉', '侯', '猴', '吼', '厚', '候', '后', '呼', '乎', '忽', '瑚', '壶', '葫', '胡', '蝴', '狐', '糊', '湖']),
+    (0xBB, ['籃', '籄', '籅', '籆', '籇', '籈', '籉', '籊', '籋', '籌', '籎', '籏', '籐', '籑', '籒', '籓', '籔', '籕', '籖', '籗', '籘', '籙', '籚', '籛', '籜', '籝', '籞', '籟', '籠', '籡', '籢', '籣', '籤', '籥', '籦', '籧', '籨', '籩', '籪', '籫', '籬', '籭', '籮', '籯', '籰', '籱', '籲', '籵', '籶', '籷', '籸', '籹', '籺', '籾', '籿', '粀', '粁', '粂', '粃', '粄', '粅', '粆', '粇', '\u{FFFD}', '粈', '粊', '粋', '粌', '粍', '粎', '粏', '粐', '粓', '粔', '粖', '粙', '粚', '粛', '粠', '粡', '粣', '粦', '粧', '粨', '粩', '粫', '粬', '粭', '粯', '粰', '粴', '粵', '粶', '粷', '粸', '粺', '粻', '弧', '虎', '唬', '护', '互', '沪', '户', '花', '哗', '华', '猾', '滑', '画', '划', '化', '话', '槐', '徊', '怀', '淮', '坏', '欢', '环', '桓', '还', '缓', '换', '患', '唤', '痪', '豢', '焕', '涣', '宦', '幻', '荒', '慌', '黄', '磺', '蝗', '簧', '皇', '凰', '惶', '煌', '晃', '幌', '恍', '谎', '灰', '挥', '辉', '徽', '恢', '蛔', '回', '毁', '悔', '慧', '卉', '惠', '晦', '贿', '秽', '会', '烩', '汇', '讳', '诲', '绘', '荤', '昏', '婚', '魂', '浑', '混', '豁', '活', '伙', '火', '获', '或', '惑', '霍', '货', '祸', '击', '圾', '基', '机', '畸', '稽', '积', '箕']),
+    (0xBC, ['粿', '糀', '糂', '糃', '糄', '糆', '糉', '糋', '糎', '糏', '糐', '糑', '糒', '糓', '糔', '糘', '糚', '糛', '糝', '糞', '糡', '糢', '糣', '糤', '糥', '糦', '糧', '糩', '糪', '糫', '糬', '糭', '糮', '糰', '糱', '糲', '糳', '糴', '糵', '糶', '糷', '糹', '糺', '糼', '糽', '糾', '糿', '紀', '紁', '紂', '紃', '約', '紅', '紆', '紇', '紈', '紉', '紋', '紌', '納', '紎', '紏', '紐', '\u{FFFD}', '紑', '紒', '紓', '純', '紕', '紖', '紗', '紘', '紙', '級', '紛', '紜', '紝', '紞', '紟', '紡', '紣', '紤', '紥', '紦', '紨', '紩', '紪', '紬', '紭', '紮', '細', '紱', '紲', '紳', '紴', '紵', '紶', '肌', '饥', '迹', '激', '讥', '鸡', '姬', '绩', '缉', '吉', '极', '棘', '辑', '籍', '集', '及', '急', '疾', '汲', '即', '嫉', '级', '挤', '几', '脊', '己', '蓟', '技', '冀', '季', '伎', '祭', '剂', '悸', '济', '寄', '寂', '计', '记', '既', '忌', '际', '妓', '继', '纪', '嘉', '枷', '夹', '佳', '家', '加', '荚', '颊', '贾', '甲', '钾', '假', '稼', '价', '架', '驾', '嫁', '歼', '监', '坚', '尖', '笺', '间', '煎', '兼', '肩', '艰', '奸', '缄', '茧', '检', '柬', '碱', '硷', '拣', '捡', '简', '俭', '剪', '减', '荐', '槛', '鉴', '践', '贱', '见', '键', '箭', '件']),
+    (0xBD, ['紷', '紸', '紹', '紺', '紻', '紼', '紽', '紾', '紿', '絀', '絁', '終', '絃', '組', '絅', '絆', '絇', '絈', '絉', '絊', '絋', '経', '絍', '絎', '絏', '結', '絑', '絒', '絓', '絔', '絕', '絖', '絗', '絘', '絙', '絚', '絛', '絜', '絝', '絞', '絟', '絠', '絡', '絢', '絣', '絤', '絥', '給', '絧', '絨', '絩', '絪', '絫', '絬', '絭', '絯', '絰', '統', '絲', '絳', '絴', '絵', '絶', '\u{FFFD}', '絸', '絹', '絺', '絻', '絼', '絽', '絾', '絿', '綀', '綁', '綂', '綃', '綄', '綅', '綆', '綇', '綈', '綉', '綊', '綋', '綌', '綍', '綎', '綏', '綐', '綑', '綒', '經', '綔', '綕', '綖', '綗', '綘', '健', '舰', '剑', '饯', '渐', '溅', '涧', '建', '僵', '姜', '将', '浆', '江', '疆', '蒋', '桨', '奖', '讲', '匠', '酱', '降', '蕉', '椒', '礁', '焦', '胶', '交', '郊', '浇', '骄', '娇', '嚼', '搅', '铰', '矫', '侥', '脚', '狡', '角', '饺', '缴', '绞', '剿', '教', '酵', '轿', '较', '叫', '窖', '揭', '接', '皆', '秸', '街', '阶', '截', '劫', '节', '桔', '杰', '捷', '睫', '竭', '洁', '结', '解', '姐', '戒', '藉', '芥', '界', '借', '介', '疥', '诫', '届', '巾', '筋', '斤', '金', '今', '津', '襟', '紧', '锦', '仅', '谨', '进', '靳', '晋', '禁', '近', '烬', '浸']),
+    (0xBE, ['継', '続', '綛', '綜', '綝', '綞', '綟', '綠', '綡', '綢', '綣', '綤', '綥', '綧', '綨', '綩', '綪', '綫', '綬', '維', '綯', '綰', '綱', '網', '綳', '綴', '綵', '綶', '綷', '綸', '綹', '綺', '綻', '綼', '綽', '綾', '綿', '緀', '緁', '緂', '緃', '緄', '緅', '緆', '緇', '緈', '緉', '緊', '緋', '緌', '緍', '緎', '総', '緐', '緑', '緒', '緓', '緔', '緕', '緖', '緗', '緘', '緙', '\u{FFFD}', '線', '緛', '緜', '緝', '緞', '緟', '締', '緡', '緢', '緣', '緤', '緥', '緦', '緧', '編', '緩', '緪', '緫', '緬', '緭', '緮', '緯', '緰', '緱', '緲', '緳', '練', '緵', '緶', '緷', '緸', '緹', '緺', '尽', '劲', '荆', '兢', '茎', '睛', '晶', '鲸', '京', '惊', '精', '粳', '经', '井', '警', '景', '颈', '静', '境', '敬', '镜', '径', '痉', '靖', '竟', '竞', '净', '炯', '窘', '揪', '究', '纠', '玖', '韭', '久', '灸', '九', '酒', '厩', '救', '旧', '臼', '舅', '咎', '就', '疚', '鞠', '拘', '狙', '疽', '居', '驹', '菊', '局', '咀', '矩', '举', '沮', '聚', '拒', '据', '巨', '具', '距', '踞', '锯', '俱', '句', '惧', '炬', '剧', '捐', '鹃', '娟', '倦', '眷', '卷', '绢', '撅', '攫', '抉', '掘', '倔', '爵', '觉', '决', '诀', '绝', '均', '菌', '钧', '军', '君', '峻']),
+    (0xBF, ['緻', '緼', '緽', '緾', '緿', '縀', '縁', '縂', '縃', '縄', '縅', '縆', '縇', '縈', '縉', '縊', '縋', '縌', '縍', '縎', '縏', '縐', '縑', '縒', '縓', '縔', '縕', '縖', '縗', '縘', '縙', '縚', '縛', '縜', '縝', '縞', '縟', '縠', '縡', '縢', '縣', '縤', '縥', '縦', '縧', '縨', '縩', '縪', '縫', '縬', '縭', '縮', '縯', '縰', '縱', '縲', '縳', '縴', '縵', '縶', '縷', '縸', '縹', '\u{FFFD}', '縺', '縼', '總', '績', '縿', '繀', '繂', '繃', '繄', '繅', '繆', '繈', '繉', '繊', '繋', '繌', '繍', '繎', '繏', '繐', '繑', '繒', '繓', '織', '繕', '繖', '繗', '繘', '繙', '繚', '繛', '繜', '繝', '俊', '竣', '浚', '郡', '骏', '喀', '咖', '卡', '咯', '开', '揩', '楷', '凯', '慨', '刊', '堪', '勘', '坎', '砍', '看', '康', '慷', '糠', '扛', '抗', '亢', '炕', '考', '拷', '烤', '靠', '坷', '苛', '柯', '棵', '磕', '颗', '科', '壳', '咳', '可', '渴', '克', '刻', '客', '课', '肯', '啃', '垦', '恳', '坑', '吭', '空', '恐', '孔', '控', '抠', '口', '扣', '寇', '枯', '哭', '窟', '苦', '酷', '库', '裤', '夸', '垮', '挎', '跨', '胯', '块', '筷', '侩', '快', '宽', '款', '匡', '筐', '狂', '框', '矿', '眶', '旷', '况', '亏', '盔', '岿', '窥', '葵', '奎', '魁', '傀']),
+    (0xC0, ['繞', '繟', '繠', '繡', '繢', '繣', '繤', '繥', '繦', '繧', '繨', '繩', '繪', '繫', '繬', '繭', '繮', '繯', '繰', '繱', '繲', '繳', '繴', '繵', '繶', '繷', '繸', '繹', '繺', '繻', '繼', '繽', '繾', '繿', '纀', '纁', '纃', '纄', '纅', '纆', '纇', '纈', '纉', '纊', '纋', '續', '纍', '纎', '纏', '纐', '纑', '纒', '纓', '纔', '纕', '纖', '纗', '纘', '纙', '纚', '纜', '纝', '纞', '\u{FFFD}', '纮', '纴', '纻', '纼', '绖', '绤', '绬', '绹', '缊', '缐', '缞', '缷', '缹', '缻', '缼', '缽', '缾', '缿', '罀', '罁', '罃', '罆', '罇', '罈', '罉', '罊', '罋', '罌', '罍', '罎', '罏', '罒', '罓', '馈', '愧', '溃', '坤', '昆', '捆', '困', '括', '扩', '廓', '阔', '垃', '拉', '喇', '蜡', '腊', '辣', '啦', '莱', '来', '赖', '蓝', '婪', '栏', '拦', '篮', '阑', '兰', '澜', '谰', '揽', '览', '懒', '缆', '烂', '滥', '琅', '榔', '狼', '廊', '郎', '朗', '浪', '捞', '劳', '牢', '老', '佬', '姥', '酪', '烙', '涝', '勒', '乐', '雷', '镭', '蕾', '磊', '累', '儡', '垒', '擂', '肋', '类', '泪', '棱', '楞', '冷', '厘', '梨', '犁', '黎', '篱', '狸', '离', '漓', '理', '李', '里', '鲤', '礼', '莉', '荔', '吏', '栗', '丽', '厉', '励', '砾', '历', '利', '傈', '例', '俐']),
+    (0xC1, ['罖', '罙', '罛', '罜', '罝', '罞', '罠', '罣', '罤', '罥', '罦', '罧', '罫', '罬', '罭', '罯', '罰', '罳', '罵', '罶', '罷', '罸', '罺', '罻', '罼', '罽', '罿', '羀', '羂', '羃', '羄', '羅', '羆', '羇', '羈', '羉', '羋', '羍', '羏', '羐', '羑', '羒', '羓', '羕', '羖', '羗', '羘', '羙', '羛', '羜', '羠', '羢', '羣', '羥', '羦', '羨', '義', '羪', '羫', '羬', '羭', '羮', '羱', '\u{FFFD}', '羳', '羴', '羵', '羶', '羷', '羺', '羻', '羾', '翀', '翂', '翃', '翄', '翆', '翇', '翈', '翉', '翋', '翍', '翏', '翐', '翑', '習', '翓', '翖', '翗', '翙', '翚', '翛', '翜', '翝', '翞', '翢', '翣', '痢', '立', '粒', '沥', '隶', '力', '璃', '哩', '俩', '联', '莲', '连', '镰', '廉', '怜', '涟', '帘', '敛', '脸', '链', '恋', '炼', '练', '粮', '凉', '梁', '粱', '良', '两', '辆', '量', '晾', '亮', '谅', '撩', '聊', '僚', '疗', '燎', '寥', '辽', '潦', '了', '撂', '镣', '廖', '料', '列', '裂', '烈', '劣', '猎', '琳', '林', '磷', '霖', '临', '邻', '鳞', '淋', '凛', '赁', '吝', '拎', '玲', '菱', '零', '龄', '铃', '伶', '羚', '凌', '灵', '陵', '岭', '领', '另', '令', '溜', '琉', '榴', '硫', '馏', '留', '刘', '瘤', '流', '柳', '六', '龙', '聋', '咙', '笼', '窿']),
+    (0xC2, ['翤', '翧', '翨', '翪', '翫', '翬', '翭', '翯', '翲', '翴', '翵', '翶', '翷', '翸', '翹', '翺', '翽', '翾', '翿', '耂', '耇', '耈', '耉', '耊', '耎', '耏', '耑', '耓', '耚', '耛', '耝', '耞', '耟', '耡', '耣', '耤', '耫', '耬', '耭', '耮', '耯', '耰', '耲', '耴', '耹', '耺', '耼', '耾', '聀', '聁', '聄', '聅', '聇', '聈', '聉', '聎', '聏', '聐', '聑', '聓', '聕', '聖', '聗', '\u{FFFD}', '聙', '聛', '聜', '聝', '聞', '聟', '聠', '聡', '聢', '聣', '聤', '聥', '聦', '聧', '聨', '聫', '聬', '聭', '聮', '聯', '聰', '聲', '聳', '聴', '聵', '聶', '職', '聸', '聹', '聺', '聻', '聼', '聽', '隆', '垄', '拢', '陇', '楼', '娄', '搂', '篓', '漏', '陋', '芦', '卢', '颅', '庐', '炉', '掳', '卤', '虏', '鲁', '麓', '碌', '露', '路', '赂', '鹿', '潞', '禄', '录', '陆', '戮', '驴', '吕', '铝', '侣', '旅', '履', '屡', '缕', '虑', '氯', '律', '率', '滤', '绿', '峦', '挛', '孪', '滦', '卵', '乱', '掠', '略', '抡', '轮', '伦', '仑', '沦', '纶', '论', '萝', '螺', '罗', '逻', '锣', '箩', '骡', '裸', '落', '洛', '骆', '络', '妈', '麻', '玛', '码', '蚂', '马', '骂', '嘛', '吗', '埋', '买', '麦', '卖', '迈', '脉', '瞒', '馒', '蛮', '满', '蔓', '曼', '慢', '漫']),
+    (0xC3, ['聾', '肁', '肂', '肅', '肈', '肊', '肍', '肎', '肏', '肐', '肑', '肒', '肔', '肕', '肗', '肙', '肞', '肣', '肦', '肧', '肨', '肬', '肰', '肳', '肵', '肶', '肸', '肹', '肻', '胅', '胇', '胈', '胉', '胊', '胋', '胏', '胐', '胑', '胒', '胓', '胔', '胕', '胘', '胟', '胠', '胢', '胣', '胦', '胮', '胵', '胷', '胹', '胻', '胾', '胿', '脀', '脁', '脃', '脄', '脅', '脇', '脈', '脋', '\u{FFFD}', '脌', '脕', '脗', '脙', '脛', '脜', '脝', '脟', '脠', '脡', '脢', '脣', '脤', '脥', '脦', '脧', '脨', '脩', '脪', '脫', '脭', '脮', '脰', '脳', '脴', '脵', '脷', '脹', '脺', '脻', '脼', '脽', '脿', '谩', '芒', '茫', '盲', '氓', '忙', '莽', '猫', '茅', '锚', '毛', '矛', '铆', '卯', '茂', '冒', '帽', '貌', '贸', '么', '玫', '枚', '梅', '酶', '霉', '煤', '没', '眉', '媒', '镁', '每', '美', '昧', '寐', '妹', '媚', '门', '闷', '们', '萌', '蒙', '檬', '盟', '锰', '猛', '梦', '孟', '眯', '醚', '靡', '糜', '迷', '谜', '弥', '米', '秘', '觅', '泌', '蜜', '密', '幂', '棉', '眠', '绵', '冕', '免', '勉', '娩', '缅', '面', '苗', '描', '瞄', '藐', '秒', '渺', '庙', '妙', '蔑', '灭', '民', '抿', '皿', '敏', '悯', '闽', '明', '螟', '鸣', '铭', '名', '命', '谬', '摸']),
+    (0xC4, ['腀', '腁', '腂', '腃', '腄', '腅', '腇', '腉', '腍', '腎', '腏', '腒', '腖', '腗', '腘', '腛', '腜', '腝', '腞', '腟', '腡', '腢', '腣', '腤', '腦', '腨', '腪', '腫', '腬', '腯', '腲', '腳', '腵', '腶', '腷', '腸', '膁', '膃', '膄', '膅', '膆', '膇', '膉', '膋', '膌', '膍', '膎', '膐', '膒', '膓', '膔', '膕', '膖', '膗', '膙', '膚', '膞', '膟', '膠', '膡', '膢', '膤', '膥', '\u{FFFD}', '膧', '膩', '膫', '膬', '膭', '膮', '膯', '膰', '膱', '膲', '膴', '膵', '膶', '膷', '膸', '膹', '膼', '膽', '膾', '膿', '臄', '臅', '臇', '臈', '臉', '臋', '臍', '臎', '臏', '臐', '臑', '臒', '臓', '摹', '蘑', '模', '膜', '磨', '摩', '魔', '抹', '末', '莫', '墨', '默', '沫', '漠', '寞', '陌', '谋', '牟', '某', '拇', '牡', '亩', '姆', '母', '墓', '暮', '幕', '募', '慕', '木', '目', '睦', '牧', '穆', '拿', '哪', '呐', '钠', '那', '娜', '纳', '氖', '乃', '奶', '耐', '奈', '南', '男', '难', '囊', '挠', '脑', '恼', '闹', '淖', '呢', '馁', '内', '嫩', '能', '妮', '霓', '倪', '泥', '尼', '拟', '你', '匿', '腻', '逆', '溺', '蔫', '拈', '年', '碾', '撵', '捻', '念', '娘', '酿', '鸟', '尿', '捏', '聂', '孽', '啮', '镊', '镍', '涅', '您', '柠', '狞', '凝', '宁']),
+    (0xC5, ['臔', '臕', '臖', '臗', '臘', '臙', '臚', '臛', '臜', '臝', '臞', '臟', '臠', '臡', '臢', '臤', '臥', '臦', '臨', '臩', '臫', '臮', '臯', '臰', '臱', '臲', '臵', '臶', '臷', '臸', '臹', '臺', '臽', '臿', '舃', '與', '興', '舉', '舊', '舋', '舎', '舏', '舑', '舓', '舕', '舖', '舗', '舘', '舙', '舚', '舝', '舠', '舤', '舥', '舦', '舧', '舩', '舮', '舲', '舺', '舼', '舽', '舿', '\u{FFFD}', '艀', '艁', '艂', '艃', '艅', '艆', '艈', '艊', '艌', '艍', '艎', '艐', '艑', '艒', '艓', '艔', '艕', '艖', '艗', '艙', '艛', '艜', '艝', '艞', '艠', '艡', '艢', '艣', '艤', '艥', '艦', '艧', '艩', '拧', '泞', '牛', '扭', '钮', '纽', '脓', '浓', '农', '弄', '奴', '努', '怒', '女', '暖', '虐', '疟', '挪', '懦', '糯', '诺', '哦', '欧', '鸥', '殴', '藕', '呕', '偶', '沤', '啪', '趴', '爬', '帕', '怕', '琶', '拍', '排', '牌', '徘', '湃', '派', '攀', '潘', '盘', '磐', '盼', '畔', '判', '叛', '乓', '庞', '旁', '耪', '胖', '抛', '咆', '刨', '炮', '袍', '跑', '泡', '呸', '胚', '培', '裴', '赔', '陪', '配', '佩', '沛', '喷', '盆', '砰', '抨', '烹', '澎', '彭', '蓬', '棚', '硼', '篷', '膨', '朋', '鹏', '捧', '碰', '坯', '砒', '霹', '批', '披', '劈', '琵', '毗']),
+    (0xC6, ['艪', '艫', '艬', '艭', '艱', '艵', '艶', '艷', '艸', '艻', '艼', '芀', '芁', '芃', '芅', '芆', '芇', '芉', '芌', '芐', '芓', '芔', '芕', '芖', '芚', '芛', '芞', '芠', '芢', '芣', '芧', '芲', '芵', '芶', '芺', '芻', '芼', '芿', '苀', '苂', '苃', '苅', '苆', '苉', '苐', '苖', '苙', '苚', '苝', '苢', '苧', '苨', '苩', '苪', '苬', '苭', '苮', '苰', '苲', '苳', '苵', '苶', '苸', '\u{FFFD}', '苺', '苼', '苽', '苾', '苿', '茀', '茊', '茋', '茍', '茐', '茒', '茓', '茖', '茘', '茙', '茝', '茞', '茟', '茠', '茡', '茢', '茣', '茤', '茥', '茦', '茩', '茪', '茮', '茰', '茲', '茷', '茻', '茽', '啤', '脾', '疲', '皮', '匹', '痞', '僻', '屁', '譬', '篇', '偏', '片', '骗', '飘', '漂', '瓢', '票', '撇', '瞥', '拼', '频', '贫', '品', '聘', '乒', '坪', '苹', '萍', '平', '凭', '瓶', '评', '屏', '坡', '泼', '颇', '婆', '破', '魄', '迫', '粕', '剖', '扑', '铺', '仆', '莆', '葡', '菩', '蒲', '埔', '朴', '圃', '普', '浦', '谱', '曝', '瀑', '期', '欺', '栖', '戚', '妻', '七', '凄', '漆', '柒', '沏', '其', '棋', '奇', '歧', '畦', '崎', '脐', '齐', '旗', '祈', '祁', '骑', '起', '岂', '乞', '企', '启', '契', '砌', '器', '气', '迄', '弃', '汽', '泣', '讫', '掐']),
+    (0xC7, ['茾', '茿', '荁', '荂', '荄', '荅', '荈', '荊', '荋', '荌', '荍', '荎', '荓', '荕', '荖', '荗', '荘', '荙', '荝', '荢', '荰', '荱', '荲', '荳', '荴', '荵', '荶', '荹', '荺', '荾', '荿', '莀', '莁', '莂', '莃', '莄', '莇', '莈', '莊', '莋', '莌', '莍', '莏', '莐', '莑', '莔', '莕', '莖', '莗', '莙', '莚', '莝', '莟', '莡', '莢', '莣', '莤', '莥', '莦', '莧', '莬', '莭', '莮', '\u{FFFD}', '莯', '莵', '莻', '莾', '莿', '菂', '菃', '菄', '菆', '菈', '菉', '菋', '菍', '菎', '菐', '菑', '菒', '菓', '菕', '菗', '菙', '菚', '菛', '菞', '菢', '菣', '菤', '菦', '菧', '菨', '菫', '菬', '菭', '恰', '洽', '牵', '扦', '钎', '铅', '千', '迁', '签', '仟', '谦', '乾', '黔', '钱', '钳', '前', '潜', '遣', '浅', '谴', '堑', '嵌', '欠', '歉', '枪', '呛', '腔', '羌', '墙', '蔷', '强', '抢', '橇', '锹', '敲', '悄', '桥', '瞧', '乔', '侨', '巧', '鞘', '撬', '翘', '峭', '俏', '窍', '切', '茄', '且', '怯', '窃', '钦', '侵', '亲', '秦', '琴', '勤', '芹', '擒', '禽', '寝', '沁', '青', '轻', '氢', '倾', '卿', '清', '擎', '晴', '氰', '情', '顷', '请', '庆', '琼', '穷', '秋', '丘', '邱', '球', '求', '囚', '酋', '泅', '趋', '区', '蛆', '曲', '躯', '屈', '驱', '渠']),
+    (0xC8, ['菮', '華', '菳', '菴', '菵', '菶', '菷', '菺', '菻', '菼', '菾', '菿', '萀', '萂', '萅', '萇', '萈', '萉', '萊', '萐', '萒', '萓', '萔', '萕', '萖', '萗', '萙', '萚', '萛', '萞', '萟', '萠', '萡', '萢', '萣', '萩', '萪', '萫', '萬', '萭', '萮', '萯', '萰', '萲', '萳', '萴', '萵', '萶', '萷', '萹', '萺', '萻', '萾', '萿', '葀', '葁', '葂', '葃', '葄', '葅', '葇', '葈', '葉', '\u{FFFD}', '葊', '葋', '葌', '葍', '葎', '葏', '葐', '葒', '葓', '葔', '葕', '葖', '葘', '葝', '葞', '葟', '葠', '葢', '葤', '葥', '葦', '葧', '葨', '葪', '葮', '葯', '葰', '葲', '葴', '葷', '葹', '葻', '葼', '取', '娶', '龋', '趣', '去', '圈', '颧', '权', '醛', '泉', '全', '痊', '拳', '犬', '券', '劝', '缺', '炔', '瘸', '却', '鹊', '榷', '确', '雀', '裙', '群', '然', '燃', '冉', '染', '瓤', '壤', '攘', '嚷', '让', '饶', '扰', '绕', '惹', '热', '壬', '仁', '人', '忍', '韧', '任', '认', '刃', '妊', '纫', '扔', '仍', '日', '戎', '茸', '蓉', '荣', '融', '熔', '溶', '容', '绒', '冗', '揉', '柔', '肉', '茹', '蠕', '儒', '孺', '如', '辱', '乳', '汝', '入', '褥', '软', '阮', '蕊', '瑞', '锐', '闰', '润', '若', '弱', '撒', '洒', '萨', '腮', '鳃', '塞', '赛', '三', '叁']),
+    (0xC9, ['葽', '葾', '葿', '蒀', '蒁', '蒃', '蒄', '蒅', '蒆', '蒊', '蒍', '蒏', '蒐', '蒑', '蒒', '蒓', '蒔', '蒕', '蒖', '蒘', '蒚', '蒛', '蒝', '蒞', '蒟', '蒠', '蒢', '蒣', '蒤', '蒥', '蒦', '蒧', '蒨', '蒩', '蒪', '蒫', '蒬', '蒭', '蒮', '蒰', '蒱', '蒳', '蒵', '蒶', '蒷', '蒻', '蒼', '蒾', '蓀', '蓂', '蓃', '蓅', '蓆', '蓇', '蓈', '蓋', '蓌', '蓎', '蓏', '蓒', '蓔', '蓕', '蓗', '\u{FFFD}', '蓘', '蓙', '蓚', '蓛', '蓜', '蓞', '蓡', '蓢', '蓤', '蓧', '蓨', '蓩', '蓪', '蓫', '蓭', '蓮', '蓯', '蓱', '蓲', '蓳', '蓴', '蓵', '蓶', '蓷', '蓸', '蓹', '蓺', '蓻', '蓽', '蓾', '蔀', '蔁', '蔂', '伞', '散', '桑', '嗓', '丧', '搔', '骚', '扫', '嫂', '瑟', '色', '涩', '森', '僧', '莎', '砂', '杀', '刹', '沙', '纱', '傻', '啥', '煞', '筛', '晒', '珊', '苫', '杉', '山', '删', '煽', '衫', '闪', '陕', '擅', '赡', '膳', '善', '汕', '扇', '缮', '墒', '伤', '商', '赏', '晌', '上', '尚', '裳', '梢', '捎', '稍', '烧', '芍', '勺', '韶', '少', '哨', '邵', '绍', '奢', '赊', '蛇', '舌', '舍', '赦', '摄', '射', '慑', '涉', '社', '设', '砷', '申', '呻', '伸', '身', '深', '娠', '绅', '神', '沈', '审', '婶', '甚', '肾', '慎', '渗', '声', '生', '甥', '牲', '升', '绳']),
+    (0xCA, ['蔃', '蔄', '蔅', '蔆', '蔇', '蔈', '蔉', '蔊', '蔋', '蔍', '蔎', '蔏', '蔐', '蔒', '蔔', '蔕', '蔖', '蔘', '蔙', '蔛', '蔜', '蔝', '蔞', '蔠', '蔢', '蔣', '蔤', '蔥', '蔦', '蔧', '蔨', '蔩', '蔪', '蔭', '蔮', '蔯', '蔰', '蔱', '蔲', '蔳', '蔴', '蔵', '蔶', '蔾', '蔿', '蕀', '蕁', '蕂', '蕄', '蕅', '蕆', '蕇', '蕋', '蕌', '蕍', '蕎', '蕏', '蕐', '蕑', '蕒', '蕓', '蕔', '蕕', '\u{FFFD}', '蕗', '蕘', '蕚', '蕛', '蕜', '蕝', '蕟', '蕠', '蕡', '蕢', '蕣', '蕥', '蕦', '蕧', '蕩', '蕪', '蕫', '蕬', '蕭', '蕮', '蕯', '蕰', '蕱', '蕳', '蕵', '蕶', '蕷', '蕸', '蕼', '蕽', '蕿', '薀', '薁', '省', '盛', '剩', '胜', '圣', '师', '失', '狮', '施', '湿', '诗', '尸', '虱', '十', '石', '拾', '时', '什', '食', '蚀', '实', '识', '史', '矢', '使', '屎', '驶', '始', '式', '示', '士', '世', '柿', '事', '拭', '誓', '逝', '势', '是', '嗜', '噬', '适', '仕', '侍', '释', '饰', '氏', '市', '恃', '室', '视', '试', '收', '手', '首', '守', '寿', '授', '售', '受', '瘦', '兽', '蔬', '枢', '梳', '殊', '抒', '输', '叔', '舒', '淑', '疏', '书', '赎', '孰', '熟', '薯', '暑', '曙', '署', '蜀', '黍', '鼠', '属', '术', '述', '树', '束', '戍', '竖', '墅', '庶', '数', '漱']),
+    (0xCB, ['薂', '薃', '薆', '薈', '薉', '薊', '薋', '薌', '薍', '薎', '薐', '薑', '薒', '薓', '薔', '薕', '薖', '薗', '薘', '薙', '薚', '薝', '薞', '薟', '薠', '薡', '薢', '薣', '薥', '薦', '薧', '薩', '薫', '薬', '薭', '薱', '薲', '薳', '薴', '薵', '薶', '薸', '薺', '薻', '薼', '薽', '薾', '薿', '藀', '藂', '藃', '藄', '藅', '藆', '藇', '藈', '藊', '藋', '藌', '藍', '藎', '藑', '藒', '\u{FFFD}', '藔', '藖', '藗', '藘', '藙', '藚', '藛', '藝', '藞', '藟', '藠', '藡', '藢', '藣', '藥', '藦', '藧', '藨', '藪', '藫', '藬', '藭', '藮', '藯', '藰', '藱', '藲', '藳', '藴', '藵', '藶', '藷', '藸', '恕', '刷', '耍', '摔', '衰', '甩', '帅', '栓', '拴', '霜', '双', '爽', '谁', '水', '睡', '税', '吮', '瞬', '顺', '舜', '说', '硕', '朔', '烁', '斯', '撕', '嘶', '思', '私', '司', '丝', '死', '肆', '寺', '嗣', '四', '伺', '似', '饲', '巳', '松', '耸', '怂', '颂', '送', '宋', '讼', '诵', '搜', '艘', '擞', '嗽', '苏', '酥', '俗', '素', '速', '粟', '僳', '塑', '溯', '宿', '诉', '肃', '酸', '蒜', '算', '虽', '隋', '随', '绥', '髓', '碎', '岁', '穗', '遂', '隧', '祟', '孙', '损', '笋', '蓑', '梭', '唆', '缩', '琐', '索', '锁', '所', '塌', '他', '它', '她', '塔']),
+    (0xCC, ['藹', '藺', '藼', '藽', '藾', '蘀', '蘁', '蘂', '蘃', '蘄', '蘆', '蘇', '蘈', '蘉', '蘊', '蘋', '蘌', '蘍', '蘎', '蘏', '蘐', '蘒', '蘓', '蘔', '蘕', '蘗', '蘘', '蘙', '蘚', '蘛', '蘜', '蘝', '蘞', '蘟', '蘠', '蘡', '蘢', '蘣', '蘤', '蘥', '蘦', '蘨', '蘪', '蘫', '蘬', '蘭', '蘮', '蘯', '蘰', '蘱', '蘲', '蘳', '蘴', '蘵', '蘶', '蘷', '蘹', '蘺', '蘻', '蘽', '蘾', '蘿', '虀', '\u{FFFD}', '虁', '虂', '虃', '虄', '虅', '虆', '虇', '虈', '虉', '虊', '虋', '虌', '虒', '虓', '處', '虖', '虗', '虘', '虙', '虛', '虜', '虝', '號', '虠', '虡', '虣', '虤', '虥', '虦', '虧', '虨', '虩', '虪', '獭', '挞', '蹋', '踏', '胎', '苔', '抬', '台', '泰', '酞', '太', '态', '汰', '坍', '摊', '贪', '瘫', '滩', '坛', '檀', '痰', '潭', '谭', '谈', '坦', '毯', '袒', '碳', '探', '叹', '炭', '汤', '塘', '搪', '堂', '棠', '膛', '唐', '糖', '倘', '躺', '淌', '趟', '烫', '掏', '涛', '滔', '绦', '萄', '桃', '逃', '淘', '陶', '讨', '套', '特', '藤', '腾', '疼', '誊', '梯', '剔', '踢', '锑', '提', '题', '蹄', '啼', '体', '替', '嚏', '惕', '涕', '剃', '屉', '天', '添', '填', '田', '甜', '恬', '舔', '腆', '挑', '条', '迢', '眺', '跳', '贴', '铁', '帖', '厅', '听', '烃']),
+    (0xCD, ['虭', '虯', '虰', '虲', '虳', '虴', '虵', '虶', '虷', '虸', '蚃', '蚄', '蚅', '蚆', '蚇', '蚈', '蚉', '蚎', '蚏', '蚐', '蚑', '蚒', '蚔', '蚖', '蚗', '蚘', '蚙', '蚚', '蚛', '蚞', '蚟', '蚠', '蚡', '蚢', '蚥', '蚦', '蚫', '蚭', '蚮', '蚲', '蚳', '蚷', '蚸', '蚹', '蚻', '蚼', '蚽', '蚾', '蚿', '蛁', '蛂', '蛃', '蛅', '蛈', '蛌', '蛍', '蛒', '蛓', '蛕', '蛖', '蛗', '蛚', '蛜', '\u{FFFD}', '蛝', '蛠', '蛡', '蛢', '蛣', '蛥', '蛦', '蛧', '蛨', '蛪', '蛫', '蛬', '蛯', '蛵', '蛶', '蛷', '蛺', '蛻', '蛼', '蛽', '蛿', '蜁', '蜄', '蜅', '蜆', '蜋', '蜌', '蜎', '蜏', '蜐', '蜑', '蜔', '蜖', '汀', '廷', '停', '亭', '庭', '挺', '艇', '通', '桐', '酮', '瞳', '同', '铜', '彤', '童', '桶', '捅', '筒', '统', '痛', '偷', '投', '头', '透', '凸', '秃', '突', '图', '徒', '途', '涂', '屠', '土', '吐', '兔', '湍', '团', '推', '颓', '腿', '蜕', '褪', '退', '吞', '屯', '臀', '拖', '托', '脱', '鸵', '陀', '驮', '驼', '椭', '妥', '拓', '唾', '挖', '哇', '蛙', '洼', '娃', '瓦', '袜', '歪', '外', '豌', '弯', '湾', '玩', '顽', '丸', '烷', '完', '碗', '挽', '晚', '皖', '惋', '宛', '婉', '万', '腕', '汪', '王', '亡', '枉', '网', '往', '旺', '望', '忘', '妄', '威']),
+    (0xCE, ['蜙', '蜛', '蜝', '蜟', '蜠', '蜤', '蜦', '蜧', '蜨', '蜪', '蜫', '蜬', '蜭', '蜯', '蜰', '蜲', '蜳', '蜵', '蜶', '蜸', '蜹', '蜺', '蜼', '蜽', '蝀', '蝁', '蝂', '蝃', '蝄', '蝅', '蝆', '蝊', '蝋', '蝍', '蝏', '蝐', '蝑', '蝒', '蝔', '蝕', '蝖', '蝘', '蝚', '蝛', '蝜', '蝝', '蝞', '蝟', '蝡', '蝢', '蝦', '蝧', '蝨', '蝩', '蝪', '蝫', '蝬', '蝭', '蝯', '蝱', '蝲', '蝳', '蝵', '\u{FFFD}', '蝷', '蝸', '蝹', '蝺', '蝿', '螀', '螁', '螄', '螆', '螇', '螉', '螊', '螌', '螎', '螏', '螐', '螑', '螒', '螔', '螕', '螖', '螘', '螙', '螚', '螛', '螜', '螝', '螞', '螠', '螡', '螢', '螣', '螤', '巍', '微', '危', '韦', '违', '桅', '围', '唯', '惟', '为', '潍', '维', '苇', '萎', '委', '伟', '伪', '尾', '纬', '未', '蔚', '味', '畏', '胃', '喂', '魏', '位', '渭', '谓', '尉', '慰', '卫', '瘟', '温', '蚊', '文', '闻', '纹', '吻', '稳', '紊', '问', '嗡', '翁', '瓮', '挝', '蜗', '涡', '窝', '我', '斡', '卧', '握', '沃', '巫', '呜', '钨', '乌', '污', '诬', '屋', '无', '芜', '梧', '吾', '吴', '毋', '武', '五', '捂', '午', '舞', '伍', '侮', '坞', '戊', '雾', '晤', '物', '勿', '务', '悟', '误', '昔', '熙', '析', '西', '硒', '矽', '晰', '嘻', '吸', '锡', '牺']),
+    (0xCF, ['螥', '螦', '螧', '螩', '螪', '螮', '螰', '螱', '螲', '螴', '螶', '螷', '螸', '螹', '螻', '螼', '螾', '螿', '蟁', '蟂', '蟃', '蟄', '蟅', '蟇', '蟈', '蟉', '蟌', '蟍', '蟎', '蟏', '蟐', '蟔', '蟕', '蟖', '蟗', '蟘', '蟙', '蟚', '蟜', '蟝', '蟞', '蟟', '蟡', '蟢', '蟣', '蟤', '蟦', '蟧', '蟨', '蟩', '蟫', '蟬', '蟭', '蟯', '蟰', '蟱', '蟲', '蟳', '蟴', '蟵', '蟶', '蟷', '蟸', '\u{FFFD}', '蟺', '蟻', '蟼', '蟽', '蟿', '蠀', '蠁', '蠂', '蠄', '蠅', '蠆', '蠇', '蠈', '蠉', '蠋', '蠌', '蠍', '蠎', '蠏', '蠐', '蠑', '蠒', '蠔', '蠗', '蠘', '蠙', '蠚', '蠜', '蠝', '蠞', '蠟', '蠠', '蠣', '稀', '息', '希', '悉', '膝', '夕', '惜', '熄', '烯', '溪', '汐', '犀', '檄', '袭', '席', '习', '媳', '喜', '铣', '洗', '系', '隙', '戏', '细', '瞎', '虾', '匣', '霞', '辖', '暇', '峡', '侠', '狭', '下', '厦', '夏', '吓', '掀', '锨', '先', '仙', '鲜', '纤', '咸', '贤', '衔', '舷', '闲', '涎', '弦', '嫌', '显', '险', '现', '献', '县', '腺', '馅', '羡', '宪', '陷', '限', '线', '相', '厢', '镶', '香', '箱', '襄', '湘', '乡', '翔', '祥', '详', '想', '响', '享', '项', '巷', '橡', '像', '向', '象', '萧', '硝', '霄', '削', '哮', '嚣', '销', '消', '宵', '淆', '晓']),
+    (0xD0, ['蠤', '蠥', '蠦', '蠧', '蠨', '蠩', '蠪', '蠫', '蠬', '蠭', '蠮', '蠯', '蠰', '蠱', '蠳', '蠴', '蠵', '蠶', '蠷', '蠸', '蠺', '蠻', '蠽', '蠾', '蠿', '衁', '衂', '衃', '衆', '衇', '衈', '衉', '衊', '衋', '衎', '衏', '衐', '衑', '衒', '術', '衕', '衖', '衘', '衚', '衛', '衜', '衝', '衞', '衟', '衠', '衦', '衧', '衪', '衭', '衯', '衱', '衳', '衴', '衵', '衶', '衸', '衹', '衺', '\u{FFFD}', '衻', '衼', '袀', '袃', '袆', '袇', '袉', '袊', '袌', '袎', '袏', '袐', '袑', '袓', '袔', '袕', '袗', '袘', '袙', '袚', '袛', '袝', '袞', '袟', '袠', '袡', '袣', '袥', '袦', '袧', '袨', '袩', '袪', '小', '孝', '校', '肖', '啸', '笑', '效', '楔', '些', '歇', '蝎', '鞋', '协', '挟', '携', '邪', '斜', '胁', '谐', '写', '械', '卸', '蟹', '懈', '泄', '泻', '谢', '屑', '薪', '芯', '锌', '欣', '辛', '新', '忻', '心', '信', '衅', '星', '腥', '猩', '惺', '兴', '刑', '型', '形', '邢', '行', '醒', '幸', '杏', '性', '姓', '兄', '凶', '胸', '匈', '汹', '雄', '熊', '休', '修', '羞', '朽', '嗅', '锈', '秀', '袖', '绣', '墟', '戌', '需', '虚', '嘘', '须', '徐', '许', '蓄', '酗', '叙', '旭', '序', '畜', '恤', '絮', '婿', '绪', '续', '轩', '喧', '宣', '悬', '旋', '玄']),
+    (0xD1, ['袬', '袮', '袯', '袰', '袲', '袳', '袴', '袵', '袶', '袸', '袹', '袺', '袻', '袽', '袾', '袿', '裀', '裃', '裄', '裇', '裈', '裊', '裋', '裌', '裍', '裏', '裐', '裑', '裓', '裖', '裗', '裚', '裛', '補', '裝', '裞', '裠', '裡', '裦', '裧', '裩', '裪', '裫', '裬', '裭', '裮', '裯', '裲', '裵', '裶', '裷', '裺', '裻', '製', '裿', '褀', '褁', '褃', '褄', '褅', '褆', '複', '褈', '\u{FFFD}', '褉', '褋', '褌', '褍', '褎', '褏', '褑', '褔', '褕', '褖', '褗', '褘', '褜', '褝', '褞', '褟', '褠', '褢', '褣', '褤', '褦', '褧', '褨', '褩', '褬', '褭', '褮', '褯', '褱', '褲', '褳', '褵', '褷', '选', '癣', '眩', '绚', '靴', '薛', '学', '穴', '雪', '血', '勋', '熏', '循', '旬', '询', '寻', '驯', '巡', '殉', '汛', '训', '讯', '逊', '迅', '压', '押', '鸦', '鸭', '呀', '丫', '芽', '牙', '蚜', '崖', '衙', '涯', '雅', '哑', '亚', '讶', '焉', '咽', '阉', '烟', '淹', '盐', '严', '研', '蜒', '岩', '延', '言', '颜', '阎', '炎', '沿', '奄', '掩', '眼', '衍', '演', '艳', '堰', '燕', '厌', '砚', '雁', '唁', '彦', '焰', '宴', '谚', '验', '殃', '央', '鸯', '秧', '杨', '扬', '佯', '疡', '羊', '洋', '阳', '氧', '仰', '痒', '养', '样', '漾', '邀', '腰', '妖', '瑶']),
+    (0xD2, ['褸', '褹', '褺', '褻', '褼', '褽', '褾', '褿', '襀', '襂', '襃', '襅', '襆', '襇', '襈', '襉', '襊', '襋', '襌', '襍', '襎', '襏', '襐', '襑', '襒', '襓', '襔', '襕', '襖', '襗', '襘', '襙', '襚', '襛', '襜', '襝', '襠', '襡', '襢', '襣', '襤', '襥', '襧', '襨', '襩', '襪', '襫', '襬', '襭', '襮', '襯', '襰', '襱', '襲', '襳', '襴', '襵', '襶', '襷', '襸', '襹', '襺', '襼', '\u{FFFD}', '襽', '襾', '覀', '覂', '覄', '覅', '覇', '覈', '覉', '覊', '見', '覌', '覍', '覎', '規', '覐', '覑', '覒', '覓', '覔', '覕', '視', '覗', '覘', '覙', '覚', '覛', '覜', '覝', '覞', '覟', '覠', '覡', '摇', '尧', '遥', '窑', '谣', '姚', '咬', '舀', '药', '要', '耀', '椰', '噎', '耶', '爷', '野', '冶', '也', '页', '掖', '业', '叶', '曳', '腋', '夜', '液', '一', '壹', '医', '揖', '铱', '依', '伊', '衣', '颐', '夷', '遗', '移', '仪', '胰', '疑', '沂', '宜', '姨', '彝', '椅', '蚁', '倚', '已', '乙', '矣', '以', '艺', '抑', '易', '邑', '屹', '亿', '役', '臆', '逸', '肄', '疫', '亦', '裔', '意', '毅', '忆', '义', '益', '溢', '诣', '议', '谊', '译', '异', '翼', '翌', '绎', '茵', '荫', '因', '殷', '音', '阴', '姻', '吟', '银', '淫', '寅', '饮', '尹', '引', '隐']),
+    (0xD3, ['覢', '覣', '覤', '覥', '覦', '覧', '覨', '覩', '親', '覫', '覬', '覭', '覮', '覯', '覰', '覱', '覲', '観', '覴', '覵', '覶', '覷', '覸', '覹', '覺', '覻', '覼', '覽', '覾', '覿', '觀', '觃', '觍', '觓', '觔', '觕', '觗', '觘', '觙', '觛', '觝', '觟', '觠', '觡', '觢', '觤', '觧', '觨', '觩', '觪', '觬', '觭', '觮', '觰', '觱', '觲', '觴', '觵', '觶', '觷', '觸', '觹', '觺', '\u{FFFD}', '觻', '觼', '觽', '觾', '觿', '訁', '訂', '訃', '訄', '訅', '訆', '計', '訉', '訊', '訋', '訌', '訍', '討', '訏', '訐', '訑', '訒', '訓', '訔', '訕', '訖', '託', '記', '訙', '訚', '訛', '訜', '訝', '印', '英', '樱', '婴', '鹰', '应', '缨', '莹', '萤', '营', '荧', '蝇', '迎', '赢', '盈', '影', '颖', '硬', '映', '哟', '拥', '佣', '臃', '痈', '庸', '雍', '踊', '蛹', '咏', '泳', '涌', '永', '恿', '勇', '用', '幽', '优', '悠', '忧', '尤', '由', '邮', '铀', '犹', '油', '游', '酉', '有', '友', '右', '佑', '釉', '诱', '又', '幼', '迂', '淤', '于', '盂', '榆', '虞', '愚', '舆', '余', '俞', '逾', '鱼', '愉', '渝', '渔', '隅', '予', '娱', '雨', '与', '屿', '禹', '宇', '语', '羽', '玉', '域', '芋', '郁', '吁', '遇', '喻', '峪', '御', '愈', '欲', '狱', '育', '誉']),
+    (0xD4, ['訞', '訟', '訠', '訡', '訢', '訣', '訤', '訥', '訦', '訧', '訨', '訩', '訪', '訫', '訬', '設', '訮', '訯', '訰', '許', '訲', '訳', '訴', '訵', '訶', '訷', '訸', '訹', '診', '註', '証', '訽', '訿', '詀', '詁', '詂', '詃', '詄', '詅', '詆', '詇', '詉', '詊', '詋', '詌', '詍', '詎', '詏', '詐', '詑', '詒', '詓', '詔', '評', '詖', '詗', '詘', '詙', '詚', '詛', '詜', '詝', '詞', '\u{FFFD}', '詟', '詠', '詡', '詢', '詣', '詤', '詥', '試', '詧', '詨', '詩', '詪', '詫', '詬', '詭', '詮', '詯', '詰', '話', '該', '詳', '詴', '詵', '詶', '詷', '詸', '詺', '詻', '詼', '詽', '詾', '詿', '誀', '浴', '寓', '裕', '预', '豫', '驭', '鸳', '渊', '冤', '元', '垣', '袁', '原', '援', '辕', '园', '员', '圆', '猿', '源', '缘', '远', '苑', '愿', '怨', '院', '曰', '约', '越', '跃', '钥', '岳', '粤', '月', '悦', '阅', '耘', '云', '郧', '匀', '陨', '允', '运', '蕴', '酝', '晕', '韵', '孕', '匝', '砸', '杂', '栽', '哉', '灾', '宰', '载', '再', '在', '咱', '攒', '暂', '赞', '赃', '脏', '葬', '遭', '糟', '凿', '藻', '枣', '早', '澡', '蚤', '躁', '噪', '造', '皂', '灶', '燥', '责', '择', '则', '泽', '贼', '怎', '增', '憎', '曾', '赠', '扎', '喳', '渣', '札', '轧']),
+    (0xD5, ['誁', '誂', '誃', '誄', '誅', '誆', '誇', '誈', '誋', '誌', '認', '誎', '誏', '誐', '誑', '誒', '誔', '誕', '誖', '誗', '誘', '誙', '誚', '誛', '誜', '誝', '語', '誟', '誠', '誡', '誢', '誣', '誤', '誥', '誦', '誧', '誨', '誩', '說', '誫', '説', '読', '誮', '誯', '誰', '誱', '課', '誳', '誴', '誵', '誶', '誷', '誸', '誹', '誺', '誻', '誼', '誽', '誾', '調', '諀', '諁', '諂', '\u{FFFD}', '諃', '諄', '諅', '諆', '談', '諈', '諉', '諊', '請', '諌', '諍', '諎', '諏', '諐', '諑', '諒', '諓', '諔', '諕', '論', '諗', '諘', '諙', '諚', '諛', '諜', '諝', '諞', '諟', '諠', '諡', '諢', '諣', '铡', '闸', '眨', '栅', '榨', '咋', '乍', '炸', '诈', '摘', '斋', '宅', '窄', '债', '寨', '瞻', '毡', '詹', '粘', '沾', '盏', '斩', '辗', '崭', '展', '蘸', '栈', '占', '战', '站', '湛', '绽', '樟', '章', '彰', '漳', '张', '掌', '涨', '杖', '丈', '帐', '账', '仗', '胀', '瘴', '障', '招', '昭', '找', '沼', '赵', '照', '罩', '兆', '肇', '召', '遮', '折', '哲', '蛰', '辙', '者', '锗', '蔗', '这', '浙', '珍', '斟', '真', '甄', '砧', '臻', '贞', '针', '侦', '枕', '疹', '诊', '震', '振', '镇', '阵', '蒸', '挣', '睁', '征', '狰', '争', '怔', '整', '拯', '正', '政']),
+    (0xD6, ['諤', '諥', '諦', '諧', '諨', '諩', '諪', '諫', '諬', '諭', '諮', '諯', '諰', '諱', '諲', '諳', '諴', '諵', '諶', '諷', '諸', '諹', '諺', '諻', '諼', '諽', '諾', '諿', '謀', '謁', '謂', '謃', '謄', '謅', '謆', '謈', '謉', '謊', '謋', '謌', '謍', '謎', '謏', '謐', '謑', '謒', '謓', '謔', '謕', '謖', '謗', '謘', '謙', '謚', '講', '謜', '謝', '謞', '謟', '謠', '謡', '謢', '謣', '\u{FFFD}', '謤', '謥', '謧', '謨', '謩', '謪', '謫', '謬', '謭', '謮', '謯', '謰', '謱', '謲', '謳', '謴', '謵', '謶', '謷', '謸', '謹', '謺', '謻', '謼', '謽', '謾', '謿', '譀', '譁', '譂', '譃', '譄', '譅', '帧', '症', '郑', '证', '芝', '枝', '支', '吱', '蜘', '知', '肢', '脂', '汁', '之', '织', '职', '直', '植', '殖', '执', '值', '侄', '址', '指', '止', '趾', '只', '旨', '纸', '志', '挚', '掷', '至', '致', '置', '帜', '峙', '制', '智', '秩', '稚', '质', '炙', '痔', '滞', '治', '窒', '中', '盅', '忠', '钟', '衷', '终', '种', '肿', '重', '仲', '众', '舟', '周', '州', '洲', '诌', '粥', '轴', '肘', '帚', '咒', '皱', '宙', '昼', '骤', '珠', '株', '蛛', '朱', '猪', '诸', '诛', '逐', '竹', '烛', '煮', '拄', '瞩', '嘱', '主', '著', '柱', '助', '蛀', '贮', '铸', '筑']),
+    (0xD7, ['譆', '譇', '譈', '證', '譊', '譋', '譌', '譍', '譎', '譏', '譐', '譑', '譒', '譓', '譔', '譕', '譖', '譗', '識', '譙', '譚', '譛', '譜', '譝', '譞', '譟', '譠', '譡', '譢', '譣', '譤', '譥', '譧', '譨', '譩', '譪', '譫', '譭', '譮', '譯', '議', '譱', '譲', '譳', '譴', '譵', '譶', '護', '譸', '譹', '譺', '譻', '譼', '譽', '譾', '譿', '讀', '讁', '讂', '讃', '讄', '讅', '讆', '\u{FFFD}', '讇', '讈', '讉', '變', '讋', '讌', '讍', '讎', '讏', '讐', '讑', '讒', '讓', '讔', '讕', '讖', '讗', '讘', '讙', '讚', '讛', '讜', '讝', '讞', '讟', '讬', '讱', '讻', '诇', '诐', '诪', '谉', '谞', '住', '注', '祝', '驻', '抓', '爪', '拽', '专', '砖', '转', '撰', '赚', '篆', '桩', '庄', '装', '妆', '撞', '壮', '状', '椎', '锥', '追', '赘', '坠', '缀', '谆', '准', '捉', '拙', '卓', '桌', '琢', '茁', '酌', '啄', '着', '灼', '浊', '兹', '咨', '资', '姿', '滋', '淄', '孜', '紫', '仔', '籽', '滓', '子', '自', '渍', '字', '鬃', '棕', '踪', '宗', '综', '总', '纵', '邹', '走', '奏', '揍', '租', '足', '卒', '族', '祖', '诅', '阻', '组', '钻', '纂', '嘴', '醉', '最', '罪', '尊', '遵', '昨', '左', '佐', '柞', '做', '作', '坐', '座', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xD8, ['谸', '谹', '谺', '谻', '谼', '谽', '谾', '谿', '豀', '豂', '豃', '豄', '豅', '豈', '豊', '豋', '豍', '豎', '豏', '豐', '豑', '豒', '豓', '豔', '豖', '豗', '豘', '豙', '豛', '豜', '豝', '豞', '豟', '豠', '豣', '豤', '豥', '豦', '豧', '豨', '豩', '豬', '豭', '豮', '豯', '豰', '豱', '豲', '豴', '豵', '豶', '豷', '豻', '豼', '豽', '豾', '豿', '貀', '貁', '貃', '貄', '貆', '貇', '\u{FFFD}', '貈', '貋', '貍', '貎', '貏', '貐', '貑', '貒', '貓', '貕', '貖', '貗', '貙', '貚', '貛', '貜', '貝', '貞', '貟', '負', '財', '貢', '貣', '貤', '貥', '貦', '貧', '貨', '販', '貪', '貫', '責', '貭', '亍', '丌', '兀', '丐', '廿', '卅', '丕', '亘', '丞', '鬲', '孬', '噩', '丨', '禺', '丿', '匕', '乇', '夭', '爻', '卮', '氐', '囟', '胤', '馗', '毓', '睾', '鼗', '丶', '亟', '鼐', '乜', '乩', '亓', '芈', '孛', '啬', '嘏', '仄', '厍', '厝', '厣', '厥', '厮', '靥', '赝', '匚', '叵', '匦', '匮', '匾', '赜', '卦', '卣', '刂', '刈', '刎', '刭', '刳', '刿', '剀', '剌', '剞', '剡', '剜', '蒯', '剽', '劂', '劁', '劐', '劓', '冂', '罔', '亻', '仃', '仉', '仂', '仨', '仡', '仫', '仞', '伛', '仳', '伢', '佤', '仵', '伥', '伧', '伉', '伫', '佞', '佧', '攸', '佚', '佝']),
+    (0xD9, ['貮', '貯', '貰', '貱', '貲', '貳', '貴', '貵', '貶', '買', '貸', '貹', '貺', '費', '貼', '貽', '貾', '貿', '賀', '賁', '賂', '賃', '賄', '賅', '賆', '資', '賈', '賉', '賊', '賋', '賌', '賍', '賎', '賏', '賐', '賑', '賒', '賓', '賔', '賕', '賖', '賗', '賘', '賙', '賚', '賛', '賜', '賝', '賞', '賟', '賠', '賡', '賢', '賣', '賤', '賥', '賦', '賧', '賨', '賩', '質', '賫', '賬', '\u{FFFD}', '賭', '賮', '賯', '賰', '賱', '賲', '賳', '賴', '賵', '賶', '賷', '賸', '賹', '賺', '賻', '購', '賽', '賾', '賿', '贀', '贁', '贂', '贃', '贄', '贅', '贆', '贇', '贈', '贉', '贊', '贋', '贌', '贍', '佟', '佗', '伲', '伽', '佶', '佴', '侑', '侉', '侃', '侏', '佾', '佻', '侪', '佼', '侬', '侔', '俦', '俨', '俪', '俅', '俚', '俣', '俜', '俑', '俟', '俸', '倩', '偌', '俳', '倬', '倏', '倮', '倭', '俾', '倜', '倌', '倥', '倨', '偾', '偃', '偕', '偈', '偎', '偬', '偻', '傥', '傧', '傩', '傺', '僖', '儆', '僭', '僬', '僦', '僮', '儇', '儋', '仝', '氽', '佘', '佥', '俎', '龠', '汆', '籴', '兮', '巽', '黉', '馘', '冁', '夔', '勹', '匍', '訇', '匐', '凫', '夙', '兕', '亠', '兖', '亳', '衮', '袤', '亵', '脔', '裒', '禀', '嬴', '蠃', '羸', '冫', '冱', '冽', '冼']),
+    (0xDA, ['贎', '贏', '贐', '贑', '贒', '贓', '贔', '贕', '贖', '贗', '贘', '贙', '贚', '贛', '贜', '贠', '赑', '赒', '赗', '赟', '赥', '赨', '赩', '赪', '赬', '赮', '赯', '赱', '赲', '赸', '赹', '赺', '赻', '赼', '赽', '赾', '赿', '趀', '趂', '趃', '趆', '趇', '趈', '趉', '趌', '趍', '趎', '趏', '趐', '趒', '趓', '趕', '趖', '趗', '趘', '趙', '趚', '趛', '趜', '趝', '趞', '趠', '趡', '\u{FFFD}', '趢', '趤', '趥', '趦', '趧', '趨', '趩', '趪', '趫', '趬', '趭', '趮', '趯', '趰', '趲', '趶', '趷', '趹', '趻', '趽', '跀', '跁', '跂', '跅', '跇', '跈', '跉', '跊', '跍', '跐', '跒', '跓', '跔', '凇', '冖', '冢', '冥', '讠', '讦', '讧', '讪', '讴', '讵', '讷', '诂', '诃', '诋', '诏', '诎', '诒', '诓', '诔', '诖', '诘', '诙', '诜', '诟', '诠', '诤', '诨', '诩', '诮', '诰', '诳', '诶', '诹', '诼', '诿', '谀', '谂', '谄', '谇', '谌', '谏', '谑', '谒', '谔', '谕', '谖', '谙', '谛', '谘', '谝', '谟', '谠', '谡', '谥', '谧', '谪', '谫', '谮', '谯', '谲', '谳', '谵', '谶', '卩', '卺', '阝', '阢', '阡', '阱', '阪', '阽', '阼', '陂', '陉', '陔', '陟', '陧', '陬', '陲', '陴', '隈', '隍', '隗', '隰', '邗', '邛', '邝', '邙', '邬', '邡', '邴', '邳', '邶', '邺']),
+    (0xDB, ['跕', '跘', '跙', '跜', '跠', '跡', '跢', '跥', '跦', '跧', '跩', '跭', '跮', '跰', '跱', '跲', '跴', '跶', '跼', '跾', '跿', '踀', '踁', '踂', '踃', '踄', '踆', '踇', '踈', '踋', '踍', '踎', '踐', '踑', '踒', '踓', '踕', '踖', '踗', '踘', '踙', '踚', '踛', '踜', '踠', '踡', '踤', '踥', '踦', '踧', '踨', '踫', '踭', '踰', '踲', '踳', '踴', '踶', '踷', '踸', '踻', '踼', '踾', '\u{FFFD}', '踿', '蹃', '蹅', '蹆', '蹌', '蹍', '蹎', '蹏', '蹐', '蹓', '蹔', '蹕', '蹖', '蹗', '蹘', '蹚', '蹛', '蹜', '蹝', '蹞', '蹟', '蹠', '蹡', '蹢', '蹣', '蹤', '蹥', '蹧', '蹨', '蹪', '蹫', '蹮', '蹱', '邸', '邰', '郏', '郅', '邾', '郐', '郄', '郇', '郓', '郦', '郢', '郜', '郗', '郛', '郫', '郯', '郾', '鄄', '鄢', '鄞', '鄣', '鄱', '鄯', '鄹', '酃', '酆', '刍', '奂', '劢', '劬', '劭', '劾', '哿', '勐', '勖', '勰', '叟', '燮', '矍', '廴', '凵', '凼', '鬯', '厶', '弁', '畚', '巯', '坌', '垩', '垡', '塾', '墼', '壅', '壑', '圩', '圬', '圪', '圳', '圹', '圮', '圯', '坜', '圻', '坂', '坩', '垅', '坫', '垆', '坼', '坻', '坨', '坭', '坶', '坳', '垭', '垤', '垌', '垲', '埏', '垧', '垴', '垓', '垠', '埕', '埘', '埚', '埙', '埒', '垸', '埴', '埯', '埸', '埤', '埝']),
+    (0xDC, ['蹳', '蹵', '蹷', '蹸', '蹹', '蹺', '蹻', '蹽', '蹾', '躀', '躂', '躃', '躄', '躆', '躈', '躉', '躊', '躋', '躌', '躍', '躎', '躑', '躒', '躓', '躕', '躖', '躗', '躘', '躙', '躚', '躛', '躝', '躟', '躠', '躡', '躢', '躣', '躤', '躥', '躦', '躧', '躨', '躩', '躪', '躭', '躮', '躰', '躱', '躳', '躴', '躵', '躶', '躷', '躸', '躹', '躻', '躼', '躽', '躾', '躿', '軀', '軁', '軂', '\u{FFFD}', '軃', '軄', '軅', '軆', '軇', '軈', '軉', '車', '軋', '軌', '軍', '軏', '軐', '軑', '軒', '軓', '軔', '軕', '軖', '軗', '軘', '軙', '軚', '軛', '軜', '軝', '軞', '軟', '軠', '軡', '転', '軣', '軤', '堋', '堍', '埽', '埭', '堀', '堞', '堙', '塄', '堠', '塥', '塬', '墁', '墉', '墚', '墀', '馨', '鼙', '懿', '艹', '艽', '艿', '芏', '芊', '芨', '芄', '芎', '芑', '芗', '芙', '芫', '芸', '芾', '芰', '苈', '苊', '苣', '芘', '芷', '芮', '苋', '苌', '苁', '芩', '芴', '芡', '芪', '芟', '苄', '苎', '芤', '苡', '茉', '苷', '苤', '茏', '茇', '苜', '苴', '苒', '苘', '茌', '苻', '苓', '茑', '茚', '茆', '茔', '茕', '苠', '苕', '茜', '荑', '荛', '荜', '茈', '莒', '茼', '茴', '茱', '莛', '荞', '茯', '荏', '荇', '荃', '荟', '荀', '茗', '荠', '茭', '茺', '茳', '荦', '荥']),
+    (0xDD, ['軥', '軦', '軧', '軨', '軩', '軪', '軫', '軬', '軭', '軮', '軯', '軰', '軱', '軲', '軳', '軴', '軵', '軶', '軷', '軸', '軹', '軺', '軻', '軼', '軽', '軾', '軿', '輀', '輁', '輂', '較', '輄', '輅', '輆', '輇', '輈', '載', '輊', '輋', '輌', '輍', '輎', '輏', '輐', '輑', '輒', '輓', '輔', '輕', '輖', '輗', '輘', '輙', '輚', '輛', '輜', '輝', '輞', '輟', '輠', '輡', '輢', '輣', '\u{FFFD}', '輤', '輥', '輦', '輧', '輨', '輩', '輪', '輫', '輬', '輭', '輮', '輯', '輰', '輱', '輲', '輳', '輴', '輵', '輶', '輷', '輸', '輹', '輺', '輻', '輼', '輽', '輾', '輿', '轀', '轁', '轂', '轃', '轄', '荨', '茛', '荩', '荬', '荪', '荭', '荮', '莰', '荸', '莳', '莴', '莠', '莪', '莓', '莜', '莅', '荼', '莶', '莩', '荽', '莸', '荻', '莘', '莞', '莨', '莺', '莼', '菁', '萁', '菥', '菘', '堇', '萘', '萋', '菝', '菽', '菖', '萜', '萸', '萑', '萆', '菔', '菟', '萏', '萃', '菸', '菹', '菪', '菅', '菀', '萦', '菰', '菡', '葜', '葑', '葚', '葙', '葳', '蒇', '蒈', '葺', '蒉', '葸', '萼', '葆', '葩', '葶', '蒌', '蒎', '萱', '葭', '蓁', '蓍', '蓐', '蓦', '蒽', '蓓', '蓊', '蒿', '蒺', '蓠', '蒡', '蒹', '蒴', '蒗', '蓥', '蓣', '蔌', '甍', '蔸', '蓰', '蔹', '蔟', '蔺']),
+    (0xDE, ['轅', '轆', '轇', '轈', '轉', '轊', '轋', '轌', '轍', '轎', '轏', '轐', '轑', '轒', '轓', '轔', '轕', '轖', '轗', '轘', '轙', '轚', '轛', '轜', '轝', '轞', '轟', '轠', '轡', '轢', '轣', '轤', '轥', '轪', '辀', '辌', '辒', '辝', '辠', '辡', '辢', '辤', '辥', '辦', '辧', '辪', '辬', '辭', '辮', '辯', '農', '辳', '辴', '辵', '辷', '辸', '辺', '辻', '込', '辿', '迀', '迃', '迆', '\u{FFFD}', '迉', '迊', '迋', '迌', '迍', '迏', '迒', '迖', '迗', '迚', '迠', '迡', '迣', '迧', '迬', '迯', '迱', '迲', '迴', '迵', '迶', '迺', '迻', '迼', '迾', '迿', '逇', '逈', '逌', '逎', '逓', '逕', '逘', '蕖', '蔻', '蓿', '蓼', '蕙', '蕈', '蕨', '蕤', '蕞', '蕺', '瞢', '蕃', '蕲', '蕻', '薤', '薨', '薇', '薏', '蕹', '薮', '薜', '薅', '薹', '薷', '薰', '藓', '藁', '藜', '藿', '蘧', '蘅', '蘩', '蘖', '蘼', '廾', '弈', '夼', '奁', '耷', '奕', '奚', '奘', '匏', '尢', '尥', '尬', '尴', '扌', '扪', '抟', '抻', '拊', '拚', '拗', '拮', '挢', '拶', '挹', '捋', '捃', '掭', '揶', '捱', '捺', '掎', '掴', '捭', '掬', '掊', '捩', '掮', '掼', '揲', '揸', '揠', '揿', '揄', '揞', '揎', '摒', '揆', '掾', '摅', '摁', '搋', '搛', '搠', '搌', '搦', '搡', '摞', '撄', '摭', '撖']),
+    (0xDF, ['這', '逜', '連', '逤', '逥', '逧', '逨', '逩', '逪', '逫', '逬', '逰', '週', '進', '逳', '逴', '逷', '逹', '逺', '逽', '逿', '遀', '遃', '遅', '遆', '遈', '遉', '遊', '運', '遌', '過', '達', '違', '遖', '遙', '遚', '遜', '遝', '遞', '遟', '遠', '遡', '遤', '遦', '遧', '適', '遪', '遫', '遬', '遯', '遰', '遱', '遲', '遳', '遶', '遷', '選', '遹', '遺', '遻', '遼', '遾', '邁', '\u{FFFD}', '還', '邅', '邆', '邇', '邉', '邊', '邌', '邍', '邎', '邏', '邐', '邒', '邔', '邖', '邘', '邚', '邜', '邞', '邟', '邠', '邤', '邥', '邧', '邨', '邩', '邫', '邭', '邲', '邷', '邼', '邽', '邿', '郀', '摺', '撷', '撸', '撙', '撺', '擀', '擐', '擗', '擤', '擢', '攉', '攥', '攮', '弋', '忒', '甙', '弑', '卟', '叱', '叽', '叩', '叨', '叻', '吒', '吖', '吆', '呋', '呒', '呓', '呔', '呖', '呃', '吡', '呗', '呙', '吣', '吲', '咂', '咔', '呷', '呱', '呤', '咚', '咛', '咄', '呶', '呦', '咝', '哐', '咭', '哂', '咴', '哒', '咧', '咦', '哓', '哔', '呲', '咣', '哕', '咻', '咿', '哌', '哙', '哚', '哜', '咩', '咪', '咤', '哝', '哏', '哞', '唛', '哧', '唠', '哽', '唔', '哳', '唢', '唣', '唏', '唑', '唧', '唪', '啧', '喏', '喵', '啉', '啭', '啁', '啕', '唿', '啐', '唼']),
+    (0xE0, ['郂', '郃', '郆', '郈', '郉', '郋', '郌', '郍', '郒', '郔', '郕', '郖', '郘', '郙', '郚', '郞', '郟', '郠', '郣', '郤', '郥', '郩', '郪', '郬', '郮', '郰', '郱', '郲', '郳', '郵', '郶', '郷', '郹', '郺', '郻', '郼', '郿', '鄀', '鄁', '鄃', '鄅', '鄆', '鄇', '鄈', '鄉', '鄊', '鄋', '鄌', '鄍', '鄎', '鄏', '鄐', '鄑', '鄒', '鄓', '鄔', '鄕', '鄖', '鄗', '鄘', '鄚', '鄛', '鄜', '\u{FFFD}', '鄝', '鄟', '鄠', '鄡', '鄤', '鄥', '鄦', '鄧', '鄨', '鄩', '鄪', '鄫', '鄬', '鄭', '鄮', '鄰', '鄲', '鄳', '鄴', '鄵', '鄶', '鄷', '鄸', '鄺', '鄻', '鄼', '鄽', '鄾', '鄿', '酀', '酁', '酂', '酄', '唷', '啖', '啵', '啶', '啷', '唳', '唰', '啜', '喋', '嗒', '喃', '喱', '喹', '喈', '喁', '喟', '啾', '嗖', '喑', '啻', '嗟', '喽', '喾', '喔', '喙', '嗪', '嗷', '嗉', '嘟', '嗑', '嗫', '嗬', '嗔', '嗦', '嗝', '嗄', '嗯', '嗥', '嗲', '嗳', '嗌', '嗍', '嗨', '嗵', '嗤', '辔', '嘞', '嘈', '嘌', '嘁', '嘤', '嘣', '嗾', '嘀', '嘧', '嘭', '噘', '嘹', '噗', '嘬', '噍', '噢', '噙', '噜', '噌', '噔', '嚆', '噤', '噱', '噫', '噻', '噼', '嚅', '嚓', '嚯', '囔', '囗', '囝', '囡', '囵', '囫', '囹', '囿', '圄', '圊', '圉', '圜', '帏', '帙', '帔', '帑', '帱', '帻', '帼']),
+    (0xE1, ['酅', '酇', '酈', '酑', '酓', '酔', '酕', '酖', '酘', '酙', '酛', '酜', '酟', '酠', '酦', '酧', '酨', '酫', '酭', '酳', '酺', '酻', '酼', '醀', '醁', '醂', '醃', '醄', '醆', '醈', '醊', '醎', '醏', '醓', '醔', '醕', '醖', '醗', '醘', '醙', '醜', '醝', '醞', '醟', '醠', '醡', '醤', '醥', '醦', '醧', '醨', '醩', '醫', '醬', '醰', '醱', '醲', '醳', '醶', '醷', '醸', '醹', '醻', '\u{FFFD}', '醼', '醽', '醾', '醿', '釀', '釁', '釂', '釃', '釄', '釅', '釆', '釈', '釋', '釐', '釒', '釓', '釔', '釕', '釖', '釗', '釘', '釙', '釚', '釛', '針', '釞', '釟', '釠', '釡', '釢', '釣', '釤', '釥', '帷', '幄', '幔', '幛', '幞', '幡', '岌', '屺', '岍', '岐', '岖', '岈', '岘', '岙', '岑', '岚', '岜', '岵', '岢', '岽', '岬', '岫', '岱', '岣', '峁', '岷', '峄', '峒', '峤', '峋', '峥', '崂', '崃', '崧', '崦', '崮', '崤', '崞', '崆', '崛', '嵘', '崾', '崴', '崽', '嵬', '嵛', '嵯', '嵝', '嵫', '嵋', '嵊', '嵩', '嵴', '嶂', '嶙', '嶝', '豳', '嶷', '巅', '彳', '彷', '徂', '徇', '徉', '後', '徕', '徙', '徜', '徨', '徭', '徵', '徼', '衢', '彡', '犭', '犰', '犴', '犷', '犸', '狃', '狁', '狎', '狍', '狒', '狨', '狯', '狩', '狲', '狴', '狷', '猁', '狳', '猃', '狺']),
+    (0xE2, ['釦', '釧', '釨', '釩', '釪', '釫', '釬', '釭', '釮', '釯', '釰', '釱', '釲', '釳', '釴', '釵', '釶', '釷', '釸', '釹', '釺', '釻', '釼', '釽', '釾', '釿', '鈀', '鈁', '鈂', '鈃', '鈄', '鈅', '鈆', '鈇', '鈈', '鈉', '鈊', '鈋', '鈌', '鈍', '鈎', '鈏', '鈐', '鈑', '鈒', '鈓', '鈔', '鈕', '鈖', '鈗', '鈘', '鈙', '鈚', '鈛', '鈜', '鈝', '鈞', '鈟', '鈠', '鈡', '鈢', '鈣', '鈤', '\u{FFFD}', '鈥', '鈦', '鈧', '鈨', '鈩', '鈪', '鈫', '鈬', '鈭', '鈮', '鈯', '鈰', '鈱', '鈲', '鈳', '鈴', '鈵', '鈶', '鈷', '鈸', '鈹', '鈺', '鈻', '鈼', '鈽', '鈾', '鈿', '鉀', '鉁', '鉂', '鉃', '鉄', '鉅', '狻', '猗', '猓', '猡', '猊', '猞', '猝', '猕', '猢', '猹', '猥', '猬', '猸', '猱', '獐', '獍', '獗', '獠', '獬', '獯', '獾', '舛', '夥', '飧', '夤', '夂', '饣', '饧', '饨', '饩', '饪', '饫', '饬', '饴', '饷', '饽', '馀', '馄', '馇', '馊', '馍', '馐', '馑', '馓', '馔', '馕', '庀', '庑', '庋', '庖', '庥', '庠', '庹', '庵', '庾', '庳', '赓', '廒', '廑', '廛', '廨', '廪', '膺', '忄', '忉', '忖', '忏', '怃', '忮', '怄', '忡', '忤', '忾', '怅', '怆', '忪', '忭', '忸', '怙', '怵', '怦', '怛', '怏', '怍', '怩', '怫', '怊', '怿', '怡', '恸', '恹', '恻', '恺', '恂']),
+    (0xE3, ['鉆', '鉇', '鉈', '鉉', '鉊', '鉋', '鉌', '鉍', '鉎', '鉏', '鉐', '鉑', '鉒', '鉓', '鉔', '鉕', '鉖', '鉗', '鉘', '鉙', '鉚', '鉛', '鉜', '鉝', '鉞', '鉟', '鉠', '鉡', '鉢', '鉣', '鉤', '鉥', '鉦', '鉧', '鉨', '鉩', '鉪', '鉫', '鉬', '鉭', '鉮', '鉯', '鉰', '鉱', '鉲', '鉳', '鉵', '鉶', '鉷', '鉸', '鉹', '鉺', '鉻', '鉼', '鉽', '鉾', '鉿', '銀', '銁', '銂', '銃', '銄', '銅', '\u{FFFD}', '銆', '銇', '銈', '銉', '銊', '銋', '銌', '銍', '銏', '銐', '銑', '銒', '銓', '銔', '銕', '銖', '銗', '銘', '銙', '銚', '銛', '銜', '銝', '銞', '銟', '銠', '銡', '銢', '銣', '銤', '銥', '銦', '銧', '恪', '恽', '悖', '悚', '悭', '悝', '悃', '悒', '悌', '悛', '惬', '悻', '悱', '惝', '惘', '惆', '惚', '悴', '愠', '愦', '愕', '愣', '惴', '愀', '愎', '愫', '慊', '慵', '憬', '憔', '憧', '憷', '懔', '懵', '忝', '隳', '闩', '闫', '闱', '闳', '闵', '闶', '闼', '闾', '阃', '阄', '阆', '阈', '阊', '阋', '阌', '阍', '阏', '阒', '阕', '阖', '阗', '阙', '阚', '丬', '爿', '戕', '氵', '汔', '汜', '汊', '沣', '沅', '沐', '沔', '沌', '汨', '汩', '汴', '汶', '沆', '沩', '泐', '泔', '沭', '泷', '泸', '泱', '泗', '沲', '泠', '泖', '泺', '泫', '泮', '沱', '泓', '泯', '泾']),
+    (0xE4, ['銨', '銩', '銪', '銫', '銬', '銭', '銯', '銰', '銱', '銲', '銳', '銴', '銵', '銶', '銷', '銸', '銹', '銺', '銻', '銼', '銽', '銾', '銿', '鋀', '鋁', '鋂', '鋃', '鋄', '鋅', '鋆', '鋇', '鋉', '鋊', '鋋', '鋌', '鋍', '鋎', '鋏', '鋐', '鋑', '鋒', '鋓', '鋔', '鋕', '鋖', '鋗', '鋘', '鋙', '鋚', '鋛', '鋜', '鋝', '鋞', '鋟', '鋠', '鋡', '鋢', '鋣', '鋤', '鋥', '鋦', '鋧', '鋨', '\u{FFFD}', '鋩', '鋪', '鋫', '鋬', '鋭', '鋮', '鋯', '鋰', '鋱', '鋲', '鋳', '鋴', '鋵', '鋶', '鋷', '鋸', '鋹', '鋺', '鋻', '鋼', '鋽', '鋾', '鋿', '錀', '錁', '錂', '錃', '錄', '錅', '錆', '錇', '錈', '錉', '洹', '洧', '洌', '浃', '浈', '洇', '洄', '洙', '洎', '洫', '浍', '洮', '洵', '洚', '浏', '浒', '浔', '洳', '涑', '浯', '涞', '涠', '浞', '涓', '涔', '浜', '浠', '浼', '浣', '渚', '淇', '淅', '淞', '渎', '涿', '淠', '渑', '淦', '淝', '淙', '渖', '涫', '渌', '涮', '渫', '湮', '湎', '湫', '溲', '湟', '溆', '湓', '湔', '渲', '渥', '湄', '滟', '溱', '溘', '滠', '漭', '滢', '溥', '溧', '溽', '溻', '溷', '滗', '溴', '滏', '溏', '滂', '溟', '潢', '潆', '潇', '漤', '漕', '滹', '漯', '漶', '潋', '潴', '漪', '漉', '漩', '澉', '澍', '澌', '潸', '潲', '潼', '潺', '濑']),
+    (0xE5, ['錊', '錋', '錌', '錍', '錎', '錏', '錐', '錑', '錒', '錓', '錔', '錕', '錖', '錗', '錘', '錙', '錚', '錛', '錜', '錝', '錞', '錟', '錠', '錡', '錢', '錣', '錤', '錥', '錦', '錧', '錨', '錩', '錪', '錫', '錬', '錭', '錮', '錯', '錰', '錱', '録', '錳', '錴', '錵', '錶', '錷', '錸', '錹', '錺', '錻', '錼', '錽', '錿', '鍀', '鍁', '鍂', '鍃', '鍄', '鍅', '鍆', '鍇', '鍈', '鍉', '\u{FFFD}', '鍊', '鍋', '鍌', '鍍', '鍎', '鍏', '鍐', '鍑', '鍒', '鍓', '鍔', '鍕', '鍖', '鍗', '鍘', '鍙', '鍚', '鍛', '鍜', '鍝', '鍞', '鍟', '鍠', '鍡', '鍢', '鍣', '鍤', '鍥', '鍦', '鍧', '鍨', '鍩', '鍫', '濉', '澧', '澹', '澶', '濂', '濡', '濮', '濞', '濠', '濯', '瀚', '瀣', '瀛', '瀹', '瀵', '灏', '灞', '宀', '宄', '宕', '宓', '宥', '宸', '甯', '骞', '搴', '寤', '寮', '褰', '寰', '蹇', '謇', '辶', '迓', '迕', '迥', '迮', '迤', '迩', '迦', '迳', '迨', '逅', '逄', '逋', '逦', '逑', '逍', '逖', '逡', '逵', '逶', '逭', '逯', '遄', '遑', '遒', '遐', '遨', '遘', '遢', '遛', '暹', '遴', '遽', '邂', '邈', '邃', '邋', '彐', '彗', '彖', '彘', '尻', '咫', '屐', '屙', '孱', '屣', '屦', '羼', '弪', '弩', '弭', '艴', '弼', '鬻', '屮', '妁', '妃', '妍', '妩', '妪', '妣']),
+    (0xE6, ['鍬', '鍭', '鍮', '鍯', '鍰', '鍱', '鍲', '鍳', '鍴', '鍵', '鍶', '鍷', '鍸', '鍹', '鍺', '鍻', '鍼', '鍽', '鍾', '鍿', '鎀', '鎁', '鎂', '鎃', '鎄', '鎅', '鎆', '鎇', '鎈', '鎉', '鎊', '鎋', '鎌', '鎍', '鎎', '鎐', '鎑', '鎒', '鎓', '鎔', '鎕', '鎖', '鎗', '鎘', '鎙', '鎚', '鎛', '鎜', '鎝', '鎞', '鎟', '鎠', '鎡', '鎢', '鎣', '鎤', '鎥', '鎦', '鎧', '鎨', '鎩', '鎪', '鎫', '\u{FFFD}', '鎬', '鎭', '鎮', '鎯', '鎰', '鎱', '鎲', '鎳', '鎴', '鎵', '鎶', '鎷', '鎸', '鎹', '鎺', '鎻', '鎼', '鎽', '鎾', '鎿', '鏀', '鏁', '鏂', '鏃', '鏄', '鏅', '鏆', '鏇', '鏈', '鏉', '鏋', '鏌', '鏍', '妗', '姊', '妫', '妞', '妤', '姒', '妲', '妯', '姗', '妾', '娅', '娆', '姝', '娈', '姣', '姘', '姹', '娌', '娉', '娲', '娴', '娑', '娣', '娓', '婀', '婧', '婊', '婕', '娼', '婢', '婵', '胬', '媪', '媛', '婷', '婺', '媾', '嫫', '媲', '嫒', '嫔', '媸', '嫠', '嫣', '嫱', '嫖', '嫦', '嫘', '嫜', '嬉', '嬗', '嬖', '嬲', '嬷', '孀', '尕', '尜', '孚', '孥', '孳', '孑', '孓', '孢', '驵', '驷', '驸', '驺', '驿', '驽', '骀', '骁', '骅', '骈', '骊', '骐', '骒', '骓', '骖', '骘', '骛', '骜', '骝', '骟', '骠', '骢', '骣', '骥', '骧', '纟', '纡', '纣', '纥', '纨', '纩']),
+    (0xE7, ['鏎', '鏏', '鏐', '鏑', '鏒', '鏓', '鏔', '鏕', '鏗', '鏘', '鏙', '鏚', '鏛', '鏜', '鏝', '鏞', '鏟', '鏠', '鏡', '鏢', '鏣', '鏤', '鏥', '鏦', '鏧', '鏨', '鏩', '鏪', '鏫', '鏬', '鏭', '鏮', '鏯', '鏰', '鏱', '鏲', '鏳', '鏴', '鏵', '鏶', '鏷', '鏸', '鏹', '鏺', '鏻', '鏼', '鏽', '鏾', '鏿', '鐀', '鐁', '鐂', '鐃', '鐄', '鐅', '鐆', '鐇', '鐈', '鐉', '鐊', '鐋', '鐌', '鐍', '\u{FFFD}', '鐎', '鐏', '鐐', '鐑', '鐒', '鐓', '鐔', '鐕', '鐖', '鐗', '鐘', '鐙', '鐚', '鐛', '鐜', '鐝', '鐞', '鐟', '鐠', '鐡', '鐢', '鐣', '鐤', '鐥', '鐦', '鐧', '鐨', '鐩', '鐪', '鐫', '鐬', '鐭', '鐮', '纭', '纰', '纾', '绀', '绁', '绂', '绉', '绋', '绌', '绐', '绔', '绗', '绛', '绠', '绡', '绨', '绫', '绮', '绯', '绱', '绲', '缍', '绶', '绺', '绻', '绾', '缁', '缂', '缃', '缇', '缈', '缋', '缌', '缏', '缑', '缒', '缗', '缙', '缜', '缛', '缟', '缡', '缢', '缣', '缤', '缥', '缦', '缧', '缪', '缫', '缬', '缭', '缯', '缰', '缱', '缲', '缳', '缵', '幺', '畿', '巛', '甾', '邕', '玎', '玑', '玮', '玢', '玟', '珏', '珂', '珑', '玷', '玳', '珀', '珉', '珈', '珥', '珙', '顼', '琊', '珩', '珧', '珞', '玺', '珲', '琏', '琪', '瑛', '琦', '琥', '琨', '琰', '琮', '琬']),
+    (0xE8, ['鐯', '鐰', '鐱', '鐲', '鐳', '鐴', '鐵', '鐶', '鐷', '鐸', '鐹', '鐺', '鐻', '鐼', '鐽', '鐿', '鑀', '鑁', '鑂', '鑃', '鑄', '鑅', '鑆', '鑇', '鑈', '鑉', '鑊', '鑋', '鑌', '鑍', '鑎', '鑏', '鑐', '鑑', '鑒', '鑓', '鑔', '鑕', '鑖', '鑗', '鑘', '鑙', '鑚', '鑛', '鑜', '鑝', '鑞', '鑟', '鑠', '鑡', '鑢', '鑣', '鑤', '鑥', '鑦', '鑧', '鑨', '鑩', '鑪', '鑬', '鑭', '鑮', '鑯', '\u{FFFD}', '鑰', '鑱', '鑲', '鑳', '鑴', '鑵', '鑶', '鑷', '鑸', '鑹', '鑺', '鑻', '鑼', '鑽', '鑾', '鑿', '钀', '钁', '钂', '钃', '钄', '钑', '钖', '钘', '铇', '铏', '铓', '铔', '铚', '铦', '铻', '锜', '锠', '琛', '琚', '瑁', '瑜', '瑗', '瑕', '瑙', '瑷', '瑭', '瑾', '璜', '璎', '璀', '璁', '璇', '璋', '璞', '璨', '璩', '璐', '璧', '瓒', '璺', '韪', '韫', '韬', '杌', '杓', '杞', '杈', '杩', '枥', '枇', '杪', '杳', '枘', '枧', '杵', '枨', '枞', '枭', '枋', '杷', '杼', '柰', '栉', '柘', '栊', '柩', '枰', '栌', '柙', '枵', '柚', '枳', '柝', '栀', '柃', '枸', '柢', '栎', '柁', '柽', '栲', '栳', '桠', '桡', '桎', '桢', '桄', '桤', '梃', '栝', '桕', '桦', '桁', '桧', '桀', '栾', '桊', '桉', '栩', '梵', '梏', '桴', '桷', '梓', '桫', '棂', '楮', '棼', '椟', '椠', '棹']),
+    (0xE9, ['锧', '锳', '锽', '镃', '镈', '镋', '镕', '镚', '镠', '镮', '镴', '镵', '長', '镸', '镹', '镺', '镻', '镼', '镽', '镾', '門', '閁', '閂', '閃', '閄', '閅', '閆', '閇', '閈', '閉', '閊', '開', '閌', '閍', '閎', '閏', '閐', '閑', '閒', '間', '閔', '閕', '閖', '閗', '閘', '閙', '閚', '閛', '閜', '閝', '閞', '閟', '閠', '閡', '関', '閣', '閤', '閥', '閦', '閧', '閨', '閩', '閪', '\u{FFFD}', '閫', '閬', '閭', '閮', '閯', '閰', '閱', '閲', '閳', '閴', '閵', '閶', '閷', '閸', '閹', '閺', '閻', '閼', '閽', '閾', '閿', '闀', '闁', '闂', '闃', '闄', '闅', '闆', '闇', '闈', '闉', '闊', '闋', '椤', '棰', '椋', '椁', '楗', '棣', '椐', '楱', '椹', '楠', '楂', '楝', '榄', '楫', '榀', '榘', '楸', '椴', '槌', '榇', '榈', '槎', '榉', '楦', '楣', '楹', '榛', '榧', '榻', '榫', '榭', '槔', '榱', '槁', '槊', '槟', '榕', '槠', '榍', '槿', '樯', '槭', '樗', '樘', '橥', '槲', '橄', '樾', '檠', '橐', '橛', '樵', '檎', '橹', '樽', '樨', '橘', '橼', '檑', '檐', '檩', '檗', '檫', '猷', '獒', '殁', '殂', '殇', '殄', '殒', '殓', '殍', '殚', '殛', '殡', '殪', '轫', '轭', '轱', '轲', '轳', '轵', '轶', '轸', '轷', '轹', '轺', '轼', '轾', '辁', '辂', '辄', '辇', '辋']),
+    (0xEA, ['闌', '闍', '闎', '闏', '闐', '闑', '闒', '闓', '闔', '闕', '闖', '闗', '闘', '闙', '闚', '闛', '關', '闝', '闞', '闟', '闠', '闡', '闢', '闣', '闤', '闥', '闦', '闧', '闬', '闿', '阇', '阓', '阘', '阛', '阞', '阠', '阣', '阤', '阥', '阦', '阧', '阨', '阩', '阫', '阬', '阭', '阯', '阰', '阷', '阸', '阹', '阺', '阾', '陁', '陃', '陊', '陎', '陏', '陑', '陒', '陓', '陖', '陗', '\u{FFFD}', '陘', '陙', '陚', '陜', '陝', '陞', '陠', '陣', '陥', '陦', '陫', '陭', '陮', '陯', '陰', '陱', '陳', '陸', '陹', '険', '陻', '陼', '陽', '陾', '陿', '隀', '隁', '隂', '隃', '隄', '隇', '隉', '隊', '辍', '辎', '辏', '辘', '辚', '軎', '戋', '戗', '戛', '戟', '戢', '戡', '戥', '戤', '戬', '臧', '瓯', '瓴', '瓿', '甏', '甑', '甓', '攴', '旮', '旯', '旰', '昊', '昙', '杲', '昃', '昕', '昀', '炅', '曷', '昝', '昴', '昱', '昶', '昵', '耆', '晟', '晔', '晁', '晏', '晖', '晡', '晗', '晷', '暄', '暌', '暧', '暝', '暾', '曛', '曜', '曦', '曩', '贲', '贳', '贶', '贻', '贽', '赀', '赅', '赆', '赈', '赉', '赇', '赍', '赕', '赙', '觇', '觊', '觋', '觌', '觎', '觏', '觐', '觑', '牮', '犟', '牝', '牦', '牯', '牾', '牿', '犄', '犋', '犍', '犏', '犒', '挈', '挲', '掰']),
+    (0xEB, ['隌', '階', '隑', '隒', '隓', '隕', '隖', '隚', '際', '隝', '隞', '隟', '隠', '隡', '隢', '隣', '隤', '隥', '隦', '隨', '隩', '險', '隫', '隬', '隭', '隮', '隯', '隱', '隲', '隴', '隵', '隷', '隸', '隺', '隻', '隿', '雂', '雃', '雈', '雊', '雋', '雐', '雑', '雓', '雔', '雖', '雗', '雘', '雙', '雚', '雛', '雜', '雝', '雞', '雟', '雡', '離', '難', '雤', '雥', '雦', '雧', '雫', '\u{FFFD}', '雬', '雭', '雮', '雰', '雱', '雲', '雴', '雵', '雸', '雺', '電', '雼', '雽', '雿', '霂', '霃', '霅', '霊', '霋', '霌', '霐', '霑', '霒', '霔', '霕', '霗', '霘', '霙', '霚', '霛', '霝', '霟', '霠', '搿', '擘', '耄', '毪', '毳', '毽', '毵', '毹', '氅', '氇', '氆', '氍', '氕', '氘', '氙', '氚', '氡', '氩', '氤', '氪', '氲', '攵', '敕', '敫', '牍', '牒', '牖', '爰', '虢', '刖', '肟', '肜', '肓', '肼', '朊', '肽', '肱', '肫', '肭', '肴', '肷', '胧', '胨', '胩', '胪', '胛', '胂', '胄', '胙', '胍', '胗', '朐', '胝', '胫', '胱', '胴', '胭', '脍', '脎', '胲', '胼', '朕', '脒', '豚', '脶', '脞', '脬', '脘', '脲', '腈', '腌', '腓', '腴', '腙', '腚', '腱', '腠', '腩', '腼', '腽', '腭', '腧', '塍', '媵', '膈', '膂', '膑', '滕', '膣', '膪', '臌', '朦', '臊', '膻']),
+    (0xEC, ['霡', '霢', '霣', '霤', '霥', '霦', '霧', '霨', '霩', '霫', '霬', '霮', '霯', '霱', '霳', '霴', '霵', '霶', '霷', '霺', '霻', '霼', '霽', '霿', '靀', '靁', '靂', '靃', '靄', '靅', '靆', '靇', '靈', '靉', '靊', '靋', '靌', '靍', '靎', '靏', '靐', '靑', '靔', '靕', '靗', '靘', '靚', '靜', '靝', '靟', '靣', '靤', '靦', '靧', '靨', '靪', '靫', '靬', '靭', '靮', '靯', '靰', '靱', '\u{FFFD}', '靲', '靵', '靷', '靸', '靹', '靺', '靻', '靽', '靾', '靿', '鞀', '鞁', '鞂', '鞃', '鞄', '鞆', '鞇', '鞈', '鞉', '鞊', '鞌', '鞎', '鞏', '鞐', '鞓', '鞕', '鞖', '鞗', '鞙', '鞚', '鞛', '鞜', '鞝', '臁', '膦', '欤', '欷', '欹', '歃', '歆', '歙', '飑', '飒', '飓', '飕', '飙', '飚', '殳', '彀', '毂', '觳', '斐', '齑', '斓', '於', '旆', '旄', '旃', '旌', '旎', '旒', '旖', '炀', '炜', '炖', '炝', '炻', '烀', '炷', '炫', '炱', '烨', '烊', '焐', '焓', '焖', '焯', '焱', '煳', '煜', '煨', '煅', '煲', '煊', '煸', '煺', '熘', '熳', '熵', '熨', '熠', '燠', '燔', '燧', '燹', '爝', '爨', '灬', '焘', '煦', '熹', '戾', '戽', '扃', '扈', '扉', '礻', '祀', '祆', '祉', '祛', '祜', '祓', '祚', '祢', '祗', '祠', '祯', '祧', '祺', '禅', '禊', '禚', '禧', '禳', '忑', '忐']),
+    (0xED, ['鞞', '鞟', '鞡', '鞢', '鞤', '鞥', '鞦', '鞧', '鞨', '鞩', '鞪', '鞬', '鞮', '鞰', '鞱', '鞳', '鞵', '鞶', '鞷', '鞸', '鞹', '鞺', '鞻', '鞼', '鞽', '鞾', '鞿', '韀', '韁', '韂', '韃', '韄', '韅', '韆', '韇', '韈', '韉', '韊', '韋', '韌', '韍', '韎', '韏', '韐', '韑', '韒', '韓', '韔', '韕', '韖', '韗', '韘', '韙', '韚', '韛', '韜', '韝', '韞', '韟', '韠', '韡', '韢', '韣', '\u{FFFD}', '韤', '韥', '韨', '韮', '韯', '韰', '韱', '韲', '韴', '韷', '韸', '韹', '韺', '韻', '韼', '韽', '韾', '響', '頀', '頁', '頂', '頃', '頄', '項', '順', '頇', '須', '頉', '頊', '頋', '頌', '頍', '頎', '怼', '恝', '恚', '恧', '恁', '恙', '恣', '悫', '愆', '愍', '慝', '憩', '憝', '懋', '懑', '戆', '肀', '聿', '沓', '泶', '淼', '矶', '矸', '砀', '砉', '砗', '砘', '砑', '斫', '砭', '砜', '砝', '砹', '砺', '砻', '砟', '砼', '砥', '砬', '砣', '砩', '硎', '硭', '硖', '硗', '砦', '硐', '硇', '硌', '硪', '碛', '碓', '碚', '碇', '碜', '碡', '碣', '碲', '碹', '碥', '磔', '磙', '磉', '磬', '磲', '礅', '磴', '礓', '礤', '礞', '礴', '龛', '黹', '黻', '黼', '盱', '眄', '眍', '盹', '眇', '眈', '眚', '眢', '眙', '眭', '眦', '眵', '眸', '睐', '睑', '睇', '睃', '睚', '睨']),
+    (0xEE, ['頏', '預', '頑', '頒', '頓', '頔', '頕', '頖', '頗', '領', '頙', '頚', '頛', '頜', '頝', '頞', '頟', '頠', '頡', '頢', '頣', '頤', '頥', '頦', '頧', '頨', '頩', '頪', '頫', '頬', '頭', '頮', '頯', '頰', '頱', '頲', '頳', '頴', '頵', '頶', '頷', '頸', '頹', '頺', '頻', '頼', '頽', '頾', '頿', '顀', '顁', '顂', '顃', '顄', '顅', '顆', '顇', '顈', '顉', '顊', '顋', '題', '額', '\u{FFFD}', '顎', '顏', '顐', '顑', '顒', '顓', '顔', '顕', '顖', '顗', '願', '顙', '顚', '顛', '顜', '顝', '類', '顟', '顠', '顡', '顢', '顣', '顤', '顥', '顦', '顧', '顨', '顩', '顪', '顫', '顬', '顭', '顮', '睢', '睥', '睿', '瞍', '睽', '瞀', '瞌', '瞑', '瞟', '瞠', '瞰', '瞵', '瞽', '町', '畀', '畎', '畋', '畈', '畛', '畲', '畹', '疃', '罘', '罡', '罟', '詈', '罨', '罴', '罱', '罹', '羁', '罾', '盍', '盥', '蠲', '钅', '钆', '钇', '钋', '钊', '钌', '钍', '钏', '钐', '钔', '钗', '钕', '钚', '钛', '钜', '钣', '钤', '钫', '钪', '钭', '钬', '钯', '钰', '钲', '钴', '钶', '钷', '钸', '钹', '钺', '钼', '钽', '钿', '铄', '铈', '铉', '铊', '铋', '铌', '铍', '铎', '铐', '铑', '铒', '铕', '铖', '铗', '铙', '铘', '铛', '铞', '铟', '铠', '铢', '铤', '铥', '铧', '铨', '铪']),
+    (0xEF, ['顯', '顰', '顱', '顲', '顳', '顴', '颋', '颎', '颒', '颕', '颙', '颣', '風', '颩', '颪', '颫', '颬', '颭', '颮', '颯', '颰', '颱', '颲', '颳', '颴', '颵', '颶', '颷', '颸', '颹', '颺', '颻', '颼', '颽', '颾', '颿', '飀', '飁', '飂', '飃', '飄', '飅', '飆', '飇', '飈', '飉', '飊', '飋', '飌', '飍', '飏', '飐', '飔', '飖', '飗', '飛', '飜', '飝', '飠', '飡', '飢', '飣', '飤', '\u{FFFD}', '飥', '飦', '飩', '飪', '飫', '飬', '飭', '飮', '飯', '飰', '飱', '飲', '飳', '飴', '飵', '飶', '飷', '飸', '飹', '飺', '飻', '飼', '飽', '飾', '飿', '餀', '餁', '餂', '餃', '餄', '餅', '餆', '餇', '铩', '铫', '铮', '铯', '铳', '铴', '铵', '铷', '铹', '铼', '铽', '铿', '锃', '锂', '锆', '锇', '锉', '锊', '锍', '锎', '锏', '锒', '锓', '锔', '锕', '锖', '锘', '锛', '锝', '锞', '锟', '锢', '锪', '锫', '锩', '锬', '锱', '锲', '锴', '锶', '锷', '锸', '锼', '锾', '锿', '镂', '锵', '镄', '镅', '镆', '镉', '镌', '镎', '镏', '镒', '镓', '镔', '镖', '镗', '镘', '镙', '镛', '镞', '镟', '镝', '镡', '镢', '镤', '镥', '镦', '镧', '镨', '镩', '镪', '镫', '镬', '镯', '镱', '镲', '镳', '锺', '矧', '矬', '雉', '秕', '秭', '秣', '秫', '稆', '嵇', '稃', '稂', '稞', '稔']),
+    (0xF0, ['餈', '餉', '養', '餋', '餌', '餎', '餏', '餑', '餒', '餓', '餔', '餕', '餖', '餗', '餘', '餙', '餚', '餛', '餜', '餝', '餞', '餟', '餠', '餡', '餢', '餣', '餤', '餥', '餦', '餧', '館', '餩', '餪', '餫', '餬', '餭', '餯', '餰', '餱', '餲', '餳', '餴', '餵', '餶', '餷', '餸', '餹', '餺', '餻', '餼', '餽', '餾', '餿', '饀', '饁', '饂', '饃', '饄', '饅', '饆', '饇', '饈', '饉', '\u{FFFD}', '饊', '饋', '饌', '饍', '饎', '饏', '饐', '饑', '饒', '饓', '饖', '饗', '饘', '饙', '饚', '饛', '饜', '饝', '饞', '饟', '饠', '饡', '饢', '饤', '饦', '饳', '饸', '饹', '饻', '饾', '馂', '馃', '馉', '稹', '稷', '穑', '黏', '馥', '穰', '皈', '皎', '皓', '皙', '皤', '瓞', '瓠', '甬', '鸠', '鸢', '鸨', '鸩', '鸪', '鸫', '鸬', '鸲', '鸱', '鸶', '鸸', '鸷', '鸹', '鸺', '鸾', '鹁', '鹂', '鹄', '鹆', '鹇', '鹈', '鹉', '鹋', '鹌', '鹎', '鹑', '鹕', '鹗', '鹚', '鹛', '鹜', '鹞', '鹣', '鹦', '鹧', '鹨', '鹩', '鹪', '鹫', '鹬', '鹱', '鹭', '鹳', '疒', '疔', '疖', '疠', '疝', '疬', '疣', '疳', '疴', '疸', '痄', '疱', '疰', '痃', '痂', '痖', '痍', '痣', '痨', '痦', '痤', '痫', '痧', '瘃', '痱', '痼', '痿', '瘐', '瘀', '瘅', '瘌', '瘗', '瘊', '瘥', '瘘', '瘕', '瘙']),
+    (0xF1, ['馌', '馎', '馚', '馛', '馜', '馝', '馞', '馟', '馠', '馡', '馢', '馣', '馤', '馦', '馧', '馩', '馪', '馫', '馬', '馭', '馮', '馯', '馰', '馱', '馲', '馳', '馴', '馵', '馶', '馷', '馸', '馹', '馺', '馻', '馼', '馽', '馾', '馿', '駀', '駁', '駂', '駃', '駄', '駅', '駆', '駇', '駈', '駉', '駊', '駋', '駌', '駍', '駎', '駏', '駐', '駑', '駒', '駓', '駔', '駕', '駖', '駗', '駘', '\u{FFFD}', '駙', '駚', '駛', '駜', '駝', '駞', '駟', '駠', '駡', '駢', '駣', '駤', '駥', '駦', '駧', '駨', '駩', '駪', '駫', '駬', '駭', '駮', '駯', '駰', '駱', '駲', '駳', '駴', '駵', '駶', '駷', '駸', '駹', '瘛', '瘼', '瘢', '瘠', '癀', '瘭', '瘰', '瘿', '瘵', '癃', '瘾', '瘳', '癍', '癞', '癔', '癜', '癖', '癫', '癯', '翊', '竦', '穸', '穹', '窀', '窆', '窈', '窕', '窦', '窠', '窬', '窨', '窭', '窳', '衤', '衩', '衲', '衽', '衿', '袂', '袢', '裆', '袷', '袼', '裉', '裢', '裎', '裣', '裥', '裱', '褚', '裼', '裨', '裾', '裰', '褡', '褙', '褓', '褛', '褊', '褴', '褫', '褶', '襁', '襦', '襻', '疋', '胥', '皲', '皴', '矜', '耒', '耔', '耖', '耜', '耠', '耢', '耥', '耦', '耧', '耩', '耨', '耱', '耋', '耵', '聃', '聆', '聍', '聒', '聩', '聱', '覃', '顸', '颀', '颃']),
+    (0xF2, ['駺', '駻', '駼', '駽', '駾', '駿', '騀', '騁', '騂', '騃', '騄', '騅', '騆', '騇', '騈', '騉', '騊', '騋', '騌', '騍', '騎', '騏', '騐', '騑', '騒', '験', '騔', '騕', '騖', '騗', '騘', '騙', '騚', '騛', '騜', '騝', '騞', '騟', '騠', '騡', '騢', '騣', '騤', '騥', '騦', '騧', '騨', '騩', '騪', '騫', '騬', '騭', '騮', '騯', '騰', '騱', '騲', '騳', '騴', '騵', '騶', '騷', '騸', '\u{FFFD}', '騹', '騺', '騻', '騼', '騽', '騾', '騿', '驀', '驁', '驂', '驃', '驄', '驅', '驆', '驇', '驈', '驉', '驊', '驋', '驌', '驍', '驎', '驏', '驐', '驑', '驒', '驓', '驔', '驕', '驖', '驗', '驘', '驙', '颉', '颌', '颍', '颏', '颔', '颚', '颛', '颞', '颟', '颡', '颢', '颥', '颦', '虍', '虔', '虬', '虮', '虿', '虺', '虼', '虻', '蚨', '蚍', '蚋', '蚬', '蚝', '蚧', '蚣', '蚪', '蚓', '蚩', '蚶', '蛄', '蚵', '蛎', '蚰', '蚺', '蚱', '蚯', '蛉', '蛏', '蚴', '蛩', '蛱', '蛲', '蛭', '蛳', '蛐', '蜓', '蛞', '蛴', '蛟', '蛘', '蛑', '蜃', '蜇', '蛸', '蜈', '蜊', '蜍', '蜉', '蜣', '蜻', '蜞', '蜥', '蜮', '蜚', '蜾', '蝈', '蜴', '蜱', '蜩', '蜷', '蜿', '螂', '蜢', '蝽', '蝾', '蝻', '蝠', '蝰', '蝌', '蝮', '螋', '蝓', '蝣', '蝼', '蝤', '蝙', '蝥', '螓', '螯', '螨', '蟒']),
+    (0xF3, ['驚', '驛', '驜', '驝', '驞', '驟', '驠', '驡', '驢', '驣', '驤', '驥', '驦', '驧', '驨', '驩', '驪', '驫', '驲', '骃', '骉', '骍', '骎', '骔', '骕', '骙', '骦', '骩', '骪', '骫', '骬', '骭', '骮', '骯', '骲', '骳', '骴', '骵', '骹', '骻', '骽', '骾', '骿', '髃', '髄', '髆', '髇', '髈', '髉', '髊', '髍', '髎', '髏', '髐', '髒', '體', '髕', '髖', '髗', '髙', '髚', '髛', '髜', '\u{FFFD}', '髝', '髞', '髠', '髢', '髣', '髤', '髥', '髧', '髨', '髩', '髪', '髬', '髮', '髰', '髱', '髲', '髳', '髴', '髵', '髶', '髷', '髸', '髺', '髼', '髽', '髾', '髿', '鬀', '鬁', '鬂', '鬄', '鬅', '鬆', '蟆', '螈', '螅', '螭', '螗', '螃', '螫', '蟥', '螬', '螵', '螳', '蟋', '蟓', '螽', '蟑', '蟀', '蟊', '蟛', '蟪', '蟠', '蟮', '蠖', '蠓', '蟾', '蠊', '蠛', '蠡', '蠹', '蠼', '缶', '罂', '罄', '罅', '舐', '竺', '竽', '笈', '笃', '笄', '笕', '笊', '笫', '笏', '筇', '笸', '笪', '笙', '笮', '笱', '笠', '笥', '笤', '笳', '笾', '笞', '筘', '筚', '筅', '筵', '筌', '筝', '筠', '筮', '筻', '筢', '筲', '筱', '箐', '箦', '箧', '箸', '箬', '箝', '箨', '箅', '箪', '箜', '箢', '箫', '箴', '篑', '篁', '篌', '篝', '篚', '篥', '篦', '篪', '簌', '篾', '篼', '簏', '簖', '簋']),
+    (0xF4, ['鬇', '鬉', '鬊', '鬋', '鬌', '鬍', '鬎', '鬐', '鬑', '鬒', '鬔', '鬕', '鬖', '鬗', '鬘', '鬙', '鬚', '鬛', '鬜', '鬝', '鬞', '鬠', '鬡', '鬢', '鬤', '鬥', '鬦', '鬧', '鬨', '鬩', '鬪', '鬫', '鬬', '鬭', '鬮', '鬰', '鬱', '鬳', '鬴', '鬵', '鬶', '鬷', '鬸', '鬹', '鬺', '鬽', '鬾', '鬿', '魀', '魆', '魊', '魋', '魌', '魎', '魐', '魒', '魓', '魕', '魖', '魗', '魘', '魙', '魚', '\u{FFFD}', '魛', '魜', '魝', '魞', '魟', '魠', '魡', '魢', '魣', '魤', '魥', '魦', '魧', '魨', '魩', '魪', '魫', '魬', '魭', '魮', '魯', '魰', '魱', '魲', '魳', '魴', '魵', '魶', '魷', '魸', '魹', '魺', '魻', '簟', '簪', '簦', '簸', '籁', '籀', '臾', '舁', '舂', '舄', '臬', '衄', '舡', '舢', '舣', '舭', '舯', '舨', '舫', '舸', '舻', '舳', '舴', '舾', '艄', '艉', '艋', '艏', '艚', '艟', '艨', '衾', '袅', '袈', '裘', '裟', '襞', '羝', '羟', '羧', '羯', '羰', '羲', '籼', '敉', '粑', '粝', '粜', '粞', '粢', '粲', '粼', '粽', '糁', '糇', '糌', '糍', '糈', '糅', '糗', '糨', '艮', '暨', '羿', '翎', '翕', '翥', '翡', '翦', '翩', '翮', '翳', '糸', '絷', '綦', '綮', '繇', '纛', '麸', '麴', '赳', '趄', '趔', '趑', '趱', '赧', '赭', '豇', '豉', '酊', '酐', '酎', '酏', '酤']),
+    (0xF5, ['魼', '魽', '魾', '魿', '鮀', '鮁', '鮂', '鮃', '鮄', '鮅', '鮆', '鮇', '鮈', '鮉', '鮊', '鮋', '鮌', '鮍', '鮎', '鮏', '鮐', '鮑', '鮒', '鮓', '鮔', '鮕', '鮖', '鮗', '鮘', '鮙', '鮚', '鮛', '鮜', '鮝', '鮞', '鮟', '鮠', '鮡', '鮢', '鮣', '鮤', '鮥', '鮦', '鮧', '鮨', '鮩', '鮪', '鮫', '鮬', '鮭', '鮮', '鮯', '鮰', '鮱', '鮲', '鮳', '鮴', '鮵', '鮶', '鮷', '鮸', '鮹', '鮺', '\u{FFFD}', '鮻', '鮼', '鮽', '鮾', '鮿', '鯀', '鯁', '鯂', '鯃', '鯄', '鯅', '鯆', '鯇', '鯈', '鯉', '鯊', '鯋', '鯌', '鯍', '鯎', '鯏', '鯐', '鯑', '鯒', '鯓', '鯔', '鯕', '鯖', '鯗', '鯘', '鯙', '鯚', '鯛', '酢', '酡', '酰', '酩', '酯', '酽', '酾', '酲', '酴', '酹', '醌', '醅', '醐', '醍', '醑', '醢', '醣', '醪', '醭', '醮', '醯', '醵', '醴', '醺', '豕', '鹾', '趸', '跫', '踅', '蹙', '蹩', '趵', '趿', '趼', '趺', '跄', '跖', '跗', '跚', '跞', '跎', '跏', '跛', '跆', '跬', '跷', '跸', '跣', '跹', '跻', '跤', '踉', '跽', '踔', '踝', '踟', '踬', '踮', '踣', '踯', '踺', '蹀', '踹', '踵', '踽', '踱', '蹉', '蹁', '蹂', '蹑', '蹒', '蹊', '蹰', '蹶', '蹼', '蹯', '蹴', '躅', '躏', '躔', '躐', '躜', '躞', '豸', '貂', '貊', '貅', '貘', '貔', '斛', '觖', '觞', '觚', '觜']),
+    (0xF6, ['鯜', '鯝', '鯞', '鯟', '鯠', '鯡', '鯢', '鯣', '鯤', '鯥', '鯦', '鯧', '鯨', '鯩', '鯪', '鯫', '鯬', '鯭', '鯮', '鯯', '鯰', '鯱', '鯲', '鯳', '鯴', '鯵', '鯶', '鯷', '鯸', '鯹', '鯺', '鯻', '鯼', '鯽', '鯾', '鯿', '鰀', '鰁', '鰂', '鰃', '鰄', '鰅', '鰆', '鰇', '鰈', '鰉', '鰊', '鰋', '鰌', '鰍', '鰎', '鰏', '鰐', '鰑', '鰒', '鰓', '鰔', '鰕', '鰖', '鰗', '鰘', '鰙', '鰚', '\u{FFFD}', '鰛', '鰜', '鰝', '鰞', '鰟', '鰠', '鰡', '鰢', '鰣', '鰤', '鰥', '鰦', '鰧', '鰨', '鰩', '鰪', '鰫', '鰬', '鰭', '鰮', '鰯', '鰰', '鰱', '鰲', '鰳', '鰴', '鰵', '鰶', '鰷', '鰸', '鰹', '鰺', '鰻', '觥', '觫', '觯', '訾', '謦', '靓', '雩', '雳', '雯', '霆', '霁', '霈', '霏', '霎', '霪', '霭', '霰', '霾', '龀', '龃', '龅', '龆', '龇', '龈', '龉', '龊', '龌', '黾', '鼋', '鼍', '隹', '隼', '隽', '雎', '雒', '瞿', '雠', '銎', '銮', '鋈', '錾', '鍪', '鏊', '鎏', '鐾', '鑫', '鱿', '鲂', '鲅', '鲆', '鲇', '鲈', '稣', '鲋', '鲎', '鲐', '鲑', '鲒', '鲔', '鲕', '鲚', '鲛', '鲞', '鲟', '鲠', '鲡', '鲢', '鲣', '鲥', '鲦', '鲧', '鲨', '鲩', '鲫', '鲭', '鲮', '鲰', '鲱', '鲲', '鲳', '鲴', '鲵', '鲶', '鲷', '鲺', '鲻', '鲼', '鲽', '鳄', '鳅', '鳆', '鳇', '鳊', '鳋']),
+    (0xF7, ['鰼', '鰽', '鰾', '鰿', '鱀', '鱁', '鱂', '鱃', '鱄', '鱅', '鱆', '鱇', '鱈', '鱉', '鱊', '鱋', '鱌', '鱍', '鱎', '鱏', '鱐', '鱑', '鱒', '鱓', '鱔', '鱕', '鱖', '鱗', '鱘', '鱙', '鱚', '鱛', '鱜', '鱝', '鱞', '鱟', '鱠', '鱡', '鱢', '鱣', '鱤', '鱥', '鱦', '鱧', '鱨', '鱩', '鱪', '鱫', '鱬', '鱭', '鱮', '鱯', '鱰', '鱱', '鱲', '鱳', '鱴', '鱵', '鱶', '鱷', '鱸', '鱹', '鱺', '\u{FFFD}', '鱻', '鱽', '鱾', '鲀', '鲃', '鲄', '鲉', '鲊', '鲌', '鲏', '鲓', '鲖', '鲗', '鲘', '鲙', '鲝', '鲪', '鲬', '鲯', '鲹', '鲾', '鲿', '鳀', '鳁', '鳂', '鳈', '鳉', '鳑', '鳒', '鳚', '鳛', '鳠', '鳡', '鳌', '鳍', '鳎', '鳏', '鳐', '鳓', '鳔', '鳕', '鳗', '鳘', '鳙', '鳜', '鳝', '鳟', '鳢', '靼', '鞅', '鞑', '鞒', '鞔', '鞯', '鞫', '鞣', '鞲', '鞴', '骱', '骰', '骷', '鹘', '骶', '骺', '骼', '髁', '髀', '髅', '髂', '髋', '髌', '髑', '魅', '魃', '魇', '魉', '魈', '魍', '魑', '飨', '餍', '餮', '饕', '饔', '髟', '髡', '髦', '髯', '髫', '髻', '髭', '髹', '鬈', '鬏', '鬓', '鬟', '鬣', '麽', '麾', '縻', '麂', '麇', '麈', '麋', '麒', '鏖', '麝', '麟', '黛', '黜', '黝', '黠', '黟', '黢', '黩', '黧', '黥', '黪', '黯', '鼢', '鼬', '鼯', '鼹', '鼷', '鼽', '鼾', '齄']),
+    (0xF8, ['鳣', '鳤', '鳥', '鳦', '鳧', '鳨', '鳩', '鳪', '鳫', '鳬', '鳭', '鳮', '鳯', '鳰', '鳱', '鳲', '鳳', '鳴', '鳵', '鳶', '鳷', '鳸', '鳹', '鳺', '鳻', '鳼', '鳽', '鳾', '鳿', '鴀', '鴁', '鴂', '鴃', '鴄', '鴅', '鴆', '鴇', '鴈', '鴉', '鴊', '鴋', '鴌', '鴍', '鴎', '鴏', '鴐', '鴑', '鴒', '鴓', '鴔', '鴕', '鴖', '鴗', '鴘', '鴙', '鴚', '鴛', '鴜', '鴝', '鴞', '鴟', '鴠', '鴡', '\u{FFFD}', '鴢', '鴣', '鴤', '鴥', '鴦', '鴧', '鴨', '鴩', '鴪', '鴫', '鴬', '鴭', '鴮', '鴯', '鴰', '鴱', '鴲', '鴳', '鴴', '鴵', '鴶', '鴷', '鴸', '鴹', '鴺', '鴻', '鴼', '鴽', '鴾', '鴿', '鵀', '鵁', '鵂', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xF9, ['鵃', '鵄', '鵅', '鵆', '鵇', '鵈', '鵉', '鵊', '鵋', '鵌', '鵍', '鵎', '鵏', '鵐', '鵑', '鵒', '鵓', '鵔', '鵕', '鵖', '鵗', '鵘', '鵙', '鵚', '鵛', '鵜', '鵝', '鵞', '鵟', '鵠', '鵡', '鵢', '鵣', '鵤', '鵥', '鵦', '鵧', '鵨', '鵩', '鵪', '鵫', '鵬', '鵭', '鵮', '鵯', '鵰', '鵱', '鵲', '鵳', '鵴', '鵵', '鵶', '鵷', '鵸', '鵹', '鵺', '鵻', '鵼', '鵽', '鵾', '鵿', '鶀', '鶁', '\u{FFFD}', '鶂', '鶃', '鶄', '鶅', '鶆', '鶇', '鶈', '鶉', '鶊', '鶋', '鶌', '鶍', '鶎', '鶏', '鶐', '鶑', '鶒', '鶓', '鶔', '鶕', '鶖', '鶗', '鶘', '鶙', '鶚', '鶛', '鶜', '鶝', '鶞', '鶟', '鶠', '鶡', '鶢', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xFA, ['鶣', '鶤', '鶥', '鶦', '鶧', '鶨', '鶩', '鶪', '鶫', '鶬', '鶭', '鶮', '鶯', '鶰', '鶱', '鶲', '鶳', '鶴', '鶵', '鶶', '鶷', '鶸', '鶹', '鶺', '鶻', '鶼', '鶽', '鶾', '鶿', '鷀', '鷁', '鷂', '鷃', '鷄', '鷅', '鷆', '鷇', '鷈', '鷉', '鷊', '鷋', '鷌', '鷍', '鷎', '鷏', '鷐', '鷑', '鷒', '鷓', '鷔', '鷕', '鷖', '鷗', '鷘', '鷙', '鷚', '鷛', '鷜', '鷝', '鷞', '鷟', '鷠', '鷡', '\u{FFFD}', '鷢', '鷣', '鷤', '鷥', '鷦', '鷧', '鷨', '鷩', '鷪', '鷫', '鷬', '鷭', '鷮', '鷯', '鷰', '鷱', '鷲', '鷳', '鷴', '鷵', '鷶', '鷷', '鷸', '鷹', '鷺', '鷻', '鷼', '鷽', '鷾', '鷿', '鸀', '鸁', '鸂', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xFB, ['鸃', '鸄', '鸅', '鸆', '鸇', '鸈', '鸉', '鸊', '鸋', '鸌', '鸍', '鸎', '鸏', '鸐', '鸑', '鸒', '鸓', '鸔', '鸕', '鸖', '鸗', '鸘', '鸙', '鸚', '鸛', '鸜', '鸝', '鸞', '鸤', '鸧', '鸮', '鸰', '鸴', '鸻', '鸼', '鹀', '鹍', '鹐', '鹒', '鹓', '鹔', '鹖', '鹙', '鹝', '鹟', '鹠', '鹡', '鹢', '鹥', '鹮', '鹯', '鹲', '鹴', '鹵', '鹶', '鹷', '鹸', '鹹', '鹺', '鹻', '鹼', '鹽', '麀', '\u{FFFD}', '麁', '麃', '麄', '麅', '麆', '麉', '麊', '麌', '麍', '麎', '麏', '麐', '麑', '麔', '麕', '麖', '麗', '麘', '麙', '麚', '麛', '麜', '麞', '麠', '麡', '麢', '麣', '麤', '麥', '麧', '麨', '麩', '麪', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xFC, ['麫', '麬', '麭', '麮', '麯', '麰', '麱', '麲', '麳', '麵', '麶', '麷', '麹', '麺', '麼', '麿', '黀', '黁', '黂', '黃', '黅', '黆', '黇', '黈', '黊', '黋', '黌', '黐', '黒', '黓', '黕', '黖', '黗', '黙', '黚', '點', '黡', '黣', '黤', '黦', '黨', '黫', '黬', '黭', '黮', '黰', '黱', '黲', '黳', '黴', '黵', '黶', '黷', '黸', '黺', '黽', '黿', '鼀', '鼁', '鼂', '鼃', '鼄', '鼅', '\u{FFFD}', '鼆', '鼇', '鼈', '鼉', '鼊', '鼌', '鼏', '鼑', '鼒', '鼔', '鼕', '鼖', '鼘', '鼚', '鼛', '鼜', '鼝', '鼞', '鼟', '鼡', '鼣', '鼤', '鼥', '鼦', '鼧', '鼨', '鼩', '鼪', '鼫', '鼭', '鼮', '鼰', '鼱', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xFD, ['鼲', '鼳', '鼴', '鼵', '鼶', '鼸', '鼺', '鼼', '鼿', '齀', '齁', '齂', '齃', '齅', '齆', '齇', '齈', '齉', '齊', '齋', '齌', '齍', '齎', '齏', '齒', '齓', '齔', '齕', '齖', '齗', '齘', '齙', '齚', '齛', '齜', '齝', '齞', '齟', '齠', '齡', '齢', '齣', '齤', '齥', '齦', '齧', '齨', '齩', '齪', '齫', '齬', '齭', '齮', '齯', '齰', '齱', '齲', '齳', '齴', '齵', '齶', '齷', '齸', '\u{FFFD}', '齹', '齺', '齻', '齼', '齽', '齾', '龁', '龂', '龍', '龎', '龏', '龐', '龑', '龒', '龓', '龔', '龕', '龖', '龗', '龘', '龜', '龝', '龞', '龡', '龢', '龣', '龤', '龥', '郎', '凉', '秊', '裏', '隣', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+    (0xFE, ['兀', '嗀', '﨎', '﨏', '﨑', '﨓', '﨔', '礼', '﨟', '蘒', '﨡', '﨣', '﨤', '﨧', '﨨', '﨩', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}', '\u{FFFD}']),
+];
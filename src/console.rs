@@ -0,0 +1,341 @@
+//! Conversion of the console settings in a ConsoleDataBlock (section 2.5.1) into a Windows Terminal
+//! profile and a color scheme, and an ANSI escape preview of the console palette.
+
+use std::fmt;
+use {ConsoleDataBlock, FontWeight, LinkInfo, ShellLink, ExtraData};
+
+/// Names of the Windows Terminal scheme colors, in the order of the console ColorTable.
+/// The console table is indexed by FOREGROUND_BLUE / _GREEN / _RED / _INTENSITY bits, so its
+/// order differs from the ANSI color order.
+const COLOR_TABLE_NAMES: [&str;16] = [
+    "black",
+    "blue",
+    "green",
+    "cyan",
+    "red",
+    "purple",
+    "yellow",
+    "white",
+    "brightBlack",
+    "brightBlue",
+    "brightGreen",
+    "brightCyan",
+    "brightRed",
+    "brightPurple",
+    "brightYellow",
+    "brightWhite",
+];
+
+/// ColorTable indices in ANSI order (black, red, green, yellow, blue, magenta, cyan, white)
+const ANSI_ORDER: [usize;8] = [0, 4, 2, 6, 1, 5, 3, 7];
+
+/// Pixels per inch the console font height is specified in, Windows Terminal expects points
+const CONSOLE_DPI: u32 = 96;
+
+/// Windows Terminal rejects `cursorHeight` values below 1
+const CURSOR_HEIGHT_MIN: u32 = 1;
+
+/// A color of the console ColorTable
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl From<u32> for Rgb {
+    /// Converts a COLORREF (`0x00BBGGRR`)
+    fn from(input: u32) -> Rgb {
+        Rgb {
+            r: input as u8,
+            g: (input >> 8) as u8,
+            b: (input >> 16) as u8,
+        }
+    }
+}
+
+impl From<Rgb> for u32 {
+    fn from(input: Rgb) -> u32 {
+        ((input.b as u32) << 16) + ((input.g as u32) << 8) + (input.r as u32)
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+/// A Windows Terminal color scheme built from the console ColorTable and fill attributes
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct ColorScheme {
+    pub name: String,
+    /// Text color, selected by the foreground bits of the FillAttributes
+    pub foreground: Rgb,
+    /// Window color, selected by the background bits of the FillAttributes
+    pub background: Rgb,
+    /// The 16 console colors in ColorTable order
+    pub color_table: [Rgb;16],
+}
+
+/// A Windows Terminal profile reproducing the look of a console shortcut
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct WindowsTerminalProfile {
+    pub name: String,
+    /// The command line the console shortcut launches, if known
+    pub commandline: Option<String>,
+    /// The working directory of the console shortcut, if known
+    pub starting_directory: Option<String>,
+    pub color_scheme: ColorScheme,
+    pub font_face: String,
+    /// Font size in points, 0 if the FontSize field leaves the height to the console default
+    pub font_size: u32,
+    pub font_weight: FontWeight,
+    /// Cursor height in percent of the character cell
+    pub cursor_height: u32,
+    /// Initial window width in characters
+    pub initial_cols: i16,
+    /// Initial window height in characters
+    pub initial_rows: i16,
+}
+
+impl ConsoleDataBlock {
+    /// Builds a color scheme named `name` from the ColorTable and the FillAttributes
+    pub fn color_scheme(&self, name: &str) -> ColorScheme {
+        let mut color_table = [Rgb { r: 0, g: 0, b: 0 }; 16];
+        for (rgb, colorref) in color_table.iter_mut().zip(self.color_table.iter()) {
+            *rgb = Rgb::from(*colorref);
+        }
+
+        ColorScheme {
+            name: name.to_string(),
+            foreground: color_table[self.fill_attributes.foreground_index()],
+            background: color_table[self.fill_attributes.background_index()],
+            color_table,
+        }
+    }
+
+    /// Builds a Windows Terminal profile named `name` (the color scheme gets the same name)
+    pub fn windows_terminal_profile(&self, name: &str) -> WindowsTerminalProfile {
+        WindowsTerminalProfile {
+            name: name.to_string(),
+            commandline: None,
+            starting_directory: None,
+            color_scheme: self.color_scheme(name),
+            font_face: self.face_name.clone(),
+            font_size: (self.font_height() as u32 * 72 + CONSOLE_DPI / 2) / CONSOLE_DPI,
+            font_weight: self.font_weight,
+            cursor_height: u32::from(self.cursor_size),
+            initial_cols: self.window_size_x,
+            initial_rows: self.window_size_y,
+        }
+    }
+
+    /// Renders the 16 colors of the ColorTable as ANSI escape sequences (24-bit color), as two rows
+    /// of normal and bright colors in ANSI order, followed by a sample of the default text colors.
+    pub fn ansi_palette_preview(&self) -> String {
+        let scheme = self.color_scheme("");
+        let mut preview = String::new();
+
+        for &offset in &[0, 8] {
+            for &index in ANSI_ORDER.iter() {
+                let rgb = scheme.color_table[index + offset];
+                preview.push_str(&format!("\x1b[48;2;{};{};{}m {:>2} \x1b[0m", rgb.r, rgb.g, rgb.b, index + offset));
+            }
+            preview.push('\n');
+        }
+
+        let (fg, bg) = (scheme.foreground, scheme.background);
+        preview.push_str(&format!(
+            "\x1b[38;2;{};{};{};48;2;{};{};{}m {} \x1b[0m\n",
+            fg.r, fg.g, fg.b, bg.r, bg.g, bg.b, self.face_name,
+        ));
+
+        preview
+    }
+}
+
+impl ShellLink {
+    /// Builds a Windows Terminal profile from the ConsoleDataBlock of the link, with the command line
+    /// taken from the LinkInfo target path and arguments, and the starting directory from the working
+    /// directory. Returns `None` if the link has no ConsoleDataBlock.
+    pub fn windows_terminal_profile(&self, name: &str) -> Option<WindowsTerminalProfile> {
+        let console = self.extra_data.iter().filter_map(|block| match *block {
+            ExtraData::ConsoleProps(ref console) => Some(console),
+            _ => None,
+        }).next()?;
+
+        let mut profile = console.windows_terminal_profile(name);
+
+        let target = self.link_info.as_ref().and_then(LinkInfo::target_path);
+        profile.commandline = match (target, self.arguments.as_ref()) {
+            (Some(target), Some(arguments)) => Some(format!("\"{}\" {}", target, arguments.string)),
+            (Some(target), None) => Some(format!("\"{}\"", target)),
+            (None, _) => None,
+        };
+        profile.starting_directory = self.working_dir.as_ref().map(|working_dir| working_dir.string.clone());
+
+        Some(profile)
+    }
+}
+
+impl ColorScheme {
+    /// Serializes the scheme as an entry of the Windows Terminal `schemes` list
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n");
+        json.push_str(&format!("    \"name\": {},\n", json_string(&self.name)));
+        json.push_str(&format!("    \"foreground\": \"{}\",\n", self.foreground));
+        json.push_str(&format!("    \"background\": \"{}\",\n", self.background));
+        json.push_str(&format!("    \"cursorColor\": \"{}\",\n", self.foreground));
+        json.push_str(&format!("    \"selectionBackground\": \"{}\"", self.foreground));
+        for (name, rgb) in COLOR_TABLE_NAMES.iter().zip(self.color_table.iter()) {
+            json.push_str(&format!(",\n    \"{}\": \"{}\"", name, rgb));
+        }
+        json.push_str("\n}");
+        json
+    }
+}
+
+impl WindowsTerminalProfile {
+    /// Serializes the profile as a `profiles.json` fragment containing the initial window size,
+    /// the profile and its color scheme
+    pub fn to_json(&self) -> String {
+        let mut profile = String::from("{\n");
+        profile.push_str(&format!("    \"name\": {},\n", json_string(&self.name)));
+        if let Some(ref commandline) = self.commandline {
+            profile.push_str(&format!("    \"commandline\": {},\n", json_string(commandline)));
+        }
+        if let Some(ref starting_directory) = self.starting_directory {
+            profile.push_str(&format!("    \"startingDirectory\": {},\n", json_string(starting_directory)));
+        }
+        profile.push_str(&format!("    \"colorScheme\": {},\n", json_string(&self.color_scheme.name)));
+        profile.push_str("    \"font\": {\n");
+        profile.push_str(&format!("        \"face\": {},\n", json_string(&self.font_face)));
+        // Windows Terminal falls back to its own default size if `size` is missing
+        if self.font_size > 0 {
+            profile.push_str(&format!("        \"size\": {},\n", self.font_size));
+        }
+        profile.push_str(&format!("        \"weight\": {}\n", u32::from(self.font_weight)));
+        profile.push_str("    },\n");
+        profile.push_str("    \"cursorShape\": \"vintage\",\n");
+        profile.push_str(&format!("    \"cursorHeight\": {}\n", self.cursor_height.max(CURSOR_HEIGHT_MIN)));
+        profile.push('}');
+
+        let mut json = String::from("{\n");
+        json.push_str(&format!("    \"initialCols\": {},\n", self.initial_cols));
+        json.push_str(&format!("    \"initialRows\": {},\n", self.initial_rows));
+        json.push_str(&format!("    \"profiles\": {{\n        \"list\": [\n{}\n        ]\n    }},\n", indent(&profile, 12)));
+        json.push_str(&format!("    \"schemes\": [\n{}\n    ]\n", indent(&self.color_scheme.to_json(), 8)));
+        json.push('}');
+        json
+    }
+}
+
+fn indent(input: &str, spaces: usize) -> String {
+    let prefix = " ".repeat(spaces);
+    input.lines().map(|line| format!("{}{}", prefix, line)).collect::<Vec<_>>().join("\n")
+}
+
+/// Quotes and escapes a string for use in JSON
+fn json_string(input: &str) -> String {
+    let mut json = String::from("\"");
+    for c in input.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+fn campbell_console() -> ConsoleDataBlock {
+    use {CursorSize, FillAttributes, FontFamily, FontPitch};
+
+    ConsoleDataBlock {
+        block_size: 0xCC,
+        block_signature: 0xA0000002,
+        fill_attributes: FillAttributes::ForegroundBlue | FillAttributes::ForegroundGreen | FillAttributes::ForegroundRed,
+        popup_fill_attributes: FillAttributes::ForegroundBlue | FillAttributes::ForegroundRed | FillAttributes::BackgroundIntensity
+            | FillAttributes::BackgroundBlue | FillAttributes::BackgroundGreen | FillAttributes::BackgroundRed,
+        screen_buffer_size_x: 120,
+        screen_buffer_size_y: 9001,
+        window_size_x: 120,
+        window_size_y: 30,
+        window_origin_x: 0,
+        window_origin_y: 0,
+        font_size: 16 << 16,
+        font_family: FontFamily::Modern,
+        font_pitch: FontPitch::Vector | FontPitch::TrueType,
+        font_weight: FontWeight::Regular(400),
+        face_name: "Consolas".to_string(),
+        cursor_size: CursorSize::Small(25),
+        full_screen: false,
+        quick_edit: true,
+        insert_mode: true,
+        auto_position: true,
+        history_buffer_size: 50,
+        number_of_history_buffers: 4,
+        history_no_dup: false,
+        color_table: [
+            0x000C0C0C, 0x00DA3700, 0x000EA113, 0x00DD963A, 0x001F0FC5, 0x00981788, 0x00009CC1, 0x00CCCCCC,
+            0x00767676, 0x00FF783B, 0x000CC616, 0x00D6D661, 0x005648E7, 0x009E00B4, 0x00A5F1F9, 0x00F2F2F2,
+        ],
+    }
+}
+
+#[test]
+fn console_color_scheme() {
+    let scheme = campbell_console().color_scheme("Campbell");
+    assert_eq!(scheme.foreground.to_string(), "#CCCCCC");
+    assert_eq!(scheme.background.to_string(), "#0C0C0C");
+    assert_eq!(u32::from(scheme.color_table[1]), 0x00DA3700);
+
+    let json = scheme.to_json();
+    assert!(json.contains("\"blue\": \"#0037DA\""));
+    assert!(json.contains("\"red\": \"#C50F1F\""));
+    assert!(json.contains("\"brightYellow\": \"#F9F1A5\""));
+}
+
+#[test]
+fn console_windows_terminal_profile() {
+    let profile = campbell_console().windows_terminal_profile("Legacy \"cmd\"");
+    assert_eq!(profile.font_size, 12);
+    assert_eq!(profile.cursor_height, 25);
+    assert_eq!((profile.initial_cols, profile.initial_rows), (120, 30));
+
+    let json = profile.to_json();
+    assert!(json.contains("\"initialCols\": 120"));
+    assert!(json.contains("\"name\": \"Legacy \\\"cmd\\\"\""));
+    assert!(json.contains("\"face\": \"Consolas\""));
+    assert!(json.contains("\"cursorHeight\": 25"));
+}
+
+#[test]
+fn console_windows_terminal_profile_default_font_size() {
+    let mut console = campbell_console();
+    console.font_size = 0;
+    let profile = console.windows_terminal_profile("Default");
+    assert_eq!(profile.font_size, 0);
+
+    let json = profile.to_json();
+    assert!(!json.contains("\"size\""));
+    let font: Vec<&str> = json.lines().skip_while(|line| !line.contains("\"font\"")).take(4).map(str::trim).collect();
+    assert_eq!(font, vec!["\"font\": {", "\"face\": \"Consolas\",", "\"weight\": 400", "},"]);
+}
+
+#[test]
+fn console_ansi_palette_preview() {
+    let preview = campbell_console().ansi_palette_preview();
+    let lines: Vec<&str> = preview.lines().collect();
+    assert_eq!(lines.len(), 3);
+    // second ANSI color is red, which is index 4 of the console ColorTable
+    assert!(lines[0].starts_with("\x1b[48;2;12;12;12m  0 \x1b[0m\x1b[48;2;197;15;31m  4 "));
+    assert!(lines[2].starts_with("\x1b[38;2;204;204;204;48;2;12;12;12m Consolas "));
+}
@@ -0,0 +1,126 @@
+//! Decoding of the MSI descriptor stored in a DarwinDataBlock (section 2.5.3). Advertised shortcuts
+//! store the product code, the feature name and the component code of the Windows Installer package
+//! that installs the link target, with both GUIDs compressed to 20 characters.
+
+use error::DarwinDescriptorParseError;
+use guid::Guid;
+use DarwinDataBlock;
+
+/// Length of a GUID compressed with the MSI base85 encoding
+const COMPRESSED_GUID_LEN: usize = 20;
+
+/// Alphabet of the MSI base85 encoding, the index of a character is its digit value
+const BASE85_ALPHABET: &[u8;85] = b"!$%&'()*+,-.0123456789=?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[]^_`abcdefghijklmnopqrstuvwxyz{}~";
+
+/// Separates the feature name from a compressed component code
+const COMPONENT_DELIMITER: char = '>';
+/// Ends the feature name if the descriptor has no component code
+const NO_COMPONENT_DELIMITER: char = '<';
+
+/// A decoded MSI descriptor (also known as Darwin descriptor):
+///
+/// ```no_run,ignore
+/// DESCRIPTOR = PRODUCT_CODE [FEATURE] [">" COMPONENT_CODE / "<"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct DarwinDescriptor {
+    /// ProductCode of the package that owns the advertised shortcut
+    pub product_code: Guid,
+    /// Feature (in the Feature table of the package) the shortcut belongs to. Empty if the
+    /// package has only one feature.
+    pub feature: String,
+    /// ComponentId of the component that installs the link target, if present
+    pub component_code: Option<Guid>,
+}
+
+impl DarwinDescriptor {
+    pub fn try_from(input: &str) -> Result<Self, DarwinDescriptorParseError> {
+        use self::DarwinDescriptorParseError::*;
+
+        let input = input.trim_end_matches('\0');
+        let product_code = decode_compressed_guid(input)?;
+        let rest = &input[COMPRESSED_GUID_LEN..];
+
+        let feature_end = rest.find(&[COMPONENT_DELIMITER, NO_COMPONENT_DELIMITER][..]).unwrap_or(rest.len());
+        let feature = rest[..feature_end].to_string();
+
+        let component_code = if rest[feature_end..].starts_with(COMPONENT_DELIMITER) {
+            let component = &rest[feature_end + 1..];
+            Some(decode_compressed_guid(component).map_err(|e| match e {
+                InvalidDescriptorLength(_) => InvalidDescriptorLength(input.len()),
+                e => e,
+            })?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            product_code,
+            feature,
+            component_code,
+        })
+    }
+}
+
+impl DarwinDataBlock {
+    /// Decodes the MSI descriptor of the block, preferring the Unicode application identifier
+    /// over the ANSI one (which SHOULD be ignored according to [MS-SHLLINK]).
+    pub fn descriptor(&self) -> Result<DarwinDescriptor, DarwinDescriptorParseError> {
+        match self.darwin_data_unicode {
+            Some(ref unicode) => DarwinDescriptor::try_from(unicode),
+            None => DarwinDescriptor::try_from(self.darwin_data_ansi.as_str()),
+        }
+    }
+}
+
+/// Decodes the first 20 characters of the input as a GUID packed with the MSI base85 encoding: each
+/// group of 5 characters is a little-endian base85 number that makes up 4 bytes of the GUID packet
+/// representation.
+fn decode_compressed_guid(input: &str) -> Result<Guid, DarwinDescriptorParseError> {
+    use self::DarwinDescriptorParseError::*;
+
+    let compressed = input.as_bytes();
+    if compressed.len() < COMPRESSED_GUID_LEN || !input.is_char_boundary(COMPRESSED_GUID_LEN) {
+        return Err(InvalidDescriptorLength(input.len()));
+    }
+
+    let mut bytes = [0; 16];
+    for (group, chunk) in compressed[..COMPRESSED_GUID_LEN].chunks(5).enumerate() {
+        let mut value: u64 = 0;
+        for &c in chunk.iter().rev() {
+            let digit = BASE85_ALPHABET.iter().position(|x| *x == c).ok_or(InvalidBase85Character(c as char))?;
+            value = value * 85 + digit as u64;
+        }
+
+        if value > u32::MAX as u64 {
+            return Err(InvalidCompressedGuid(group));
+        }
+
+        bytes[group * 4..group * 4 + 4].copy_from_slice(&[value as u8, (value >> 8) as u8, (value >> 16) as u8, (value >> 24) as u8]);
+    }
+
+    Ok(Guid::from_bytes(&bytes))
+}
+
+#[test]
+fn decode_darwin_descriptor() {
+    // Excel shortcut of Office 2007 Professional Plus
+    let descriptor = DarwinDescriptor::try_from("w_1^VX!!!!!!!!!MKKSkEXCELFiles>tW{~$4Q]c@II=l2xaTO5Z").unwrap();
+    assert_eq!(descriptor.product_code.to_string(), "{91120000-0030-0000-0000-0000000FF1CE}");
+    assert_eq!(descriptor.feature, "EXCELFiles");
+    assert_eq!(descriptor.component_code.unwrap().to_string(), "{0638C49D-BB8B-4CD1-B191-052E8F325736}");
+
+    let descriptor = DarwinDescriptor::try_from("w_1^VX!!!!!!!!!MKKSkEXCELFiles<").unwrap();
+    assert_eq!(descriptor.feature, "EXCELFiles");
+    assert_eq!(descriptor.component_code, None);
+}
+
+#[test]
+fn decode_darwin_descriptor_errors() {
+    use error::DarwinDescriptorParseError::*;
+
+    assert_eq!(DarwinDescriptor::try_from("w_1^VX!!!!"), Err(InvalidDescriptorLength(10)));
+    assert_eq!(DarwinDescriptor::try_from("w_1^VX!!!!!!!!!MKKSkEXCELFiles>tW{~$"), Err(InvalidDescriptorLength(36)));
+    assert_eq!(DarwinDescriptor::try_from("w_1^VX!!!!!!!!!MKKS#EXCELFiles"), Err(InvalidBase85Character('#')));
+    assert_eq!(DarwinDescriptor::try_from("~~~~~!!!!!!!!!!MKKSkEXCELFiles"), Err(InvalidCompressedGuid(0)));
+}
@@ -1,3 +1,6 @@
+use guid::Guid;
+use code_page::CodePage;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum ShellLinkHeaderParseError {
     /// Header too short, expected 76 bytes, got n bytes instead
@@ -5,11 +8,7 @@ pub enum ShellLinkHeaderParseError {
     /// Header says it's n bytes long, but the correct size is 76 bytes - corrupt header
     CorruptHeaderLength(u32),
     /// Shell link is not of class LINK_CLSID.
-    CorruptHeaderClsId([u32;4]),
-    /// Link flags field could not be parsed - contains unknown or invalid bits
-    InvalidLinkFlags(u32),
-    /// File attributes coult not be parsed - contains unknow or invalid bits
-    InvalidFileAttributes(u32),
+    CorruptHeaderClsId(Guid),
     InvalidHotKeyFlags(HotKeyFlagsParseError),
 }
 
@@ -17,6 +16,10 @@ pub enum ShellLinkHeaderParseError {
 pub enum ShellLinkParseError {
     HeaderParseError(ShellLinkHeaderParseError),
     IdListParseError(LinkTargetIdListParseError),
+    LinkInfoParseError(LinkInfoParseError),
+    StringDataParseError(StringDataParseError),
+    ExtraDataParseError(ExtraDataParseError),
+    StringDecodeError(CodePageDecodeError),
 }
 
 impl From<ShellLinkHeaderParseError> for ShellLinkParseError {
@@ -31,15 +34,157 @@ impl From<LinkTargetIdListParseError> for ShellLinkParseError {
     }
 }
 
+impl From<LinkInfoParseError> for ShellLinkParseError {
+    fn from(e: LinkInfoParseError) -> Self {
+        ShellLinkParseError::LinkInfoParseError(e)
+    }
+}
+
+impl From<StringDataParseError> for ShellLinkParseError {
+    fn from(e: StringDataParseError) -> Self {
+        ShellLinkParseError::StringDataParseError(e)
+    }
+}
+
+impl From<ExtraDataParseError> for ShellLinkParseError {
+    fn from(e: ExtraDataParseError) -> Self {
+        ShellLinkParseError::ExtraDataParseError(e)
+    }
+}
+
+impl From<CodePageDecodeError> for ShellLinkParseError {
+    fn from(e: CodePageDecodeError) -> Self {
+        ShellLinkParseError::StringDecodeError(e)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum LinkTargetIdListParseError {
-    // TODO: remove later
-    Unimplemented
+    /// Input too short to contain the 2-byte IDListSize field, got n bytes instead
+    InvalidIdListSizeLength(usize),
+    /// IDListSize says the IDList is n bytes long, but only m bytes of input are left
+    IdListTruncated(u16, usize),
+    /// ItemID at the given offset in the IDList has a size smaller than its own ItemIDSize field
+    ItemIdTooShort(usize, u16),
+    /// ItemID at the given offset in the IDList extends past the end of the IDList
+    ItemIdTooLong(usize, u16),
+    /// IDList ended at the given offset without a TerminalID
+    MissingTerminalId(usize),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum LinkInfoParseError {
-    Unimplemented
+    /// Input too short to contain a LinkInfo header, expected at least 28 bytes, got n bytes instead
+    InvalidLinkInfoLength(usize),
+    /// LinkInfoSize is smaller than the header or larger than the remaining input
+    CorruptLinkInfoSize(u32),
+    /// LinkInfoHeaderSize is neither 0x1C nor at least 0x24 (or exceeds LinkInfoSize)
+    CorruptLinkInfoHeaderSize(u32),
+    /// An offset points outside of the structure that contains it
+    InvalidOffset(u32),
+    /// Input too short to contain a VolumeID header, expected at least 16 bytes, got n bytes instead
+    InvalidVolumeIdLength(usize),
+    /// VolumeIDSize is not greater than 0x10 or exceeds the LinkInfo
+    CorruptVolumeIdSize(u32),
+    /// DriveType is not one of the DRIVE_* values
+    InvalidDriveType(u32),
+    /// Input too short to contain a CommonNetworkRelativeLink header, expected at least 20 bytes,
+    /// got n bytes instead
+    InvalidCommonNetworkRelativeLinkLength(usize),
+    /// CommonNetworkRelativeLinkSize is smaller than 0x14 or exceeds the LinkInfo
+    CorruptCommonNetworkRelativeLinkSize(u32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum StringDataParseError {
+    /// Input too short to contain the 2-byte CountCharacters field, got n bytes instead
+    InvalidStringDataLength(usize),
+    /// CountCharacters says the string has n characters, but only m bytes of input are left
+    StringDataTruncated(u16, usize),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum ExtraDataParseError {
+    /// Remaining input too short to contain a BlockSize (or a block too short to contain a
+    /// BlockSignature), got n bytes instead
+    InvalidExtraDataLength(usize),
+    /// BlockSize says the block is n bytes long, but only m bytes of input are left
+    ExtraDataBlockTruncated(u32, usize),
+    /// BlockSize (second field) is not valid for the block with the given signature (first field)
+    InvalidBlockSize(u32, u32),
+    /// The IDList of a VistaAndAboveIDListDataBlock could not be parsed
+    InvalidIdList(LinkTargetIdListParseError),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum PropertyStoreParseError {
+    /// Input too short to contain the next size field at the given offset, got n bytes instead
+    PropertyStoreTruncated(usize, usize),
+    /// Storage at the given offset has a StorageSize that is too small to contain a header or
+    /// extends past the end of the property store
+    InvalidStorageSize(usize, u32),
+    /// Storage at the given offset has a Version other than 0x53505331 ('SPS1')
+    InvalidStorageVersion(usize, u32),
+    /// Property value at the given offset has a ValueSize that is too small to contain a
+    /// name and a value type or extends past the end of its storage
+    InvalidValueSize(usize, u32),
+    /// String-named property value at the given offset has a NameSize that does not fit into
+    /// its ValueSize
+    InvalidNameSize(usize, u32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum DarwinDescriptorParseError {
+    /// Descriptor too short to contain a compressed product code (or a compressed component code
+    /// after the '>' delimiter), got n bytes instead
+    InvalidDescriptorLength(usize),
+    /// Character is not part of the MSI base85 alphabet
+    InvalidBase85Character(char),
+    /// The n-th 5-character group of a compressed GUID does not fit into 32 bits
+    InvalidCompressedGuid(usize),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum GuidParseError {
+    /// String is not 36 characters long (38 with braces), got n characters instead
+    InvalidGuidLength(usize),
+    /// String is not of the form `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`, the character at the
+    /// given position is not a hex digit or hyphen where one is expected
+    InvalidGuidCharacter(usize),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum SidParseError {
+    /// Input too short to contain the SID header or its sub-authorities, got n bytes instead
+    InvalidSidLength(usize),
+    /// Revision is not 1
+    InvalidSidRevision(u8),
+    /// SID has more than 15 sub-authorities
+    InvalidSubAuthorityCount(usize),
+    /// The n-th hyphen-separated component of a SID string is not valid
+    InvalidSidString(usize),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum CodePageDecodeError {
+    /// The code page has no decoding table in this crate
+    UnsupportedCodePage(CodePage),
+    /// The byte (or byte sequence) at the given offset is not mapped to a character in the code page
+    UnmappableBytes(CodePage, usize),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum ShellItemParseError {
+    /// The ItemID has no data, not even a class type indicator
+    EmptyShellItem,
+    /// The data of an item with the given class type is too short for its fields, got n bytes
+    ShellItemTruncated(u8, usize),
+    /// The extension block with the given signature is too short for its fields, its size is n bytes
+    ExtensionBlockTruncated(u32, u16),
+    /// The extension block with the given signature has a version this crate can't decode
+    InvalidExtensionBlockVersion(u32, u16),
+    /// A string that is not stored in Unicode could not be decoded with the configured code page
+    StringDecodeError(CodePageDecodeError),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
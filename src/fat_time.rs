@@ -0,0 +1,101 @@
+//! FAT (MS-DOS) date and time: a 16-bit date and a 16-bit time with a resolution of two seconds,
+//! as stored in file entry shell items. The time zone isn't stored, shell items use UTC.
+
+use std::fmt;
+use filetime::{FileTime, TICKS_PER_SECOND, UNIX_EPOCH_TICKS, SECONDS_PER_DAY, days_from_civil};
+use shell_link_header::u16_from_input;
+
+/// Year of the FAT epoch, the year 0 of a FAT date
+const FAT_EPOCH_YEAR: u32 = 1980;
+
+/// A FAT date and time. The raw values are kept as-is, since they are not necessarily valid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct FatDateTime {
+    /// Bits 0-4: day (1-31), bits 5-8: month (1-12), bits 9-15: years since 1980
+    pub date: u16,
+    /// Bits 0-4: seconds divided by 2 (0-29), bits 5-10: minutes (0-59), bits 11-15: hours (0-23)
+    pub time: u16,
+}
+
+impl FatDateTime {
+    /// Parses a FAT date and time, date first. Input **must** be 4 bytes large!
+    pub fn from_bytes(input: &[u8]) -> Self {
+        FatDateTime {
+            date: u16_from_input(&input[0..2]),
+            time: u16_from_input(&input[2..4]),
+        }
+    }
+
+    /// Parses a FAT date and time, returning `None` if both values are zero ("not set")
+    pub fn from_bytes_nonzero(input: &[u8]) -> Option<Self> {
+        match FatDateTime::from_bytes(input) {
+            FatDateTime { date: 0, time: 0 } => None,
+            time => Some(time),
+        }
+    }
+
+    pub fn year(&self) -> u32 {
+        FAT_EPOCH_YEAR + (self.date >> 9) as u32
+    }
+
+    pub fn month(&self) -> u32 {
+        ((self.date >> 5) & 0x0F) as u32
+    }
+
+    pub fn day(&self) -> u32 {
+        (self.date & 0x1F) as u32
+    }
+
+    pub fn hour(&self) -> u32 {
+        (self.time >> 11) as u32
+    }
+
+    pub fn minute(&self) -> u32 {
+        ((self.time >> 5) & 0x3F) as u32
+    }
+
+    pub fn second(&self) -> u32 {
+        (self.time & 0x1F) as u32 * 2
+    }
+
+    /// Whether all fields are in range. Days are not checked against the length of the month.
+    pub fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month()) && (1..=31).contains(&self.day())
+            && self.hour() < 24 && self.minute() < 60 && self.second() < 60
+    }
+
+    /// Converts the date and time, interpreted as UTC, to a FileTime. Returns `None` if a field
+    /// is out of range.
+    pub fn to_file_time(&self) -> Option<FileTime> {
+        if !self.is_valid() {
+            return None;
+        }
+        // FAT dates start in 1980, so the seconds since the Unix epoch are never negative
+        let days = days_from_civil(self.year() as i64, self.month(), self.day());
+        let seconds = (days * SECONDS_PER_DAY) as u64 + (self.hour() * 3600 + self.minute() * 60 + self.second()) as u64;
+        Some(FileTime::new(UNIX_EPOCH_TICKS + seconds * TICKS_PER_SECOND))
+    }
+}
+
+impl fmt::Display for FatDateTime {
+    /// Formats the date and time like RFC 3339, but without a time zone, e.g. `2019-12-07T09:14:52`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year(), self.month(), self.day(), self.hour(), self.minute(), self.second(),
+        )
+    }
+}
+
+#[test]
+fn fat_date_time() {
+    let time = FatDateTime::from_bytes(&[0x87, 0x4F, 0xDA, 0x49]);
+    assert_eq!(time.to_string(), "2019-12-07T09:14:52");
+    assert_eq!(time.to_file_time().unwrap().to_string(), "2019-12-07T09:14:52Z");
+    assert_eq!(FatDateTime { date: 0x0021, time: 0 }.to_file_time().unwrap().to_string(), "1980-01-01T00:00:00Z");
+    assert_eq!(FatDateTime { date: 0x505D, time: 0 }.to_file_time().unwrap().to_string(), "2020-02-29T00:00:00Z");
+
+    assert_eq!(FatDateTime::from_bytes_nonzero(&[0; 4]), None);
+    assert!(!FatDateTime { date: 0x0020, time: 0 }.is_valid());
+    assert_eq!(FatDateTime { date: 0x0021, time: 0xC000 }.to_file_time(), None);
+}
@@ -0,0 +1,208 @@
+//! FILETIME structure ([MS-DTYP] section 2.3.3): the number of 100-nanosecond intervals since
+//! January 1, 1601 (UTC).
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use shell_link_header::u64_from_input;
+
+/// 100-nanosecond intervals per second
+pub(crate) const TICKS_PER_SECOND: u64 = 10_000_000;
+/// Ticks between 1601-01-01 and the Unix epoch (1970-01-01)
+pub(crate) const UNIX_EPOCH_TICKS: u64 = 116_444_736_000_000_000;
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
+/// Last year RFC 3339 can represent, it only allows four-digit years
+const RFC3339_MAX_YEAR: i64 = 9999;
+
+/// A FILETIME timestamp. The raw tick count is kept as-is, so no precision is lost; conversions
+/// to other representations are done on demand.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct FileTime(u64);
+
+impl FileTime {
+    /// Creates a FileTime from the number of 100-nanosecond intervals since 1601-01-01
+    pub fn new(ticks: u64) -> Self {
+        FileTime(ticks)
+    }
+
+    /// Parses a FILETIME structure (two little-endian 32-bit values, low part first).
+    /// Input **must** be 8 bytes large!
+    pub fn from_bytes(input: &[u8]) -> Self {
+        FileTime(u64_from_input(input))
+    }
+
+    /// Parses a FILETIME structure, returning `None` if the value is zero ("not set")
+    pub fn from_bytes_nonzero(input: &[u8]) -> Option<Self> {
+        match FileTime::from_bytes(input) {
+            FileTime(0) => None,
+            time => Some(time),
+        }
+    }
+
+    /// Number of 100-nanosecond intervals since 1601-01-01 (UTC)
+    pub fn ticks(&self) -> u64 {
+        self.0
+    }
+
+    /// Ticks relative to the Unix epoch, negative for timestamps before 1970
+    fn unix_ticks(&self) -> i128 {
+        self.0 as i128 - UNIX_EPOCH_TICKS as i128
+    }
+
+    /// Whole seconds since the Unix epoch, rounded towards negative infinity, so that
+    /// `unix_seconds() * 1_000_000_000 + unix_subsec_nanos()` is always the exact time
+    pub fn unix_seconds(&self) -> i64 {
+        self.unix_ticks().div_euclid(TICKS_PER_SECOND as i128) as i64
+    }
+
+    /// Nanoseconds past `unix_seconds()`, always in `0..1_000_000_000`
+    pub fn unix_subsec_nanos(&self) -> u32 {
+        (self.unix_ticks().rem_euclid(TICKS_PER_SECOND as i128) * 100) as u32
+    }
+
+    /// Nanoseconds since the Unix epoch, negative for timestamps before 1970
+    pub fn unix_nanos(&self) -> i128 {
+        self.unix_ticks() * 100
+    }
+
+    /// Converts the timestamp to a `SystemTime`. Returns `None` if the platform cannot
+    /// represent the time (e.g. dates before 1970 on some platforms).
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let ticks = self.unix_ticks();
+        let duration = |ticks: u128| Duration::new((ticks / TICKS_PER_SECOND as u128) as u64, ((ticks % TICKS_PER_SECOND as u128) * 100) as u32);
+        if ticks >= 0 {
+            UNIX_EPOCH.checked_add(duration(ticks as u128))
+        } else {
+            UNIX_EPOCH.checked_sub(duration((-ticks) as u128))
+        }
+    }
+
+    /// Converts a `SystemTime` to a FileTime, rounding down (towards the past) to 100-nanosecond
+    /// precision. Returns `None` if the time is before 1601 or after the end of the FILETIME range.
+    pub fn from_system_time(time: SystemTime) -> Option<Self> {
+        let unix_nanos = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_nanos() as i128,
+            Err(before) => -(before.duration().as_nanos() as i128),
+        };
+        let ticks = UNIX_EPOCH_TICKS as i128 + unix_nanos.div_euclid(100);
+
+        if ticks < 0 || ticks > u64::MAX as i128 {
+            None
+        } else {
+            Some(FileTime(ticks as u64))
+        }
+    }
+
+    /// Formats the timestamp as RFC 3339 in UTC, e.g. `2019-12-07T09:14:52.3031200Z`. The fraction
+    /// is written with all seven digits of the 100-nanosecond precision and omitted if zero.
+    /// Returns `None` for years after 9999, which RFC 3339 can't represent; the `Display`
+    /// implementation writes those with more than four digits instead.
+    pub fn to_rfc3339(&self) -> Option<String> {
+        let (year, _, _) = civil_from_days(self.unix_seconds().div_euclid(SECONDS_PER_DAY));
+        if year > RFC3339_MAX_YEAR {
+            None
+        } else {
+            Some(self.to_string())
+        }
+    }
+}
+
+impl From<FileTime> for u64 {
+    fn from(input: FileTime) -> u64 {
+        input.0
+    }
+}
+
+impl fmt::Display for FileTime {
+    /// Formats the timestamp like `to_rfc3339`, but also years after 9999
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let seconds = self.unix_seconds();
+        let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+        let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+        write!(
+            f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year, month, day, second_of_day / 3600, (second_of_day / 60) % 60, second_of_day % 60,
+        )?;
+
+        let fraction = self.0 % TICKS_PER_SECOND;
+        if fraction != 0 {
+            write!(f, ".{:07}", fraction)?;
+        }
+
+        f.write_str("Z")
+    }
+}
+
+/// Converts days since 1970-01-01 to a (year, month, day) date in the proleptic Gregorian calendar
+/// (algorithm from Howard Hinnant, "chrono-Compatible Low-Level Date Algorithms")
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Converts a date in the proleptic Gregorian calendar to days since 1970-01-01, the inverse of
+/// `civil_from_days`
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 } as i64;
+    let day_of_year = (153 * mp + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[test]
+fn file_time_conversions() {
+    let epoch = FileTime::new(0);
+    assert_eq!(epoch.to_rfc3339().as_deref(), Some("1601-01-01T00:00:00Z"));
+    assert_eq!(epoch.unix_seconds(), -11_644_473_600);
+
+    let unix = FileTime::new(UNIX_EPOCH_TICKS);
+    assert_eq!(unix.to_rfc3339().as_deref(), Some("1970-01-01T00:00:00Z"));
+    assert_eq!(unix.to_system_time(), Some(UNIX_EPOCH));
+
+    // one tick before the Unix epoch
+    let before = FileTime::new(UNIX_EPOCH_TICKS - 1);
+    assert_eq!(before.unix_seconds(), -1);
+    assert_eq!(before.unix_subsec_nanos(), 999_999_900);
+    assert_eq!(before.unix_nanos(), -100);
+    assert_eq!(before.to_rfc3339().as_deref(), Some("1969-12-31T23:59:59.9999999Z"));
+
+    let write_time = FileTime::from_bytes(&[0xA0, 0x8E, 0x73, 0xC8, 0xDE, 0xAC, 0xD5, 0x01]);
+    assert_eq!(write_time.to_rfc3339().as_deref(), Some("2019-12-07T09:14:52.3031200Z"));
+    assert_eq!(FileTime::new(132_274_080_000_000_000).to_rfc3339().as_deref(), Some("2020-02-29T00:00:00Z"));
+
+    let far_future = FileTime::new(u64::MAX);
+    assert_eq!(far_future.to_rfc3339(), None);
+    assert_eq!(far_future.to_string(), "60056-05-28T05:36:10.9551615Z");
+    assert_eq!(FileTime::new(2_650_467_743_999_999_999).to_rfc3339().as_deref(), Some("9999-12-31T23:59:59.9999999Z"));
+    assert_eq!(FileTime::new(2_650_467_744_000_000_000).to_rfc3339(), None);
+    assert_eq!(FileTime::from_bytes_nonzero(&[0; 8]), None);
+}
+
+#[test]
+fn file_time_system_time_round_trip() {
+    let time = FileTime::new(132_201_836_923_031_200);
+    assert_eq!(FileTime::from_system_time(time.to_system_time().unwrap()), Some(time));
+
+    let pre_unix = FileTime::new(UNIX_EPOCH_TICKS - 12_345_678_901);
+    if let Some(system_time) = pre_unix.to_system_time() {
+        assert_eq!(FileTime::from_system_time(system_time), Some(pre_unix));
+    }
+
+    // sub-tick nanoseconds are rounded towards the past on both sides of the Unix epoch
+    let after = UNIX_EPOCH + Duration::from_nanos(150);
+    assert_eq!(FileTime::from_system_time(after), Some(FileTime::new(UNIX_EPOCH_TICKS + 1)));
+    if let Some(before) = UNIX_EPOCH.checked_sub(Duration::from_nanos(150)) {
+        assert_eq!(FileTime::from_system_time(before), Some(FileTime::new(UNIX_EPOCH_TICKS - 2)));
+    }
+}
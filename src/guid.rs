@@ -0,0 +1,184 @@
+//! GUID packet representation ([MS-DTYP] section 2.3.4.2), as used for class identifiers, known
+//! folder identifiers, distributed link tracking object identifiers and MSI product and component codes.
+
+use std::fmt;
+use std::str::FromStr;
+use error::GuidParseError;
+use shell_link_header::{u16_from_input, u32_from_input};
+
+/// A globally unique identifier. The first three fields are stored little-endian in the
+/// packet representation, the last eight bytes are stored as-is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Guid {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8;8],
+}
+
+/// Positions of the hyphens in the registry format (without braces)
+const HYPHEN_POSITIONS: [usize;4] = [8, 13, 18, 23];
+/// Length of the registry format without braces
+const GUID_STRING_LEN: usize = 36;
+
+impl Guid {
+    /// The GUID with all bits set to zero (`GUID_NULL`)
+    pub const NULL: Guid = Guid::new(0, 0, 0, [0; 8]);
+
+    pub const fn new(data1: u32, data2: u16, data3: u16, data4: [u8;8]) -> Self {
+        Guid {
+            data1,
+            data2,
+            data3,
+            data4,
+        }
+    }
+
+    /// Parses a GUID in packet representation. Input **must** be 16 bytes large!
+    pub fn from_bytes(input: &[u8]) -> Self {
+        assert!(input.len() == 16);
+
+        let mut data4 = [0; 8];
+        data4.copy_from_slice(&input[8..16]);
+
+        Guid {
+            data1: u32_from_input(&input[0..4]),
+            data2: u16_from_input(&input[4..6]),
+            data3: u16_from_input(&input[6..8]),
+            data4,
+        }
+    }
+
+    /// Serializes the GUID in packet representation
+    pub fn to_bytes(&self) -> [u8;16] {
+        let mut bytes = [0; 16];
+        bytes[0..4].copy_from_slice(&[self.data1 as u8, (self.data1 >> 8) as u8, (self.data1 >> 16) as u8, (self.data1 >> 24) as u8]);
+        bytes[4..6].copy_from_slice(&[self.data2 as u8, (self.data2 >> 8) as u8]);
+        bytes[6..8].copy_from_slice(&[self.data3 as u8, (self.data3 >> 8) as u8]);
+        bytes[8..16].copy_from_slice(&self.data4);
+        bytes
+    }
+
+    /// Version of a RFC 4122 UUID, stored in the high nibble of `data3`
+    pub fn version(&self) -> u8 {
+        (self.data3 >> 12) as u8
+    }
+}
+
+impl fmt::Display for Guid {
+    /// Formats the GUID in registry format, e.g. `{00021401-0000-0000-C000-000000000046}`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{{:08X}-{:04X}-{:04X}-{:02X}{:02X}-", self.data1, self.data2, self.data3, self.data4[0], self.data4[1])?;
+        for byte in &self.data4[2..] {
+            write!(f, "{:02X}", byte)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl FromStr for Guid {
+    type Err = GuidParseError;
+
+    /// Parses a GUID in registry format, with or without braces, in upper or lower case
+    fn from_str(input: &str) -> Result<Self, GuidParseError> {
+        use self::GuidParseError::*;
+
+        let (inner, offset) = if input.starts_with('{') && input.ends_with('}') && input.len() > 1 {
+            (&input[1..input.len() - 1], 1)
+        } else {
+            (input, 0)
+        };
+
+        if inner.len() != GUID_STRING_LEN || !inner.is_ascii() {
+            return Err(InvalidGuidLength(input.chars().count()));
+        }
+
+        let mut digits = Vec::with_capacity(32);
+        for (i, c) in inner.bytes().enumerate() {
+            if HYPHEN_POSITIONS.contains(&i) {
+                if c != b'-' {
+                    return Err(InvalidGuidCharacter(i + offset));
+                }
+            } else {
+                digits.push((c as char).to_digit(16).ok_or(InvalidGuidCharacter(i + offset))? as u8);
+            }
+        }
+
+        let hex = |range: ::std::ops::Range<usize>| digits[range].iter().fold(0u32, |acc, d| (acc << 4) | *d as u32);
+
+        let mut data4 = [0; 8];
+        for (i, byte) in data4.iter_mut().enumerate() {
+            *byte = hex(16 + i * 2..18 + i * 2) as u8;
+        }
+
+        Ok(Guid {
+            data1: hex(0..8),
+            data2: hex(8..12) as u16,
+            data3: hex(12..16) as u16,
+            data4,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Guid {
+    /// Serializes the GUID as a string in registry format
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Guid {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GuidVisitor;
+
+        impl<'de> ::serde::de::Visitor<'de> for GuidVisitor {
+            type Value = Guid;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a GUID string such as {00021401-0000-0000-C000-000000000046}")
+            }
+
+            fn visit_str<E: ::serde::de::Error>(self, value: &str) -> Result<Guid, E> {
+                value.parse().map_err(|e| E::custom(format!("invalid GUID {:?}: {:?}", value, e)))
+            }
+        }
+
+        deserializer.deserialize_str(GuidVisitor)
+    }
+}
+
+#[test]
+fn guid_from_bytes() {
+    let bytes = [0x01, 0x14, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46];
+    let guid = Guid::from_bytes(&bytes);
+    assert_eq!(guid.data1, 0x00021401);
+    assert_eq!(guid.to_string(), "{00021401-0000-0000-C000-000000000046}");
+    assert_eq!(guid.to_bytes(), bytes);
+}
+
+#[test]
+fn guid_from_str() {
+    use error::GuidParseError::*;
+
+    let guid: Guid = "{62AB5D82-FDC1-4DC3-A9DD-070D1D495D97}".parse().unwrap();
+    assert_eq!(guid, Guid::new(0x62AB5D82, 0xFDC1, 0x4DC3, [0xA9, 0xDD, 0x07, 0x0D, 0x1D, 0x49, 0x5D, 0x97]));
+    assert_eq!("62ab5d82-fdc1-4dc3-a9dd-070d1d495d97".parse(), Ok(guid));
+    assert_eq!(guid.to_string().parse(), Ok(guid));
+
+    assert_eq!("{62AB5D82-FDC1-4DC3-A9DD}".parse::<Guid>(), Err(InvalidGuidLength(25)));
+    assert_eq!("{62AB5D82-FDC1-4DC3-A9DD_070D1D495D97}".parse::<Guid>(), Err(InvalidGuidCharacter(24)));
+    assert_eq!("62AB5D8G-FDC1-4DC3-A9DD-070D1D495D97".parse::<Guid>(), Err(InvalidGuidCharacter(7)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn guid_deserialize() {
+    use serde::Deserialize;
+    use serde::de::value::{Error, StrDeserializer};
+    use serde::de::IntoDeserializer;
+
+    let deserializer: StrDeserializer<Error> = "{00021401-0000-0000-C000-000000000046}".into_deserializer();
+    assert_eq!(Guid::deserialize(deserializer).unwrap().data1, 0x00021401);
+}
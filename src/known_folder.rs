@@ -0,0 +1,193 @@
+//! Names of the folders referenced by a SpecialFolderDataBlock (CSIDL values) and by a
+//! KnownFolderDataBlock (KNOWNFOLDERID values), and lookup of the ItemID of the IDList at which the
+//! folder ends and the path relative to it begins.
+
+use guid::Guid;
+use {IdList, ItemId, KnownFolderDataBlock, SpecialFolderDataBlock};
+
+/// Names of the CSIDL values (from shlobj.h)
+pub const CSIDL_NAMES: [(u32, &str); 57] = [
+    (0x00, "CSIDL_DESKTOP"),
+    (0x01, "CSIDL_INTERNET"),
+    (0x02, "CSIDL_PROGRAMS"),
+    (0x03, "CSIDL_CONTROLS"),
+    (0x04, "CSIDL_PRINTERS"),
+    (0x05, "CSIDL_PERSONAL"),
+    (0x06, "CSIDL_FAVORITES"),
+    (0x07, "CSIDL_STARTUP"),
+    (0x08, "CSIDL_RECENT"),
+    (0x09, "CSIDL_SENDTO"),
+    (0x0A, "CSIDL_BITBUCKET"),
+    (0x0B, "CSIDL_STARTMENU"),
+    (0x0C, "CSIDL_MYDOCUMENTS"),
+    (0x0D, "CSIDL_MYMUSIC"),
+    (0x0E, "CSIDL_MYVIDEO"),
+    (0x10, "CSIDL_DESKTOPDIRECTORY"),
+    (0x11, "CSIDL_DRIVES"),
+    (0x12, "CSIDL_NETWORK"),
+    (0x13, "CSIDL_NETHOOD"),
+    (0x14, "CSIDL_FONTS"),
+    (0x15, "CSIDL_TEMPLATES"),
+    (0x16, "CSIDL_COMMON_STARTMENU"),
+    (0x17, "CSIDL_COMMON_PROGRAMS"),
+    (0x18, "CSIDL_COMMON_STARTUP"),
+    (0x19, "CSIDL_COMMON_DESKTOPDIRECTORY"),
+    (0x1A, "CSIDL_APPDATA"),
+    (0x1B, "CSIDL_PRINTHOOD"),
+    (0x1C, "CSIDL_LOCAL_APPDATA"),
+    (0x1D, "CSIDL_ALTSTARTUP"),
+    (0x1E, "CSIDL_COMMON_ALTSTARTUP"),
+    (0x1F, "CSIDL_COMMON_FAVORITES"),
+    (0x20, "CSIDL_INTERNET_CACHE"),
+    (0x21, "CSIDL_COOKIES"),
+    (0x22, "CSIDL_HISTORY"),
+    (0x23, "CSIDL_COMMON_APPDATA"),
+    (0x24, "CSIDL_WINDOWS"),
+    (0x25, "CSIDL_SYSTEM"),
+    (0x26, "CSIDL_PROGRAM_FILES"),
+    (0x27, "CSIDL_MYPICTURES"),
+    (0x28, "CSIDL_PROFILE"),
+    (0x29, "CSIDL_SYSTEMX86"),
+    (0x2A, "CSIDL_PROGRAM_FILESX86"),
+    (0x2B, "CSIDL_PROGRAM_FILES_COMMON"),
+    (0x2C, "CSIDL_PROGRAM_FILES_COMMONX86"),
+    (0x2D, "CSIDL_COMMON_TEMPLATES"),
+    (0x2E, "CSIDL_COMMON_DOCUMENTS"),
+    (0x2F, "CSIDL_COMMON_ADMINTOOLS"),
+    (0x30, "CSIDL_ADMINTOOLS"),
+    (0x31, "CSIDL_CONNECTIONS"),
+    (0x35, "CSIDL_COMMON_MUSIC"),
+    (0x36, "CSIDL_COMMON_PICTURES"),
+    (0x37, "CSIDL_COMMON_VIDEO"),
+    (0x38, "CSIDL_RESOURCES"),
+    (0x39, "CSIDL_RESOURCES_LOCALIZED"),
+    (0x3A, "CSIDL_COMMON_OEM_LINKS"),
+    (0x3B, "CSIDL_CDBURN_AREA"),
+    (0x3D, "CSIDL_COMPUTERSNEARME"),
+];
+
+/// Names and default paths of the KNOWNFOLDERID values (from KnownFolders.h). Virtual folders have
+/// no path.
+pub const KNOWN_FOLDERS: [(Guid, &str, Option<&str>); 46] = [
+    (Guid::new(0xB4BFCC3A, 0xDB2C, 0x424C, [0xB0, 0x29, 0x7F, 0xE9, 0x9A, 0x87, 0xC6, 0x41]), "FOLDERID_Desktop", Some(r"%USERPROFILE%\Desktop")),
+    (Guid::new(0xFDD39AD0, 0x238F, 0x46AF, [0xAD, 0xB4, 0x6C, 0x85, 0x48, 0x03, 0x69, 0xC7]), "FOLDERID_Documents", Some(r"%USERPROFILE%\Documents")),
+    (Guid::new(0x374DE290, 0x123F, 0x4565, [0x91, 0x64, 0x39, 0xC4, 0x92, 0x5E, 0x46, 0x7B]), "FOLDERID_Downloads", Some(r"%USERPROFILE%\Downloads")),
+    (Guid::new(0x4BD8D571, 0x6D19, 0x48D3, [0xBE, 0x97, 0x42, 0x22, 0x20, 0x08, 0x0E, 0x43]), "FOLDERID_Music", Some(r"%USERPROFILE%\Music")),
+    (Guid::new(0x33E28130, 0x4E1E, 0x4676, [0x83, 0x5A, 0x98, 0x39, 0x5C, 0x3B, 0xC3, 0xBB]), "FOLDERID_Pictures", Some(r"%USERPROFILE%\Pictures")),
+    (Guid::new(0x18989B1D, 0x99B5, 0x455B, [0x84, 0x1C, 0xAB, 0x7C, 0x74, 0xE4, 0xDD, 0xFC]), "FOLDERID_Videos", Some(r"%USERPROFILE%\Videos")),
+    (Guid::new(0x1777F761, 0x68AD, 0x4D8A, [0x87, 0xBD, 0x30, 0xB7, 0x59, 0xFA, 0x33, 0xDD]), "FOLDERID_Favorites", Some(r"%USERPROFILE%\Favorites")),
+    (Guid::new(0xBFB9D5E0, 0xC6A9, 0x404C, [0xB2, 0xB2, 0xAE, 0x6D, 0xB6, 0xAF, 0x49, 0x68]), "FOLDERID_Links", Some(r"%USERPROFILE%\Links")),
+    (Guid::new(0x56784854, 0xC6CB, 0x462B, [0x81, 0x69, 0x88, 0xE3, 0x50, 0xAC, 0xB8, 0x82]), "FOLDERID_Contacts", Some(r"%USERPROFILE%\Contacts")),
+    (Guid::new(0x4C5C32FF, 0xBB9D, 0x43B0, [0xB5, 0xB4, 0x2D, 0x72, 0xE5, 0x4E, 0xAA, 0xA4]), "FOLDERID_SavedGames", Some(r"%USERPROFILE%\Saved Games")),
+    (Guid::new(0xA52BBA46, 0xE9E1, 0x435F, [0xB3, 0xD9, 0x28, 0xDA, 0xA6, 0x48, 0xC0, 0xF6]), "FOLDERID_SkyDrive", Some(r"%USERPROFILE%\OneDrive")),
+    (Guid::new(0x5E6C858F, 0x0E22, 0x4760, [0x9A, 0xFE, 0xEA, 0x33, 0x17, 0xB6, 0x71, 0x73]), "FOLDERID_Profile", Some(r"%USERPROFILE%")),
+    (Guid::new(0x0762D272, 0xC50A, 0x4BB0, [0xA3, 0x82, 0x69, 0x7D, 0xCD, 0x72, 0x9B, 0x80]), "FOLDERID_UserProfiles", Some(r"%SystemDrive%\Users")),
+    (Guid::new(0x3EB685DB, 0x65F9, 0x4CF6, [0xA0, 0x3A, 0xE3, 0xEF, 0x65, 0x72, 0x9F, 0x3D]), "FOLDERID_RoamingAppData", Some(r"%APPDATA%")),
+    (Guid::new(0xF1B32785, 0x6FBA, 0x4FCF, [0x9D, 0x55, 0x7B, 0x8E, 0x7F, 0x15, 0x70, 0x91]), "FOLDERID_LocalAppData", Some(r"%LOCALAPPDATA%")),
+    (Guid::new(0xA520A1A4, 0x1780, 0x4FF6, [0xBD, 0x18, 0x16, 0x73, 0x43, 0xC5, 0xAF, 0x16]), "FOLDERID_LocalAppDataLow", Some(r"%USERPROFILE%\AppData\LocalLow")),
+    (Guid::new(0x5CD7AEE2, 0x2219, 0x4A67, [0xB8, 0x5D, 0x6C, 0x9C, 0xE1, 0x56, 0x60, 0xCB]), "FOLDERID_UserProgramFiles", Some(r"%LOCALAPPDATA%\Programs")),
+    (Guid::new(0x625B53C3, 0xAB48, 0x4EC1, [0xBA, 0x1F, 0xA1, 0xEF, 0x41, 0x46, 0xFC, 0x19]), "FOLDERID_StartMenu", Some(r"%APPDATA%\Microsoft\Windows\Start Menu")),
+    (Guid::new(0xA77F5D77, 0x2E2B, 0x44C3, [0xA6, 0xA2, 0xAB, 0xA6, 0x01, 0x05, 0x4A, 0x51]), "FOLDERID_Programs", Some(r"%APPDATA%\Microsoft\Windows\Start Menu\Programs")),
+    (Guid::new(0xB97D20BB, 0xF46A, 0x4C97, [0xBA, 0x10, 0x5E, 0x36, 0x08, 0x43, 0x08, 0x54]), "FOLDERID_Startup", Some(r"%APPDATA%\Microsoft\Windows\Start Menu\Programs\StartUp")),
+    (Guid::new(0x724EF170, 0xA42D, 0x4FEF, [0x9F, 0x26, 0xB6, 0x0E, 0x84, 0x6F, 0xBA, 0x4F]), "FOLDERID_AdminTools", Some(r"%APPDATA%\Microsoft\Windows\Start Menu\Programs\Administrative Tools")),
+    (Guid::new(0xAE50C081, 0xEBD2, 0x438A, [0x86, 0x55, 0x8A, 0x09, 0x2E, 0x34, 0x98, 0x7A]), "FOLDERID_Recent", Some(r"%APPDATA%\Microsoft\Windows\Recent")),
+    (Guid::new(0x8983036C, 0x27C0, 0x404B, [0x8F, 0x08, 0x10, 0x2D, 0x10, 0xDC, 0xFD, 0x74]), "FOLDERID_SendTo", Some(r"%APPDATA%\Microsoft\Windows\SendTo")),
+    (Guid::new(0xA63293E8, 0x664E, 0x48DB, [0xA0, 0x79, 0xDF, 0x75, 0x9E, 0x05, 0x09, 0xF7]), "FOLDERID_Templates", Some(r"%APPDATA%\Microsoft\Windows\Templates")),
+    (Guid::new(0x52A4F021, 0x7B75, 0x48A9, [0x9F, 0x6B, 0x4B, 0x87, 0xA2, 0x10, 0xBC, 0x8F]), "FOLDERID_QuickLaunch", Some(r"%APPDATA%\Microsoft\Internet Explorer\Quick Launch")),
+    (Guid::new(0x9E3995AB, 0x1F9C, 0x4F13, [0xB8, 0x27, 0x48, 0xB2, 0x4B, 0x6C, 0x71, 0x74]), "FOLDERID_UserPinned", Some(r"%APPDATA%\Microsoft\Internet Explorer\Quick Launch\User Pinned")),
+    (Guid::new(0x62AB5D82, 0xFDC1, 0x4DC3, [0xA9, 0xDD, 0x07, 0x0D, 0x1D, 0x49, 0x5D, 0x97]), "FOLDERID_ProgramData", Some(r"%ALLUSERSPROFILE%")),
+    (Guid::new(0xA4115719, 0xD62E, 0x491D, [0xAA, 0x7C, 0xE7, 0x4B, 0x8B, 0xE3, 0xB0, 0x67]), "FOLDERID_CommonStartMenu", Some(r"%ALLUSERSPROFILE%\Microsoft\Windows\Start Menu")),
+    (Guid::new(0x0139D44E, 0x6AFE, 0x49F2, [0x86, 0x90, 0x3D, 0xAF, 0xCA, 0xE6, 0xFF, 0xB8]), "FOLDERID_CommonPrograms", Some(r"%ALLUSERSPROFILE%\Microsoft\Windows\Start Menu\Programs")),
+    (Guid::new(0x82A5EA35, 0xD9CD, 0x47C5, [0x96, 0x29, 0xE1, 0x5D, 0x2F, 0x71, 0x4E, 0x6E]), "FOLDERID_CommonStartup", Some(r"%ALLUSERSPROFILE%\Microsoft\Windows\Start Menu\Programs\StartUp")),
+    (Guid::new(0xDFDF76A2, 0xC82A, 0x4D63, [0x90, 0x6A, 0x56, 0x44, 0xAC, 0x45, 0x73, 0x85]), "FOLDERID_Public", Some(r"%PUBLIC%")),
+    (Guid::new(0xC4AA340D, 0xF20F, 0x4863, [0xAF, 0xEF, 0xF8, 0x7E, 0xF2, 0xE6, 0xBA, 0x25]), "FOLDERID_PublicDesktop", Some(r"%PUBLIC%\Desktop")),
+    (Guid::new(0xED4824AF, 0xDCE4, 0x45A8, [0x81, 0xE2, 0xFC, 0x79, 0x65, 0x08, 0x36, 0x34]), "FOLDERID_PublicDocuments", Some(r"%PUBLIC%\Documents")),
+    (Guid::new(0x905E63B6, 0xC1BF, 0x494E, [0xB2, 0x9C, 0x65, 0xB7, 0x32, 0xD3, 0xD2, 0x1A]), "FOLDERID_ProgramFiles", Some(r"%ProgramFiles%")),
+    (Guid::new(0x6D809377, 0x6AF0, 0x444B, [0x89, 0x57, 0xA3, 0x77, 0x3F, 0x02, 0x20, 0x0E]), "FOLDERID_ProgramFilesX64", Some(r"%ProgramFiles%")),
+    (Guid::new(0x7C5A40EF, 0xA0FB, 0x4BFC, [0x87, 0x4A, 0xC0, 0xF2, 0xE0, 0xB9, 0xFA, 0x8E]), "FOLDERID_ProgramFilesX86", Some(r"%ProgramFiles(x86)%")),
+    (Guid::new(0xF7F1ED05, 0x9F6D, 0x47A2, [0xAA, 0xAE, 0x29, 0xD3, 0x17, 0xC6, 0xF0, 0x66]), "FOLDERID_ProgramFilesCommon", Some(r"%ProgramFiles%\Common Files")),
+    (Guid::new(0xDE974D24, 0xD9C6, 0x4D3E, [0xBF, 0x91, 0xF4, 0x45, 0x51, 0x20, 0xB9, 0x17]), "FOLDERID_ProgramFilesCommonX86", Some(r"%ProgramFiles(x86)%\Common Files")),
+    (Guid::new(0xF38BF404, 0x1D43, 0x42F2, [0x93, 0x05, 0x67, 0xDE, 0x0B, 0x28, 0xFC, 0x23]), "FOLDERID_Windows", Some(r"%windir%")),
+    (Guid::new(0x1AC14E77, 0x02E7, 0x4E5D, [0xB7, 0x44, 0x2E, 0xB1, 0xAE, 0x51, 0x98, 0xB7]), "FOLDERID_System", Some(r"%windir%\system32")),
+    (Guid::new(0xD65231B0, 0xB2F1, 0x4857, [0xA4, 0xCE, 0xA8, 0xE7, 0xC6, 0xEA, 0x7D, 0x27]), "FOLDERID_SystemX86", Some(r"%windir%\SysWOW64")),
+    (Guid::new(0xFD228CB7, 0xAE11, 0x4AE3, [0x86, 0x4C, 0x16, 0xF3, 0x91, 0x0A, 0xB8, 0xFE]), "FOLDERID_Fonts", Some(r"%windir%\Fonts")),
+    (Guid::new(0x0AC0837C, 0xBBF8, 0x452A, [0x85, 0x0D, 0x79, 0xD0, 0x8E, 0x66, 0x7C, 0xA7]), "FOLDERID_ComputerFolder", None),
+    (Guid::new(0x82A74AEB, 0xAEB4, 0x465C, [0xA0, 0x14, 0xD0, 0x97, 0xEE, 0x34, 0x6D, 0x63]), "FOLDERID_ControlPanelFolder", None),
+    (Guid::new(0xD20BEEC4, 0x5CA8, 0x4905, [0xAE, 0x3B, 0xBF, 0x25, 0x1E, 0xA0, 0x9B, 0x53]), "FOLDERID_NetworkFolder", None),
+    (Guid::new(0xB7534046, 0x3ECB, 0x4C18, [0xBE, 0x4E, 0x64, 0xCD, 0x4C, 0xB7, 0xD6, 0xAC]), "FOLDERID_RecycleBinFolder", None),
+];
+
+/// Returns the name of a CSIDL value, e.g. `CSIDL_PROGRAM_FILES` for 0x26
+pub fn csidl_name(csidl: u32) -> Option<&'static str> {
+    CSIDL_NAMES.iter().find(|(id, _)| *id == csidl).map(|out| out.1)
+}
+
+/// Returns the name of a KNOWNFOLDERID, e.g. `FOLDERID_Desktop`
+pub fn known_folder_name(known_folder_id: &Guid) -> Option<&'static str> {
+    KNOWN_FOLDERS.iter().find(|(id, _, _)| id == known_folder_id).map(|out| out.1)
+}
+
+/// Returns the default path of a KNOWNFOLDERID, e.g. `%USERPROFILE%\Desktop` for `FOLDERID_Desktop`
+pub fn known_folder_default_path(known_folder_id: &Guid) -> Option<&'static str> {
+    KNOWN_FOLDERS.iter().find(|(id, _, _)| id == known_folder_id).and_then(|out| out.2)
+}
+
+impl IdList {
+    /// Returns the index and the ItemID that starts at the given offset, in bytes, into the IDList.
+    /// Returns `None` if no ItemID starts at the offset.
+    pub fn item_at_offset(&self, offset: u32) -> Option<(usize, &ItemId)> {
+        let mut item_offset = 0;
+        for (index, item_id) in self.item_id_list.iter().enumerate() {
+            if item_offset == offset as usize {
+                return Some((index, item_id));
+            }
+            item_offset += item_id.item_id_size as usize;
+        }
+        None
+    }
+}
+
+impl SpecialFolderDataBlock {
+    /// Name of the special folder, e.g. `CSIDL_COMMON_APPDATA`
+    pub fn name(&self) -> Option<&'static str> {
+        csidl_name(self.special_folder_id)
+    }
+
+    /// Returns the index and the ItemID of the first child of the special folder in the link
+    /// target IDList (which MUST be the IDList the block belongs to)
+    pub fn child_item<'a>(&self, id_list: &'a IdList) -> Option<(usize, &'a ItemId)> {
+        id_list.item_at_offset(self.offset)
+    }
+}
+
+impl KnownFolderDataBlock {
+    /// Name of the known folder, e.g. `FOLDERID_ProgramData`
+    pub fn name(&self) -> Option<&'static str> {
+        known_folder_name(&self.known_folder_id)
+    }
+
+    /// Default path of the known folder, e.g. `%ALLUSERSPROFILE%` for `FOLDERID_ProgramData`
+    pub fn default_path(&self) -> Option<&'static str> {
+        known_folder_default_path(&self.known_folder_id)
+    }
+
+    /// Returns the index and the ItemID of the first child of the known folder in the link
+    /// target IDList (which MUST be the IDList the block belongs to)
+    pub fn child_item<'a>(&self, id_list: &'a IdList) -> Option<(usize, &'a ItemId)> {
+        id_list.item_at_offset(self.offset)
+    }
+}
+
+#[test]
+fn folder_names() {
+    assert_eq!(csidl_name(0x26), Some("CSIDL_PROGRAM_FILES"));
+    assert_eq!(csidl_name(0x0C), Some("CSIDL_MYDOCUMENTS"));
+    assert_eq!(csidl_name(0x0F), None);
+
+    let desktop: Guid = "{B4BFCC3A-DB2C-424C-B029-7FE99A87C641}".parse().unwrap();
+    assert_eq!(known_folder_name(&desktop), Some("FOLDERID_Desktop"));
+    assert_eq!(known_folder_default_path(&desktop), Some("%USERPROFILE%\\Desktop"));
+    let system_x86: Guid = "{D65231B0-B2F1-4857-A4CE-A8E7C6EA7D27}".parse().unwrap();
+    assert_eq!(known_folder_default_path(&system_x86), Some("%windir%\\SysWOW64"));
+    assert_eq!(known_folder_default_path(&"{0AC0837C-BBF8-452A-850D-79D08E667CA7}".parse().unwrap()), None);
+}
@@ -6,41 +6,239 @@
 //! Linking and Embedding (OLE), but they also can be used by applications that need the ability to
 //! store a reference to a target file.
 
+// bitflags constants are named after the flags in the specification
+#![allow(non_upper_case_globals)]
+
 #[macro_use]
 extern crate bitflags;
-extern crate time;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 pub mod shell_link_header;
 pub mod error;
+pub mod console;
+pub mod darwin;
+pub mod filetime;
+pub mod fat_time;
+pub mod guid;
+pub mod tracker;
+pub mod property_store;
+pub mod property_value;
+pub mod property_key;
+pub mod sid;
+pub mod known_folder;
+pub mod code_page;
+pub mod shell_item;
+pub mod shell_folder;
 
 use error::*;
-use shell_link_header::ShellLinkHeader;
+use shell_link_header::{ShellLinkHeader, u16_from_input, i16_from_input, u32_from_input};
+use guid::Guid;
+use tracker::Droid;
+use property_store::PropertyStore;
+use code_page::{AnsiString, CodePage, DecodeMode, DEFAULT_CODE_PAGE};
+use code_page::detection::{CodePageGuess, Confidence};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub struct ShellLink {
     pub header: ShellLinkHeader,
     pub link_target_id_list: Option<LinkTargetIdList>,
     pub link_info: Option<LinkInfo>,
-    pub string_data: Option<StringData>,
-    pub extra_data: Option<ExtraData>,
+    /// NAME_STRING: a description of the shortcut, present if the HasName flag is set.
+    pub name: Option<StringData>,
+    /// RELATIVE_PATH: the location of the link target relative to the link file, present if the
+    /// HasRelativePath flag is set.
+    pub relative_path: Option<StringData>,
+    /// WORKING_DIR: the working directory used when activating the link target, present if the
+    /// HasWorkingDir flag is set.
+    pub working_dir: Option<StringData>,
+    /// COMMAND_LINE_ARGUMENTS: the arguments passed to the link target, present if the HasArguments
+    /// flag is set.
+    pub arguments: Option<StringData>,
+    /// ICON_LOCATION: the location of the icon of the shell link, present if the HasIconLocation
+    /// flag is set.
+    pub icon_location: Option<StringData>,
+    /// The extra data blocks in the order they appear in the link file.
+    pub extra_data: Vec<ExtraData>,
+    /// The guessed code page of the strings that are not stored in Unicode, present if the link was
+    /// parsed with `ParseOptions::detect_code_page` set.
+    pub code_page_guess: Option<CodePageGuess>,
+}
+
+/// Options for parsing a shell link
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct ParseOptions {
+    /// The code page of the strings that are not stored in Unicode, i.e. the system default code
+    /// page of the machine that created the link. Defaults to Windows-1252.
+    pub code_page: CodePage,
+    /// Whether bytes that are not mapped in the code page are an error or replaced with U+FFFD.
+    /// Defaults to `DecodeMode::Lossy`.
+    pub decode_mode: DecodeMode,
+    /// Guess the code page from the contents of the link (see `ShellLink::detect_code_page`)
+    /// instead of using `code_page`. `code_page` is still used if the guess has a low confidence.
+    /// Defaults to false.
+    pub detect_code_page: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            code_page: DEFAULT_CODE_PAGE,
+            decode_mode: DecodeMode::Lossy,
+            detect_code_page: false,
+        }
+    }
 }
 
 impl ShellLink {
+    /// Parses a shell link, decoding strings that are not stored in Unicode with Windows-1252
     pub fn try_from(input: &[u8]) -> Result<Self, ShellLinkParseError> {
+        Self::try_from_with_options(input, &ParseOptions::default())
+    }
+
+    /// Parses a shell link, decoding strings that are not stored in Unicode as configured in `options`
+    pub fn try_from_with_options(input: &[u8], options: &ParseOptions) -> Result<Self, ShellLinkParseError> {
+        let mut shell_link = Self::parse(input)?;
+
+        let mut code_page = options.code_page;
+        if options.detect_code_page {
+            let guess = shell_link.detect_code_page();
+            if guess.confidence > Confidence::Low {
+                code_page = guess.code_page;
+            }
+            shell_link.code_page_guess = Some(guess);
+        }
+
+        if code_page != DEFAULT_CODE_PAGE || options.decode_mode != DecodeMode::Lossy {
+            shell_link.decode_ansi_strings(code_page, options.decode_mode)?;
+        }
+        Ok(shell_link)
+    }
+
+    /// The bytes of all strings that are not stored in Unicode, with their Unicode copy if the link
+    /// has one
+    pub(crate) fn ansi_strings(&self) -> Vec<(&[u8], Option<&str>)> {
+        let mut strings = Vec::new();
+
+        for string_data in [&self.name, &self.relative_path, &self.working_dir, &self.arguments, &self.icon_location] {
+            if let Some(bytes) = string_data.as_ref().and_then(|s| s.ansi_bytes.as_ref()) {
+                strings.push((&bytes[..], None));
+            }
+        }
+
+        if let Some(ref link_info) = self.link_info {
+            if let Some(ref local_base_path) = link_info.local_base_path {
+                strings.push((&local_base_path.bytes[..], link_info.local_base_path_unicode.as_deref()));
+            }
+            strings.push((&link_info.common_path_suffix.bytes[..], link_info.common_path_suffix_unicode.as_deref()));
+            if let Some(volume_label) = link_info.volume_id.as_ref().and_then(|v| v.volume_label.as_ref()) {
+                strings.push((&volume_label.bytes[..], None));
+            }
+            if let Some(ref link) = link_info.common_network_relative_link {
+                strings.push((&link.net_name.bytes[..], link.net_name_unicode.as_deref()));
+                if let Some(ref device_name) = link.device_name {
+                    strings.push((&device_name.bytes[..], link.device_name_unicode.as_deref()));
+                }
+            }
+        }
+
+        for extra_data in &self.extra_data {
+            match *extra_data {
+                ExtraData::DarwinProps(ref block) => strings.push((&block.darwin_data_ansi.bytes[..], block.darwin_data_unicode.as_deref())),
+                ExtraData::EnvironmentProps(ref block) => strings.push((&block.target_ansi.bytes[..], block.target_unicode.as_deref())),
+                ExtraData::IconEnvironmentProps(ref block) => strings.push((&block.target_ansi.bytes[..], block.target_unicode.as_deref())),
+                ExtraData::TrackerProps(ref block) => strings.push((&block.machine_id.bytes[..], None)),
+                _ => {},
+            }
+        }
+
+        strings
+    }
+
+    /// Decodes all strings that are not stored in Unicode again with the given code page: the
+    /// StringData structures of a link without the IsUnicode flag, the LocalBasePath,
+    /// CommonPathSuffix, volume label, NetName and DeviceName of the LinkInfo and the ANSI strings
+    /// of the extra data blocks. On error, the strings decoded so far keep their new value.
+    pub fn decode_ansi_strings(&mut self, code_page: CodePage, mode: DecodeMode) -> Result<(), CodePageDecodeError> {
+        for string_data in [&mut self.name, &mut self.relative_path, &mut self.working_dir, &mut self.arguments, &mut self.icon_location] {
+            if let Some(ref mut string_data) = *string_data {
+                if let Some(ref bytes) = string_data.ansi_bytes {
+                    string_data.string = code_page.decode(bytes, mode)?;
+                }
+            }
+        }
+
+        if let Some(ref mut link_info) = self.link_info {
+            if let Some(ref mut local_base_path) = link_info.local_base_path {
+                local_base_path.redecode(code_page, mode)?;
+            }
+            link_info.common_path_suffix.redecode(code_page, mode)?;
+            if let Some(ref mut volume_label) = link_info.volume_id.as_mut().and_then(|v| v.volume_label.as_mut()) {
+                volume_label.redecode(code_page, mode)?;
+            }
+            if let Some(ref mut link) = link_info.common_network_relative_link {
+                link.net_name.redecode(code_page, mode)?;
+                if let Some(ref mut device_name) = link.device_name {
+                    device_name.redecode(code_page, mode)?;
+                }
+            }
+        }
+
+        for extra_data in &mut self.extra_data {
+            match *extra_data {
+                ExtraData::DarwinProps(ref mut block) => block.darwin_data_ansi.redecode(code_page, mode)?,
+                ExtraData::EnvironmentProps(ref mut block) => block.target_ansi.redecode(code_page, mode)?,
+                ExtraData::IconEnvironmentProps(ref mut block) => block.target_ansi.redecode(code_page, mode)?,
+                ExtraData::TrackerProps(ref mut block) => block.machine_id.redecode(code_page, mode)?,
+                _ => {},
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse(input: &[u8]) -> Result<Self, ShellLinkParseError> {
         use shell_link_header::{HEADER_LEN, LinkFlags};
         let header = ShellLinkHeader::try_from(input)?;
+        let mut offset = HEADER_LEN;
+
         let link_target_id_list = if header.link_flags.contains(LinkFlags::HasLinkTargetIDList) {
-            Some(LinkTargetIdList::try_from(&input[HEADER_LEN..])?)
+            let id_list = LinkTargetIdList::try_from(&input[offset..])?;
+            offset += id_list.size();
+            Some(id_list)
         } else {
             None
         };
 
+        let link_info = if header.link_flags.contains(LinkFlags::HasLinkInfo) {
+            let link_info = LinkInfo::try_from(&input[offset..])?;
+            offset += link_info.link_info_size as usize;
+            Some(link_info)
+        } else {
+            None
+        };
+
+        // STRING_DATA = [NAME_STRING] [RELATIVE_PATH] [WORKING_DIR] [COMMAND_LINE_ARGUMENTS] [ICON_LOCATION]
+        let is_unicode = header.link_flags.contains(LinkFlags::IsUnicode);
+        let name = StringData::try_from_flag(input, &mut offset, header.link_flags.contains(LinkFlags::HasName), is_unicode)?;
+        let relative_path = StringData::try_from_flag(input, &mut offset, header.link_flags.contains(LinkFlags::HasRelativePath), is_unicode)?;
+        let working_dir = StringData::try_from_flag(input, &mut offset, header.link_flags.contains(LinkFlags::HasWorkingDir), is_unicode)?;
+        let arguments = StringData::try_from_flag(input, &mut offset, header.link_flags.contains(LinkFlags::HasArguments), is_unicode)?;
+        let icon_location = StringData::try_from_flag(input, &mut offset, header.link_flags.contains(LinkFlags::HasIconLocation), is_unicode)?;
+
+        let extra_data = ExtraData::try_from_section(&input[offset..])?;
+
         Ok(Self {
             header,
             link_target_id_list,
-            link_info: None,
-            string_data: None,
-            extra_data: None,
+            link_info,
+            name,
+            relative_path,
+            working_dir,
+            arguments,
+            icon_location,
+            extra_data,
+            code_page_guess: None,
         })
     }
 }
@@ -84,9 +282,75 @@ pub struct LinkTargetIdList {
 impl LinkTargetIdList {
     pub fn try_from(input: &[u8]) -> Result<Self, LinkTargetIdListParseError> {
         use self::LinkTargetIdListParseError::*;
+
         // IDListSize (2 bytes)
+        if input.len() < 2 {
+            return Err(InvalidIdListSizeLength(input.len()));
+        }
+
+        let id_list_size = u16_from_input(&input[0..2]);
+
         // IDList (variable)
-        Err(Unimplemented)
+        let input = &input[2..];
+        if input.len() < id_list_size as usize {
+            return Err(IdListTruncated(id_list_size, input.len()));
+        }
+
+        let id_list = IdList::try_from(&input[..id_list_size as usize])?;
+
+        Ok(Self {
+            id_list_size,
+            id_list,
+        })
+    }
+
+    /// Size, in bytes, of the whole structure (IDListSize field + IDList) in the link file
+    pub fn size(&self) -> usize {
+        2 + self.id_list_size as usize
+    }
+}
+
+impl IdList {
+    /// Parses the ItemIDs of an IDList. The input must span exactly the IDList, including the
+    /// TerminalID, so that items running past the end of the list can be detected.
+    pub fn try_from(input: &[u8]) -> Result<Self, LinkTargetIdListParseError> {
+        use self::LinkTargetIdListParseError::*;
+
+        let mut item_id_list = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            if input.len() < offset + 2 {
+                return Err(MissingTerminalId(offset));
+            }
+
+            let item_id_size = u16_from_input(&input[offset..offset + 2]);
+
+            // TerminalID
+            if item_id_size == 0 {
+                break;
+            }
+
+            if item_id_size < 2 {
+                return Err(ItemIdTooShort(offset, item_id_size));
+            }
+
+            let item_end = offset + item_id_size as usize;
+            if item_end > input.len() {
+                return Err(ItemIdTooLong(offset, item_id_size));
+            }
+
+            item_id_list.push(ItemId {
+                item_id_size,
+                data: input[offset + 2..item_end].to_vec(),
+            });
+
+            offset = item_end;
+        }
+
+        Ok(Self {
+            item_id_list,
+        })
     }
 }
 
@@ -146,7 +410,7 @@ pub struct LinkInfo {
     /// page, which is used to construct the full path to the link item or link target by appending the
     /// string in the CommonPathSuffix field. This field is present if the VolumeIDAndLocalBasePath
     /// flag is set.
-    pub local_base_path: String,
+    pub local_base_path: Option<AnsiString>,
     /// An optional CommonNetworkRelativeLink structure
     /// (section 2.3.2) that specifies information about the network location where the link target is
     /// stored.
@@ -154,7 +418,7 @@ pub struct LinkInfo {
     /// A NULL–terminated string, defined by the system default code
     /// page, which is used to construct the full path to the link item or link target by being appended to
     /// the string in the LocalBasePath field.
-    pub common_path_suffix: String,
+    pub common_path_suffix: AnsiString,
     /// An optional, NULL–terminated, Unicode string that is used to
     /// construct the full path to the link item or link target by appending the string in the
     /// CommonPathSuffixUnicode field. This field can be present only if the
@@ -165,7 +429,7 @@ pub struct LinkInfo {
     /// to construct the full path to the link item or link target by being appended to the string in the
     /// LocalBasePathUnicode field. This field can be present only if the value of the
     /// LinkInfoHeaderSize field is greater than or equal to 0x00000024.
-    pub common_path_suffix_unicde: Option<String>,
+    pub common_path_suffix_unicode: Option<String>,
 }
 
 /// A 32-bit, unsigned integer that specifies the size, in bytes, of the
@@ -216,7 +480,7 @@ impl DriveType {
     pub fn try_from(input: u32) -> Option<Self> {
         DRIVE_TYPE_MAP.iter()
         .find(|x| x.1 == input)
-        .and_then(|out| Some(out.0))
+        .map(|out| out.0)
     }
 }
 
@@ -224,7 +488,7 @@ impl From<DriveType> for u32 {
     fn from(input: DriveType) -> u32 {
         DRIVE_TYPE_MAP.iter()
         .find(|x| x.0 == input)
-        .and_then(|out| Some(out.1))
+        .map(|out| out.1)
         .unwrap()
     }
 }
@@ -237,12 +501,12 @@ bitflags! {
         ///
         /// If not set, the DeviceNameOffset field does not contain an offset to the device name, and
         /// its value MUST be zero.
-        const ValidDevice   = 0xFFFFFFFF >> 0;
+        const ValidDevice   = 1 << 0;
         /// If set, the NetProviderType field contains the network provider type.
         ///
         /// If not set, the NetProviderType field does not contain the network provider type, and its
         /// value MUST be zero.
-        const ValidNetType  = 0xFFFFFFFF >> 1;
+        const ValidNetType  = 1 << 1;
     }
 }
 
@@ -266,7 +530,7 @@ bitflags! {
         /// fields are zero. If the value of the LinkInfoHeaderSize field
         /// is greater than or equal to 0x00000024, the value of the
         /// LocalBasePathOffsetUnicode field is zero.
-        const VolumeIDAndLocalBasePath   = 0xFFFFFFFF >> 0;
+        const VolumeIDAndLocalBasePath   = 1 << 0;
         /// If set, the CommonNetworkRelativeLink field is present,
         /// and its location is specified by the value of the
         /// CommonNetworkRelativeLinkOffset field.
@@ -274,10 +538,12 @@ bitflags! {
         /// If not set, the CommonNetworkRelativeLink field is not
         /// present, and the value of the
         /// CommonNetworkRelativeLinkOffset field is zero.
-        const CommonNetworkRelativeLinkAndPathSuffix  = 0xFFFFFFFF >> 1;
+        const CommonNetworkRelativeLinkAndPathSuffix  = 1 << 1;
     }
 }
 
+// Not listed in [MS-SHLLINK], but written by the SMB redirector for links to Windows shares
+const WNNC_NET_LANMAN: u32 = 0x00020000;
 const WNNC_NET_AVID: u32 = 0x001A0000;
 const WNNC_NET_DOCUSPACE: u32 = 0x001B0000;
 const WNNC_NET_MANGOSOFT: u32 = 0x001C0000;
@@ -320,7 +586,8 @@ const WNNC_NET_MFILES: u32 = 0x00410000;
 const WNNC_NET_MS_NFS: u32 = 0x00420000;
 const WNNC_NET_GOOGLE: u32 = 0x00430000;
 
-const NETWORK_PROVIDER_TYPE_MAP: [(NetworkProviderType, u32);41] = [
+const NETWORK_PROVIDER_TYPE_MAP: [(NetworkProviderType, u32);42] = [
+    (NetworkProviderType::Lanman, WNNC_NET_LANMAN),
     (NetworkProviderType::Avid, WNNC_NET_AVID),
     (NetworkProviderType::Docuspace, WNNC_NET_DOCUSPACE),
     (NetworkProviderType::Mangosoft, WNNC_NET_MANGOSOFT),
@@ -369,6 +636,7 @@ const NETWORK_PROVIDER_TYPE_MAP: [(NetworkProviderType, u32);41] = [
 /// value MUST be ignored.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum NetworkProviderType {
+    Lanman,
     Avid,
     Docuspace,
     Mangosoft,
@@ -410,46 +678,164 @@ pub enum NetworkProviderType {
     Mfiles,
     MsNfs,
     Google,
+    /// A provider type that is not one of the WNNC_NET_* values above
+    Other(u32),
 }
 
 impl NetworkProviderType {
     pub fn try_from(input: u32) -> Option<Self> {
         NETWORK_PROVIDER_TYPE_MAP.iter()
         .find(|x| x.1 == input)
-        .and_then(|out| Some(out.0))
+        .map(|out| out.0)
     }
 }
 
 impl From<NetworkProviderType> for u32 {
     fn from(input: NetworkProviderType) -> u32 {
-        NETWORK_PROVIDER_TYPE_MAP.iter()
-        .find(|x| x.0 == input)
-        .and_then(|out| Some(out.1))
-        .unwrap()
+        match input {
+            NetworkProviderType::Other(network_provider_type) => network_provider_type,
+            named => NETWORK_PROVIDER_TYPE_MAP.iter().find(|x| x.0 == named).map(|out| out.1).unwrap(),
+        }
     }
 }
 
+// LinkInfoHeaderSize without and with the optional Unicode offsets
+const LINK_INFO_HEADER_LEN: u32 = 0x0000001C;
+const LINK_INFO_HEADER_LEN_UNICODE: u32 = 0x00000024;
+
 impl LinkInfo {
     pub fn try_from(input: &[u8]) -> Result<Self, LinkInfoParseError> {
         use self::LinkInfoParseError::*;
 
+        if input.len() < LINK_INFO_HEADER_LEN as usize {
+            return Err(InvalidLinkInfoLength(input.len()));
+        }
+
         // LinkInfoSize: 4 bytes
+        let link_info_size = u32_from_input(&input[0..4]);
+        if link_info_size < LINK_INFO_HEADER_LEN || link_info_size as usize > input.len() {
+            return Err(CorruptLinkInfoSize(link_info_size));
+        }
+
+        // all offsets are relative to the start of the LinkInfo and must stay within LinkInfoSize
+        let input = &input[..link_info_size as usize];
+
         // LinkInfoHeaderSize 4 bytes
+        let link_info_header_size = u32_from_input(&input[4..8]);
+        let link_info_size_header = match link_info_header_size {
+            LINK_INFO_HEADER_LEN => LinkInfoHeaderSize::Unspecified,
+            s if s >= LINK_INFO_HEADER_LEN_UNICODE && s <= link_info_size => LinkInfoHeaderSize::Specified(s),
+            s => return Err(CorruptLinkInfoHeaderSize(s)),
+        };
+
         // LinkInfoFlags 4 bytes
+        let link_info_flags_bytes = u32_from_input(&input[8..12]);
+        let link_info_flags = LinkInfoFlags::from_bits_truncate(link_info_flags_bytes);
+
         // VolumeIdOffset 4 bytes
+        let volume_id_offset = u32_from_input(&input[12..16]);
         // LocalBasePathOffset 4 bytes
+        let local_base_path_offset = u32_from_input(&input[16..20]);
         // CommonNetworkRelativeLinkOffset 4 bytes
+        let common_network_relative_link_offset = u32_from_input(&input[20..24]);
         // CommonPathSuffixOffset 4 bytes
-        // LocalPathOffsetUnicode 4 bytes
-        // CommonPathSuffixOffsetUnicode 4 bytes
+        let common_path_suffix_offset = u32_from_input(&input[24..28]);
+
+        // LocalPathOffsetUnicode 4 bytes, CommonPathSuffixOffsetUnicode 4 bytes
+        let (local_base_path_offset_unicode, common_path_suffix_offset_unicode) = match link_info_size_header {
+            LinkInfoHeaderSize::Specified(_) => (u32_from_input(&input[28..32]), u32_from_input(&input[32..36])),
+            LinkInfoHeaderSize::Unspecified => (0, 0),
+        };
+
+        let has_volume_id = link_info_flags.contains(LinkInfoFlags::VolumeIDAndLocalBasePath);
+        let has_network_link = link_info_flags.contains(LinkInfoFlags::CommonNetworkRelativeLinkAndPathSuffix);
+
         // VolumeId (optional, variable size)
+        let volume_id = if has_volume_id {
+            Some(VolumeId::try_from(link_info_slice(input, volume_id_offset)?)?)
+        } else {
+            None
+        };
+
         // LocalBasePath (optional, variable size)
+        let local_base_path = if has_volume_id {
+            Some(ansi_string_from_input(link_info_slice(input, local_base_path_offset)?))
+        } else {
+            None
+        };
+
         // CommonNetworkRelativeLink (variable)
+        let common_network_relative_link = if has_network_link {
+            Some(CommonNetworkRelativeLink::try_from(link_info_slice(input, common_network_relative_link_offset)?)?)
+        } else {
+            None
+        };
+
         // CommonPathSuffix (variable)
+        let common_path_suffix = ansi_string_from_input(link_info_slice(input, common_path_suffix_offset)?);
+
         // LocalBasePathUnicode (variable)
+        let local_base_path_unicode = if has_volume_id && local_base_path_offset_unicode != 0 {
+            Some(unicode_string_from_input(link_info_slice(input, local_base_path_offset_unicode)?))
+        } else {
+            None
+        };
+
         // CommonPathSuffixUnicode (variable)
-        Err(Unimplemented)
+        let common_path_suffix_unicode = if common_path_suffix_offset_unicode != 0 {
+            Some(unicode_string_from_input(link_info_slice(input, common_path_suffix_offset_unicode)?))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            link_info_size,
+            link_info_size_header,
+            link_info_flags,
+            volume_id_offset,
+            local_base_path_offset,
+            common_network_relative_link_offset,
+            common_path_suffix_offset,
+            local_base_path_offset_unicode,
+            common_path_suffix_offset_unicode,
+            volume_id,
+            local_base_path,
+            common_network_relative_link,
+            common_path_suffix,
+            local_base_path_unicode,
+            common_path_suffix_unicode,
+        })
+    }
+
+    /// Builds the full path of the link target, either by appending the CommonPathSuffix to the
+    /// LocalBasePath, or by joining the NetName of the CommonNetworkRelativeLink and the
+    /// CommonPathSuffix with a backslash. The Unicode versions of the strings are preferred if present.
+    /// Returns `None` if the LinkInfo contains neither a LocalBasePath nor a CommonNetworkRelativeLink.
+    pub fn target_path(&self) -> Option<String> {
+        let suffix = self.common_path_suffix_unicode.as_deref().unwrap_or_else(|| self.common_path_suffix.as_str());
+
+        if let Some(ref local_base_path) = self.local_base_path {
+            let base = self.local_base_path_unicode.as_deref().unwrap_or_else(|| local_base_path.as_str());
+            return Some(format!("{}{}", base, suffix));
+        }
+
+        self.common_network_relative_link.as_ref().map(|link| {
+            let net_name = link.net_name_unicode.as_deref().unwrap_or_else(|| link.net_name.as_str());
+            if suffix.is_empty() || net_name.ends_with('\\') {
+                format!("{}{}", net_name, suffix)
+            } else {
+                format!("{}\\{}", net_name, suffix)
+            }
+        })
+    }
+}
+
+/// Returns the part of the LinkInfo starting at `offset`
+fn link_info_slice(input: &[u8], offset: u32) -> Result<&[u8], LinkInfoParseError> {
+    if offset as usize >= input.len() {
+        return Err(LinkInfoParseError::InvalidOffset(offset));
     }
+    Ok(&input[offset as usize..])
 }
 
 /// The VolumeID structure specifies information about the volume that a link target was on when the
@@ -477,9 +863,19 @@ pub struct VolumeId {
     /// If the value of this field is 0x00000014, it MUST be ignored, and the value of the
     /// VolumeLabelOffsetUnicode field MUST be used to locate the volume label string.
     pub volume_label_offset: u32,
-    /// A buffer of data that contains the volume label of the drive as a string defined by
-    /// the system default code page or Unicode characters, as specified by preceding fields.
-    pub data: String,
+    /// An optional, 32-bit, unsigned integer that specifies the location of a
+    /// string that contains the volume label of the drive that the link target is stored on. This value is an
+    /// offset, in bytes, from the start of the VolumeID structure to a NULL-terminated string of Unicode
+    /// characters. The volume label string is located in the Data field of this structure.
+    ///
+    /// If the value of the VolumeLabelOffset field is not 0x00000014, this field MUST NOT be used.
+    pub volume_label_offset_unicode: Option<u32>,
+    /// The volume label of the drive as a string defined by the system default code page. Not
+    /// present if the VolumeLabelOffset field is 0x00000014.
+    pub volume_label: Option<AnsiString>,
+    /// The volume label of the drive as a Unicode string. Only present if the VolumeLabelOffset
+    /// field is 0x00000014.
+    pub volume_label_unicode: Option<String>,
 }
 
 /// The CommonNetworkRelativeLink structure specifies information about the network location where a
@@ -498,34 +894,172 @@ pub struct CommonNetworkRelativeLink {
     /// A 32-bit, unsigned integer that specifies the location of the NetName
     /// field. This value is an offset, in bytes, from the start of the CommonNetworkRelativeLink structure.
     pub net_name_offset: u32,
+    /// A 32-bit, unsigned integer that specifies the location of the
+    /// DeviceName field. If the ValidDevice flag is set, this value is an offset, in bytes, from the start of
+    /// the CommonNetworkRelativeLink structure; otherwise, this value MUST be zero.
+    pub device_name_offset: u32,
     /// A 32-bit, unsigned integer that specifies the type of network
-    /// provider. If the ValidNetType flag is set, this value MUST be one of the following; otherwise, this
-    /// value MUST be ignored.
-    pub network_provider_type: NetworkProviderType,
+    /// provider. Only present if the ValidNetType flag is set.
+    pub network_provider_type: Option<NetworkProviderType>,
     /// An optional, 32-bit, unsigned integer that specifies the location
     /// of the NetNameUnicode field. This value is an offset, in bytes, from the start of the
     /// CommonNetworkRelativeLink structure. This field MUST be present if the value of the
     /// NetNameOffset field is greater than 0x00000014; otherwise, this field MUST NOT be present.
-    pub net_name_offset_unicode: u32,
+    pub net_name_offset_unicode: Option<u32>,
     /// An optional, 32-bit, unsigned integer that specifies the
     /// location of the DeviceNameUnicode field. This value is an offset, in bytes, from the start of the
     /// CommonNetworkRelativeLink structure. This field MUST be present if the value of the
     /// NetNameOffset field is greater than 0x00000014; otherwise, this field MUST NOT be present.
-    pub device_name_offset_unicode: u32,
+    pub device_name_offset_unicode: Option<u32>,
     /// A NULL–terminated string, as defined by the system default code page, which
     /// specifies a server share path; for example, "\\server\share".
-    pub net_name: String,
+    pub net_name: AnsiString,
     /// A NULL–terminated string, as defined by the system default code page,
-    /// which specifies a device; for example, the drive letter "D:".
-    pub device_name: String,
+    /// which specifies a device; for example, the drive letter "D:". Only present if the ValidDevice
+    /// flag is set.
+    pub device_name: Option<AnsiString>,
     /// An optional, NULL–terminated, Unicode string that is the Unicode
     /// version of the NetName string. This field MUST be present if the value of the NetNameOffset
     /// field is greater than 0x00000014; otherwise, this field MUST NOT be present.
-    pub net_name_unicode: String,
+    pub net_name_unicode: Option<String>,
     /// An optional, NULL–terminated, Unicode string that is the Unicode
     /// version of the DeviceName string. This field MUST be present if the value of the NetNameOffset
     /// field is greater than 0x00000014; otherwise, this field MUST NOT be present.
-    pub device_name_unicode: String,
+    pub device_name_unicode: Option<String>,
+}
+
+// VolumeLabelOffset value that redirects to VolumeLabelOffsetUnicode
+const VOLUME_LABEL_OFFSET_UNICODE: u32 = 0x00000014;
+
+impl VolumeId {
+    pub fn try_from(input: &[u8]) -> Result<Self, LinkInfoParseError> {
+        use self::LinkInfoParseError::*;
+
+        if input.len() < 0x10 {
+            return Err(InvalidVolumeIdLength(input.len()));
+        }
+
+        // VolumeIDSize 4 bytes
+        let volume_id_size = u32_from_input(&input[0..4]);
+        if volume_id_size <= 0x10 || volume_id_size as usize > input.len() {
+            return Err(CorruptVolumeIdSize(volume_id_size));
+        }
+
+        let input = &input[..volume_id_size as usize];
+
+        // DriveType 4 bytes
+        let drive_type_bytes = u32_from_input(&input[4..8]);
+        let drive_type = DriveType::try_from(drive_type_bytes).ok_or(InvalidDriveType(drive_type_bytes))?;
+        // DriveSerialNumber 4 bytes
+        let drive_serial_number = u32_from_input(&input[8..12]);
+        // VolumeLabelOffset 4 bytes
+        let volume_label_offset = u32_from_input(&input[12..16]);
+
+        let (volume_label_offset_unicode, volume_label, volume_label_unicode) = if volume_label_offset == VOLUME_LABEL_OFFSET_UNICODE {
+            // VolumeLabelOffsetUnicode 4 bytes
+            if input.len() < 0x14 {
+                return Err(CorruptVolumeIdSize(volume_id_size));
+            }
+            let offset = u32_from_input(&input[16..20]);
+            let label = unicode_string_from_input(link_info_slice(input, offset)?);
+            (Some(offset), None, Some(label))
+        } else {
+            let label = ansi_string_from_input(link_info_slice(input, volume_label_offset)?);
+            (None, Some(label), None)
+        };
+
+        Ok(Self {
+            volume_id_size,
+            drive_type,
+            drive_serial_number,
+            volume_label_offset,
+            volume_label_offset_unicode,
+            volume_label,
+            volume_label_unicode,
+        })
+    }
+}
+
+// Minimum CommonNetworkRelativeLinkSize, NetNameOffset values above this indicate Unicode offsets
+const COMMON_NETWORK_RELATIVE_LINK_LEN: u32 = 0x00000014;
+
+impl CommonNetworkRelativeLink {
+    pub fn try_from(input: &[u8]) -> Result<Self, LinkInfoParseError> {
+        use self::LinkInfoParseError::*;
+
+        if input.len() < COMMON_NETWORK_RELATIVE_LINK_LEN as usize {
+            return Err(InvalidCommonNetworkRelativeLinkLength(input.len()));
+        }
+
+        // CommonNetworkRelativeLinkSize 4 bytes
+        let common_network_relative_link_size = u32_from_input(&input[0..4]);
+        if common_network_relative_link_size < COMMON_NETWORK_RELATIVE_LINK_LEN || common_network_relative_link_size as usize > input.len() {
+            return Err(CorruptCommonNetworkRelativeLinkSize(common_network_relative_link_size));
+        }
+
+        let input = &input[..common_network_relative_link_size as usize];
+
+        // CommonNetworkRelativeLinkFlags 4 bytes
+        let flags_bytes = u32_from_input(&input[4..8]);
+        let common_network_relative_link_flags = CommonNetworkRelativeLinkFlags::from_bits_truncate(flags_bytes);
+        // NetNameOffset 4 bytes
+        let net_name_offset = u32_from_input(&input[8..12]);
+        // DeviceNameOffset 4 bytes
+        let device_name_offset = u32_from_input(&input[12..16]);
+        // NetworkProviderType 4 bytes
+        let network_provider_type_bytes = u32_from_input(&input[16..20]);
+        let network_provider_type = if common_network_relative_link_flags.contains(CommonNetworkRelativeLinkFlags::ValidNetType) {
+            Some(NetworkProviderType::try_from(network_provider_type_bytes).unwrap_or(NetworkProviderType::Other(network_provider_type_bytes)))
+        } else {
+            None
+        };
+
+        // NetNameOffsetUnicode 4 bytes, DeviceNameOffsetUnicode 4 bytes (optional)
+        let (net_name_offset_unicode, device_name_offset_unicode) = if net_name_offset > COMMON_NETWORK_RELATIVE_LINK_LEN {
+            if input.len() < 0x1C {
+                return Err(CorruptCommonNetworkRelativeLinkSize(common_network_relative_link_size));
+            }
+            (Some(u32_from_input(&input[20..24])), Some(u32_from_input(&input[24..28])))
+        } else {
+            (None, None)
+        };
+
+        // NetName (variable)
+        let net_name = ansi_string_from_input(link_info_slice(input, net_name_offset)?);
+
+        // DeviceName (variable)
+        let device_name = if common_network_relative_link_flags.contains(CommonNetworkRelativeLinkFlags::ValidDevice) {
+            Some(ansi_string_from_input(link_info_slice(input, device_name_offset)?))
+        } else {
+            None
+        };
+
+        // NetNameUnicode (variable)
+        let net_name_unicode = match net_name_offset_unicode {
+            Some(offset) => Some(unicode_string_from_input(link_info_slice(input, offset)?)),
+            None => None,
+        };
+
+        // DeviceNameUnicode (variable)
+        let device_name_unicode = match device_name_offset_unicode {
+            Some(offset) if offset != 0 => Some(unicode_string_from_input(link_info_slice(input, offset)?)),
+            _ => None,
+        };
+
+        Ok(Self {
+            common_network_relative_link_size,
+            common_network_relative_link_flags,
+            net_name_offset,
+            device_name_offset,
+            network_provider_type,
+            net_name_offset_unicode,
+            device_name_offset_unicode,
+            net_name,
+            device_name,
+            net_name_unicode,
+            device_name_unicode,
+        })
+    }
 }
 
 /// StringData refers to a set of structures that convey user interface and path identification information.
@@ -566,6 +1100,61 @@ pub struct StringData {
     /// Unicode string with a length specified by the CountCharacters field. This string MUST NOT be
     /// NULL-terminated.
     pub string: String,
+    /// The bytes of the String field if it is not stored in Unicode, so that it can be decoded
+    /// again with another code page. `None` if the IsUnicode flag is set.
+    pub ansi_bytes: Option<Vec<u8>>,
+}
+
+impl StringData {
+    /// Parses a StringData structure. If `is_unicode` is set (LinkFlags::IsUnicode), the string is read
+    /// as `count_characters` UTF-16LE code units, otherwise as `count_characters` bytes in the system
+    /// default code page.
+    pub fn try_from(input: &[u8], is_unicode: bool) -> Result<Self, StringDataParseError> {
+        use self::StringDataParseError::*;
+
+        // CountCharacters 2 bytes
+        if input.len() < 2 {
+            return Err(InvalidStringDataLength(input.len()));
+        }
+
+        let count_characters = u16_from_input(&input[0..2]);
+        let string_len = if is_unicode { count_characters as usize * 2 } else { count_characters as usize };
+
+        // String (variable)
+        let input = &input[2..];
+        if input.len() < string_len {
+            return Err(StringDataTruncated(count_characters, input.len()));
+        }
+
+        let (string, ansi_bytes) = if is_unicode {
+            (unicode_string(&input[..string_len]), None)
+        } else {
+            let ansi = ansi_string(&input[..string_len]);
+            (ansi.string, Some(ansi.bytes))
+        };
+
+        Ok(Self {
+            count_characters,
+            string,
+            ansi_bytes,
+        })
+    }
+
+    /// Parses the StringData at `offset` if `present` is set, and advances the offset past it
+    fn try_from_flag(input: &[u8], offset: &mut usize, present: bool, is_unicode: bool) -> Result<Option<Self>, StringDataParseError> {
+        if !present {
+            return Ok(None);
+        }
+
+        let string_data = Self::try_from(&input[*offset..], is_unicode)?;
+        *offset += string_data.size(is_unicode);
+        Ok(Some(string_data))
+    }
+
+    /// Size, in bytes, of the structure (CountCharacters field + String) in the link file
+    pub fn size(&self, is_unicode: bool) -> usize {
+        2 + if is_unicode { self.count_characters as usize * 2 } else { self.count_characters as usize }
+    }
 }
 
 /// An optional array of bytes that contains zero or more property data
@@ -583,11 +1172,134 @@ pub enum ExtraData {
     SpecialFolderProps(SpecialFolderDataBlock),
     TrackerProps(TrackerDataBlock),
     VistaAndAboveIdListProps(VistaAndAboveIdListDataBlock),
+    /// A data block with a signature that is not defined in [MS-SHLLINK]. `data` holds the
+    /// block contents following the BlockSize and BlockSignature fields.
+    Unknown { signature: u32, data: Vec<u8> },
+    /// A data block with a signature defined in [MS-SHLLINK] that could not be parsed. `data`
+    /// holds the block contents following the BlockSize and BlockSignature fields.
+    Invalid { signature: u32, data: Vec<u8>, error: ExtraDataParseError },
     // A 32-bit, unsigned integer that indicates the end of the extra data section.
     // This value MUST be less than 0x00000004.
     // TerminalBlock to indicate the end of the EXTRA_DATA section
 }
 
+const ENVIRONMENT_PROPS_SIGNATURE: u32 = 0xA0000001;
+const CONSOLE_PROPS_SIGNATURE: u32 = 0xA0000002;
+const TRACKER_PROPS_SIGNATURE: u32 = 0xA0000003;
+const CONSOLE_FE_PROPS_SIGNATURE: u32 = 0xA0000004;
+const SPECIAL_FOLDER_PROPS_SIGNATURE: u32 = 0xA0000005;
+const DARWIN_PROPS_SIGNATURE: u32 = 0xA0000006;
+const ICON_ENVIRONMENT_PROPS_SIGNATURE: u32 = 0xA0000007;
+const SHIM_PROPS_SIGNATURE: u32 = 0xA0000008;
+const PROPERTY_STORE_PROPS_SIGNATURE: u32 = 0xA0000009;
+const KNOWN_FOLDER_PROPS_SIGNATURE: u32 = 0xA000000B;
+const VISTA_AND_ABOVE_ID_LIST_PROPS_SIGNATURE: u32 = 0xA000000C;
+
+// BlockSize values below this mark the TerminalBlock
+const TERMINAL_BLOCK_LEN: u32 = 0x00000004;
+// BlockSize + BlockSignature
+const BLOCK_HEADER_LEN: u32 = 0x00000008;
+
+impl ExtraData {
+    /// Parses a single extra data block. The input must span exactly the block, as given by its
+    /// BlockSize field.
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        use self::ExtraData::*;
+
+        if input.len() < BLOCK_HEADER_LEN as usize {
+            return Err(ExtraDataParseError::InvalidExtraDataLength(input.len()));
+        }
+
+        let block_signature = u32_from_input(&input[4..8]);
+
+        let extra_data = match block_signature {
+            CONSOLE_PROPS_SIGNATURE => ConsoleProps(ConsoleDataBlock::try_from(input)?),
+            CONSOLE_FE_PROPS_SIGNATURE => ConsoleFeProps(ConsoleFeDataBlock::try_from(input)?),
+            DARWIN_PROPS_SIGNATURE => DarwinProps(DarwinDataBlock::try_from(input)?),
+            ENVIRONMENT_PROPS_SIGNATURE => EnvironmentProps(EnvironmentVariableDataBlock::try_from(input)?),
+            ICON_ENVIRONMENT_PROPS_SIGNATURE => IconEnvironmentProps(IconEnvironmentDataBlock::try_from(input)?),
+            KNOWN_FOLDER_PROPS_SIGNATURE => KnownFolderProps(KnownFolderDataBlock::try_from(input)?),
+            PROPERTY_STORE_PROPS_SIGNATURE => PropertyStoreProps(PropertyStoreDataBlock::try_from(input)?),
+            SHIM_PROPS_SIGNATURE => ShimProps(ShimDataBlock::try_from(input)?),
+            SPECIAL_FOLDER_PROPS_SIGNATURE => SpecialFolderProps(SpecialFolderDataBlock::try_from(input)?),
+            TRACKER_PROPS_SIGNATURE => TrackerProps(TrackerDataBlock::try_from(input)?),
+            VISTA_AND_ABOVE_ID_LIST_PROPS_SIGNATURE => VistaAndAboveIdListProps(VistaAndAboveIdListDataBlock::try_from(input)?),
+            signature => Unknown { signature, data: input[BLOCK_HEADER_LEN as usize..].to_vec() },
+        };
+
+        Ok(extra_data)
+    }
+
+    /// Parses the EXTRA_DATA section at the end of a link file: a list of data blocks,
+    /// each starting with BlockSize and BlockSignature, up to the TerminalBlock.
+    ///
+    /// ```no_run,ignore
+    /// EXTRA_DATA = *EXTRA_DATA_BLOCK TERMINAL_BLOCK
+    /// ```
+    ///
+    /// The TerminalBlock is missing in some links written by third party tools, so the end of
+    /// the input is treated as the end of the section, too. A block that is well delimited but
+    /// can't be parsed is kept as `ExtraData::Invalid` instead of failing the whole section.
+    pub fn try_from_section(input: &[u8]) -> Result<Vec<Self>, ExtraDataParseError> {
+        use self::ExtraDataParseError::*;
+
+        let mut extra_data = Vec::new();
+        let mut offset = 0;
+
+        while offset < input.len() {
+            if input.len() < offset + 4 {
+                return Err(InvalidExtraDataLength(input.len() - offset));
+            }
+
+            // BlockSize 4 bytes
+            let block_size = u32_from_input(&input[offset..offset + 4]);
+
+            // TerminalBlock
+            if block_size < TERMINAL_BLOCK_LEN {
+                break;
+            }
+
+            if block_size < BLOCK_HEADER_LEN {
+                return Err(InvalidExtraDataLength(block_size as usize));
+            }
+
+            if input.len() < offset + block_size as usize {
+                return Err(ExtraDataBlockTruncated(block_size, input.len() - offset));
+            }
+
+            let block = &input[offset..offset + block_size as usize];
+            extra_data.push(match Self::try_from(block) {
+                Ok(extra_data) => extra_data,
+                Err(error) => ExtraData::Invalid {
+                    signature: u32_from_input(&block[4..8]),
+                    data: block[BLOCK_HEADER_LEN as usize..].to_vec(),
+                    error,
+                },
+            });
+            offset += block_size as usize;
+        }
+
+        Ok(extra_data)
+    }
+}
+
+/// Checks that the BlockSize of a data block is `expected` (or at least `expected` if `is_minimum`
+/// is set) and that the input is exactly BlockSize bytes long
+fn check_block_size(input: &[u8], expected: u32, is_minimum: bool) -> Result<u32, ExtraDataParseError> {
+    if input.len() < BLOCK_HEADER_LEN as usize {
+        return Err(ExtraDataParseError::InvalidExtraDataLength(input.len()));
+    }
+
+    let block_signature = u32_from_input(&input[4..8]);
+    let block_size = u32_from_input(&input[0..4]);
+
+    if block_size as usize != input.len() || block_size < expected || (!is_minimum && block_size != expected) {
+        return Err(ExtraDataParseError::InvalidBlockSize(block_signature, block_size));
+    }
+
+    Ok(block_size)
+}
+
 /// The ConsoleDataBlock structure specifies the display settings to use when a link target specifies an
 /// application that is run in a console window.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -595,6 +1307,9 @@ pub struct ConsoleDataBlock {
     /// A 32-bit, unsigned integer that specifies the size of the ConsoleDataBlock
     /// structure. This value MUST be 0x000000CC.
     pub block_size: u32,
+    /// A 32-bit, unsigned integer that specifies the signature of the
+    /// ConsoleDataBlock extra data section. This value MUST be 0xA0000002.
+    pub block_signature: u32,
     /// A 16-bit, unsigned integer that specifies the fill attributes that control the
     /// foreground and background text colors in the console window. The following bit definitions can be
     /// combined to specify 16 different values each for the foreground and background colors:
@@ -605,32 +1320,39 @@ pub struct ConsoleDataBlock {
     pub popup_fill_attributes: FillAttributes,
     /// A 16-bit, signed integer that specifies the horizontal size (X axis), in
     /// characters, of the console window buffer.
-    pub screen_buffer_size_x: u16,
+    pub screen_buffer_size_x: i16,
     /// A 16-bit, signed integer that specifies the vertical size (Y axis), in
     /// characters, of the console window buffer.
-    pub screen_buffer_size_y: u16,
+    pub screen_buffer_size_y: i16,
     /// A 16-bit, signed integer that specifies the horizontal size (X axis), in
     /// characters, of the console window.
-    pub window_size_x: u16,
+    pub window_size_x: i16,
     /// A 16-bit, signed integer that specifies the vertical size (Y axis), in
     /// characters, of the console window.
-    pub window_size_y: u16,
+    pub window_size_y: i16,
     /// A 16-bit, signed integer that specifies the horizontal coordinate (X axis),
     /// in pixels, of the console window origin.
-    pub window_origin_x: u16,
+    pub window_origin_x: i16,
     /// A 16-bit, signed integer that specifies the vertical coordinate (Y axis), in
     /// pixels, of the console window origin.
-    pub window_origin_y: u16,
+    pub window_origin_y: i16,
 
     // unused1: 4 bytes
     // unused2: 4 bytes
 
     /// A 32-bit, unsigned integer that specifies the size, in pixels, of the font used in
-    /// the console window.
+    /// the console window. The high word holds the font height, the low word the font width
+    /// (which is zero for TrueType fonts).
     pub font_size: u32,
     /// A 32-bit, unsigned integer that specifies the family of the font used in the
     /// console window. This value MUST be one of the following:
     pub font_family: FontFamily,
+    /// The pitch and technology of the font used in the console window, stored in the
+    /// low bits of the FontFamily field.
+    pub font_pitch: FontPitch,
+    /// A 32-bit, unsigned integer that specifies the stroke weight of the font used in
+    /// the console window.
+    pub font_weight: FontWeight,
     /// A 32-character (64 bytes) Unicode string that specifies the face name of the font used
     /// in the console window.
     pub face_name: String,
@@ -657,7 +1379,7 @@ pub struct ConsoleDataBlock {
     pub number_of_history_buffers: u32,
     /// A 32-bit, unsigned integer that specifies whether to remove duplicates in
     /// the history buffer.
-    pub history_no_dup: u32,
+    pub history_no_dup: bool,
     /// A table of 16 32-bit, unsigned integers specifying the RGB colors that are
     /// used for text in the console window. The values of the fill attribute fields FillAttributes and
     /// PopupFillAttributes are used as indexes into this table to specify the final foreground and
@@ -678,7 +1400,7 @@ pub struct ConsoleFeDataBlock {
     /// A 32-bit, unsigned integer that specifies a code page language code identifier.
     /// For details concerning the structure and meaning of language code identifiers, see [MS-LCID]. For
     /// additional background information, see [MSCHARSET] and [MSDN-CODEPAGE].
-    pub code_page: u32,
+    pub code_page: CodePage,
 }
 
 /// The DarwinDataBlock structure specifies an application identifier that can be used instead of a link
@@ -693,7 +1415,7 @@ pub struct DarwinDataBlock {
     pub block_signature: u32,
     /// A NULL–terminated string, defined by the system default code page,
     /// which specifies an application identifier. This field SHOULD be ignored.
-    pub darwin_data_ansi: String, // [u8;260],
+    pub darwin_data_ansi: AnsiString, // [u8;260],
     /// An optional, NULL–terminated, Unicode string that specifies an
     /// application identifier.
     pub darwin_data_unicode: Option<String>, // Option<[u8;520]>,
@@ -711,10 +1433,15 @@ pub struct EnvironmentVariableDataBlock {
     pub block_signature: u32,
     /// A NULL-terminated string, defined by the system default code page, which
     /// specifies a path to environment variable information.
-    pub target_ansi: String, // [u8;260],
+    pub target_ansi: AnsiString, // [u8;260],
+    /// The bytes of the 260-byte TargetAnsi buffer after the NULL terminator. These are not
+    /// necessarily zero and may contain remnants of an earlier, longer path.
+    pub target_ansi_residue: Vec<u8>,
     /// An optional, NULL-terminated, Unicode string that specifies a path to
     /// environment variable information.
     pub target_unicode: Option<String>, // Option<[u8;520]>,
+    /// The bytes of the 520-byte TargetUnicode buffer after the NULL terminator
+    pub target_unicode_residue: Vec<u8>,
 }
 
 /// The IconEnvironmentDataBlock structure specifies the path to an icon. The path is encoded using
@@ -730,10 +1457,15 @@ pub struct IconEnvironmentDataBlock {
     pub block_signature: u32,
     /// A NULL-terminated string, defined by the system default code page, which
     /// specifies a path that is constructed with environment variables.
-    pub target_ansi: String, // [u8;260],
+    pub target_ansi: AnsiString, // [u8;260],
+    /// The bytes of the 260-byte TargetAnsi buffer after the NULL terminator. These are not
+    /// necessarily zero and may contain remnants of an earlier, longer path.
+    pub target_ansi_residue: Vec<u8>,
     /// An optional, NULL-terminated, Unicode string that specifies a path
     /// that is constructed with environment variables.
     pub target_unicode: Option<String>, // [u8;520],
+    /// The bytes of the 520-byte TargetUnicode buffer after the NULL terminator
+    pub target_unicode_residue: Vec<u8>,
 }
 
 /// The KnownFolderDataBlock structure specifies the location of a known folder. This data can be used
@@ -749,7 +1481,7 @@ pub struct KnownFolderDataBlock {
     pub block_signature: u32,
     /// A value in GUID packet representation ([MS-DTYP] section 2.3.2.2)
     /// that specifies the folder GUID ID.
-    pub known_folder_id: u16,
+    pub known_folder_id: Guid,
     /// A 32-bit, unsigned integer that specifies the location of the ItemID of the first
     /// child segment of the IDList specified by KnownFolderID. This value is the offset, in bytes, into
     /// the link target IDList.
@@ -767,7 +1499,7 @@ pub struct PropertyStoreDataBlock {
     /// PropertyStoreDataBlock extra data section. This value MUST be 0xA0000009.
     pub block_signature: u32,
     /// A serialized property storage structure ([MS-PROPSTORE] section 2.2).
-    pub property_store: Vec<u8>,
+    pub property_store: PropertyStore,
 }
 
 /// The ShimDataBlock structure specifies the name of a shim that can be applied when activating a link
@@ -822,13 +1554,13 @@ pub struct TrackerDataBlock {
     pub version: u32,
     /// A character string, as defined by the system default code page, which
     /// specifies the NetBIOS name of the machine where the link target was last known to reside.
-    pub machine_id: String,
+    pub machine_id: AnsiString,
     /// Two values in GUID packet representation ([MS-DTYP] section 2.3.2.2) that are
     /// used to find the link target with the Link Tracking service, as specified in [MS-DLTW].
-    pub droid: [u128;2],
+    pub droid: Droid,
     /// Two values in GUID packet representation that are used to find the link
     /// target with the Link Tracking service
-    pub droid_birth: [u128;2],
+    pub droid_birth: Droid,
 }
 
 /// The VistaAndAboveIDListDataBlock structure specifies an alternate IDList that can be used instead of
@@ -846,6 +1578,200 @@ pub struct VistaAndAboveIdListDataBlock {
     pub id_list: IdList,
 }
 
+impl ConsoleDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        let block_size = check_block_size(input, 0x000000CC, false)?;
+
+        let fill_attributes_bytes = u16_from_input(&input[8..10]);
+        let popup_fill_attributes_bytes = u16_from_input(&input[10..12]);
+        let font_family_bytes = u32_from_input(&input[36..40]);
+        let cursor_size_bytes = u32_from_input(&input[108..112]);
+
+        let mut color_table = [0; 16];
+        for (i, color) in color_table.iter_mut().enumerate() {
+            *color = u32_from_input(&input[140 + i * 4..144 + i * 4]);
+        }
+
+        Ok(Self {
+            block_size,
+            block_signature: u32_from_input(&input[4..8]),
+            fill_attributes: FillAttributes::from_bits_truncate(fill_attributes_bytes),
+            popup_fill_attributes: FillAttributes::from_bits_truncate(popup_fill_attributes_bytes),
+            screen_buffer_size_x: i16_from_input(&input[12..14]),
+            screen_buffer_size_y: i16_from_input(&input[14..16]),
+            window_size_x: i16_from_input(&input[16..18]),
+            window_size_y: i16_from_input(&input[18..20]),
+            window_origin_x: i16_from_input(&input[20..22]),
+            window_origin_y: i16_from_input(&input[22..24]),
+            font_size: u32_from_input(&input[32..36]),
+            // the family is stored in the high nibble, the low nibble specifies the font pitch
+            font_family: FontFamily::try_from((font_family_bytes & 0xF0) as u16).unwrap_or(FontFamily::Other((font_family_bytes & 0xF0) as u16)),
+            font_pitch: FontPitch::from_bits_truncate(font_family_bytes),
+            font_weight: FontWeight::from(u32_from_input(&input[40..44])),
+            face_name: unicode_string_from_input(&input[44..108]),
+            cursor_size: CursorSize::try_from(cursor_size_bytes).unwrap_or(CursorSize::Other(cursor_size_bytes)),
+            full_screen: u32_from_input(&input[112..116]) != 0,
+            quick_edit: u32_from_input(&input[116..120]) != 0,
+            insert_mode: u32_from_input(&input[120..124]) != 0,
+            auto_position: u32_from_input(&input[124..128]) != 0,
+            history_buffer_size: u32_from_input(&input[128..132]),
+            number_of_history_buffers: u32_from_input(&input[132..136]),
+            history_no_dup: u32_from_input(&input[136..140]) != 0,
+            color_table,
+        })
+    }
+
+    /// Height of the console font in pixels (high word of the FontSize field)
+    pub fn font_height(&self) -> u16 {
+        (self.font_size >> 16) as u16
+    }
+
+    /// Width of the console font in pixels (low word of the FontSize field), zero for TrueType fonts
+    pub fn font_width(&self) -> u16 {
+        self.font_size as u16
+    }
+}
+
+impl ConsoleFeDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        Ok(Self {
+            block_size: check_block_size(input, 0x0000000C, false)?,
+            block_signature: u32_from_input(&input[4..8]),
+            code_page: CodePage::from(u32_from_input(&input[8..12])),
+        })
+    }
+}
+
+impl DarwinDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        let block_size = check_block_size(input, 0x00000314, false)?;
+        let darwin_data_unicode = unicode_string_from_input(&input[268..788]);
+
+        Ok(Self {
+            block_size,
+            block_signature: u32_from_input(&input[4..8]),
+            darwin_data_ansi: ansi_string_from_input(&input[8..268]),
+            darwin_data_unicode: if darwin_data_unicode.is_empty() { None } else { Some(darwin_data_unicode) },
+        })
+    }
+}
+
+impl EnvironmentVariableDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        let block_size = check_block_size(input, 0x00000314, false)?;
+        let (target_ansi, target_ansi_residue) = fixed_size_ansi_string(&input[8..268]);
+        let (target_unicode, target_unicode_residue) = fixed_size_unicode_string(&input[268..788]);
+
+        Ok(Self {
+            block_size,
+            block_signature: u32_from_input(&input[4..8]),
+            target_ansi,
+            target_ansi_residue: target_ansi_residue.to_vec(),
+            target_unicode: if target_unicode.is_empty() { None } else { Some(target_unicode) },
+            target_unicode_residue: target_unicode_residue.to_vec(),
+        })
+    }
+}
+
+impl IconEnvironmentDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        let block_size = check_block_size(input, 0x00000314, false)?;
+        let (target_ansi, target_ansi_residue) = fixed_size_ansi_string(&input[8..268]);
+        let (target_unicode, target_unicode_residue) = fixed_size_unicode_string(&input[268..788]);
+
+        Ok(Self {
+            block_size,
+            block_signature: u32_from_input(&input[4..8]),
+            target_ansi,
+            target_ansi_residue: target_ansi_residue.to_vec(),
+            target_unicode: if target_unicode.is_empty() { None } else { Some(target_unicode) },
+            target_unicode_residue: target_unicode_residue.to_vec(),
+        })
+    }
+}
+
+impl KnownFolderDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        Ok(Self {
+            block_size: check_block_size(input, 0x0000001C, false)?,
+            block_signature: u32_from_input(&input[4..8]),
+            known_folder_id: Guid::from_bytes(&input[8..24]),
+            offset: u32_from_input(&input[24..28]),
+        })
+    }
+}
+
+impl PropertyStoreDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        Ok(Self {
+            block_size: check_block_size(input, 0x0000000C, true)?,
+            block_signature: u32_from_input(&input[4..8]),
+            // storages that can't be parsed are kept as raw bytes in the property store
+            property_store: PropertyStore::from_bytes_partial(&input[8..]),
+        })
+    }
+}
+
+/// Compatibility layer that requests elevation for the link target
+const RUN_AS_ADMIN_LAYER: &str = "RUNASADMIN";
+
+impl ShimDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        Ok(Self {
+            block_size: check_block_size(input, 0x00000088, true)?,
+            block_signature: u32_from_input(&input[4..8]),
+            layer_name: unicode_string_from_input(&input[8..]),
+        })
+    }
+
+    /// The individual compatibility layers, e.g. `["RUNASADMIN", "WINXPSP3"]` for
+    /// `"~ RUNASADMIN WINXPSP3"`. The `~` and `$` markers used in the AppCompatFlags registry
+    /// notation are skipped.
+    pub fn layers(&self) -> Vec<&str> {
+        self.layer_name.split_whitespace().filter(|layer| *layer != "~" && *layer != "$").collect()
+    }
+
+    /// Whether the shim layers force the link target to run elevated
+    pub fn runs_as_admin(&self) -> bool {
+        self.layers().iter().any(|layer| layer.eq_ignore_ascii_case(RUN_AS_ADMIN_LAYER))
+    }
+}
+
+impl SpecialFolderDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        Ok(Self {
+            block_size: check_block_size(input, 0x00000010, false)?,
+            block_signature: u32_from_input(&input[4..8]),
+            special_folder_id: u32_from_input(&input[8..12]),
+            offset: u32_from_input(&input[12..16]),
+        })
+    }
+}
+
+impl TrackerDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        Ok(Self {
+            block_size: check_block_size(input, 0x00000060, false)?,
+            block_signature: u32_from_input(&input[4..8]),
+            length: u32_from_input(&input[8..12]),
+            version: u32_from_input(&input[12..16]),
+            machine_id: ansi_string_from_input(&input[16..32]),
+            droid: Droid::from_bytes(&input[32..64]),
+            droid_birth: Droid::from_bytes(&input[64..96]),
+        })
+    }
+}
+
+impl VistaAndAboveIdListDataBlock {
+    pub fn try_from(input: &[u8]) -> Result<Self, ExtraDataParseError> {
+        Ok(Self {
+            block_size: check_block_size(input, 0x0000000A, true)?,
+            block_signature: u32_from_input(&input[4..8]),
+            id_list: IdList::try_from(&input[8..]).map_err(ExtraDataParseError::InvalidIdList)?,
+        })
+    }
+}
+
 /// A 32-bit, unsigned integer that specifies the size of the cursor, in pixels, used
 /// in the console window.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -853,6 +1779,8 @@ pub enum CursorSize {
     Small(u32),
     Medium(u32),
     Large(u32),
+    /// A size larger than 100, which is not valid
+    Other(u32),
 }
 
 impl CursorSize {
@@ -873,7 +1801,7 @@ impl From<CursorSize> for u32 {
     fn from(input: CursorSize) -> u32 {
         use self::CursorSize::*;
         match input {
-            Small(i) | Medium(i) | Large(i) => i,
+            Small(i) | Medium(i) | Large(i) | Other(i) => i,
         }
     }
 }
@@ -910,47 +1838,713 @@ pub enum FontFamily {
     Script,
     /// The font is a novelty font; for example, "Old English".
     Decorative,
+    /// A family that is not one of the FF_* values above
+    Other(u16),
 }
 
 impl FontFamily {
     pub fn try_from(input: u16) -> Option<Self> {
         FONT_FAMILY_MAP.iter()
         .find(|x| x.1 == input)
-        .and_then(|out| Some(out.0))
+        .map(|out| out.0)
     }
 }
 
 impl From<FontFamily> for u16 {
     fn from(input: FontFamily) -> u16 {
-        FONT_FAMILY_MAP.iter()
-        .find(|x| x.0 == input)
-        .and_then(|out| Some(out.1))
-        .unwrap()
+        match input {
+            FontFamily::Other(font_family) => font_family,
+            named => FONT_FAMILY_MAP.iter().find(|x| x.0 == named).map(|out| out.1).unwrap(),
+        }
     }
 }
 
-/// A 16-bit, unsigned integer that specifies the stroke weight of the font used in
+bitflags! {
+    /// Pitch and technology flags that can be combined with the FontFamily value.
+    pub struct FontPitch: u32 {
+        /// Despite its name, the font is variable-pitch if this bit is set.
+        const FixedPitch = 0x0001;
+        /// The font is a vector font.
+        const Vector = 0x0002;
+        /// The font is a TrueType font.
+        const TrueType = 0x0004;
+        /// The font is a device font.
+        const Device = 0x0008;
+    }
+}
+
+// FW_BOLD, weights at or above it are displayed bold
+const FW_BOLD: u32 = 700;
+
+/// A 32-bit, unsigned integer that specifies the stroke weight of the font used in
 /// the console window.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum FontWeight {
-    Regular,
-    Bold,
+    /// A font weight below 700 (`FW_NORMAL` is 400)
+    Regular(u32),
+    /// A font weight of 700 (`FW_BOLD`) or above
+    Bold(u32),
+}
+
+impl From<u32> for FontWeight {
+    fn from(input: u32) -> FontWeight {
+        if input >= FW_BOLD {
+            FontWeight::Bold(input)
+        } else {
+            FontWeight::Regular(input)
+        }
+    }
+}
+
+impl From<FontWeight> for u32 {
+    fn from(input: FontWeight) -> u32 {
+        use self::FontWeight::*;
+        match input {
+            Regular(i) | Bold(i) => i,
+        }
+    }
 }
 
 bitflags! {
+    /// The foreground and background text colors in the console window. The four foreground
+    /// bits and the four background bits each form an index into the ColorTable, the
+    /// COMMON_LVB_* bits above them select additional character attributes.
     pub struct FillAttributes: u16 {
         const ForegroundBlue = 0x0001;
         const ForegroundGreen = 0x0002;
         const ForegroundRed = 0x0004;
-        const BackgroundBlue = 0x0008;
+        const ForegroundIntensity = 0x0008;
+        const BackgroundBlue = 0x0010;
         const BackgroundGreen = 0x0020;
         const BackgroundRed = 0x0040;
-        const BackgroundIntense = 0x0080;
+        const BackgroundIntensity = 0x0080;
+        const LeadingByte = 0x0100;
+        const TrailingByte = 0x0200;
+        const GridHorizontal = 0x0400;
+        const GridLeftVertical = 0x0800;
+        const GridRightVertical = 0x1000;
+        const ReverseVideo = 0x4000;
+        const Underscore = 0x8000;
+    }
+}
+
+impl FillAttributes {
+    /// Index of the foreground color in the ColorTable
+    pub fn foreground_index(&self) -> usize {
+        (self.bits() & 0x0F) as usize
     }
+
+    /// Index of the background color in the ColorTable
+    pub fn background_index(&self) -> usize {
+        ((self.bits() >> 4) & 0x0F) as usize
+    }
+}
+
+/// Decodes a string defined by the system default code page. The code page is assumed to be
+/// `DEFAULT_CODE_PAGE` here, `ShellLink::decode_ansi_strings` decodes the bytes with another one.
+fn ansi_string(input: &[u8]) -> AnsiString {
+    AnsiString::decode_default(input)
+}
+
+/// Decodes a UTF-16LE string, unpaired surrogates are replaced with U+FFFD.
+fn unicode_string(input: &[u8]) -> String {
+    let chars = input.chunks(2)
+    .filter(|c| c.len() == 2)
+    .map(u16_from_input);
+    ::std::char::decode_utf16(chars)
+    .map(|c| c.unwrap_or(::std::char::REPLACEMENT_CHARACTER))
+    .collect()
+}
+
+/// Reads a NULL-terminated string, defined by the system default code page.
+/// If no NULL terminator is found, the whole input is read.
+fn ansi_string_from_input(input: &[u8]) -> AnsiString {
+    let len = input.iter().position(|byte| *byte == 0).unwrap_or(input.len());
+    ansi_string(&input[..len])
+}
+
+/// Reads a NULL-terminated UTF-16LE string. If no NULL terminator is found, the whole input is read.
+fn unicode_string_from_input(input: &[u8]) -> String {
+    let len = input.chunks(2).position(|c| c == [0, 0]).unwrap_or(input.len() / 2);
+    unicode_string(&input[..len * 2])
+}
+
+/// Reads a NULL-terminated string from a fixed-size buffer, defined by the system default code page.
+/// Returns the string and the bytes after the NULL terminator (empty if there is no terminator).
+fn fixed_size_ansi_string(input: &[u8]) -> (AnsiString, &[u8]) {
+    match input.iter().position(|byte| *byte == 0) {
+        Some(len) => (ansi_string(&input[..len]), &input[len + 1..]),
+        None => (ansi_string(input), &[]),
+    }
+}
+
+/// Reads a NULL-terminated UTF-16LE string from a fixed-size buffer. Returns the string and the
+/// bytes after the NULL terminator (empty if there is no terminator).
+fn fixed_size_unicode_string(input: &[u8]) -> (String, &[u8]) {
+    match input.chunks(2).position(|c| c == [0, 0]) {
+        Some(len) => (unicode_string(&input[..len * 2]), &input[len * 2 + 2..]),
+        None => (unicode_string(input), &[]),
+    }
+}
+
+#[cfg(test)]
+fn synthetic_program_data_link() -> ShellLink {
+    // written by assets/make_program_data_lnk.py, not captured from a Windows machine
+    ShellLink::try_from(&include_bytes!("../assets/ProgramData.lnk")[..]).unwrap()
 }
 
 #[test]
-fn parse_program_data_file() {
-    const BYTES: &[u8] = include_bytes!("../assets/ProgramData.lnk");
-    let shell_link = ShellLink::try_from(&BYTES);
-    println!("shell_link: {:#?}", shell_link);
+fn parse_synthetic_link_header() {
+    let header = synthetic_program_data_link().header;
+    assert_eq!(header.link_flags, shell_link_header::LinkFlags::HasLinkTargetIDList | shell_link_header::LinkFlags::HasLinkInfo | shell_link_header::LinkFlags::HasRelativePath | shell_link_header::LinkFlags::HasWorkingDir | shell_link_header::LinkFlags::IsUnicode | shell_link_header::LinkFlags::EnableTargetMetadata);
+    assert_eq!(header.creation_time.unwrap().to_string(), "2019-03-19T06:54:12.6123450Z");
+    assert_eq!(header.write_time.unwrap().ticks(), 132_201_836_923_031_200);
+}
+
+#[test]
+fn parse_synthetic_link_id_list() {
+    let id_list = synthetic_program_data_link().link_target_id_list.unwrap();
+    assert_eq!(id_list.id_list_size, 0x8F);
+    let sizes: Vec<u16> = id_list.id_list.item_id_list.iter().map(|item| item.item_id_size).collect();
+    assert_eq!(sizes, vec![0x14, 0x19, 0x60]);
+    assert_eq!(id_list.id_list.item_id_list[0].data[0], 0x1F);
+    // the path uses the long name from the 0xBEEF0004 block rather than the 8.3 primary name
+    assert_eq!(id_list.id_list.path(&shell_folder::ShellFolderRegistry::new()), "My Computer\\C:\\ProgramData");
+}
+
+#[test]
+fn decode_synthetic_link_shell_items() {
+    let shell_link = synthetic_program_data_link();
+    let id_list = shell_link.link_target_id_list.unwrap();
+    match id_list.id_list.item_id_list[1].decode().unwrap() {
+        shell_item::ShellItem::Volume(item) => {
+            assert_eq!(item.drive_path.as_deref(), Some("C:\\"));
+            assert_eq!(item.trailing_data, vec![0; 18]);
+        },
+        other => panic!("{:?}", other),
+    }
+    match id_list.id_list.item_id_list[2].decode().unwrap() {
+        shell_item::ShellItem::FileEntry(item) => {
+            assert_eq!(item.primary_name, "PROGRA~3");
+            assert!(item.is_directory());
+            assert_eq!(item.modification_time.unwrap().to_file_time().unwrap().unix_seconds(), shell_link.header.write_time.unwrap().unix_seconds());
+            assert!(item.file_attributes.contains(shell_link_header::FileAttributes::Hidden));
+        },
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn decode_synthetic_link_file_entry_extension() {
+    let id_list = synthetic_program_data_link().link_target_id_list.unwrap();
+    let item = match id_list.id_list.item_id_list[2].decode().unwrap() {
+        shell_item::ShellItem::FileEntry(item) => item,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(item.extension_blocks.len(), 1);
+    assert_eq!((item.extension_blocks[0].signature, item.extension_blocks[0].version), (0xBEEF0004, 9));
+    let extension = item.extension.unwrap();
+    assert_eq!(extension.long_name, "ProgramData");
+    assert_eq!(extension.localized_name, None);
+    assert_eq!(extension.long_name_offset, 0x2E);
+    assert_eq!(extension.creation_time.unwrap().to_string(), "2019-03-19T06:54:12");
+    assert_eq!(extension.mft_reference.map(|reference| reference.to_string()), Some("8094-2".to_string()));
+}
+
+#[test]
+fn parse_synthetic_link_info() {
+    let link_info = synthetic_program_data_link().link_info.unwrap();
+    assert_eq!(link_info.link_info_size_header, LinkInfoHeaderSize::Unspecified);
+    assert_eq!(link_info.local_base_path.as_ref().map(AnsiString::as_str), Some("C:\\ProgramData"));
+    assert_eq!(link_info.common_path_suffix, "");
+    assert!(link_info.common_network_relative_link.is_none());
+    assert_eq!(link_info.target_path(), Some("C:\\ProgramData".to_string()));
+    let volume_id = link_info.volume_id.unwrap();
+    assert_eq!(volume_id.drive_type, DriveType::Fixed);
+    assert_eq!(volume_id.drive_serial_number, 0xC4A2E67B);
+    assert_eq!(volume_id.volume_label.as_ref().map(AnsiString::as_str), Some(""));
+}
+
+#[test]
+fn parse_synthetic_link_string_data() {
+    let shell_link = synthetic_program_data_link();
+    assert_eq!(shell_link.name, None);
+    assert_eq!(shell_link.relative_path.unwrap().string, "..\\..\\..\\..\\..\\ProgramData");
+    assert_eq!(shell_link.working_dir.unwrap().string, "C:\\ProgramData");
+    assert_eq!(shell_link.arguments, None);
+    assert_eq!(shell_link.icon_location, None);
+}
+
+#[test]
+fn parse_synthetic_link_tracker_data_block() {
+    let shell_link = synthetic_program_data_link();
+    let block = shell_link.extra_data.iter().filter_map(|block| match *block {
+        ExtraData::TrackerProps(ref block) => Some(block),
+        _ => None,
+    }).next().unwrap();
+    assert_eq!(block.machine_id, "desktop-7o2l8ab");
+    assert_eq!(block.droid, block.droid_birth);
+    assert_eq!(block.droid.volume_id.to_string(), "{94C3D7A1-6B0E-4F2D-9E2A-5C7B1D3E8F10}");
+    assert_eq!(block.droid.object_id.to_string(), "{CD9F71BA-4A13-11E9-9234-0800275A1C3E}");
+    let uuid = block.birth_object_uuid().unwrap();
+    assert_eq!(uuid.mac_address.to_string(), "08:00:27:5a:1c:3e");
+    assert_eq!(Some(uuid.timestamp), shell_link.header.creation_time);
+}
+
+#[test]
+fn parse_synthetic_link_property_store_data_block() {
+    use property_store::PropertyName;
+    use property_value::PropertyValue;
+
+    let shell_link = synthetic_program_data_link();
+    let block = shell_link.extra_data.iter().filter_map(|block| match *block {
+        ExtraData::PropertyStoreProps(ref block) => Some(block),
+        _ => None,
+    }).next().unwrap();
+    assert_eq!(block.property_store.error, None);
+    let storage = block.property_store.storages.iter()
+        .find(|storage| storage.format_id.to_string() == "{B725F130-47EF-101A-A5F1-02608C9EEBAC}")
+        .unwrap();
+    let names: Vec<_> = storage.properties.iter().map(|p| p.name.clone()).collect();
+    assert_eq!(names, vec![PropertyName::Id(10), PropertyName::Id(14)]);
+    assert_eq!(storage.get(&PropertyName::Id(10)), Some(&PropertyValue::LpWStr("ProgramData".to_string())));
+    assert_eq!(storage.get(&PropertyName::Id(14)), Some(&PropertyValue::FileTime(shell_link.header.write_time.unwrap())));
+}
+
+#[test]
+fn parse_synthetic_link_sid_property() {
+    use property_store::PropertyName;
+    use property_value::PropertyValue;
+
+    let shell_link = synthetic_program_data_link();
+    let block = shell_link.extra_data.iter().filter_map(|block| match *block {
+        ExtraData::PropertyStoreProps(ref block) => Some(block),
+        _ => None,
+    }).next().unwrap();
+    let storage = &block.property_store.storages[0];
+    assert_eq!(storage.format_id.to_string(), "{46588AE2-4CBC-4338-BBFC-139326986DCE}");
+    match storage.get(&PropertyName::Id(4)) {
+        Some(&PropertyValue::Sid(value_type, ref sid)) => {
+            assert_eq!(value_type, 0x1F);
+            assert_eq!(sid.to_string(), "S-1-5-21-1004336348-1177238915-682003330-1001");
+            assert_eq!(sid.rid(), Some(1001));
+        },
+        ref other => panic!("unexpected value {:?}", other),
+    }
+}
+
+#[test]
+fn name_synthetic_link_properties() {
+    use property_key::PropertyKeyRegistry;
+    use property_value::PropertyValue;
+
+    let shell_link = synthetic_program_data_link();
+    let property_store = shell_link.extra_data.iter().filter_map(|block| match *block {
+        ExtraData::PropertyStoreProps(ref block) => Some(&block.property_store),
+        _ => None,
+    }).next().unwrap();
+    let registry = PropertyKeyRegistry::new();
+    let names: Vec<_> = property_store.named_properties(&registry).into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["SID", "System.ItemNameDisplay", "System.DateModified"]);
+    let key = registry.key("System.ItemNameDisplay").unwrap();
+    assert_eq!(property_store.get(&key).and_then(PropertyValue::as_str), Some("ProgramData"));
+}
+
+#[test]
+fn parse_synthetic_link_folder_data_blocks() {
+    let shell_link = synthetic_program_data_link();
+    let id_list = &shell_link.link_target_id_list.as_ref().unwrap().id_list;
+
+    let special_folder = shell_link.extra_data.iter().filter_map(|block| match *block {
+        ExtraData::SpecialFolderProps(ref block) => Some(block),
+        _ => None,
+    }).next().unwrap();
+    assert_eq!(special_folder.name(), Some("CSIDL_COMMON_APPDATA"));
+    assert_eq!(special_folder.child_item(id_list).map(|out| out.0), Some(2));
+
+    let known_folder = shell_link.extra_data.iter().filter_map(|block| match *block {
+        ExtraData::KnownFolderProps(ref block) => Some(block),
+        _ => None,
+    }).next().unwrap();
+    assert_eq!(known_folder.known_folder_id.to_string(), "{62AB5D82-FDC1-4DC3-A9DD-070D1D495D97}");
+    assert_eq!(known_folder.name(), Some("FOLDERID_ProgramData"));
+    assert_eq!(known_folder.default_path(), Some("%ALLUSERSPROFILE%"));
+    let (index, item_id) = known_folder.child_item(id_list).unwrap();
+    assert_eq!((index, item_id.item_id_size), (2, 0x60));
+}
+
+#[test]
+fn parse_environment_variable_data_block() {
+    let mut block = vec![0; 0x314];
+    block[0..8].copy_from_slice(&[0x14, 0x03, 0x00, 0x00, 0x01, 0x00, 0x00, 0xA0]);
+    // a shorter path written over a longer one, leaving its tail after the terminator
+    block[8..8 + 25].copy_from_slice(b"%windir%\\system32\\cmd.exe");
+    block[8..8 + 13].copy_from_slice(b"%TEMP%\\a.txt\0");
+    for (i, c) in "%TEMP%\\a.txt".encode_utf16().enumerate() {
+        block[268 + i * 2..270 + i * 2].copy_from_slice(&[c as u8, (c >> 8) as u8]);
+    }
+
+    let env = EnvironmentVariableDataBlock::try_from(&block).unwrap();
+    assert_eq!(env.target_ansi, "%TEMP%\\a.txt");
+    assert_eq!(env.target_ansi_residue.len(), 260 - 13);
+    assert_eq!(&env.target_ansi_residue[..12], b"em32\\cmd.exe");
+    assert_eq!(env.target_unicode.as_deref(), Some("%TEMP%\\a.txt"));
+    assert_eq!(env.target_unicode_residue, vec![0; 520 - 26]);
+
+    // without a Unicode string
+    block[268..788].copy_from_slice(&[0; 520]);
+    let icon = IconEnvironmentDataBlock::try_from(&block).unwrap();
+    assert_eq!(icon.target_unicode, None);
+    assert_eq!(icon.target_unicode_residue.len(), 518);
+}
+
+#[test]
+fn parse_console_fe_data_block() {
+    let block = [0x0C, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0xA0, 0xA4, 0x03, 0x00, 0x00];
+    let console_fe = ConsoleFeDataBlock::try_from(&block).unwrap();
+    assert_eq!(console_fe.code_page, CodePage::ShiftJis);
+    assert_eq!(console_fe.code_page.name(), Some("shift_jis"));
+}
+
+#[test]
+fn parse_shim_data_block() {
+    let mut block = vec![0; 0x88];
+    block[0..8].copy_from_slice(&[0x88, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0xA0]);
+    for (i, c) in "~ WINXPSP3 RunAsAdmin".encode_utf16().enumerate() {
+        block[8 + i * 2..10 + i * 2].copy_from_slice(&[c as u8, (c >> 8) as u8]);
+    }
+
+    let shim = ShimDataBlock::try_from(&block).unwrap();
+    assert_eq!(shim.layer_name, "~ WINXPSP3 RunAsAdmin");
+    assert_eq!(shim.layers(), vec!["WINXPSP3", "RunAsAdmin"]);
+    assert!(shim.runs_as_admin());
+
+    assert_eq!(ShimDataBlock::try_from(&block[..0x80]), Err(ExtraDataParseError::InvalidBlockSize(0xA0000008, 0x88)));
+}
+
+#[test]
+fn parse_vista_and_above_id_list_data_block() {
+    let block = [
+        0x14, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x00, 0xA0,
+        0x0A, 0x00, 0x2F, 0x43, 0x3A, 0x5C, 0x00, 0x00, 0x00, 0x00, // volume item "C:\"
+        0x00, 0x00,                                                 // TerminalID
+    ];
+    let vista = VistaAndAboveIdListDataBlock::try_from(&block).unwrap();
+    assert_eq!(vista.id_list.item_id_list.len(), 1);
+    assert_eq!(vista.id_list.item_id_list[0].data, vec![0x2F, 0x43, 0x3A, 0x5C, 0x00, 0x00, 0x00, 0x00]);
+
+    let mut truncated = block;
+    truncated[8] = 0x20;
+    assert_eq!(
+        VistaAndAboveIdListDataBlock::try_from(&truncated),
+        Err(ExtraDataParseError::InvalidIdList(LinkTargetIdListParseError::ItemIdTooLong(0, 0x20)))
+    );
+}
+
+#[test]
+fn parse_console_data_block() {
+    fn put(block: &mut [u8], offset: usize, bytes: &[u8]) { block[offset..offset + bytes.len()].copy_from_slice(bytes); }
+
+    let mut block = [0; 0xCC];
+    put(&mut block, 0, &[0xCC, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0xA0]);
+    put(&mut block, 8, &[0x07, 0x00, 0xF5, 0x00]);                    // fill attributes, popup fill attributes
+    put(&mut block, 12, &[0x78, 0x00, 0xB8, 0x0B, 0x78, 0x00, 0x1E, 0x00]); // 120x3000 buffer, 120x30 window
+    put(&mut block, 20, &[0xFF, 0xFF, 0x10, 0x00]);                   // origin (-1, 16)
+    put(&mut block, 32, &[0x00, 0x00, 0x10, 0x00, 0x36, 0x00, 0x00, 0x00, 0x90, 0x01, 0x00, 0x00]);
+    for (i, c) in "Consolas".encode_utf16().enumerate() {
+        put(&mut block, 44 + i * 2, &[c as u8, (c >> 8) as u8]);
+    }
+    put(&mut block, 108, &[0x19, 0x00, 0x00, 0x00]);                  // cursor size
+    put(&mut block, 116, &[0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    put(&mut block, 128, &[0x32, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]);
+    put(&mut block, 140 + 15 * 4, &[0xF2, 0xF2, 0xF2, 0x00]);
+
+    let console = match ExtraData::try_from(&block).unwrap() {
+        ExtraData::ConsoleProps(console) => console,
+        other => panic!("unexpected block {:?}", other),
+    };
+
+    assert_eq!(console.fill_attributes, FillAttributes::ForegroundBlue | FillAttributes::ForegroundGreen | FillAttributes::ForegroundRed);
+    assert_eq!(console.popup_fill_attributes.foreground_index(), 5);
+    assert_eq!(console.popup_fill_attributes.background_index(), 15);
+    assert_eq!((console.screen_buffer_size_x, console.screen_buffer_size_y), (120, 3000));
+    assert_eq!((console.window_size_x, console.window_size_y), (120, 30));
+    assert_eq!((console.window_origin_x, console.window_origin_y), (-1, 16));
+    assert_eq!((console.font_width(), console.font_height()), (0, 16));
+    assert_eq!(console.font_family, FontFamily::Modern);
+    assert_eq!(console.font_pitch, FontPitch::Vector | FontPitch::TrueType);
+    assert_eq!(console.font_weight, FontWeight::Regular(400));
+    assert_eq!(console.face_name, "Consolas");
+    assert_eq!(console.cursor_size, CursorSize::Small(25));
+    assert_eq!((console.full_screen, console.quick_edit, console.insert_mode, console.auto_position), (false, true, true, false));
+    assert_eq!((console.history_buffer_size, console.number_of_history_buffers, console.history_no_dup), (50, 4, true));
+    assert_eq!(console.color_table[15], 0x00F2F2F2);
+
+    // unknown font families and cursor sizes are kept
+    put(&mut block, 36, &[0x76, 0x00, 0x00, 0x00]);
+    put(&mut block, 108, &[0xC8, 0x00, 0x00, 0x00]);
+    let console = ConsoleDataBlock::try_from(&block).unwrap();
+    assert_eq!(console.font_family, FontFamily::Other(0x70));
+    assert_eq!(console.cursor_size, CursorSize::Other(200));
+
+    // COMMON_LVB_* bits are kept next to the colors, undefined bits are ignored
+    put(&mut block, 8, &[0x07, 0xA1]);
+    let console = ConsoleDataBlock::try_from(&block).unwrap();
+    assert_eq!(console.fill_attributes, FillAttributes::ForegroundBlue | FillAttributes::ForegroundGreen
+        | FillAttributes::ForegroundRed | FillAttributes::LeadingByte | FillAttributes::Underscore);
+    assert_eq!(console.fill_attributes.foreground_index(), 7);
+    assert_eq!(console.fill_attributes.background_index(), 0);
+}
+
+#[test]
+fn parse_extra_data_section() {
+    use error::ExtraDataParseError::*;
+
+    let section = [
+        // SpecialFolderDataBlock
+        0x10, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0xA0, 0x23, 0x00, 0x00, 0x00, 0x2D, 0x00, 0x00, 0x00,
+        // unknown block
+        0x0A, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0xA0, 0xAB, 0xCD,
+        // TerminalBlock
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let extra_data = ExtraData::try_from_section(&section).unwrap();
+    assert_eq!(extra_data.len(), 2);
+    assert_eq!(extra_data[1], ExtraData::Unknown { signature: 0xA000000A, data: vec![0xAB, 0xCD] });
+    match extra_data[0] {
+        ExtraData::SpecialFolderProps(ref block) => {
+            assert_eq!(block.special_folder_id, 0x23);
+            assert_eq!(block.offset, 0x2D);
+        },
+        ref other => panic!("unexpected block {:?}", other),
+    }
+
+    assert_eq!(ExtraData::try_from_section(&section[..20]), Err(ExtraDataBlockTruncated(0x0A, 4)));
+}
+
+#[test]
+fn parse_extra_data_section_with_invalid_block() {
+    use error::ExtraDataParseError::*;
+
+    let section = [
+        // SpecialFolderDataBlock
+        0x10, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0xA0, 0x23, 0x00, 0x00, 0x00, 0x2D, 0x00, 0x00, 0x00,
+        // SpecialFolderDataBlock, which must be exactly 0x10 bytes long
+        0x0C, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0xA0, 0x23, 0x00, 0x00, 0x00,
+        // unknown block
+        0x0A, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0xA0, 0xAB, 0xCD,
+        // TerminalBlock
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let extra_data = ExtraData::try_from_section(&section).unwrap();
+    assert_eq!(extra_data.len(), 3);
+    match extra_data[0] {
+        ExtraData::SpecialFolderProps(ref block) => assert_eq!(block.special_folder_id, 0x23),
+        ref other => panic!("unexpected block {:?}", other),
+    }
+    assert_eq!(extra_data[1], ExtraData::Invalid {
+        signature: 0xA0000005,
+        data: vec![0x23, 0x00, 0x00, 0x00],
+        error: InvalidBlockSize(0xA0000005, 0x0C),
+    });
+    assert_eq!(extra_data[2], ExtraData::Unknown { signature: 0xA000000A, data: vec![0xAB, 0xCD] });
+    assert_eq!(ExtraData::try_from(&section[16..28]), Err(InvalidBlockSize(0xA0000005, 0x0C)));
+}
+
+#[test]
+fn parse_property_store_data_block_with_invalid_storage() {
+    let mut block = vec![0x24, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0xA0];
+    // a storage without properties, with Version 'SPS2' instead of 'SPS1'
+    block.extend_from_slice(&[0x18, 0x00, 0x00, 0x00, 0x32, 0x53, 0x50, 0x53]);
+    block.extend_from_slice(&[0x30, 0xF1, 0x25, 0xB7, 0xEF, 0x47, 0x1A, 0x10, 0xA5, 0xF1, 0x02, 0x60, 0x8C, 0x9E, 0xEB, 0xAC]);
+    block.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+
+    let block = PropertyStoreDataBlock::try_from(&block).unwrap();
+    assert!(block.property_store.storages.is_empty());
+    assert_eq!(block.property_store.unparsed.len(), 0x1C);
+    assert_eq!(block.property_store.error, Some(PropertyStoreParseError::InvalidStorageVersion(0, 0x53505332)));
+}
+
+#[test]
+fn parse_string_data() {
+    use error::StringDataParseError::*;
+
+    let unicode = [0x03, 0x00, b'-', 0x00, 0xE4, 0x00, b'v', 0x00, 0xFF];
+    let string_data = StringData::try_from(&unicode, true).unwrap();
+    assert_eq!(string_data.string, "-\u{e4}v");
+    assert_eq!(string_data.size(true), 8);
+
+    // the code page string is not NULL-terminated, a zero byte belongs to the string
+    let ansi = [0x03, 0x00, b'a', 0xE4, b'b'];
+    assert_eq!(StringData::try_from(&ansi, false).unwrap().string, "a\u{e4}b");
+
+    assert_eq!(StringData::try_from(&[0x01], true), Err(InvalidStringDataLength(1)));
+    assert_eq!(StringData::try_from(&[0x02, 0x00, b'a', 0x00], true), Err(StringDataTruncated(2, 2)));
+}
+
+#[test]
+fn parse_ansi_link_with_code_page() {
+    use code_page::DecodeMode;
+
+    // header of the fixture with only the HasName flag set, a Windows-1251 name and the terminal block
+    let mut bytes = include_bytes!("../assets/ProgramData.lnk")[..0x4C].to_vec();
+    bytes[20..24].copy_from_slice(&[0x04, 0x00, 0x00, 0x00]);
+    bytes.extend_from_slice(&[0x06, 0x00, 0xCF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2]);
+    bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+
+    let name = ShellLink::try_from(&bytes).unwrap().name.unwrap();
+    assert_eq!(name.string, "\u{cf}\u{f0}\u{e8}\u{e2}\u{e5}\u{f2}");
+    assert_eq!(name.ansi_bytes, Some(vec![0xCF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2]));
+
+    let options = ParseOptions { code_page: CodePage::Windows1251, decode_mode: DecodeMode::Strict, ..ParseOptions::default() };
+    let shell_link = ShellLink::try_from_with_options(&bytes, &options).unwrap();
+    assert_eq!(shell_link.name.unwrap().string, "Привет");
+
+    // 0x98 is not mapped in Windows-1251
+    bytes[0x4E] = 0x98;
+    assert_eq!(
+        ShellLink::try_from_with_options(&bytes, &options),
+        Err(ShellLinkParseError::StringDecodeError(CodePageDecodeError::UnmappableBytes(CodePage::Windows1251, 0)))
+    );
+}
+
+#[test]
+fn detect_ansi_link_code_page() {
+    use code_page::detection::CodePageHint;
+
+    let header = &include_bytes!("../assets/ProgramData.lnk")[..0x4C];
+    let link_with_name = |name: &[u8], extra_data: &[u8]| {
+        let mut bytes = header.to_vec();
+        bytes[20..24].copy_from_slice(&[0x04, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(&[name.len() as u8, 0x00]);
+        bytes.extend_from_slice(name);
+        bytes.extend_from_slice(extra_data);
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        ShellLink::try_from(&bytes).unwrap()
+    };
+
+    let guess = link_with_name(b"notes.txt", &[]).detect_code_page();
+    assert_eq!((guess.code_page, guess.confidence, guess.hint), (CodePage::Windows1252, Confidence::High, CodePageHint::AsciiOnly));
+
+    let guess = link_with_name(b"\xCF\xF0\xE8\xE2\xE5\xF2", &[]).detect_code_page();
+    assert_eq!((guess.code_page, guess.confidence, guess.hint), (CodePage::Windows1251, Confidence::Medium, CodePageHint::ByteFrequency));
+
+    // "デスクトップ" in Shift JIS
+    let desktop = b"\x83\x66\x83\x58\x83\x4E\x83\x67\x83\x62\x83\x76";
+    let guess = link_with_name(desktop, &[]).detect_code_page();
+    assert_eq!((guess.code_page, guess.hint), (CodePage::ShiftJis, CodePageHint::ByteFrequency));
+
+    // an EnvironmentVariableDataBlock with the Unicode copy of the Shift JIS path
+    let mut block = vec![0; 0x314];
+    block[0..8].copy_from_slice(&[0x14, 0x03, 0x00, 0x00, 0x01, 0x00, 0x00, 0xA0]);
+    block[8..8 + desktop.len()].copy_from_slice(desktop);
+    for (i, c) in "デスクトップ".encode_utf16().enumerate() {
+        block[268 + i * 2..270 + i * 2].copy_from_slice(&[c as u8, (c >> 8) as u8]);
+    }
+    let guess = link_with_name(b"a", &block).detect_code_page();
+    assert_eq!((guess.code_page, guess.confidence, guess.hint), (CodePage::ShiftJis, Confidence::High, CodePageHint::UnicodeCopies));
+
+    // "é" matches the Unicode copy in Windows-1252 first, but 0x8D of the name is only mapped in
+    // Windows-1250
+    let mut block = vec![0; 0x314];
+    block[0..8].copy_from_slice(&[0x14, 0x03, 0x00, 0x00, 0x01, 0x00, 0x00, 0xA0]);
+    block[8] = 0xE9;
+    block[268..270].copy_from_slice(&[0xE9, 0x00]);
+    let guess = link_with_name(b"\x8D", &block).detect_code_page();
+    assert_eq!((guess.code_page, guess.confidence, guess.hint), (CodePage::Windows1250, Confidence::High, CodePageHint::UnicodeCopies));
+
+    // a ConsoleFEDataBlock with OEM code page 866 hints at Windows-1251
+    let console_fe = [0x0C, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0xA0, 0x62, 0x03, 0x00, 0x00];
+    let guess = link_with_name(b"\xE4", &console_fe).detect_code_page();
+    assert_eq!((guess.code_page, guess.confidence, guess.hint), (CodePage::Windows1251, Confidence::Medium, CodePageHint::ConsoleCodePage));
+
+    let mut bytes = header.to_vec();
+    bytes[20..24].copy_from_slice(&[0x04, 0x00, 0x00, 0x00]);
+    bytes.extend_from_slice(&[0x06, 0x00, 0xCF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2, 0x00, 0x00, 0x00, 0x00]);
+    let options = ParseOptions { detect_code_page: true, ..ParseOptions::default() };
+    let shell_link = ShellLink::try_from_with_options(&bytes, &options).unwrap();
+    assert_eq!(shell_link.code_page_guess.map(|guess| guess.code_page), Some(CodePage::Windows1251));
+    assert_eq!(shell_link.name.unwrap().string, "Привет");
+}
+
+#[test]
+fn parse_network_link_info() {
+    use error::LinkInfoParseError::*;
+
+    fn push_u32(v: &mut Vec<u8>, x: u32) { v.extend_from_slice(&[x as u8, (x >> 8) as u8, (x >> 16) as u8, (x >> 24) as u8]); }
+    fn push_utf16(v: &mut Vec<u8>, s: &str) { for c in s.encode_utf16().chain(Some(0)) { v.extend_from_slice(&[c as u8, (c >> 8) as u8]); } }
+
+    // CommonNetworkRelativeLink with Unicode offsets
+    let mut cnrl = Vec::new();
+    push_u32(&mut cnrl, 0);                 // size, patched below
+    push_u32(&mut cnrl, 0x3);               // ValidDevice | ValidNetType
+    push_u32(&mut cnrl, 0x1C);              // NetNameOffset
+    push_u32(&mut cnrl, 0x2B);              // DeviceNameOffset
+    push_u32(&mut cnrl, 0x00020000);        // WNNC_NET_LANMAN
+    push_u32(&mut cnrl, 0x30);              // NetNameOffsetUnicode
+    push_u32(&mut cnrl, 0x4E);              // DeviceNameOffsetUnicode
+    cnrl.extend_from_slice(b"\\\\SERVER\\SHARE\0");
+    cnrl.extend_from_slice(b"Z:\0\0\0");
+    push_utf16(&mut cnrl, "\\\\SERVER\\SHARE");
+    push_utf16(&mut cnrl, "Z:");
+    let cnrl_len = cnrl.len() as u32;
+    cnrl[0..4].copy_from_slice(&[cnrl_len as u8, 0, 0, 0]);
+
+    // unknown flag bits are ignored, unknown provider types are kept
+    let mut unknown_provider = cnrl.clone();
+    unknown_provider[4..8].copy_from_slice(&[0x03, 0x01, 0x00, 0x00]);
+    unknown_provider[16..20].copy_from_slice(&[0x00, 0x00, 0xFF, 0x00]);
+    let unknown_provider = CommonNetworkRelativeLink::try_from(&unknown_provider).unwrap();
+    assert_eq!(unknown_provider.common_network_relative_link_flags, CommonNetworkRelativeLinkFlags::ValidDevice | CommonNetworkRelativeLinkFlags::ValidNetType);
+    assert_eq!(unknown_provider.network_provider_type, Some(NetworkProviderType::Other(0x00FF0000)));
+    assert_eq!(u32::from(NetworkProviderType::Other(0x00FF0000)), 0x00FF0000);
+
+    let mut link_info = Vec::new();
+    let cnrl_offset = 0x24;
+    let suffix_offset = cnrl_offset + cnrl_len;
+    let suffix_offset_unicode = suffix_offset + 10;
+    push_u32(&mut link_info, suffix_offset_unicode + 20);
+    push_u32(&mut link_info, 0x24);
+    push_u32(&mut link_info, 0x2);          // CommonNetworkRelativeLinkAndPathSuffix
+    push_u32(&mut link_info, 0);
+    push_u32(&mut link_info, 0);
+    push_u32(&mut link_info, cnrl_offset);
+    push_u32(&mut link_info, suffix_offset);
+    push_u32(&mut link_info, 0);
+    push_u32(&mut link_info, suffix_offset_unicode);
+    link_info.extend_from_slice(&cnrl);
+    link_info.extend_from_slice(b"notes.txt\0");
+    push_utf16(&mut link_info, "notes.txt");
+
+    let link_info = LinkInfo::try_from(&link_info).unwrap();
+    assert_eq!(link_info.link_info_size_header, LinkInfoHeaderSize::Specified(0x24));
+    assert_eq!(link_info.volume_id, None);
+    assert_eq!(link_info.local_base_path, None);
+    assert_eq!(link_info.common_path_suffix, "notes.txt");
+    assert_eq!(link_info.common_path_suffix_unicode, Some("notes.txt".to_string()));
+    assert_eq!(link_info.target_path(), Some("\\\\SERVER\\SHARE\\notes.txt".to_string()));
+
+    let cnrl = link_info.common_network_relative_link.unwrap();
+    assert_eq!(cnrl.network_provider_type, Some(NetworkProviderType::Lanman));
+    assert_eq!(cnrl.net_name, "\\\\SERVER\\SHARE");
+    assert_eq!(cnrl.device_name.as_ref().map(AnsiString::as_str), Some("Z:"));
+    assert_eq!(cnrl.net_name_unicode, Some("\\\\SERVER\\SHARE".to_string()));
+    assert_eq!(cnrl.device_name_unicode, Some("Z:".to_string()));
+
+    assert_eq!(VolumeId::try_from(&[0x11, 0x00, 0x00, 0x00]), Err(InvalidVolumeIdLength(4)));
+    assert_eq!(CommonNetworkRelativeLink::try_from(&[0x14, 0x00]), Err(InvalidCommonNetworkRelativeLinkLength(2)));
+}
+
+#[test]
+fn parse_id_list_errors() {
+    use error::LinkTargetIdListParseError::*;
+
+    assert_eq!(LinkTargetIdList::try_from(&[0x04]), Err(InvalidIdListSizeLength(1)));
+    assert_eq!(LinkTargetIdList::try_from(&[0x08, 0x00, 0x00, 0x00]), Err(IdListTruncated(8, 2)));
+    // item claims 6 bytes, but the IDList is only 4 bytes long
+    assert_eq!(LinkTargetIdList::try_from(&[0x04, 0x00, 0x06, 0x00, 0xAA, 0xBB]), Err(ItemIdTooLong(0, 6)));
+    assert_eq!(LinkTargetIdList::try_from(&[0x04, 0x00, 0x01, 0x00, 0x00, 0x00]), Err(ItemIdTooShort(0, 1)));
+    assert_eq!(LinkTargetIdList::try_from(&[0x04, 0x00, 0x04, 0x00, 0xAA, 0xBB]), Err(MissingTerminalId(4)));
 }
@@ -0,0 +1,172 @@
+//! Property keys (PROPERTYKEY, a format ID and a property ID) and their canonical names, such as
+//! `System.Link.TargetParsingPath`. The names of well-known keys are built in, others can be
+//! registered at runtime.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use guid::Guid;
+use property_store::{PropertyName, PropertyStore};
+use property_value::PropertyValue;
+
+/// Identifies a property: the format ID of its property set and its ID within the set
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct PropertyKey {
+    pub format_id: Guid,
+    pub property_id: u32,
+}
+
+impl PropertyKey {
+    pub const fn new(format_id: Guid, property_id: u32) -> Self {
+        PropertyKey {
+            format_id,
+            property_id,
+        }
+    }
+}
+
+impl fmt::Display for PropertyKey {
+    /// Formats the key as `{FMTID} PID`, the notation used by the Windows property system
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.format_id, self.property_id)
+    }
+}
+
+const FMTID_LINK: Guid = Guid::new(0xB9B4B3FC, 0x2B51, 0x4A42, [0xB5, 0xD8, 0x32, 0x41, 0x46, 0xAF, 0xCF, 0x25]);
+const FMTID_LINK_ARGUMENTS: Guid = Guid::new(0x436F2667, 0x14E2, 0x4FEB, [0xB3, 0x0A, 0x14, 0x6C, 0x53, 0xB5, 0xB6, 0x74]);
+const FMTID_LINK_TARGET_EXTENSION: Guid = Guid::new(0x7A7D76F4, 0xB630, 0x4BD7, [0x95, 0xFF, 0x37, 0xCC, 0x51, 0xA9, 0x75, 0xC9]);
+const FMTID_INTERNET_SHORTCUT: Guid = Guid::new(0x5CBF2787, 0x48CF, 0x4208, [0xB9, 0x0E, 0xEE, 0x5E, 0x5D, 0x42, 0x02, 0x94]);
+const FMTID_APP_USER_MODEL: Guid = Guid::new(0x9F4C2855, 0x9F79, 0x4B39, [0xA8, 0xD0, 0xE1, 0xD4, 0x2D, 0xE1, 0xD5, 0xF3]);
+const FMTID_STORAGE: Guid = Guid::new(0xB725F130, 0x47EF, 0x101A, [0xA5, 0xF1, 0x02, 0x60, 0x8C, 0x9E, 0xEB, 0xAC]);
+const FMTID_SHELL_DETAILS: Guid = Guid::new(0x28636AA6, 0x953D, 0x11D2, [0xB5, 0xD6, 0x00, 0xC0, 0x4F, 0xD9, 0x18, 0xD0]);
+const FMTID_FILE_NAME: Guid = Guid::new(0x41CF5AE0, 0xF75A, 0x4806, [0xBD, 0x87, 0x59, 0xC7, 0xD9, 0x24, 0x8E, 0xB9]);
+const FMTID_VOLUME: Guid = Guid::new(0x446D16B1, 0x8DAD, 0x4870, [0xA7, 0x48, 0x40, 0x2E, 0xA4, 0x3D, 0x78, 0x8C]);
+const FMTID_ITEM_PATH: Guid = Guid::new(0xE3E0584C, 0xB788, 0x4A5A, [0xBB, 0x20, 0x7F, 0x5A, 0x44, 0xC9, 0xAC, 0xDD]);
+const FMTID_KIND: Guid = Guid::new(0x1E3EE840, 0xBC2B, 0x476C, [0x82, 0x37, 0x2A, 0xCD, 0x1A, 0x83, 0x9B, 0x22]);
+/// Property set with the SID of the user that created the link. It has no documented canonical
+/// name, so the name used by common forensic tools is used instead.
+const FMTID_SID: Guid = Guid::new(0x46588AE2, 0x4CBC, 0x4338, [0xBB, 0xFC, 0x13, 0x93, 0x26, 0x98, 0x6D, 0xCE]);
+
+/// Built-in names of well-known property keys (from propkey.h)
+pub const WELL_KNOWN_PROPERTY_KEYS: [(PropertyKey, &str); 40] = [
+    (PropertyKey::new(FMTID_LINK, 2), "System.Link.TargetParsingPath"),
+    (PropertyKey::new(FMTID_LINK, 3), "System.Link.Status"),
+    (PropertyKey::new(FMTID_LINK, 5), "System.Link.Comment"),
+    (PropertyKey::new(FMTID_LINK, 8), "System.Link.TargetSFGAOFlags"),
+    (PropertyKey::new(FMTID_LINK_ARGUMENTS, 100), "System.Link.Arguments"),
+    (PropertyKey::new(FMTID_LINK_TARGET_EXTENSION, 2), "System.Link.TargetExtension"),
+    (PropertyKey::new(FMTID_INTERNET_SHORTCUT, 2), "System.Link.TargetUrl"),
+    (PropertyKey::new(FMTID_INTERNET_SHORTCUT, 21), "System.Link.Description"),
+    (PropertyKey::new(FMTID_INTERNET_SHORTCUT, 23), "System.Link.DateVisited"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 2), "System.AppUserModel.RelaunchCommand"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 3), "System.AppUserModel.RelaunchIconResource"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 4), "System.AppUserModel.RelaunchDisplayNameResource"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 5), "System.AppUserModel.ID"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 6), "System.AppUserModel.IsDestListSeparator"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 8), "System.AppUserModel.ExcludeFromShowInNewInstall"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 9), "System.AppUserModel.PreventPinning"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 11), "System.AppUserModel.IsDualMode"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 12), "System.AppUserModel.StartPinOption"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 14), "System.AppUserModel.HostEnvironment"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 15), "System.AppUserModel.PackageInstallPath"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 16), "System.AppUserModel.RecordState"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 17), "System.AppUserModel.PackageFamilyName"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 18), "System.AppUserModel.InstalledBy"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 21), "System.AppUserModel.PackageRelativeApplicationID"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 23), "System.AppUserModel.ExcludedFromLauncher"),
+    (PropertyKey::new(FMTID_APP_USER_MODEL, 26), "System.AppUserModel.ToastActivatorCLSID"),
+    (PropertyKey::new(FMTID_STORAGE, 4), "System.ItemTypeText"),
+    (PropertyKey::new(FMTID_STORAGE, 10), "System.ItemNameDisplay"),
+    (PropertyKey::new(FMTID_STORAGE, 12), "System.Size"),
+    (PropertyKey::new(FMTID_STORAGE, 13), "System.FileAttributes"),
+    (PropertyKey::new(FMTID_STORAGE, 14), "System.DateModified"),
+    (PropertyKey::new(FMTID_STORAGE, 15), "System.DateCreated"),
+    (PropertyKey::new(FMTID_STORAGE, 16), "System.DateAccessed"),
+    (PropertyKey::new(FMTID_SHELL_DETAILS, 11), "System.ItemType"),
+    (PropertyKey::new(FMTID_SHELL_DETAILS, 24), "System.ParsingName"),
+    (PropertyKey::new(FMTID_FILE_NAME, 100), "System.FileName"),
+    (PropertyKey::new(FMTID_VOLUME, 104), "System.VolumeId"),
+    (PropertyKey::new(FMTID_ITEM_PATH, 7), "System.ItemPathDisplay"),
+    (PropertyKey::new(FMTID_KIND, 3), "System.Kind"),
+    (PropertyKey::new(FMTID_SID, 4), "SID"),
+];
+
+/// Property keys whose values are SIDs, stored as strings or in binary form
+pub const SID_PROPERTY_KEYS: [PropertyKey; 1] = [
+    PropertyKey::new(FMTID_SID, 4),
+];
+
+/// Maps property keys to their canonical names. A new registry knows the
+/// `WELL_KNOWN_PROPERTY_KEYS`, more keys can be added with `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyKeyRegistry {
+    names: BTreeMap<PropertyKey, String>,
+}
+
+impl Default for PropertyKeyRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PropertyKeyRegistry {
+    /// Creates a registry containing the well-known property keys
+    pub fn new() -> Self {
+        PropertyKeyRegistry {
+            names: WELL_KNOWN_PROPERTY_KEYS.iter().map(|(key, name)| (*key, name.to_string())).collect(),
+        }
+    }
+
+    /// Registers (or renames) a property key
+    pub fn insert<S: Into<String>>(&mut self, key: PropertyKey, name: S) {
+        self.names.insert(key, name.into());
+    }
+
+    /// Returns the canonical name of the key, if it is known
+    pub fn name(&self, key: &PropertyKey) -> Option<&str> {
+        self.names.get(key).map(|name| name.as_str())
+    }
+
+    /// Returns the key with the given canonical name, if it is known
+    pub fn key(&self, name: &str) -> Option<PropertyKey> {
+        self.names.iter().find(|&(_, n)| n == name).map(|(key, _)| *key)
+    }
+}
+
+impl PropertyStore {
+    /// Returns the value of the property with the given key
+    pub fn get(&self, key: &PropertyKey) -> Option<&PropertyValue> {
+        self.storages.iter()
+        .filter(|storage| storage.format_id == key.format_id)
+        .filter_map(|storage| storage.get(&PropertyName::Id(key.property_id)))
+        .next()
+    }
+
+    /// Lists all properties of the store with a readable name: the canonical name if the key is
+    /// registered, `{FMTID} PID` or `{FMTID} Name` otherwise.
+    pub fn named_properties(&self, registry: &PropertyKeyRegistry) -> Vec<(String, &PropertyValue)> {
+        self.storages.iter().flat_map(|storage| storage.properties.iter().map(move |property| {
+            let name = match property.name {
+                PropertyName::Id(property_id) => {
+                    let key = PropertyKey::new(storage.format_id, property_id);
+                    registry.name(&key).map(|name| name.to_string()).unwrap_or_else(|| key.to_string())
+                },
+                PropertyName::Name(ref name) => format!("{} {}", storage.format_id, name),
+            };
+            (name, &property.value)
+        })).collect()
+    }
+}
+
+#[test]
+fn property_key_registry() {
+    let mut registry = PropertyKeyRegistry::new();
+    let key = PropertyKey::new(FMTID_LINK, 2);
+    assert_eq!(registry.name(&key), Some("System.Link.TargetParsingPath"));
+    assert_eq!(registry.key("System.DateModified"), Some(PropertyKey::new(FMTID_STORAGE, 14)));
+
+    let custom = PropertyKey::new(Guid::NULL, 1);
+    assert_eq!(registry.name(&custom), None);
+    registry.insert(custom, "Contoso.Custom");
+    assert_eq!(registry.name(&custom), Some("Contoso.Custom"));
+    assert_eq!(custom.to_string(), "{00000000-0000-0000-0000-000000000000} 1");
+}
@@ -0,0 +1,281 @@
+//! Serialized property storage ([MS-PROPSTORE] section 2.2), as stored in a PropertyStoreDataBlock
+//! (section 2.5.7). A property store is a sequence of storages, each holding the values of the
+//! properties of one property set (identified by its format ID).
+
+use error::PropertyStoreParseError;
+use guid::Guid;
+use property_key::{PropertyKey, SID_PROPERTY_KEYS};
+use property_value::PropertyValue;
+use shell_link_header::{u16_from_input, u32_from_input};
+use unicode_string_from_input;
+
+/// Version of a serialized property storage, 'SPS1'
+pub const PROPERTY_STORAGE_VERSION: u32 = 0x53505331;
+/// Property values of storages with this format ID are identified by name instead of by integer ID
+pub const STRING_NAMED_FORMAT_ID: Guid = Guid::new(0xD5CDD505, 0x2E9C, 0x101B, [0x93, 0x97, 0x08, 0x00, 0x2B, 0x2C, 0xF9, 0xAE]);
+
+/// Length of the StorageSize, Version and FormatID fields of a storage
+const STORAGE_HEADER_LEN: usize = 24;
+/// Length of the ValueSize, Id and Reserved fields of an integer-named property value
+const INTEGER_NAME_HEADER_LEN: usize = 9;
+/// Length of the ValueSize, NameSize and Reserved fields of a string-named property value
+const STRING_NAME_HEADER_LEN: usize = 9;
+/// Length of the Type and Padding fields of a TypedPropertyValue, the smallest possible value
+const TYPED_VALUE_HEADER_LEN: usize = 4;
+
+/// A serialized property store: a list of storages, terminated by a storage with a size of 0
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct PropertyStore {
+    pub storages: Vec<PropertyStorage>,
+    /// The input starting at the first storage that could not be parsed, empty if the whole store
+    /// was parsed
+    pub unparsed: Vec<u8>,
+    /// Why parsing stopped at `unparsed`, `None` if the whole store was parsed
+    pub error: Option<PropertyStoreParseError>,
+}
+
+/// A serialized property storage: the values of the properties of one property set
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct PropertyStorage {
+    /// A 32-bit, unsigned integer that specifies the total size, in bytes, of this structure.
+    pub storage_size: u32,
+    /// A 32-bit, unsigned integer. This value MUST be 0x53505331.
+    pub version: u32,
+    /// A GUID that specifies the semantic meaning of the property values in this storage.
+    pub format_id: Guid,
+    /// The property values, in the order in which they are stored
+    pub properties: Vec<Property>,
+}
+
+/// A serialized property value, identified by an integer ID or by a name
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Property {
+    pub name: PropertyName,
+    pub value: PropertyValue,
+}
+
+/// Identifies a property within its storage
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum PropertyName {
+    /// Integer ID of the property, used by all storages except `STRING_NAMED_FORMAT_ID`
+    Id(u32),
+    /// Name of the property, used by storages with the format ID `STRING_NAMED_FORMAT_ID`
+    Name(String),
+}
+
+impl PropertyStore {
+    /// Parses a property store, failing on the first storage that could not be parsed
+    pub fn try_from(input: &[u8]) -> Result<Self, PropertyStoreParseError> {
+        let property_store = Self::from_bytes_partial(input);
+        match property_store.error {
+            Some(e) => Err(e),
+            None => Ok(property_store),
+        }
+    }
+
+    /// Parses a property store up to the first storage that could not be parsed. The storages
+    /// before it are kept, the rest of the input goes to `unparsed` and the reason to `error`.
+    pub fn from_bytes_partial(input: &[u8]) -> Self {
+        use self::PropertyStoreParseError::*;
+
+        let mut storages = Vec::new();
+        let mut offset = 0;
+
+        let error = loop {
+            // Like the ExtraData section, tolerate a missing terminating storage at the end of input
+            if offset == input.len() {
+                break None;
+            }
+            if input.len() - offset < 4 {
+                break Some(PropertyStoreTruncated(offset, input.len() - offset));
+            }
+
+            let storage_size = u32_from_input(&input[offset..offset + 4]);
+            if storage_size == 0 {
+                break None;
+            }
+            if (storage_size as usize) < STORAGE_HEADER_LEN || storage_size as usize > input.len() - offset {
+                break Some(InvalidStorageSize(offset, storage_size));
+            }
+
+            match PropertyStorage::try_from(&input[offset..offset + storage_size as usize]) {
+                Ok(storage) => storages.push(storage),
+                Err(e) => break Some(with_offset(e, offset)),
+            }
+            offset += storage_size as usize;
+        };
+
+        Self {
+            storages,
+            unparsed: if error.is_some() { input[offset..].to_vec() } else { Vec::new() },
+            error,
+        }
+    }
+}
+
+impl PropertyStorage {
+    /// Parses a storage. Input **must** be exactly StorageSize bytes large!
+    pub fn try_from(input: &[u8]) -> Result<Self, PropertyStoreParseError> {
+        use self::PropertyStoreParseError::*;
+
+        let storage_size = u32_from_input(&input[0..4]);
+        let version = u32_from_input(&input[4..8]);
+        if version != PROPERTY_STORAGE_VERSION {
+            return Err(InvalidStorageVersion(0, version));
+        }
+
+        let format_id = Guid::from_bytes(&input[8..24]);
+        let is_string_named = format_id == STRING_NAMED_FORMAT_ID;
+
+        let mut properties = Vec::new();
+        let mut offset = STORAGE_HEADER_LEN;
+
+        loop {
+            if offset == input.len() {
+                break;
+            }
+            if input.len() - offset < 4 {
+                return Err(PropertyStoreTruncated(offset, input.len() - offset));
+            }
+
+            let value_size = u32_from_input(&input[offset..offset + 4]);
+            if value_size == 0 {
+                break;
+            }
+            if value_size as usize > input.len() - offset {
+                return Err(InvalidValueSize(offset, value_size));
+            }
+
+            let value = &input[offset..offset + value_size as usize];
+            let property = if is_string_named {
+                Property::try_from_string_named(value)
+            } else {
+                Property::try_from_integer_named(value)
+            };
+            let mut property = property.map_err(|e| with_offset(e, offset))?;
+            if let PropertyName::Id(property_id) = property.name {
+                if SID_PROPERTY_KEYS.contains(&PropertyKey::new(format_id, property_id)) {
+                    property.value = property.value.into_sid();
+                }
+            }
+            properties.push(property);
+            offset += value_size as usize;
+        }
+
+        Ok(Self {
+            storage_size,
+            version,
+            format_id,
+            properties,
+        })
+    }
+
+    /// Returns the value of the property with the given name
+    pub fn get(&self, name: &PropertyName) -> Option<&PropertyValue> {
+        self.properties.iter().find(|p| p.name == *name).map(|p| &p.value)
+    }
+}
+
+impl Property {
+    /// Parses an integer-named property value. Input **must** be exactly ValueSize bytes large!
+    fn try_from_integer_named(input: &[u8]) -> Result<Self, PropertyStoreParseError> {
+        if input.len() < INTEGER_NAME_HEADER_LEN + TYPED_VALUE_HEADER_LEN {
+            return Err(PropertyStoreParseError::InvalidValueSize(0, input.len() as u32));
+        }
+
+        Ok(Self {
+            name: PropertyName::Id(u32_from_input(&input[4..8])),
+            value: value_or_raw(&input[INTEGER_NAME_HEADER_LEN..]),
+        })
+    }
+
+    /// Parses a string-named property value. Input **must** be exactly ValueSize bytes large!
+    fn try_from_string_named(input: &[u8]) -> Result<Self, PropertyStoreParseError> {
+        use self::PropertyStoreParseError::*;
+
+        if input.len() < STRING_NAME_HEADER_LEN + TYPED_VALUE_HEADER_LEN {
+            return Err(InvalidValueSize(0, input.len() as u32));
+        }
+
+        let name_size = u32_from_input(&input[4..8]);
+        let name_end = STRING_NAME_HEADER_LEN + name_size as usize;
+        if name_end + TYPED_VALUE_HEADER_LEN > input.len() {
+            return Err(InvalidNameSize(0, name_size));
+        }
+
+        Ok(Self {
+            name: PropertyName::Name(unicode_string_from_input(&input[STRING_NAME_HEADER_LEN..name_end])),
+            value: value_or_raw(&input[name_end..]),
+        })
+    }
+}
+
+/// Decodes a TypedPropertyValue, keeping it as `PropertyValue::Raw` if it is too short for its
+/// type. Input **must** be at least 4 bytes large!
+fn value_or_raw(input: &[u8]) -> PropertyValue {
+    PropertyValue::try_from(input).unwrap_or_else(|| {
+        PropertyValue::Raw(u16_from_input(&input[0..2]), input[TYPED_VALUE_HEADER_LEN..].to_vec())
+    })
+}
+
+/// Makes the offset of an error relative to the start of the enclosing structure
+fn with_offset(e: PropertyStoreParseError, base: usize) -> PropertyStoreParseError {
+    use self::PropertyStoreParseError::*;
+    match e {
+        PropertyStoreTruncated(offset, len) => PropertyStoreTruncated(base + offset, len),
+        InvalidStorageSize(offset, size) => InvalidStorageSize(base + offset, size),
+        InvalidStorageVersion(offset, version) => InvalidStorageVersion(base + offset, version),
+        InvalidValueSize(offset, size) => InvalidValueSize(base + offset, size),
+        InvalidNameSize(offset, size) => InvalidNameSize(base + offset, size),
+    }
+}
+
+#[test]
+fn parse_property_store() {
+    use error::PropertyStoreParseError::*;
+
+    let mut store = vec![
+        0x3C, 0x00, 0x00, 0x00, 0x31, 0x53, 0x50, 0x53,             // StorageSize, 'SPS1'
+        0x05, 0xD5, 0xCD, 0xD5, 0x9C, 0x2E, 0x1B, 0x10, 0x93, 0x97, 0x08, 0x00, 0x2B, 0x2C, 0xF9, 0xAE,
+        0x20, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00,       // ValueSize, NameSize, Reserved
+        0x4E, 0x00, 0x61, 0x00, 0x6D, 0x00, 0x65, 0x00, 0x00, 0x00, // "Name"
+        0x13, 0x00, 0x00, 0x00, 0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // VT_UI4 42
+        0x00, 0x00, 0x00, 0x00,                                     // end of storage
+        0x00, 0x00, 0x00, 0x00,                                     // end of store
+    ];
+    let property_store = PropertyStore::try_from(&store).unwrap();
+    assert_eq!(property_store.storages.len(), 1);
+    let storage = &property_store.storages[0];
+    assert_eq!(storage.format_id, STRING_NAMED_FORMAT_ID);
+    let value = storage.get(&PropertyName::Name("Name".to_string()));
+    assert_eq!(value, Some(&PropertyValue::UI4(42)));
+
+    // a value that is too short for its type doesn't make the whole store fail
+    store[43] = 0x48;
+    let property_store = PropertyStore::try_from(&store).unwrap();
+    let value = property_store.storages[0].get(&PropertyName::Name("Name".to_string()));
+    assert_eq!(value, Some(&PropertyValue::Raw(0x48, vec![0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])));
+    store[43] = 0x13;
+
+    store[4] = 0x32;
+    assert_eq!(PropertyStore::try_from(&store), Err(InvalidStorageVersion(0, 0x53505332)));
+    store[4] = 0x31;
+    store[28] = 0x40;
+    assert_eq!(PropertyStore::try_from(&store), Err(InvalidNameSize(24, 0x40)));
+    store[28] = 0x0A;
+    store[0] = 0x50;
+    assert_eq!(PropertyStore::try_from(&store), Err(InvalidStorageSize(0, 0x50)));
+    store[0] = 0x3C;
+
+    // the storages before an invalid one are kept by a partial parse
+    let mut second = store[..0x3C].to_vec();
+    second[4] = 0x32;
+    let mut two_storages = store[..0x3C].to_vec();
+    two_storages.extend_from_slice(&second);
+    two_storages.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+    let property_store = PropertyStore::from_bytes_partial(&two_storages);
+    assert_eq!(property_store.storages.len(), 1);
+    assert_eq!(property_store.unparsed, &two_storages[0x3C..]);
+    assert_eq!(property_store.error, Some(InvalidStorageVersion(0x3C, 0x53505332)));
+    assert_eq!(PropertyStore::from_bytes_partial(&store).error, None);
+}
@@ -0,0 +1,228 @@
+//! Typed property values ([MS-OLEPS] section 2.15), as stored in serialized property storages.
+//! Serialized property stores always use the Unicode code page, so all string types are UTF-16.
+
+use filetime::FileTime;
+use guid::Guid;
+use sid::Sid;
+use shell_link_header::{u16_from_input, u32_from_input, u64_from_input};
+use unicode_string_from_input;
+
+pub const VT_EMPTY: u16 = 0x0000;
+pub const VT_I4: u16 = 0x0003;
+pub const VT_BSTR: u16 = 0x0008;
+pub const VT_BOOL: u16 = 0x000B;
+pub const VT_UI4: u16 = 0x0013;
+pub const VT_I8: u16 = 0x0014;
+pub const VT_UI8: u16 = 0x0015;
+pub const VT_LPSTR: u16 = 0x001E;
+pub const VT_LPWSTR: u16 = 0x001F;
+pub const VT_FILETIME: u16 = 0x0040;
+pub const VT_BLOB: u16 = 0x0041;
+pub const VT_CLSID: u16 = 0x0048;
+/// Combined with a scalar type: the value is a counted array of values of that type
+pub const VT_VECTOR: u16 = 0x1000;
+
+/// Length of the Type and Padding fields of a TypedPropertyValue
+const TYPED_VALUE_HEADER_LEN: usize = 4;
+
+/// A decoded TypedPropertyValue
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum PropertyValue {
+    /// VT_EMPTY: the property has no value
+    Empty,
+    /// VT_I4: 32-bit signed integer
+    I4(i32),
+    /// VT_UI4: 32-bit unsigned integer
+    UI4(u32),
+    /// VT_I8: 64-bit signed integer
+    I8(i64),
+    /// VT_UI8: 64-bit unsigned integer
+    UI8(u64),
+    /// VT_BOOL: VARIANT_BOOL, 0xFFFF is true and 0x0000 is false
+    Bool(bool),
+    /// VT_BSTR: length-prefixed string
+    BStr(String),
+    /// VT_LPSTR: null-terminated string
+    LpStr(String),
+    /// VT_LPWSTR: null-terminated UTF-16 string
+    LpWStr(String),
+    /// VT_FILETIME: timestamp
+    FileTime(FileTime),
+    /// VT_CLSID: GUID
+    Clsid(Guid),
+    /// VT_BLOB: length-prefixed array of bytes
+    Blob(Vec<u8>),
+    /// VT_VECTOR combined with one of the types above except VT_EMPTY, the first field is the element type
+    Vector(u16, Vec<PropertyValue>),
+    /// A VT_LPWSTR or VT_BLOB value of a property known to hold a SID (see `SID_PROPERTY_KEYS`),
+    /// the first field is the type the SID was stored as
+    Sid(u16, Sid),
+    /// A value of a type this crate does not decode, or one that is too short for its type, without
+    /// the Type and Padding fields
+    Raw(u16, Vec<u8>),
+}
+
+impl PropertyValue {
+    /// Decodes a TypedPropertyValue. Returns `None` if the input is too short for the
+    /// type of the value.
+    pub fn try_from(input: &[u8]) -> Option<Self> {
+        if input.len() < TYPED_VALUE_HEADER_LEN {
+            return None;
+        }
+
+        let value_type = u16_from_input(&input[0..2]);
+        let data = &input[TYPED_VALUE_HEADER_LEN..];
+
+        let element_type = value_type & !VT_VECTOR;
+        if value_type & VT_VECTOR != 0 && element_type != VT_EMPTY && is_supported_scalar(element_type) {
+            let count = u32_from_input(data.get(0..4)?);
+            let mut offset = 4;
+            // every element takes at least 2 bytes, so a corrupt count can't make us allocate too much
+            let mut elements = Vec::with_capacity((count as usize).min(data.len() / 2));
+            for _ in 0..count {
+                elements.push(read_scalar(element_type, data, &mut offset)?);
+                // fixed-size elements are packed, but each string or blob is padded to a multiple
+                // of 4 bytes (the padding may be missing at the end of the input)
+                if is_variable_length(element_type) {
+                    offset = (offset + 3) & !3;
+                }
+            }
+            return Some(PropertyValue::Vector(element_type, elements));
+        }
+
+        if is_supported_scalar(value_type) {
+            read_scalar(value_type, data, &mut 0)
+        } else {
+            Some(PropertyValue::Raw(value_type, data.to_vec()))
+        }
+    }
+
+    /// The VARENUM type of the value
+    pub fn value_type(&self) -> u16 {
+        use self::PropertyValue::*;
+        match *self {
+            Empty => VT_EMPTY,
+            I4(_) => VT_I4,
+            UI4(_) => VT_UI4,
+            I8(_) => VT_I8,
+            UI8(_) => VT_UI8,
+            Bool(_) => VT_BOOL,
+            BStr(_) => VT_BSTR,
+            LpStr(_) => VT_LPSTR,
+            LpWStr(_) => VT_LPWSTR,
+            FileTime(_) => VT_FILETIME,
+            Clsid(_) => VT_CLSID,
+            Blob(_) => VT_BLOB,
+            Vector(element_type, _) => VT_VECTOR | element_type,
+            Sid(value_type, _) | Raw(value_type, _) => value_type,
+        }
+    }
+
+    /// Converts a string or binary SID to `PropertyValue::Sid`, other values are returned as-is
+    pub fn into_sid(self) -> Self {
+        let sid = match self {
+            PropertyValue::LpWStr(ref s) => s.parse().ok(),
+            PropertyValue::Blob(ref b) => Sid::try_from(b).ok().filter(|sid| sid.size() == b.len()),
+            _ => None,
+        };
+        match sid {
+            Some(sid) => PropertyValue::Sid(self.value_type(), sid),
+            None => self,
+        }
+    }
+
+    /// Returns the string of a VT_LPWSTR, VT_LPSTR or VT_BSTR value
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            PropertyValue::LpWStr(ref s) | PropertyValue::LpStr(ref s) | PropertyValue::BStr(ref s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+fn is_supported_scalar(value_type: u16) -> bool {
+    matches!(value_type, VT_EMPTY | VT_I4 | VT_UI4 | VT_I8 | VT_UI8 | VT_BOOL | VT_BSTR | VT_LPSTR |
+        VT_LPWSTR | VT_FILETIME | VT_CLSID | VT_BLOB)
+}
+
+fn is_variable_length(value_type: u16) -> bool {
+    matches!(value_type, VT_BSTR | VT_LPSTR | VT_LPWSTR | VT_BLOB)
+}
+
+/// Reads a value of the given (supported, non-vector) type at the offset and advances the
+/// offset past the value, without any padding that follows it
+fn read_scalar(value_type: u16, data: &[u8], offset: &mut usize) -> Option<PropertyValue> {
+    use self::PropertyValue::*;
+
+    let mut take = |len: usize| -> Option<&[u8]> {
+        let bytes = data.get(*offset..offset.checked_add(len)?)?;
+        *offset += len;
+        Some(bytes)
+    };
+
+    let value = match value_type {
+        VT_EMPTY => Empty,
+        VT_I4 => I4(u32_from_input(take(4)?) as i32),
+        VT_UI4 => UI4(u32_from_input(take(4)?)),
+        VT_I8 => I8(u64_from_input(take(8)?) as i64),
+        VT_UI8 => UI8(u64_from_input(take(8)?)),
+        VT_BOOL => Bool(u16_from_input(take(2)?) != 0),
+        VT_FILETIME => FileTime(::filetime::FileTime::from_bytes(take(8)?)),
+        VT_CLSID => Clsid(Guid::from_bytes(take(16)?)),
+        VT_BSTR | VT_LPSTR => {
+            // CodePageString in the Unicode code page: size in bytes, then UTF-16 characters
+            let size = u32_from_input(take(4)?) as usize;
+            let string = unicode_string_from_input(take(size)?);
+            if value_type == VT_BSTR { BStr(string) } else { LpStr(string) }
+        },
+        VT_LPWSTR => {
+            let count = u32_from_input(take(4)?) as usize;
+            LpWStr(unicode_string_from_input(take(count.checked_mul(2)?)?))
+        },
+        VT_BLOB => {
+            let size = u32_from_input(take(4)?) as usize;
+            Blob(take(size)?.to_vec())
+        },
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+#[test]
+fn decode_property_values() {
+    use self::PropertyValue::*;
+
+    assert_eq!(PropertyValue::try_from(&[0x00, 0x00, 0x00, 0x00]), Some(Empty));
+    assert_eq!(PropertyValue::try_from(&[0x03, 0x00, 0x00, 0x00, 0xFE, 0xFF, 0xFF, 0xFF]), Some(I4(-2)));
+    assert_eq!(PropertyValue::try_from(&[0x0B, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00]), Some(Bool(true)));
+    assert_eq!(PropertyValue::try_from(&[0x15, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]), Some(UI8(0x2_0000_0001)));
+    assert_eq!(
+        PropertyValue::try_from(&[0x1F, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x68, 0x00, 0x69, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        Some(LpWStr("hi".to_string()))
+    );
+    assert_eq!(
+        PropertyValue::try_from(&[0x41, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x00]),
+        Some(Blob(vec![1, 2, 3]))
+    );
+
+    let vector = [
+        0x1F, 0x10, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x61, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let value = PropertyValue::try_from(&vector).unwrap();
+    assert_eq!(value, Vector(VT_LPWSTR, vec![LpWStr("a".to_string()), LpWStr(String::new())]));
+    assert_eq!(value.value_type(), 0x101F);
+
+    // VARIANT_BOOL elements are packed, only the vector as a whole is padded
+    let bools = [
+        0x0B, 0x10, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0xFF, 0xFF, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00,
+    ];
+    assert_eq!(PropertyValue::try_from(&bools), Some(Vector(VT_BOOL, vec![Bool(true), Bool(false), Bool(true)])));
+    assert_eq!(PropertyValue::try_from(&bools[..12]), None);
+
+    assert_eq!(PropertyValue::try_from(&[0x02, 0x00, 0x00, 0x00, 0x2A, 0x00]), Some(Raw(0x02, vec![0x2A, 0x00])));
+    assert_eq!(PropertyValue::try_from(&[0x48, 0x00, 0x00, 0x00, 0x00, 0x00]), None);
+}
@@ -0,0 +1,147 @@
+//! Display names of shell folders, identified by the CLSIDs stored in root folder shell items
+//! (e.g. My Computer or the Recycle Bin), and rendering of IDLists as paths.
+
+use std::collections::BTreeMap;
+use guid::Guid;
+use shell_item::ShellItem;
+use IdList;
+
+/// Built-in display names of shell folder CLSIDs
+pub const SHELL_FOLDER_NAMES: [(Guid, &str); 32] = [
+    (Guid::new(0x20D04FE0, 0x3AEA, 0x1069, [0xA2, 0xD8, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D]), "My Computer"),
+    (Guid::new(0x208D2C60, 0x3AEA, 0x1069, [0xA2, 0xD7, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D]), "My Network Places"),
+    (Guid::new(0xF02C1A0D, 0xBE21, 0x4350, [0x88, 0xB0, 0x73, 0x67, 0xFC, 0x96, 0xEF, 0x3C]), "Network"),
+    (Guid::new(0x21EC2020, 0x3AEA, 0x1069, [0xA2, 0xDD, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D]), "Control Panel"),
+    (Guid::new(0x26EE0668, 0xA00A, 0x44D7, [0x93, 0x71, 0xBE, 0xB0, 0x64, 0xC9, 0x86, 0x83]), "Control Panel"),
+    (Guid::new(0xED7BA470, 0x8E54, 0x465E, [0x82, 0x5C, 0x99, 0x71, 0x20, 0x43, 0xE0, 0x1C]), "All Tasks"),
+    (Guid::new(0xD20EA4E1, 0x3957, 0x11D2, [0xA4, 0x0B, 0x0C, 0x50, 0x20, 0x52, 0x41, 0x53]), "Administrative Tools"),
+    (Guid::new(0x7007ACC7, 0x3202, 0x11D1, [0xAA, 0xD2, 0x00, 0x80, 0x5F, 0xC1, 0x27, 0x0E]), "Network Connections"),
+    (Guid::new(0x2227A280, 0x3AEA, 0x1069, [0xA2, 0xDE, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D]), "Printers"),
+    (Guid::new(0x645FF040, 0x5081, 0x101B, [0x9F, 0x08, 0x00, 0xAA, 0x00, 0x2F, 0x95, 0x4E]), "Recycle Bin"),
+    (Guid::new(0x031E4825, 0x7B94, 0x4DC3, [0xB1, 0x31, 0xE9, 0x46, 0xB4, 0x4C, 0x8D, 0xD5]), "Libraries"),
+    (Guid::new(0x018D5C66, 0x4533, 0x4307, [0x9B, 0x53, 0x22, 0x4D, 0xE2, 0xED, 0x1F, 0xE6]), "OneDrive"),
+    (Guid::new(0x59031A47, 0x3F72, 0x44A7, [0x89, 0xC5, 0x55, 0x95, 0xFE, 0x6B, 0x30, 0xEE]), "Users Files"),
+    (Guid::new(0x450D8FBA, 0xAD25, 0x11D0, [0x98, 0xA8, 0x08, 0x00, 0x36, 0x1B, 0x11, 0x03]), "My Documents"),
+    (Guid::new(0x4336A54D, 0x038B, 0x4685, [0xAB, 0x02, 0x99, 0xBB, 0x52, 0xD3, 0xFB, 0x8B]), "Public"),
+    (Guid::new(0x679F85CB, 0x0220, 0x4080, [0xB2, 0x9B, 0x55, 0x40, 0xCC, 0x05, 0xAA, 0xB6]), "Quick Access"),
+    (Guid::new(0x22877A6D, 0x37A1, 0x461A, [0x91, 0xB0, 0xDB, 0xDA, 0x5A, 0xAE, 0xBC, 0x99]), "Recent Places"),
+    (Guid::new(0x871C5380, 0x42A0, 0x1069, [0xA2, 0xEA, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D]), "Internet Explorer"),
+    (Guid::new(0x9343812E, 0x1C37, 0x4A49, [0xA1, 0x2E, 0x4B, 0x2D, 0x81, 0x0D, 0x95, 0x6B]), "Search Home"),
+    (Guid::new(0x04731B67, 0xD933, 0x450A, [0x90, 0xE6, 0x4A, 0xCD, 0x2E, 0x94, 0x08, 0xFE]), "Search Folder"),
+    (Guid::new(0x4234D49B, 0x0245, 0x4DF3, [0xB7, 0x80, 0x38, 0x93, 0x94, 0x34, 0x56, 0xE1]), "Applications"),
+    (Guid::new(0xED228FDF, 0x9EA8, 0x4870, [0x83, 0xB1, 0x96, 0xB0, 0x2C, 0xFE, 0x0D, 0x52]), "Games"),
+    (Guid::new(0x8E908FC9, 0xBECC, 0x40F6, [0x91, 0x5B, 0xF4, 0xCA, 0x0E, 0x70, 0xD0, 0x3D]), "Network and Sharing Center"),
+    (Guid::new(0x35786D3C, 0xB075, 0x49B9, [0x88, 0xDD, 0x02, 0x98, 0x76, 0xE1, 0x1C, 0x01]), "Portable Devices"),
+    (Guid::new(0x5E591A74, 0xDF96, 0x48D3, [0x8D, 0x67, 0x17, 0x33, 0xBC, 0xEE, 0x28, 0xBA]), "Delegate Folder"),
+    // the folders in My Computer of Windows 8.1 and later
+    (Guid::new(0xB4BFCC3A, 0xDB2C, 0x424C, [0xB0, 0x29, 0x7F, 0xE9, 0x9A, 0x87, 0xC6, 0x41]), "Desktop"),
+    (Guid::new(0xD3162B92, 0x9365, 0x467A, [0x95, 0x6B, 0x92, 0x70, 0x3A, 0xCA, 0x08, 0xAF]), "Documents"),
+    (Guid::new(0x088E3905, 0x0323, 0x4B02, [0x98, 0x26, 0x5D, 0x99, 0x42, 0x8E, 0x11, 0x5F]), "Downloads"),
+    (Guid::new(0x3DFDF296, 0xDBEC, 0x4FB4, [0x81, 0xD1, 0x6A, 0x34, 0x38, 0xBC, 0xF4, 0xDE]), "Music"),
+    (Guid::new(0x24AD3AD4, 0xA569, 0x4530, [0x98, 0xE1, 0xAB, 0x02, 0xF9, 0x41, 0x7A, 0xA8]), "Pictures"),
+    (Guid::new(0xF86FA3AB, 0x70D2, 0x4FC7, [0x9C, 0x99, 0xFC, 0xBF, 0x05, 0x46, 0x7F, 0x3A]), "Videos"),
+    (Guid::new(0x0DB7E03F, 0xFC29, 0x4DC6, [0x90, 0x20, 0xFF, 0x41, 0xB5, 0x9E, 0x51, 0x3A]), "3D Objects"),
+];
+
+/// Maps shell folder CLSIDs to display names. A new registry knows the `SHELL_FOLDER_NAMES`,
+/// more CLSIDs (e.g. of shell extensions or Control Panel applets) can be added with `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellFolderRegistry {
+    names: BTreeMap<Guid, String>,
+}
+
+impl Default for ShellFolderRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShellFolderRegistry {
+    /// Creates a registry containing the built-in shell folders
+    pub fn new() -> Self {
+        ShellFolderRegistry {
+            names: SHELL_FOLDER_NAMES.iter().map(|(clsid, name)| (*clsid, name.to_string())).collect(),
+        }
+    }
+
+    /// Registers (or renames) a shell folder
+    pub fn insert<S: Into<String>>(&mut self, clsid: Guid, name: S) {
+        self.names.insert(clsid, name.into());
+    }
+
+    /// Returns the display name of the shell folder, if it is known
+    pub fn name(&self, clsid: &Guid) -> Option<&str> {
+        self.names.get(clsid).map(|name| name.as_str())
+    }
+}
+
+impl ShellItem {
+    /// Name of the item as a path component: the display name of shell folders and Control Panel
+    /// applets (the CLSID if it is not registered), the drive (or GUID) of volumes, the long name of
+    /// files (the primary name if they have none) and the location of network locations and URIs.
+    /// Other items are rendered as their class type in brackets, e.g. `[0x00]`.
+    pub fn path_component(&self, registry: &ShellFolderRegistry) -> String {
+        let clsid_name = |clsid: &Guid| registry.name(clsid).map(|name| name.to_string()).unwrap_or_else(|| clsid.to_string());
+        match *self {
+            ShellItem::RootFolder(ref item) => clsid_name(&item.clsid),
+            ShellItem::ControlPanel(ref item) => clsid_name(&item.clsid),
+            ShellItem::Volume(ref item) => match (&item.drive_path, &item.volume_guid) {
+                (Some(drive_path), _) => drive_path.clone(),
+                (None, Some(volume_guid)) => clsid_name(volume_guid),
+                (None, None) => format!("[0x{:02X}]", item.class_type),
+            },
+            ShellItem::FileEntry(ref item) => match item.extension {
+                Some(ref extension) if !extension.long_name.is_empty() => extension.long_name.clone(),
+                _ => item.primary_name.to_string(),
+            },
+            ShellItem::NetworkLocation(ref item) => item.location.to_string(),
+            ShellItem::Uri(ref item) => item.uri.clone(),
+            ShellItem::Delegate(ref item) => match item.item.decode() {
+                Ok(inner) => inner.path_component(registry),
+                Err(_) => clsid_name(&item.item_clsid),
+            },
+            ShellItem::Unknown(class_type, _) => format!("[0x{:02X}]", class_type),
+        }
+    }
+}
+
+impl IdList {
+    /// Renders the IDList as a path, e.g. `My Computer\C:\Windows`. Items that can't be decoded
+    /// are rendered as `[?]`.
+    pub fn path(&self, registry: &ShellFolderRegistry) -> String {
+        let mut path = String::new();
+        for item_id in &self.item_id_list {
+            let component = match item_id.decode() {
+                Ok(item) => item.path_component(registry),
+                Err(_) => "[?]".to_string(),
+            };
+            if !path.is_empty() && !path.ends_with('\\') {
+                path.push('\\');
+            }
+            path.push_str(&component);
+        }
+        path
+    }
+}
+
+#[test]
+fn render_id_list_path() {
+    use ItemId;
+
+    let item = |data: &[u8]| ItemId { item_id_size: data.len() as u16 + 2, data: data.to_vec() };
+    let my_computer = [0x1F, 0x50, 0xE0, 0x4F, 0xD0, 0x20, 0xEA, 0x3A, 0x69, 0x10, 0xA2, 0xD8, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D];
+    let custom = Guid::new(0x12345678, 0x9ABC, 0xDEF0, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]);
+    let mut custom_folder = vec![0x1F, 0x00];
+    custom_folder.extend_from_slice(&custom.to_bytes());
+
+    let id_list = IdList {
+        item_id_list: vec![item(&my_computer), item(b"\x2FD:\\\0"), item(b"\x31\0\0\0\0\0\0\0\0\0\x10\0USB\0")],
+    };
+    let mut registry = ShellFolderRegistry::new();
+    assert_eq!(id_list.path(&registry), "My Computer\\D:\\USB");
+
+    let id_list = IdList { item_id_list: vec![item(&custom_folder), item(&[0x00])] };
+    assert_eq!(id_list.path(&registry), "{12345678-9ABC-DEF0-1234-56789ABCDEF0}\\[0x00]");
+    registry.insert(custom, "Contoso Cloud");
+    assert_eq!(id_list.path(&registry), "Contoso Cloud\\[0x00]");
+}
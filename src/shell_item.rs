@@ -0,0 +1,740 @@
+//! Decoding of shell items, the ItemIDs written by the Windows shell namespace extensions. The
+//! first byte of an ItemID's data is a class type indicator that determines the format of the
+//! rest of the data. The formats are undocumented, the layouts follow the ones reverse engineered
+//! in libfwsi. All offsets below are relative to the class type indicator, i.e. the start of
+//! `ItemId::data`.
+
+use std::fmt;
+use guid::Guid;
+use code_page::{AnsiString, CodePage, DecodeMode, DEFAULT_CODE_PAGE};
+use error::{CodePageDecodeError, ShellItemParseError};
+use fat_time::FatDateTime;
+use shell_link_header::{FileAttributes, u16_from_input, u32_from_input};
+use {ItemId, ParseOptions, ansi_string_from_input, unicode_string_from_input};
+
+/// Class type indicator of root folder items
+const CLASS_TYPE_ROOT_FOLDER: u8 = 0x1F;
+/// Class type indicator of URI items
+const CLASS_TYPE_URI: u8 = 0x61;
+/// Class type indicator of control panel items
+const CLASS_TYPE_CONTROL_PANEL_ITEM: u8 = 0x71;
+/// Class type indicator of delegate items (e.g. the items of the Users Files folder)
+const CLASS_TYPE_DELEGATE: u8 = 0x74;
+/// Signature of delegate items, following the class type indicator and an unknown byte
+const DELEGATE_SIGNATURE: &[u8] = b"CFSF";
+/// Offset of the primary name of file entry items
+const FILE_ENTRY_NAME_OFFSET: usize = 12;
+/// Length of the size, version and signature fields of an extension block
+const EXTENSION_BLOCK_HEADER_LEN: usize = 8;
+/// The upper 16 bits of the signatures of extension blocks
+const EXTENSION_BLOCK_SIGNATURE_PREFIX: u32 = 0xBEEF_0000;
+/// Signature of the extension block of file entry items with the long name and NTFS file reference
+pub const FILE_ENTRY_EXTENSION_SIGNATURE: u32 = 0xBEEF_0004;
+/// Oldest known version of the file entry extension block (Windows XP)
+const FILE_ENTRY_EXTENSION_MIN_VERSION: u16 = 3;
+/// Newest known version of the file entry extension block (Windows 8 and later)
+const FILE_ENTRY_EXTENSION_MAX_VERSION: u16 = 9;
+
+/// A decoded ItemID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum ShellItem {
+    /// Class type 0x1F: a shell folder at the root of the namespace, e.g. My Computer
+    RootFolder(RootFolderShellItem),
+    /// Class types 0x20 to 0x2F: a drive
+    Volume(VolumeShellItem),
+    /// Class types 0x30 to 0x3F: a file or directory
+    FileEntry(FileEntryShellItem),
+    /// Class types 0x40 to 0x4F: a network share, server or domain
+    NetworkLocation(NetworkLocationShellItem),
+    /// Class type 0x61: a URI, e.g. of an FTP site
+    Uri(UriShellItem),
+    /// Class type 0x71: an applet in the Control Panel
+    ControlPanel(ControlPanelShellItem),
+    /// Class type 0x74: an item of a delegate folder wrapping another shell item
+    Delegate(DelegateShellItem),
+    /// An item with a class type this crate doesn't decode: the class type and the data
+    Unknown(u8, Vec<u8>),
+}
+
+/// A shell folder at the root of the namespace
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct RootFolderShellItem {
+    /// Position of the folder in the navigation pane
+    pub sort_index: u8,
+    /// Class identifier of the shell folder
+    pub clsid: Guid,
+}
+
+/// A drive
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct VolumeShellItem {
+    pub class_type: u8,
+    /// The lower 4 bits of the class type indicator
+    pub flags: VolumeShellItemFlags,
+    /// Path of the drive, e.g. `C:\`, present if the HasName flag is set
+    pub drive_path: Option<String>,
+    /// Identifier of the volume (or of the shell folder representing it), present in items
+    /// without a name, e.g. of class type 0x2E
+    pub volume_guid: Option<Guid>,
+    /// The bytes following the drive path or volume GUID, usually zero
+    pub trailing_data: Vec<u8>,
+}
+
+bitflags! {
+    /// The flags in the lower 4 bits of the class type indicator of a volume shell item
+    pub struct VolumeShellItemFlags: u8 {
+        /// The item contains the drive path
+        const HasName           = 0x01;
+        const Unknown1          = 0x02;
+        const Unknown2          = 0x04;
+        /// The volume is on removable media. Windows 7 and later write class type 0x2F, i.e. set
+        /// this flag, for all drives, so it is only meaningful in items of older versions.
+        const IsRemovableMedia  = 0x08;
+    }
+}
+
+/// A file or directory
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct FileEntryShellItem {
+    pub class_type: u8,
+    /// The lower 4 bits of the class type indicator
+    pub flags: FileEntryShellItemFlags,
+    /// Size of the file in bytes, 0 for directories and files larger than 4 GiB
+    pub file_size: u32,
+    /// Last modification time (UTC), `None` if not set
+    pub modification_time: Option<FatDateTime>,
+    /// The 16-bit DOS file attributes
+    pub file_attributes: FileAttributes,
+    /// The primary name of the file, usually the 8.3 short name. Stored in Unicode if the
+    /// HasUnicodeStrings flag is set, otherwise in the system default code page.
+    pub primary_name: ShellItemString,
+    /// The extension blocks following the primary name, e.g. 0xBEEF0004 with the long name
+    pub extension_blocks: Vec<ExtensionBlock>,
+    /// The decoded 0xBEEF0004 extension block, present in items written by Windows XP and later.
+    /// `None` if the block is missing or can't be decoded.
+    pub extension: Option<FileEntryExtension>,
+}
+
+/// A string of a shell item that is stored either in Unicode or in the system default code page,
+/// depending on the flags of the item
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum ShellItemString {
+    Unicode(String),
+    Ansi(AnsiString),
+}
+
+impl ShellItemString {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShellItemString::Unicode(ref string) => string,
+            ShellItemString::Ansi(ref string) => string.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for ShellItemString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for ShellItemString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for ShellItemString {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+/// The 0xBEEF0004 extension block of a file entry shell item. Its layout depends on the version:
+/// 3 (Windows XP), 7 (Vista), 8 (Windows 7) and 9 (Windows 8 and later).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct FileEntryExtension {
+    pub version: u16,
+    /// Creation time (UTC), `None` if not set
+    pub creation_time: Option<FatDateTime>,
+    /// Last access time (UTC), `None` if not set
+    pub access_time: Option<FatDateTime>,
+    /// Offset of the long name from the start of the block: 0x14, 0x26, 0x2A or 0x2E for versions
+    /// 3, 7, 8 and 9
+    pub long_name_offset: u16,
+    /// The NTFS file reference, present in version 7 and later if the file is on an NTFS volume
+    pub mft_reference: Option<MftReference>,
+    /// The long (Unicode) name of the file
+    pub long_name: String,
+    /// The localized name of the file (e.g. from a desktop.ini file), if it has one
+    pub localized_name: Option<String>,
+}
+
+/// An NTFS file reference: the index of the file's entry in the master file table ($MFT) and the
+/// sequence number of the entry, which is incremented whenever the entry is reused
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct MftReference {
+    /// 48-bit MFT entry number
+    pub entry: u64,
+    pub sequence: u16,
+}
+
+impl MftReference {
+    /// Parses a file reference, a 48-bit entry number followed by a 16-bit sequence number.
+    /// Input **must** be 8 bytes large!
+    pub fn from_bytes(input: &[u8]) -> Self {
+        let value = ::shell_link_header::u64_from_input(input);
+        MftReference {
+            entry: value & 0xFFFF_FFFF_FFFF,
+            sequence: (value >> 48) as u16,
+        }
+    }
+}
+
+impl fmt::Display for MftReference {
+    /// Formats the reference as `entry-sequence`, e.g. `8094-2`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.entry, self.sequence)
+    }
+}
+
+impl FileEntryExtension {
+    /// Decodes a 0xBEEF0004 extension block
+    pub fn try_from(block: &ExtensionBlock) -> Result<Self, ShellItemParseError> {
+        use self::ShellItemParseError::*;
+
+        // the layout of later versions is unknown, guessing it would yield garbage names
+        if block.version < FILE_ENTRY_EXTENSION_MIN_VERSION || block.version > FILE_ENTRY_EXTENSION_MAX_VERSION {
+            return Err(InvalidExtensionBlockVersion(block.signature, block.version));
+        }
+
+        // offsets are relative to the start of the block, `data` starts after the 8-byte header
+        let input = &block.data;
+        let truncated = || ExtensionBlockTruncated(block.signature, block.size);
+        let field = |offset: usize, len: usize| {
+            input.get(offset - EXTENSION_BLOCK_HEADER_LEN..offset - EXTENSION_BLOCK_HEADER_LEN + len).ok_or_else(truncated)
+        };
+
+        let creation_time = FatDateTime::from_bytes_nonzero(field(8, 4)?);
+        let access_time = FatDateTime::from_bytes_nonzero(field(12, 4)?);
+        let long_name_offset = u16_from_input(field(16, 2)?);
+
+        let (mft_reference, localized_name_size_offset, long_name_start) = match block.version {
+            3..=6 => (None, 18, 0x14),
+            7 => (Some(MftReference::from_bytes(field(20, 8)?)), 36, 0x26),
+            8 => (Some(MftReference::from_bytes(field(20, 8)?)), 36, 0x2A),
+            _ => (Some(MftReference::from_bytes(field(20, 8)?)), 36, 0x2E), // 9
+        };
+        // files on other file systems have no file reference
+        let mft_reference = mft_reference.filter(|reference| reference.entry != 0);
+        let localized_name_size = u16_from_input(field(localized_name_size_offset, 2)?);
+
+        let names = input.get(long_name_start - EXTENSION_BLOCK_HEADER_LEN..).ok_or_else(truncated)?;
+        let long_name = unicode_string_from_input(names);
+        let localized_name = if localized_name_size > 0 {
+            let localized_name = names.get(long_name.encode_utf16().count() * 2 + 2..).ok_or_else(truncated)?;
+            if block.version >= 7 {
+                Some(unicode_string_from_input(localized_name))
+            } else {
+                Some(ansi_string_from_input(localized_name).string)
+            }
+        } else {
+            None
+        };
+
+        Ok(FileEntryExtension {
+            version: block.version,
+            creation_time,
+            access_time,
+            long_name_offset,
+            mft_reference,
+            long_name,
+            localized_name,
+        })
+    }
+}
+
+bitflags! {
+    /// The flags in the lower 4 bits of the class type indicator of a file entry shell item
+    pub struct FileEntryShellItemFlags: u8 {
+        const IsDirectory       = 0x01;
+        const IsFile            = 0x02;
+        /// The primary name is stored in Unicode
+        const HasUnicodeStrings = 0x04;
+        const Unknown           = 0x08;
+    }
+}
+
+/// An extension block of a shell item, identified by a signature of the form 0xBEEFxxxx
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct ExtensionBlock {
+    /// Size of the block in bytes, including the size, version and signature fields
+    pub size: u16,
+    pub version: u16,
+    pub signature: u32,
+    /// The data following the signature. Its last two bytes are the offset of the block from the
+    /// start of the shell item.
+    pub data: Vec<u8>,
+}
+
+impl FileEntryShellItem {
+    /// Whether the item is a directory, according to the flags or the file attributes
+    pub fn is_directory(&self) -> bool {
+        self.flags.contains(FileEntryShellItemFlags::IsDirectory) || self.file_attributes.contains(FileAttributes::Directory)
+    }
+}
+
+impl ExtensionBlock {
+    /// Reads the extension blocks at the start of the input. Reading stops at the end of the input,
+    /// at a block without a 0xBEEFxxxx signature or at a block that doesn't fit into the input.
+    pub fn read_all(input: &[u8]) -> Vec<Self> {
+        let mut blocks = Vec::new();
+        let mut offset = 0;
+        while let Some(header) = input.get(offset..offset + EXTENSION_BLOCK_HEADER_LEN) {
+            let size = u16_from_input(&header[0..2]);
+            let signature = u32_from_input(&header[4..8]);
+            let data = match input.get(offset + EXTENSION_BLOCK_HEADER_LEN..offset + size as usize) {
+                Some(data) if signature & 0xFFFF_0000 == EXTENSION_BLOCK_SIGNATURE_PREFIX => data,
+                _ => break,
+            };
+            blocks.push(ExtensionBlock {
+                size,
+                version: u16_from_input(&header[2..4]),
+                signature,
+                data: data.to_vec(),
+            });
+            offset += size as usize;
+        }
+        blocks
+    }
+}
+
+/// A network share, server or domain
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct NetworkLocationShellItem {
+    pub class_type: u8,
+    pub flags: u8,
+    /// UNC path or name of the location, e.g. `\\server\share`
+    pub location: AnsiString,
+    /// Present if flag 0x80 is set
+    pub description: Option<AnsiString>,
+    /// Present if flag 0x40 is set
+    pub comments: Option<AnsiString>,
+}
+
+/// A URI
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct UriShellItem {
+    /// If flag 0x80 is set, the URI is stored in Unicode
+    pub flags: u8,
+    /// The data before the URI, e.g. the connection time and user name of FTP URIs
+    pub data: Vec<u8>,
+    pub uri: String,
+}
+
+/// An applet in the Control Panel
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct ControlPanelShellItem {
+    /// Class identifier of the applet
+    pub clsid: Guid,
+}
+
+/// An item of a delegate folder, wrapping the item of the folder it delegates to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct DelegateShellItem {
+    /// The wrapped item, usually a file entry
+    pub item: ItemId,
+    /// Class identifier of the delegate folder, {5E591A74-DF96-48D3-8D67-1733BCEE28BA}
+    pub delegate_clsid: Guid,
+    /// Class identifier of the folder the delegate folder delegates to
+    pub item_clsid: Guid,
+}
+
+impl ItemId {
+    /// Decodes the data of the ItemID according to its class type indicator. Items with an
+    /// unknown class type are returned as `ShellItem::Unknown`.
+    pub fn decode(&self) -> Result<ShellItem, ShellItemParseError> {
+        ShellItem::try_from(&self.data)
+    }
+
+    /// Decodes the data of the ItemID like `decode`, decoding strings that are not stored in
+    /// Unicode with the code page and decode mode of `options`. `detect_code_page` is ignored,
+    /// a single item has too little text to guess its code page.
+    pub fn decode_with_options(&self, options: &ParseOptions) -> Result<ShellItem, ShellItemParseError> {
+        let mut item = self.decode()?;
+        if options.code_page != DEFAULT_CODE_PAGE || options.decode_mode != DecodeMode::Lossy {
+            item.decode_ansi_strings(options.code_page, options.decode_mode)
+                .map_err(ShellItemParseError::StringDecodeError)?;
+        }
+        Ok(item)
+    }
+}
+
+impl ShellItem {
+    /// Decodes the strings of the item that are not stored in Unicode again with the given code
+    /// page: the primary name of file entries and the strings of network locations
+    pub fn decode_ansi_strings(&mut self, code_page: CodePage, mode: DecodeMode) -> Result<(), CodePageDecodeError> {
+        match *self {
+            ShellItem::FileEntry(ref mut item) => {
+                if let ShellItemString::Ansi(ref mut primary_name) = item.primary_name {
+                    primary_name.redecode(code_page, mode)?;
+                }
+            },
+            ShellItem::NetworkLocation(ref mut item) => {
+                item.location.redecode(code_page, mode)?;
+                for string in [&mut item.description, &mut item.comments] {
+                    if let Some(ref mut string) = *string {
+                        string.redecode(code_page, mode)?;
+                    }
+                }
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    /// Decodes the data of an ItemID, starting with the class type indicator
+    pub fn try_from(input: &[u8]) -> Result<Self, ShellItemParseError> {
+        use self::ShellItemParseError::*;
+
+        let class_type = *input.first().ok_or(EmptyShellItem)?;
+        let truncated = || ShellItemTruncated(class_type, input.len());
+
+        let item = match class_type {
+            CLASS_TYPE_ROOT_FOLDER => {
+                let data = input.get(0..18).ok_or_else(truncated)?;
+                ShellItem::RootFolder(RootFolderShellItem {
+                    sort_index: data[1],
+                    clsid: Guid::from_bytes(&data[2..18]),
+                })
+            },
+            0x20..=0x2F => {
+                let flags = VolumeShellItemFlags::from_bits_truncate(class_type);
+                let (drive_path, volume_guid, trailing_data) = if flags.contains(VolumeShellItemFlags::HasName) {
+                    let drive_path = ansi_string_from_input(&input[1..]);
+                    let trailing_data = input.get(2 + drive_path.bytes.len()..).unwrap_or(&[]);
+                    (Some(drive_path.string), None, trailing_data)
+                } else if input.len() >= 18 {
+                    // an unknown byte and the GUID
+                    (None, Some(Guid::from_bytes(&input[2..18])), &input[18..])
+                } else {
+                    (None, None, &input[1..])
+                };
+                ShellItem::Volume(VolumeShellItem {
+                    class_type,
+                    flags,
+                    drive_path,
+                    volume_guid,
+                    trailing_data: trailing_data.to_vec(),
+                })
+            },
+            0x30..=0x3F => {
+                if input.len() <= FILE_ENTRY_NAME_OFFSET {
+                    return Err(truncated());
+                }
+                let flags = FileEntryShellItemFlags::from_bits_truncate(class_type);
+
+                // the primary name is NULL-terminated and padded to a multiple of 2 bytes
+                let name = &input[FILE_ENTRY_NAME_OFFSET..];
+                let (primary_name, name_size) = if flags.contains(FileEntryShellItemFlags::HasUnicodeStrings) {
+                    let primary_name = unicode_string_from_input(name);
+                    let name_size = primary_name.encode_utf16().count() * 2 + 2;
+                    (ShellItemString::Unicode(primary_name), name_size)
+                } else {
+                    let primary_name = ansi_string_from_input(name);
+                    let name_size = (primary_name.bytes.len() + 2) & !1;
+                    (ShellItemString::Ansi(primary_name), name_size)
+                };
+                let extension_blocks = ExtensionBlock::read_all(name.get(name_size..).unwrap_or(&[]));
+                // a block that can't be decoded is still available in `extension_blocks`
+                let extension = extension_blocks.iter()
+                    .find(|block| block.signature == FILE_ENTRY_EXTENSION_SIGNATURE)
+                    .and_then(|block| FileEntryExtension::try_from(block).ok());
+
+                ShellItem::FileEntry(FileEntryShellItem {
+                    class_type,
+                    flags,
+                    file_size: u32_from_input(&input[2..6]),
+                    modification_time: FatDateTime::from_bytes_nonzero(&input[6..10]),
+                    file_attributes: FileAttributes::from_bits_truncate(u16_from_input(&input[10..12]) as u32),
+                    primary_name,
+                    extension_blocks,
+                    extension,
+                })
+            },
+            0x40..=0x4F => {
+                let flags = *input.get(2).ok_or_else(truncated)?;
+                let mut offset = 3;
+                let mut next_string = || -> Result<AnsiString, ShellItemParseError> {
+                    let string = ansi_string_from_input(input.get(offset..).ok_or_else(truncated)?);
+                    offset += string.bytes.len() + 1;
+                    Ok(string)
+                };
+                let location = next_string()?;
+                let description = if flags & 0x80 != 0 { Some(next_string()?) } else { None };
+                let comments = if flags & 0x40 != 0 { Some(next_string()?) } else { None };
+                ShellItem::NetworkLocation(NetworkLocationShellItem {
+                    class_type,
+                    flags,
+                    location,
+                    description,
+                    comments,
+                })
+            },
+            CLASS_TYPE_URI => {
+                let header = input.get(0..4).ok_or_else(truncated)?;
+                let flags = header[1];
+                let data_size = u16_from_input(&header[2..4]) as usize;
+                let data = input.get(4..4 + data_size).ok_or_else(truncated)?;
+                let uri = &input[4 + data_size..];
+                let uri = if flags & 0x80 != 0 {
+                    unicode_string_from_input(uri)
+                } else {
+                    ansi_string_from_input(uri).string
+                };
+                ShellItem::Uri(UriShellItem {
+                    flags,
+                    data: data.to_vec(),
+                    uri,
+                })
+            },
+            CLASS_TYPE_CONTROL_PANEL_ITEM => {
+                let data = input.get(12..28).ok_or_else(truncated)?;
+                ShellItem::ControlPanel(ControlPanelShellItem {
+                    clsid: Guid::from_bytes(data),
+                })
+            },
+            CLASS_TYPE_DELEGATE if input.get(2..6) == Some(DELEGATE_SIGNATURE) => {
+                // the wrapped item, including its size field, is followed by the two class identifiers
+                let item_size = u16_from_input(input.get(6..8).ok_or_else(truncated)?) as usize;
+                let item = input.get(8..6 + item_size).filter(|_| item_size >= 2).ok_or_else(truncated)?;
+                let clsids = input.get(6 + item_size..6 + item_size + 32).ok_or_else(truncated)?;
+                ShellItem::Delegate(DelegateShellItem {
+                    item: ItemId {
+                        item_id_size: item_size as u16,
+                        data: item.to_vec(),
+                    },
+                    delegate_clsid: Guid::from_bytes(&clsids[0..16]),
+                    item_clsid: Guid::from_bytes(&clsids[16..32]),
+                })
+            },
+            _ => ShellItem::Unknown(class_type, input[1..].to_vec()),
+        };
+
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+const MY_COMPUTER: Guid = Guid::new(0x20D04FE0, 0x3AEA, 0x1069, [0xA2, 0xD8, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D]);
+
+/// A file entry for "a.txt" with a Unicode primary name and a 0xBEEF0005 extension block
+#[cfg(test)]
+fn unicode_file_entry() -> Vec<u8> {
+    let mut file_entry = b"\x36\x00\x2A\x00\x00\x00\x87\x4F\xDA\x49\x20\x00".to_vec();
+    for c in "a.txt\0".encode_utf16() {
+        file_entry.extend_from_slice(&[c as u8, (c >> 8) as u8]);
+    }
+    file_entry.extend_from_slice(&[0x0A, 0x00, 0x00, 0x00, 0x05, 0x00, 0xEF, 0xBE, 0x0E, 0x00]);
+    file_entry
+}
+
+#[test]
+fn decode_root_folder_shell_item() {
+    use error::ShellItemParseError::*;
+
+    let root = [0x1F, 0x50, 0xE0, 0x4F, 0xD0, 0x20, 0xEA, 0x3A, 0x69, 0x10, 0xA2, 0xD8, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D];
+    assert_eq!(ShellItem::try_from(&root), Ok(ShellItem::RootFolder(RootFolderShellItem { sort_index: 0x50, clsid: MY_COMPUTER })));
+    assert_eq!(ShellItem::try_from(&root[..17]), Err(ShellItemTruncated(0x1F, 17)));
+}
+
+#[test]
+fn decode_volume_shell_items() {
+    let volume = b"\x2FC:\\\0\0\0";
+    assert_eq!(ShellItem::try_from(volume), Ok(ShellItem::Volume(VolumeShellItem {
+        class_type: 0x2F,
+        flags: VolumeShellItemFlags::all(),
+        drive_path: Some("C:\\".to_string()),
+        volume_guid: None,
+        trailing_data: vec![0, 0],
+    })));
+    match ShellItem::try_from(b"\x29E:\\\0").unwrap() {
+        ShellItem::Volume(item) => {
+            assert!(item.flags.contains(VolumeShellItemFlags::IsRemovableMedia));
+            assert_eq!(item.drive_path.as_deref(), Some("E:\\"));
+            assert!(item.trailing_data.is_empty());
+        },
+        other => panic!("{:?}", other),
+    }
+    let mut volume_guid = vec![0x2E, 0x00];
+    volume_guid.extend_from_slice(&MY_COMPUTER.to_bytes());
+    match ShellItem::try_from(&volume_guid).unwrap() {
+        ShellItem::Volume(item) => assert_eq!((item.drive_path, item.volume_guid), (None, Some(MY_COMPUTER))),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn decode_network_location_shell_item() {
+    let network = b"\x41\x01\xC0\\\\server\\share\0Share\0Comment\0\0\0";
+    match ShellItem::try_from(network).unwrap() {
+        ShellItem::NetworkLocation(item) => {
+            assert_eq!(item.location, "\\\\server\\share");
+            assert_eq!(item.description.unwrap(), "Share");
+            assert_eq!(item.comments.unwrap(), "Comment");
+        },
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn decode_uri_shell_item() {
+    let uri = b"\x61\x80\x00\x00h\0t\0t\0p\0:\0/\0/\0a\0\0\0";
+    assert_eq!(ShellItem::try_from(uri), Ok(ShellItem::Uri(UriShellItem { flags: 0x80, data: Vec::new(), uri: "http://a".to_string() })));
+}
+
+#[test]
+fn decode_delegate_shell_item() {
+    let mut delegate = b"\x74\x1ACFSF\x06\x00\x31\x00\x00\x00".to_vec();
+    delegate.extend_from_slice(&[0x74, 0x1A, 0x59, 0x5E, 0x96, 0xDF, 0xD3, 0x48, 0x8D, 0x67, 0x17, 0x33, 0xBC, 0xEE, 0x28, 0xBA]);
+    delegate.extend_from_slice(&MY_COMPUTER.to_bytes());
+    match ShellItem::try_from(&delegate).unwrap() {
+        ShellItem::Delegate(item) => {
+            assert_eq!(item.item.data, vec![0x31, 0x00, 0x00, 0x00]);
+            assert_eq!(item.delegate_clsid.to_string(), "{5E591A74-DF96-48D3-8D67-1733BCEE28BA}");
+            assert_eq!(item.item_clsid, MY_COMPUTER);
+        },
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn decode_file_entry_shell_item() {
+    use error::ShellItemParseError::*;
+
+    let file_entry = unicode_file_entry();
+    match ShellItem::try_from(&file_entry).unwrap() {
+        ShellItem::FileEntry(item) => {
+            assert!(!item.is_directory());
+            assert_eq!(item.file_size, 42);
+            assert_eq!(item.modification_time.map(|time| time.to_string()), Some("2019-12-07T09:14:52".to_string()));
+            assert_eq!(item.file_attributes, FileAttributes::Archive);
+            assert_eq!(item.primary_name, ShellItemString::Unicode("a.txt".to_string()));
+            assert_eq!(item.extension_blocks, vec![ExtensionBlock { size: 10, version: 0, signature: 0xBEEF0005, data: vec![0x0E, 0x00] }]);
+        },
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(ShellItem::try_from(&file_entry[..12]), Err(ShellItemTruncated(0x36, 12)));
+}
+
+#[test]
+fn decode_ansi_file_entry_name() {
+    use error::ShellItemParseError::*;
+
+    // an ANSI primary name is decoded with the code page of the ParseOptions
+    let item_id = ItemId { item_id_size: 22, data: b"\x32\x00\x00\x00\x00\x00\x00\x00\x00\x00\x10\x00\xCF\xF0\xE8\xE2\xE5\xF2\x00\x00".to_vec() };
+    match item_id.decode().unwrap() {
+        ShellItem::FileEntry(item) => assert_eq!(item.primary_name, "Ïðèâåò"),
+        other => panic!("{:?}", other),
+    }
+    let options = ParseOptions { code_page: CodePage::Windows1251, ..ParseOptions::default() };
+    match item_id.decode_with_options(&options).unwrap() {
+        ShellItem::FileEntry(item) => {
+            assert_eq!(item.primary_name, "Привет");
+            match item.primary_name {
+                ShellItemString::Ansi(ref name) => assert_eq!(name.bytes, b"\xCF\xF0\xE8\xE2\xE5\xF2"),
+                ref other => panic!("{:?}", other),
+            }
+        },
+        other => panic!("{:?}", other),
+    }
+    let options = ParseOptions { code_page: CodePage::UsAscii, decode_mode: DecodeMode::Strict, ..ParseOptions::default() };
+    assert_eq!(item_id.decode_with_options(&options), Err(StringDecodeError(CodePageDecodeError::UnmappableBytes(CodePage::UsAscii, 0))));
+}
+
+#[test]
+fn decode_file_entry_with_invalid_extension() {
+    // a 0xBEEF0004 block that can't be decoded doesn't make the whole item fail
+    let mut file_entry = unicode_file_entry();
+    file_entry[28] = 0x04;
+    match ShellItem::try_from(&file_entry).unwrap() {
+        ShellItem::FileEntry(item) => {
+            assert_eq!(item.extension, None);
+            assert_eq!(item.extension_blocks[0].signature, FILE_ENTRY_EXTENSION_SIGNATURE);
+        },
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn decode_file_entry_extension_version_3() {
+    // a version 3 (Windows XP) 0xBEEF0004 block with an ANSI localized name
+    let mut data = vec![0x87, 0x4F, 0xDA, 0x49, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x04, 0x00];
+    for c in "Docs\0".encode_utf16() {
+        data.extend_from_slice(&[c as u8, (c >> 8) as u8]);
+    }
+    data.extend_from_slice(b"Dokumente\0\x14\x00");
+    let block = ExtensionBlock { size: data.len() as u16 + 8, version: 3, signature: FILE_ENTRY_EXTENSION_SIGNATURE, data };
+    let extension = FileEntryExtension::try_from(&block).unwrap();
+    assert_eq!(extension.creation_time.map(|time| time.to_string()), Some("2019-12-07T09:14:52".to_string()));
+    assert_eq!(extension.access_time, None);
+    assert_eq!(extension.mft_reference, None);
+    assert_eq!(extension.long_name, "Docs");
+    assert_eq!(extension.localized_name.as_deref(), Some("Dokumente"));
+}
+
+/// A version 7 or later 0xBEEF0004 block with the long name "Programme" at `long_name_start`
+/// (offset from the start of the block) and a file reference at offset 20
+#[cfg(test)]
+fn long_name_extension_block(version: u16, long_name_start: usize, localized_name: &str) -> ExtensionBlock {
+    let mut data = vec![0; long_name_start - 8];
+    data[0..4].copy_from_slice(&[0x87, 0x4F, 0xDA, 0x49]);
+    data[8] = long_name_start as u8;
+    data[12..20].copy_from_slice(&[0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12, 0x02, 0x01]);
+    data[28] = localized_name.len() as u8;
+    for c in "Programme\0".encode_utf16().chain(localized_name.encode_utf16()) {
+        data.extend_from_slice(&[c as u8, (c >> 8) as u8]);
+    }
+    data.extend_from_slice(&[0x00, 0x00, 0x14, 0x00]);
+    ExtensionBlock { size: data.len() as u16 + 8, version, signature: FILE_ENTRY_EXTENSION_SIGNATURE, data }
+}
+
+#[test]
+fn decode_file_entry_extension_version_7() {
+    // Vista, the long name starts at offset 0x26
+    let extension = FileEntryExtension::try_from(&long_name_extension_block(7, 0x26, "")).unwrap();
+    assert_eq!(extension.long_name_offset, 0x26);
+    assert_eq!(extension.mft_reference, Some(MftReference { entry: 0x1234_5678_9ABC, sequence: 0x0102 }));
+    assert_eq!(extension.long_name, "Programme");
+    assert_eq!(extension.localized_name, None);
+}
+
+#[test]
+fn decode_file_entry_extension_version_8() {
+    // Windows 7, the long name starts at offset 0x2A
+    let extension = FileEntryExtension::try_from(&long_name_extension_block(8, 0x2A, "Program Files")).unwrap();
+    assert_eq!(extension.long_name_offset, 0x2A);
+    assert_eq!(extension.mft_reference.map(|reference| reference.to_string()), Some("20015998343868-258".to_string()));
+    assert_eq!(extension.long_name, "Programme");
+    assert_eq!(extension.localized_name.as_deref(), Some("Program Files"));
+}
+
+#[test]
+fn reject_invalid_file_entry_extensions() {
+    use error::ShellItemParseError::*;
+
+    let block = ExtensionBlock { size: 0x1C, version: 9, signature: FILE_ENTRY_EXTENSION_SIGNATURE, data: vec![0; 20] };
+    assert_eq!(FileEntryExtension::try_from(&block), Err(ExtensionBlockTruncated(FILE_ENTRY_EXTENSION_SIGNATURE, 0x1C)));
+    let block = ExtensionBlock { version: 2, ..block };
+    assert_eq!(FileEntryExtension::try_from(&block), Err(InvalidExtensionBlockVersion(FILE_ENTRY_EXTENSION_SIGNATURE, 2)));
+    let block = ExtensionBlock { version: 10, ..block };
+    assert_eq!(FileEntryExtension::try_from(&block), Err(InvalidExtensionBlockVersion(FILE_ENTRY_EXTENSION_SIGNATURE, 10)));
+}
+
+#[test]
+fn decode_unknown_shell_items() {
+    use error::ShellItemParseError::*;
+
+    assert_eq!(ShellItem::try_from(&[0x00, 0x01]), Ok(ShellItem::Unknown(0x00, vec![0x01])));
+    assert_eq!(ShellItem::try_from(&[]), Err(EmptyShellItem));
+}
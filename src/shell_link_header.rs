@@ -1,6 +1,7 @@
-///! Section 2.1 parser for a ShellLinkHeader
+//! Section 2.1 parser for a ShellLinkHeader
 
-use time::Tm;
+use filetime::FileTime;
+use guid::Guid;
 use error::{ShellLinkHeaderParseError, HotKeyFlagsParseError};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -14,15 +15,15 @@ pub struct ShellLinkHeader {
     /// CreationTime(8 bytes): A FILETIME structure ([[MS-DTYP]()] section 2.3.3) that specifies the creation
     /// time of the link target in UTC (Coordinated Universal Time). If the value is zero, there is no
     /// creation time set on the link target.
-    pub creation_time: Option<Tm>,
+    pub creation_time: Option<FileTime>,
     /// AccessTime (8 bytes): AccessTime (8 bytes): A FILETIME structure ([MS-DTYP] section 2.3.3) that specifies the access
     /// time of the link target in UTC (Coordinated Universal Time). If the value is zero, there is no access
     /// time set on the link target.
-    pub access_time: Option<Tm>,
+    pub access_time: Option<FileTime>,
     /// WriteTime (8 bytes): A FILETIME structure ([MS-DTYP] section 2.3.3) that specifies the write time
     /// of the link target in UTC (Coordinated Universal Time). If the value is zero, there is no write time
     /// set on the link target.
-    pub write_time: Option<Tm>,
+    pub write_time: Option<FileTime>,
     /// A 32-bit unsigned integer that specifies the size, in bytes, of the link target. If the
     /// link target file is larger than 0xFFFFFFFF, this value specifies the least significant
     /// 32 bits of the link target file size.
@@ -45,7 +46,7 @@ pub struct ShellLinkHeader {
 // Header length - 76 in decimal
 pub(crate) const HEADER_LEN: usize = 0x0000004C;
 /// LinkCLSID - class identifier of `00021401-0000-0000-C000-000000000046`
-const LINK_CLSID: [u32;4] = [0x00021401, 0x00000000, 0x000000C0, 0x46000000];
+pub const LINK_CLSID: Guid = Guid::new(0x00021401, 0x0000, 0x0000, [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46]);
 
 impl ShellLinkHeader {
 
@@ -68,26 +69,22 @@ impl ShellLinkHeader {
             return Err(CorruptHeaderLength(header_len));
         }
 
-        let link_clsid = [
-            u32_from_input(&input[4..8]),
-            u32_from_input(&input[8..12]),
-            u32_from_input(&input[12..16]),
-            u32_from_input(&input[16..20]),
-        ];
+        let link_clsid = Guid::from_bytes(&input[4..20]);
 
         if link_clsid != LINK_CLSID {
             return Err(CorruptHeaderClsId(link_clsid));
         }
 
         let link_flags_bytes = u32_from_input(&input[20..24]);
-        let link_flags      = LinkFlags::from_bits(link_flags_bytes).ok_or(InvalidLinkFlags(link_flags_bytes))?;
+        // Unused1, Unused2 and bits above KeepLocalIDListForUNCTarget MUST be ignored
+        let link_flags      = LinkFlags::from_bits_truncate(link_flags_bytes);
 
         let file_attributes_bytes = u32_from_input(&input[24..28]);
-        let file_attributes = FileAttributes::from_bits(file_attributes_bytes).ok_or(InvalidFileAttributes(file_attributes_bytes))?;
+        let file_attributes = FileAttributes::from_bits_truncate(file_attributes_bytes);
 
-        let creation_time   = parse_tm(&input[28..36]);
-        let access_time     = parse_tm(&input[36..44]);
-        let write_time      = parse_tm(&input[44..52]);
+        let creation_time   = FileTime::from_bytes_nonzero(&input[28..36]);
+        let access_time     = FileTime::from_bytes_nonzero(&input[36..44]);
+        let write_time      = FileTime::from_bytes_nonzero(&input[44..52]);
 
         let file_size       = u32_from_input(&input[52..56]);
         let icon_index      = i32_from_input(&input[56..60]);
@@ -96,7 +93,7 @@ impl ShellLinkHeader {
         // NOTE: This is not in the Microsoft specification, however the HotKeyFlags may be set to 0
         // (possibly to indicate "no hotkey available").
 
-        let hot_key_flags = HotKeyFlags::try_from(&input[64..66]).map_err(|e |InvalidHotKeyFlags(e))?;
+        let hot_key_flags = HotKeyFlags::try_from(&input[64..66]).map_err(InvalidHotKeyFlags)?;
 
         // left over: 10 bytes (2 + 4 + 4) = 66 bytes header, 10 bytes padding = 76 bytes
 
@@ -114,24 +111,42 @@ impl ShellLinkHeader {
     }
 }
 
+/// Input **must** be 2 bytes large!
+#[inline(always)]
+pub(crate) fn u16_from_input(input: &[u8]) -> u16 {
+    assert!(input.len() == 2);
+
+    ((input[1] as u16) << 8) +
+    (input[0] as u16)
+}
+
+#[inline(always)]
+pub(crate) fn i16_from_input(input: &[u8]) -> i16 {
+    u16_from_input(input) as i16
+}
+
 /// Input **must** be 4 bytes large!
 #[inline(always)]
-fn u32_from_input(input: &[u8]) -> u32 {
+pub(crate) fn u32_from_input(input: &[u8]) -> u32 {
     assert!(input.len() == 4);
 
     ((input[3] as u32) << 24) +
     ((input[2] as u32) << 16) +
     ((input[1] as u32) << 8)  +
-    ((input[0] as u32) << 0)
+    (input[0] as u32)
 }
 
-fn i32_from_input(input: &[u8]) -> i32 {
-    assert!(input.len() == 4);
+/// Input **must** be 8 bytes large!
+#[inline(always)]
+pub(crate) fn u64_from_input(input: &[u8]) -> u64 {
+    assert!(input.len() == 8);
 
-    ((input[3] as i32) << 24) +
-    ((input[2] as i32) << 16) +
-    ((input[1] as i32) << 8)  +
-    ((input[0] as i32) << 0)
+    ((u32_from_input(&input[4..8]) as u64) << 32) +
+    (u32_from_input(&input[0..4]) as u64)
+}
+
+fn i32_from_input(input: &[u8]) -> i32 {
+    u32_from_input(input) as i32
 }
 
 /// A 32-bit unsigned integer that specifies the expected window state of an
@@ -277,90 +292,90 @@ bitflags! {
         /// The shell link is saved with an item ID list (IDList). If this bit is set, a
         /// LinkTargetIDList structure (section 2.2) MUST follow the ShellLinkHeader.
         /// If this bit is not set, this structure MUST NOT be present.
-        const HasLinkTargetIDList           = 0xFFFFFFFF >> 0;
+        const HasLinkTargetIDList           = 1 << 0;
         /// The shell link is saved with link information. If this bit is set, a LinkInfo
         /// structure (section 2.3) MUST be present. If this bit is not set, this structure
         /// MUST NOT be present.
-        const HasLinkInfo                   = 0xFFFFFFFF >> 1;
+        const HasLinkInfo                   = 1 << 1;
         /// The shell link is saved with a name string. If this bit is set, a
         /// NAME_STRING StringData structure (section 2.4) MUST be present. If
         /// this bit is not set, this structure MUST NOT be present.
-        const HasName                       = 0xFFFFFFFF >> 2;
+        const HasName                       = 1 << 2;
         /// The shell link is saved with a relative path string. If this bit is set, a
         /// RELATIVE_PATH StringData structure (section 2.4) MUST be present. If
         /// this bit is not set, this structure MUST NOT be present.
-        const HasRelativePath               = 0xFFFFFFFF >> 3;
+        const HasRelativePath               = 1 << 3;
         /// The shell link is saved with a working directory string. If this bit is set, a
         /// WORKING_DIR StringData structure (section 2.4) MUST be present. If
         /// this bit is not set, this structure MUST NOT be present.
-        const HasWorkingDir                 = 0xFFFFFFFF >> 4;
+        const HasWorkingDir                 = 1 << 4;
         /// The shell link is saved with command line arguments. If this bit is set, a
         /// COMMAND_LINE_ARGUMENTS StringData structure (section 2.4) MUST
         /// be present. If this bit is not set, this structure MUST NOT be present.
-        const HasArguments                  = 0xFFFFFFFF >> 5;
+        const HasArguments                  = 1 << 5;
         /// The shell link is saved with an icon location string. If this bit is set, an
         /// ICON_LOCATION StringData structure (section 2.4) MUST be present. If
         /// this bit is not set, this structure MUST NOT be present.
-        const HasIconLocation               = 0xFFFFFFFF >> 6;
+        const HasIconLocation               = 1 << 6;
         /// The shell link contains Unicode encoded strings. This bit SHOULD be set. If
         /// this bit is set, the StringData section contains Unicode-encoded strings;
         /// otherwise, it contains strings that are encoded using the system default
         /// code page.
-        const IsUnicode                     = 0xFFFFFFFF >> 7;
+        const IsUnicode                     = 1 << 7;
         /// The LinkInfo structure (section 2.3) is ignored.
-        const ForceNoLinkInfo               = 0xFFFFFFFF >> 8;
+        const ForceNoLinkInfo               = 1 << 8;
         /// The shell link is saved with an
         /// EnvironmentVariableDataBlock (section 2.5.4).
-        const HasExpString                  = 0xFFFFFFFF >> 9;
+        const HasExpString                  = 1 << 9;
 
         /// The target is run in a separate virtual machine when launching a link
         /// target that is a 16-bit application.
-        const RunInSeparateProcess          = 0xFFFFFFFF >> 11;
+        const RunInSeparateProcess          = 1 << 10;
         /// The shell link is saved with a DarwinDataBlock (section 2.5.3).
-        const HasDarwinID                   = 0xFFFFFFFF >> 12;
+        const HasDarwinID                   = 1 << 12;
         /// The application is run as a different user when the target of the shell link is
         /// activated.
-        const RunAsUser                     = 0xFFFFFFFF >> 13;
+        const RunAsUser                     = 1 << 13;
         /// The shell link is saved with an IconEnvironmentDataBlock (section 2.5.5).
-        const HasExpIcon                    = 0xFFFFFFFF >> 14;
+        const HasExpIcon                    = 1 << 14;
         /// The file system location is represented in the shell namespace when the
         /// path to an item is parsed into an IDList.
-        const NoPidlAlias                   = 0xFFFFFFFF >> 15;
+        const NoPidlAlias                   = 1 << 15;
 
         /// The shell link is saved with a ShimDataBlock (section 2.5.8).
-        const RunWithShimLayer              = 0xFFFFFFFF >> 17;
+        const RunWithShimLayer              = 1 << 17;
         /// The TrackerDataBlock (section 2.5.10) is ignored.
-        const ForceNoLinkTrack              = 0xFFFFFFFF >> 18;
+        const ForceNoLinkTrack              = 1 << 18;
         /// The shell link attempts to collect target properties and store them in the
         /// PropertyStoreDataBlock (section 2.5.7) when the link target is set.
-        const EnableTargetMetadata          = 0xFFFFFFFF >> 19;
+        const EnableTargetMetadata          = 1 << 19;
         /// The EnvironmentVariableDataBlock is ignored.
-        const DisableLinkPathTracking       = 0xFFFFFFFF >> 20;
+        const DisableLinkPathTracking       = 1 << 20;
         /// The SpecialFolderDataBlock (section 2.5.9) and the
         /// KnownFolderDataBlock (section 2.5.6) are ignored when loading the shell
         /// link. If this bit is set, these extra data blocks SHOULD NOT be saved when
         /// saving the shell link.
-        const DisableKnownFolderTracking    = 0xFFFFFFFF >> 21;
+        const DisableKnownFolderTracking    = 1 << 21;
         /// If the link has a KnownFolderDataBlock (section 2.5.6), the unaliased form
         /// of the known folder IDList SHOULD be used when translating the target
         /// IDList at the time that the link is loaded.
-        const DisableKnownFolderAlias       = 0xFFFFFFFF >> 22;
+        const DisableKnownFolderAlias       = 1 << 22;
         /// Creating a link that references another link is enabled. Otherwise,
         /// specifying a link as the target IDList SHOULD NOT be allowed.
-        const AllowLinkToLink               = 0xFFFFFFFF >> 23;
+        const AllowLinkToLink               = 1 << 23;
         /// When saving a link for which the target IDList is under a known folder,
         /// either the unaliased form of that known folder or the target IDList SHOULD
         /// be used.
-        const UnaliasOnSave                 = 0xFFFFFFFF >> 24;
+        const UnaliasOnSave                 = 1 << 24;
         /// The target IDList SHOULD NOT be stored; instead, the path specified in the
         /// EnvironmentVariableDataBlock (section 2.5.4) SHOULD be used to refer to
         /// the target.
-        const PreferEnvironmentPath         = 0xFFFFFFFF >> 25;
+        const PreferEnvironmentPath         = 1 << 25;
         /// When the target is a UNC name that refers to a location on a local
         /// machine, the local path IDList in the
         /// PropertyStoreDataBlock (section 2.5.7) SHOULD be stored, so it can be
         /// used when the link is loaded on the local machine.
-        const KeepLocalIDListForUNCTarget   = 0xFFFFFFFF >> 26;
+        const KeepLocalIDListForUNCTarget   = 1 << 26;
     }
 }
 
@@ -434,7 +449,7 @@ impl HotKey {
     pub fn try_from(input: u8) -> Option<Self> {
         HOTKEY_MAP.iter()
         .find(|x| x.1 == input)
-        .and_then(|out| Some(out.0))
+        .map(|out| out.0)
     }
 }
 
@@ -442,7 +457,7 @@ impl From<HotKey> for u8 {
     fn from(input: HotKey) -> u8 {
         HOTKEY_MAP.iter()
         .find(|x| x.0 == input)
-        .and_then(|out| Some(out.1))
+        .map(|out| out.1)
         .unwrap()
     }
 }
@@ -484,125 +499,42 @@ impl From<HotKeyModifier> for u8 {
 
 bitflags! {
     pub struct FileAttributes: u32 {
-        const ReadOnly                      = 0xFFFFFFFF >> 0;
-        const Hidden                        = 0xFFFFFFFF >> 1;
-        const System                        = 0xFFFFFFFF >> 2;
-
-        const Directory                     = 0xFFFFFFFF >> 4;
-        const Archive                       = 0xFFFFFFFF >> 5;
-
-        const Normal                        = 0xFFFFFFFF >> 7;
-        const Temporary                     = 0xFFFFFFFF >> 8;
-        const Sparse                        = 0xFFFFFFFF >> 9;
-        const ReparsePoint                  = 0xFFFFFFFF >> 10;
-        const Compressed                    = 0xFFFFFFFF >> 11;
-        const Offline                       = 0xFFFFFFFF >> 12;
-        const NotContentIndexed             = 0xFFFFFFFF >> 13;
-        const Encrypted                     = 0xFFFFFFFF >> 14;
+        const ReadOnly                      = 1 << 0;
+        const Hidden                        = 1 << 1;
+        const System                        = 1 << 2;
+
+        const Directory                     = 1 << 4;
+        const Archive                       = 1 << 5;
+
+        const Normal                        = 1 << 7;
+        const Temporary                     = 1 << 8;
+        const Sparse                        = 1 << 9;
+        const ReparsePoint                  = 1 << 10;
+        const Compressed                    = 1 << 11;
+        const Offline                       = 1 << 12;
+        const NotContentIndexed             = 1 << 13;
+        const Encrypted                     = 1 << 14;
+        // attributes that are not listed in [MS-SHLLINK], from winnt.h
+        const IntegrityStream               = 1 << 15;
+        const NoScrubData                   = 1 << 17;
+        const RecallOnOpen                  = 1 << 18;
+        const Pinned                        = 1 << 19;
+        const Unpinned                      = 1 << 20;
+        const RecallOnDataAccess            = 1 << 22;
     }
 }
 
-/// Parses a FILETIME structure in UTC
-fn parse_tm(input: &[u8]) -> Option<Tm> {
-    assert!(input.len() == 8);
-
-    // The FILETIME structure represents the number of 100-nanosecond intervals since January
-    // 1, 1601. The structure consists of two 32-bit values that combine to form a single 64-bit value.
-
-    if input.iter().all(|byte| *byte == 0) {
-        return None;
-    }
-
-    let low_bit = u32_from_input(&input[0..4]);
-    let high_bit = u32_from_input(&input[4..8]);
-    let input_tm_nanoseconds = ((high_bit as u64) << 32) + (low_bit as u64);
-
-    const SECOND: u64   = 10_000_000;
-    const MINUTE: u64   = 60 * SECOND;
-    const HOUR: u64     = 60 * MINUTE;
-    const DAY: u64      = 24 * HOUR;
-
-    const START_YEAR_WINDOWS: u64 = 1601;
-    const START_YEAR_UNIX: u64 = 1900;
-
-    // Month length on normal year + leap year
-    const MONTHS_LEN: [(u64, u64);12] = [
-        (31, 31), // Jan
-        (28, 29), // Feb
-        (31, 31), // Mar
-        (30, 30), // Apr
-        (31, 31), // May
-        (30, 30), // Jun
-        (31, 31), // Jul
-        (31, 31), // Aug
-        (30, 30), // Sep
-        (31, 31), // Oct
-        (30, 30), // Nov
-        (31, 31), // Dec
-    ];
-
-    #[inline]
-    fn is_year_leap_year(year: u64) -> bool {
-        ((year & 3) == 0 && ((year % 25) != 0 || (year & 15) == 0))
-    }
-
-    let days_win_unix_diff: u64 = (START_YEAR_WINDOWS..START_YEAR_UNIX)
-        .map(|year| if is_year_leap_year(year) { 366 } else { 365 })
-        .sum();
-
-    let nanoseconds_diff = days_win_unix_diff * DAY;
-
-    let nanoseconds_since_1990 = input_tm_nanoseconds.saturating_sub(nanoseconds_diff);
-
-    let nanos_remaining = nanoseconds_since_1990 % SECOND;
-    let sec_remaining = (nanoseconds_since_1990 % MINUTE) / SECOND;
-    let min_remaining = (nanoseconds_since_1990 % HOUR) / MINUTE;
-    let hours_remaining = (nanoseconds_since_1990 % DAY) / HOUR;
-
-    let input_in_days = nanoseconds_since_1990 / DAY;
-
-    // 1990 to 1st january of the current year in days
-    let mut day_first_january_this_year = 0;
-    let mut current_year = START_YEAR_UNIX;
-    while day_first_january_this_year < input_in_days {
-        let added_day = if is_year_leap_year(current_year) { 366 } else { 365 };
-        if day_first_january_this_year + added_day > input_in_days {
-            break;
-        }
-
-        day_first_january_this_year += added_day;
-        current_year += 1;
-    }
-
-    let day_in_year = input_in_days - day_first_january_this_year;
-
-    let mut current_month = 0;
-    let mut current_day_in_year = 0;
-    let current_year_is_leap_year = is_year_leap_year(current_year);
-
-    while current_day_in_year < day_in_year {
-        let new_month_len = if current_year_is_leap_year { MONTHS_LEN[current_month].1 } else { MONTHS_LEN[current_month].0 };
-        if current_day_in_year + new_month_len > day_in_year {
-            break;
-        }
-        current_day_in_year += new_month_len;
-        current_month += 1;
-    }
-
-    let day_in_month = day_in_year - current_day_in_year;
-
-    Some(Tm {
-        tm_nsec: nanos_remaining as i32,
-        tm_sec: sec_remaining as i32,
-        tm_min: min_remaining as i32,
-        tm_hour: hours_remaining as i32,
-        tm_mday: day_in_month as i32,
-        tm_mon: (current_month + 1) as i32,
-        tm_year: current_year as i32,
-
-        tm_wday: (day_in_year % 7) as i32,
-        tm_yday: (day_in_year) as i32,
-        tm_isdst: -1,
-        tm_utcoff: 0,
-    })
-}
\ No newline at end of file
+#[test]
+fn parse_header_with_unknown_bits() {
+    let mut header = vec![0; HEADER_LEN];
+    header[0] = 0x4C;
+    header[4..20].copy_from_slice(&[0x01, 0x14, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46]);
+    // HasLinkInfo and the Unused1 and Unused2 bits
+    header[20..24].copy_from_slice(&[0x02, 0x08, 0x01, 0x00]);
+    // Archive and FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS of a OneDrive file, and an undefined bit
+    header[24..28].copy_from_slice(&[0x20, 0x00, 0x40, 0x80]);
+
+    let header = ShellLinkHeader::try_from(&header).unwrap();
+    assert_eq!(header.link_flags, LinkFlags::HasLinkInfo);
+    assert_eq!(header.file_attributes, FileAttributes::Archive | FileAttributes::RecallOnDataAccess);
+}
@@ -0,0 +1,236 @@
+//! Security identifiers ([MS-DTYP] section 2.4.2), in binary form (2.4.2.2) and string
+//! form (2.4.2.1, e.g. `S-1-5-21-1004336348-1177238915-682003330-1001`).
+
+use std::fmt;
+use std::str::FromStr;
+use error::SidParseError;
+use shell_link_header::u32_from_input;
+
+/// Revision of all SIDs in use
+pub const SID_REVISION: u8 = 1;
+/// Maximum number of sub-authorities of a SID
+const SID_MAX_SUB_AUTHORITIES: usize = 15;
+/// Length of the Revision, SubAuthorityCount and IdentifierAuthority fields
+const SID_HEADER_LEN: usize = 8;
+
+/// A security identifier, identifying a user, group or computer account
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Sid {
+    /// An 8-bit unsigned integer that specifies the revision level of the SID. This value MUST be set to 0x01.
+    pub revision: u8,
+    /// A 48-bit value that indicates the authority under which the SID was created.
+    pub identifier_authority: u64,
+    /// Up to 15 32-bit values that uniquely identify a principal relative to the IdentifierAuthority.
+    /// The last sub-authority of an account SID is its relative identifier (RID).
+    pub sub_authorities: Vec<u32>,
+}
+
+/// Names of the identifier authorities ([MS-DTYP] section 2.4.1.1)
+const AUTHORITY_NAMES: [(u64, &str); 7] = [
+    (0, "Null Authority"),
+    (1, "World Authority"),
+    (2, "Local Authority"),
+    (3, "Creator Authority"),
+    (5, "NT Authority"),
+    (15, "Resource Manager Authority"),
+    (16, "Mandatory Label Authority"),
+];
+
+/// Names of well-known SIDs ([MS-DTYP] section 2.4.2.4)
+const WELL_KNOWN_SIDS: [(&str, &str); 26] = [
+    ("S-1-0-0", "Nobody"),
+    ("S-1-1-0", "Everyone"),
+    ("S-1-2-0", "Local"),
+    ("S-1-2-1", "Console Logon"),
+    ("S-1-3-0", "Creator Owner"),
+    ("S-1-3-1", "Creator Group"),
+    ("S-1-5-1", "Dialup"),
+    ("S-1-5-2", "Network"),
+    ("S-1-5-3", "Batch"),
+    ("S-1-5-4", "Interactive"),
+    ("S-1-5-6", "Service"),
+    ("S-1-5-7", "Anonymous"),
+    ("S-1-5-11", "Authenticated Users"),
+    ("S-1-5-18", "LocalSystem"),
+    ("S-1-5-19", "LocalService"),
+    ("S-1-5-20", "NetworkService"),
+    ("S-1-5-32-544", "Administrators"),
+    ("S-1-5-32-545", "Users"),
+    ("S-1-5-32-546", "Guests"),
+    ("S-1-5-32-547", "Power Users"),
+    ("S-1-5-32-555", "Remote Desktop Users"),
+    ("S-1-16-0", "Untrusted Mandatory Level"),
+    ("S-1-16-4096", "Low Mandatory Level"),
+    ("S-1-16-8192", "Medium Mandatory Level"),
+    ("S-1-16-12288", "High Mandatory Level"),
+    ("S-1-16-16384", "System Mandatory Level"),
+];
+
+/// Names of well-known relative identifiers of domain (or local machine) accounts, S-1-5-21-x-y-z-RID
+const WELL_KNOWN_RIDS: [(u32, &str); 9] = [
+    (500, "Administrator"),
+    (501, "Guest"),
+    (502, "KRBTGT"),
+    (503, "DefaultAccount"),
+    (512, "Domain Admins"),
+    (513, "Domain Users"),
+    (514, "Domain Guests"),
+    (515, "Domain Computers"),
+    (516, "Domain Controllers"),
+];
+
+/// Sub-authority of domain and machine account SIDs (SECURITY_NT_NON_UNIQUE)
+const NT_NON_UNIQUE: u32 = 21;
+const NT_AUTHORITY: u64 = 5;
+
+impl Sid {
+    /// Parses a SID in binary form. Input may be larger than the SID, see `size()`.
+    pub fn try_from(input: &[u8]) -> Result<Self, SidParseError> {
+        use self::SidParseError::*;
+
+        if input.len() < SID_HEADER_LEN {
+            return Err(InvalidSidLength(input.len()));
+        }
+
+        let revision = input[0];
+        if revision != SID_REVISION {
+            return Err(InvalidSidRevision(revision));
+        }
+
+        let sub_authority_count = input[1] as usize;
+        if sub_authority_count > SID_MAX_SUB_AUTHORITIES {
+            return Err(InvalidSubAuthorityCount(sub_authority_count));
+        }
+        if input.len() < SID_HEADER_LEN + sub_authority_count * 4 {
+            return Err(InvalidSidLength(input.len()));
+        }
+
+        // IdentifierAuthority is the only big-endian field
+        let identifier_authority = input[2..8].iter().fold(0, |acc, byte| (acc << 8) | *byte as u64);
+        let sub_authorities = input[SID_HEADER_LEN..SID_HEADER_LEN + sub_authority_count * 4]
+            .chunks(4)
+            .map(u32_from_input)
+            .collect();
+
+        Ok(Self {
+            revision,
+            identifier_authority,
+            sub_authorities,
+        })
+    }
+
+    /// Size of the binary form of the SID, in bytes
+    pub fn size(&self) -> usize {
+        SID_HEADER_LEN + self.sub_authorities.len() * 4
+    }
+
+    /// Relative identifier of the account, the last sub-authority
+    pub fn rid(&self) -> Option<u32> {
+        self.sub_authorities.last().cloned()
+    }
+
+    /// Name of the identifier authority, e.g. "NT Authority" for S-1-5-...
+    pub fn authority_name(&self) -> Option<&'static str> {
+        AUTHORITY_NAMES.iter().find(|(authority, _)| *authority == self.identifier_authority).map(|out| out.1)
+    }
+
+    /// Name of a well-known SID (e.g. "LocalSystem" for S-1-5-18) or of a well-known account of a
+    /// domain or machine (e.g. "Administrator" for S-1-5-21-x-y-z-500)
+    pub fn well_known_name(&self) -> Option<&'static str> {
+        let string = self.to_string();
+        if let Some(out) = WELL_KNOWN_SIDS.iter().find(|(sid, _)| *sid == string) {
+            return Some(out.1);
+        }
+
+        let is_domain_account = self.identifier_authority == NT_AUTHORITY
+            && self.sub_authorities.len() == 5
+            && self.sub_authorities[0] == NT_NON_UNIQUE;
+        if !is_domain_account {
+            return None;
+        }
+        let rid = self.rid()?;
+        WELL_KNOWN_RIDS.iter().find(|(r, _)| *r == rid).map(|out| out.1)
+    }
+}
+
+impl fmt::Display for Sid {
+    /// Formats the SID in string form. Authorities that don't fit into 32 bits are written in hex.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "S-{}-", self.revision)?;
+        if self.identifier_authority >> 32 == 0 {
+            write!(f, "{}", self.identifier_authority)?;
+        } else {
+            write!(f, "0x{:012X}", self.identifier_authority)?;
+        }
+        for sub_authority in &self.sub_authorities {
+            write!(f, "-{}", sub_authority)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Sid {
+    type Err = SidParseError;
+
+    /// Parses a SID in string form, `S-1-` followed by the authority (decimal or `0x` hex) and
+    /// the sub-authorities
+    fn from_str(input: &str) -> Result<Self, SidParseError> {
+        use self::SidParseError::*;
+
+        let mut parts = input.split('-');
+        if !matches!(parts.next(), Some(s) if s.eq_ignore_ascii_case("S")) {
+            return Err(InvalidSidString(0));
+        }
+
+        let revision = parts.next().and_then(|s| s.parse::<u8>().ok()).ok_or(InvalidSidString(1))?;
+        if revision != SID_REVISION {
+            return Err(InvalidSidRevision(revision));
+        }
+
+        let identifier_authority = parts.next().and_then(|s| {
+            if s.starts_with("0x") || s.starts_with("0X") {
+                u64::from_str_radix(&s[2..], 16).ok()
+            } else {
+                s.parse::<u64>().ok()
+            }
+        }).filter(|authority| authority >> 48 == 0).ok_or(InvalidSidString(2))?;
+
+        let sub_authorities = parts.enumerate()
+            .map(|(i, s)| s.parse::<u32>().map_err(|_| InvalidSidString(i + 3)))
+            .collect::<Result<Vec<u32>, SidParseError>>()?;
+        if sub_authorities.len() > SID_MAX_SUB_AUTHORITIES {
+            return Err(InvalidSubAuthorityCount(sub_authorities.len()));
+        }
+
+        Ok(Self {
+            revision,
+            identifier_authority,
+            sub_authorities,
+        })
+    }
+}
+
+#[test]
+fn parse_sid() {
+    use error::SidParseError::*;
+
+    let bytes = [
+        0x01, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x15, 0x00, 0x00, 0x00,
+        0xDC, 0xF4, 0xDC, 0x3B, 0x83, 0x3D, 0x2B, 0x46, 0x82, 0x8B, 0xA6, 0x28, 0xE9, 0x03, 0x00, 0x00,
+    ];
+    let sid = Sid::try_from(&bytes).unwrap();
+    assert_eq!(sid.to_string(), "S-1-5-21-1004336348-1177238915-682003330-1001");
+    assert_eq!(sid.size(), bytes.len());
+    assert_eq!(sid.rid(), Some(1001));
+    assert_eq!(sid.authority_name(), Some("NT Authority"));
+    assert_eq!(sid.well_known_name(), None);
+    assert_eq!("S-1-5-21-1004336348-1177238915-682003330-1001".parse(), Ok(sid));
+
+    assert_eq!("S-1-5-18".parse::<Sid>().unwrap().well_known_name(), Some("LocalSystem"));
+    assert_eq!("S-1-5-21-1-2-3-500".parse::<Sid>().unwrap().well_known_name(), Some("Administrator"));
+    assert_eq!("S-1-0x100000000000-1".parse::<Sid>().unwrap().to_string(), "S-1-0x100000000000-1");
+
+    assert_eq!(Sid::try_from(&bytes[..20]), Err(InvalidSidLength(20)));
+    assert_eq!("S-2-5-18".parse::<Sid>(), Err(InvalidSidRevision(2)));
+    assert_eq!("S-1-5-x".parse::<Sid>(), Err(InvalidSidString(3)));
+}
@@ -0,0 +1,110 @@
+//! Decoding of the distributed link tracking identifiers stored in a TrackerDataBlock (section 2.5.10).
+//! Object identifiers created by the Link Tracking service are version 1 UUIDs (RFC 4122), which
+//! embed the creation time and the MAC address of the machine that created them.
+
+use std::fmt;
+use filetime::FileTime;
+use guid::Guid;
+use TrackerDataBlock;
+
+/// Ticks (100-nanosecond intervals) between the start of the Gregorian calendar (1582-10-15),
+/// which is the epoch of UUID timestamps, and the FILETIME epoch (1601-01-01)
+const UUID_EPOCH_TICKS: u64 = 5_748_192_000_000_000;
+
+/// Version number of time-based UUIDs
+const UUID_VERSION_TIME_BASED: u8 = 1;
+
+/// A DROID ("distributed link tracking ID"): the volume and the object identifier of a file
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Droid {
+    /// Identifier of the volume the file resides on
+    pub volume_id: Guid,
+    /// Identifier of the file on the volume (the NTFS object ID)
+    pub object_id: Guid,
+}
+
+/// The fields of a version 1 (time-based) UUID
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct TimeBasedUuid {
+    /// Time at which the UUID was generated
+    pub timestamp: FileTime,
+    /// 14-bit clock sequence, used to avoid duplicates if the clock is set back
+    pub clock_sequence: u16,
+    /// Node identifier, the MAC address of a network card of the generating machine
+    pub mac_address: MacAddress,
+}
+
+/// A 48-bit IEEE 802 MAC address
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct MacAddress(pub [u8;6]);
+
+impl Droid {
+    /// Parses a DROID from two GUIDs in packet representation. Input **must** be 32 bytes large!
+    pub fn from_bytes(input: &[u8]) -> Self {
+        assert!(input.len() == 32);
+        Droid {
+            volume_id: Guid::from_bytes(&input[0..16]),
+            object_id: Guid::from_bytes(&input[16..32]),
+        }
+    }
+
+    /// Decodes the object identifier if it is a version 1 UUID
+    pub fn object_uuid(&self) -> Option<TimeBasedUuid> {
+        TimeBasedUuid::try_from(&self.object_id)
+    }
+}
+
+impl TimeBasedUuid {
+    /// Returns `None` if the GUID is not a RFC 4122 version 1 UUID or if its timestamp is
+    /// before 1601 (which cannot be represented as a FILETIME)
+    pub fn try_from(guid: &Guid) -> Option<Self> {
+        let is_rfc_4122_variant = guid.data4[0] & 0xC0 == 0x80;
+        if guid.version() != UUID_VERSION_TIME_BASED || !is_rfc_4122_variant {
+            return None;
+        }
+
+        let ticks = (((guid.data3 & 0x0FFF) as u64) << 48) | ((guid.data2 as u64) << 32) | guid.data1 as u64;
+        let mut mac_address = [0; 6];
+        mac_address.copy_from_slice(&guid.data4[2..8]);
+
+        Some(TimeBasedUuid {
+            timestamp: FileTime::new(ticks.checked_sub(UUID_EPOCH_TICKS)?),
+            clock_sequence: (((guid.data4[0] & 0x3F) as u16) << 8) | guid.data4[1] as u16,
+            mac_address: MacAddress(mac_address),
+        })
+    }
+}
+
+impl fmt::Display for MacAddress {
+    /// Formats the address as six colon-separated hex bytes, e.g. `08:00:27:5a:1c:3e`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}", a, b, c, d, e, g)
+    }
+}
+
+impl TrackerDataBlock {
+    /// Decodes the object identifier of the link target at the time the link was created. The MAC
+    /// address identifies the machine on which the target was created.
+    pub fn birth_object_uuid(&self) -> Option<TimeBasedUuid> {
+        self.droid_birth.object_uuid()
+    }
+
+    /// Decodes the object identifier of the link target at the time the link was last updated
+    pub fn object_uuid(&self) -> Option<TimeBasedUuid> {
+        self.droid.object_uuid()
+    }
+}
+
+#[test]
+fn decode_time_based_uuid() {
+    let guid: Guid = "{CD9F71BA-4A13-11E9-9234-0800275A1C3E}".parse().unwrap();
+    let uuid = TimeBasedUuid::try_from(&guid).unwrap();
+    assert_eq!(uuid.timestamp.to_string(), "2019-03-19T06:54:12.6123450Z");
+    assert_eq!(uuid.clock_sequence, 0x1234);
+    assert_eq!(uuid.mac_address.to_string(), "08:00:27:5a:1c:3e");
+
+    // version 4 (random) UUIDs carry no timestamp
+    let random: Guid = "{94C3D7A1-6B0E-4F2D-9E2A-5C7B1D3E8F10}".parse().unwrap();
+    assert_eq!(TimeBasedUuid::try_from(&random), None);
+}